@@ -1,25 +1,56 @@
-use std::ffi::CStr;
-use windows::Win32::Foundation::GetLastError;
+use crate::error::{DumperError, ERROR_ACCESS_DENIED, ERROR_PRIVILEGE_NOT_HELD};
+use crate::report::acpi_content_hash;
+use std::collections::HashSet;
+use windows::core::{w, BSTR, PCWSTR, VARIANT};
+use windows::Win32::Foundation::{
+    GetLastError, SetLastError, ERROR_MORE_DATA, ERROR_NOT_FOUND, ERROR_NO_MORE_ITEMS, HWND,
+    RPC_E_CHANGED_MODE, WIN32_ERROR,
+};
+use windows::Win32::Security::PSECURITY_DESCRIPTOR;
+use windows::Win32::System::Com::{
+    CoCreateInstance, CoInitializeEx, CoInitializeSecurity, CoUninitialize, CLSCTX_INPROC_SERVER,
+    COINIT_APARTMENTTHREADED, EOAC_NONE, RPC_C_AUTHN_LEVEL_DEFAULT, RPC_C_IMP_LEVEL_IMPERSONATE,
+    SAFEARRAY,
+};
+use windows::Win32::System::Ole::{
+    SafeArrayAccessData, SafeArrayGetLBound, SafeArrayGetUBound, SafeArrayUnaccessData,
+};
 use windows::Win32::System::Registry::{
     RegCloseKey, RegEnumKeyExA, RegEnumValueA, RegOpenKeyExA, RegQueryValueExA, HKEY,
-    HKEY_LOCAL_MACHINE, KEY_READ, REG_BINARY,
+    HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE, KEY_READ, REG_BINARY,
 };
 use windows::Win32::System::SystemInformation::{
-    EnumSystemFirmwareTables, GetSystemFirmwareTable, FIRMWARE_TABLE_PROVIDER,
+    EnumSystemFirmwareTables, GetLocalTime, GetSystemFirmwareTable, FIRMWARE_TABLE_PROVIDER,
 };
+use windows::Win32::System::Variant::{VARENUM, VT_ARRAY, VT_BOOL, VT_I4, VT_UI1, VT_UI4};
+use windows::Win32::System::Wmi::{
+    IWbemClassObject, IWbemLocator, WbemLocator, WBEM_FLAG_FORWARD_ONLY,
+    WBEM_FLAG_RETURN_IMMEDIATELY, WBEM_GENERIC_FLAG_TYPE, WBEM_INFINITE,
+};
+use windows::Win32::UI::Shell::ShellExecuteW;
+use windows::Win32::UI::WindowsAndMessaging::SW_SHOWNORMAL;
 
 /// ACPI firmware table provider signature ('ACPI').
 pub const SIG_ACPI: u32 = u32::from_be_bytes(*b"ACPI"); // 0x41435049
 /// SMBIOS firmware table provider signature ('RSMB').
 pub const SIG_RSMB: u32 = u32::from_be_bytes(*b"RSMB"); // 0x52534D42
 
+/// How many times `get_system_firmware_table` re-sizes its buffer before giving up.
+const FIRMWARE_TABLE_READ_ATTEMPTS: usize = 3;
+
 /// Information about an ACPI table discovered in the system.
 ///
 /// This structure holds metadata for identifying and retrieving the actual table data.
 #[derive(Debug, Clone, PartialEq)]
 pub struct AcpiTableInfo {
     /// The real 4-byte signature read from the binary data (e.g., "SSDT").
+    ///
+    /// Bytes that are not printable ASCII are shown as `.`; see `signature_valid`.
     pub signature: String,
+    /// Whether every signature byte was printable ASCII.
+    pub signature_valid: bool,
+    /// The raw signature bytes, used to read the table back through the firmware API.
+    pub signature_bytes: [u8; 4],
     /// The signature as it appears in the registry key (e.g., "SSD1").
     pub registry_sig: String,
     /// The OEM ID from the ACPI header.
@@ -34,23 +65,130 @@ pub struct AcpiTableInfo {
     pub physical_address: Option<u64>,
 }
 
-/// Helper to read the real signature (first 4 bytes) from table binary data at a given registry path.
+/// A 4-byte table signature decoded for display.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableSignature {
+    /// The raw signature bytes, as used to look the table up through the firmware API.
+    pub bytes: [u8; 4],
+    /// The signature as 4 characters, with every byte that is not printable ASCII shown as `.`.
+    pub text: String,
+    /// Whether every byte was printable ASCII.
+    pub valid: bool,
+}
+
+/// Decodes a 4-byte table signature into a displayable string.
 ///
 /// # Arguments
-/// * `path` - The registry path string where the table data is stored.
+/// * `bytes` - The signature bytes, in table order.
 ///
 /// # Returns
-/// An `Option<String>` containing the 4-character signature if valid UTF-8, otherwise `None`.
-fn read_real_signature(path: &str) -> Option<String> {
-    if let Ok(data) = get_acpi_table_by_path(path) {
-        if data.len() >= 4 {
-            let sig_bytes = &data[0..4];
-            if let Ok(s) = std::str::from_utf8(sig_bytes) {
-                return Some(s.to_string());
-            }
+/// The signature, always 4 characters long, and whether it was valid.
+pub fn decode_table_signature(bytes: [u8; 4]) -> TableSignature {
+    let printable = |b: u8| b.is_ascii_graphic() || b == b' ';
+    TableSignature {
+        bytes,
+        text: bytes
+            .iter()
+            .map(|&b| if printable(b) { b as char } else { '.' })
+            .collect(),
+        valid: bytes.iter().all(|&b| printable(b)),
+    }
+}
+
+impl AcpiTableInfo {
+    /// Returns the ID that reads this table through the firmware table API.
+    ///
+    /// This uses the raw signature bytes rather than [`AcpiTableInfo::signature`], so tables
+    /// whose signature is not printable ASCII can still be read.
+    pub fn firmware_table_id(&self) -> u32 {
+        u32::from_le_bytes(self.signature_bytes)
+    }
+
+    /// Returns where [`get_acpi_table`] reads this table from.
+    ///
+    /// A table with a registry path is read from that key; any other goes through the
+    /// firmware table API by its raw signature bytes.
+    pub fn location(&self) -> AcpiTableLocation<'_> {
+        match &self.registry_path {
+            Some(path) => AcpiTableLocation::Registry(path),
+            None => AcpiTableLocation::Firmware(self.firmware_table_id()),
         }
     }
-    None
+
+    /// Returns where the table was discovered.
+    pub fn source(&self) -> AcpiSource {
+        if self.registry_path.is_some() {
+            AcpiSource::Registry
+        } else {
+            AcpiSource::Api
+        }
+    }
+}
+
+/// Where a single ACPI table is read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AcpiTableLocation<'a> {
+    /// The registry key under `HKEY_LOCAL_MACHINE` holding the table.
+    Registry(&'a str),
+    /// The firmware table API, by table ID.
+    Firmware(u32),
+}
+
+/// Where ACPI tables are discovered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AcpiSource {
+    /// `HKLM\HARDWARE\ACPI`, which lists every SSDT separately.
+    Registry,
+    /// `EnumSystemFirmwareTables`, which also reaches tables such as UEFI and RSDP.
+    Api,
+    /// The registry, plus every API table whose contents are not already listed.
+    #[default]
+    Both,
+}
+
+impl AcpiSource {
+    /// All sources, in the order they are offered in the UI.
+    pub const ALL: [AcpiSource; 3] = [AcpiSource::Both, AcpiSource::Registry, AcpiSource::Api];
+
+    /// Returns a short label for the UI.
+    pub fn label(self) -> &'static str {
+        match self {
+            AcpiSource::Registry => "Registry",
+            AcpiSource::Api => "Firmware API",
+            AcpiSource::Both => "Registry + API",
+        }
+    }
+
+    /// Returns the stable key used to persist the source and on the command line.
+    pub fn key(self) -> &'static str {
+        match self {
+            AcpiSource::Registry => "registry",
+            AcpiSource::Api => "api",
+            AcpiSource::Both => "both",
+        }
+    }
+
+    /// Parses a key produced by [`AcpiSource::key`].
+    pub fn from_key(key: &str) -> Option<AcpiSource> {
+        AcpiSource::ALL.into_iter().find(|s| s.key() == key)
+    }
+}
+
+/// Helper to read the real signature (first 4 bytes) from table binary data at a given registry path.
+///
+/// The content hash is taken from the same read, so deduplicating against the API tables
+/// does not read every registry table a second time.
+///
+/// # Arguments
+/// * `path` - The registry path string where the table data is stored.
+///
+/// # Returns
+/// The decoded signature and the content hash, or `None` if the table could not be read or
+/// is shorter than 4 bytes.
+fn read_real_signature(path: &str) -> Option<(TableSignature, u64)> {
+    let data = get_acpi_table_by_path(path).ok()?;
+    let bytes: [u8; 4] = data.get(0..4)?.try_into().ok()?;
+    Some((decode_table_signature(bytes), acpi_content_hash(&data)))
 }
 
 /// Enumerates ACPI tables by traversing the Windows Registry (`HKLM\HARDWARE\ACPI`).
@@ -59,225 +197,194 @@ fn read_real_signature(path: &str) -> Option<String> {
 /// `EnumSystemFirmwareTables` API might not return as distinct entries.
 ///
 /// # Returns
-/// A `Result` containing a vector of `AcpiTableInfo` on success, or an error string on failure.
-pub fn enum_acpi_tables_registry() -> Result<Vec<AcpiTableInfo>, String> {
+/// A `Result` containing each table with the hash of its contents (`None` if the table
+/// could not be read), or an error on failure.
+pub fn enum_acpi_tables_registry() -> Result<Vec<(AcpiTableInfo, Option<u64>)>, DumperError> {
     let mut tables = Vec::new();
     let root_path = "HARDWARE\\ACPI\0";
 
     unsafe {
         let mut h_root = HKEY::default();
-        if RegOpenKeyExA(
+        let status = RegOpenKeyExA(
             HKEY_LOCAL_MACHINE,
             windows::core::PCSTR(root_path.as_ptr()),
             0,
             KEY_READ,
             &mut h_root,
-        )
-        .is_err()
-        {
-            return Err(
-                "Failed to open HKLM\\HARDWARE\\ACPI. Verify the tool is running as Administrator."
-                    .into(),
+        );
+        if status.is_err() {
+            log::warn!(
+                "RegOpenKeyExA(HKLM\\HARDWARE\\ACPI) failed: error {}",
+                status.0
             );
+            return Err(match status.0 {
+                ERROR_ACCESS_DENIED | ERROR_PRIVILEGE_NOT_HELD => DumperError::NotAdmin,
+                _ => DumperError::KeyOpenFailed("HARDWARE\\ACPI".to_string()),
+            });
         }
+        let root = RegKey(h_root);
 
-        let mut sig_idx = 0;
-        loop {
-            let mut sig_name = [0u8; 256];
-            let mut sig_name_len = sig_name.len() as u32;
-            if RegEnumKeyExA(
-                h_root,
-                sig_idx,
-                windows::core::PSTR(sig_name.as_mut_ptr()),
-                &mut sig_name_len,
-                None,
-                windows::core::PSTR(std::ptr::null_mut()),
-                None,
-                None,
-            )
-            .is_err()
-            {
-                break;
-            }
-            let reg_sig_str = CStr::from_ptr(sig_name.as_ptr() as *const i8)
-                .to_string_lossy()
-                .into_owned();
-
-            // Open Signature Key
-            let mut h_sig = HKEY::default();
-            if RegOpenKeyExA(
-                h_root,
-                windows::core::PCSTR(sig_name.as_ptr()),
-                0,
-                KEY_READ,
-                &mut h_sig,
-            )
-            .is_ok()
-            {
-                let mut oem_idx = 0;
-                loop {
-                    let mut oem_name = [0u8; 256];
-                    let mut oem_name_len = oem_name.len() as u32;
-                    if RegEnumKeyExA(
-                        h_sig,
-                        oem_idx,
-                        windows::core::PSTR(oem_name.as_mut_ptr()),
-                        &mut oem_name_len,
-                        None,
-                        windows::core::PSTR(std::ptr::null_mut()),
-                        None,
-                        None,
-                    )
-                    .is_err()
-                    {
-                        break;
-                    }
-                    let oem_str = CStr::from_ptr(oem_name.as_ptr() as *const i8)
-                        .to_string_lossy()
-                        .into_owned();
-
-                    // Open OEM Key
-                    let mut h_oem = HKEY::default();
-                    if RegOpenKeyExA(
-                        h_sig,
-                        windows::core::PCSTR(oem_name.as_ptr()),
-                        0,
-                        KEY_READ,
-                        &mut h_oem,
-                    )
-                    .is_ok()
-                    {
-                        let mut tab_idx = 0;
-                        loop {
-                            let mut tab_name = [0u8; 256];
-                            let mut tab_name_len = tab_name.len() as u32;
-                            if RegEnumKeyExA(
-                                h_oem,
-                                tab_idx,
-                                windows::core::PSTR(tab_name.as_mut_ptr()),
-                                &mut tab_name_len,
-                                None,
-                                windows::core::PSTR(std::ptr::null_mut()),
-                                None,
-                                None,
-                            )
-                            .is_err()
-                            {
-                                break;
-                            }
-                            let tab_str = CStr::from_ptr(tab_name.as_ptr() as *const i8)
-                                .to_string_lossy()
-                                .into_owned();
-
-                            // Open Table ID Key
-                            let mut h_tab = HKEY::default();
-                            if RegOpenKeyExA(
-                                h_oem,
-                                windows::core::PCSTR(tab_name.as_ptr()),
-                                0,
-                                KEY_READ,
-                                &mut h_tab,
-                            )
-                            .is_ok()
-                            {
-                                let mut rev_idx = 0;
-                                loop {
-                                    let mut rev_name = [0u8; 256];
-                                    let mut rev_name_len = rev_name.len() as u32;
-                                    if RegEnumKeyExA(
-                                        h_tab,
-                                        rev_idx,
-                                        windows::core::PSTR(rev_name.as_mut_ptr()),
-                                        &mut rev_name_len,
-                                        None,
-                                        windows::core::PSTR(std::ptr::null_mut()),
-                                        None,
-                                        None,
-                                    )
-                                    .is_err()
-                                    {
-                                        break;
-                                    }
-                                    let rev_str = CStr::from_ptr(rev_name.as_ptr() as *const i8)
-                                        .to_string_lossy()
-                                        .into_owned();
-                                    let rev_val = u32::from_str_radix(&rev_str, 16).unwrap_or(0);
-
-                                    let full_path = format!(
-                                        "HARDWARE\\ACPI\\{}\\{}\\{}\\{}",
-                                        reg_sig_str, oem_str, tab_str, rev_str
-                                    );
-
-                                    // Read real signature from binary data
-                                    let real_sig = read_real_signature(&full_path)
-                                        .unwrap_or_else(|| reg_sig_str.clone());
-
-                                    // Try to find physical address (not always in registry, but sometimes in subkeys)
-                                    let physical_address = None;
-
-                                    tables.push(AcpiTableInfo {
-                                        signature: real_sig,
-                                        registry_sig: reg_sig_str.clone(),
-                                        oem_id: oem_str.clone(),
-                                        table_id: tab_str.clone(),
-                                        revision: rev_val,
-                                        registry_path: Some(full_path),
-                                        physical_address,
-                                    });
-
-                                    rev_idx += 1;
-                                }
-                                let _ = RegCloseKey(h_tab);
+        for sig_name in root.subkey_names() {
+            let Some(sig_key) = root.open_subkey(&sig_name) else {
+                continue;
+            };
+            let reg_sig_str = String::from_utf8_lossy(&sig_name).into_owned();
+
+            for oem_name in sig_key.subkey_names() {
+                let Some(oem_key) = sig_key.open_subkey(&oem_name) else {
+                    continue;
+                };
+                let oem_str = String::from_utf8_lossy(&oem_name).into_owned();
+
+                for tab_name in oem_key.subkey_names() {
+                    let Some(tab_key) = oem_key.open_subkey(&tab_name) else {
+                        continue;
+                    };
+                    let tab_str = String::from_utf8_lossy(&tab_name).into_owned();
+
+                    for rev_name in tab_key.subkey_names() {
+                        let rev_str = String::from_utf8_lossy(&rev_name).into_owned();
+                        let rev_val = u32::from_str_radix(&rev_str, 16).unwrap_or(0);
+
+                        let full_path = format!(
+                            "HARDWARE\\ACPI\\{}\\{}\\{}\\{}",
+                            reg_sig_str, oem_str, tab_str, rev_str
+                        );
+                        log::debug!("Registry table key: {}", full_path);
+
+                        // Read real signature from binary data
+                        let (real_sig, hash) = match read_real_signature(&full_path) {
+                            Some((sig, hash)) => (sig, Some(hash)),
+                            None => {
+                                let sig = TableSignature {
+                                    bytes: sig_name.as_slice().try_into().unwrap_or_default(),
+                                    text: reg_sig_str.clone(),
+                                    valid: true,
+                                };
+                                (sig, None)
                             }
-                            tab_idx += 1;
-                        }
-                        let _ = RegCloseKey(h_oem);
+                        };
+
+                        // Try to find physical address (not always in registry, but sometimes in subkeys)
+                        let physical_address = None;
+
+                        tables.push((
+                            AcpiTableInfo {
+                                signature: real_sig.text,
+                                signature_valid: real_sig.valid,
+                                signature_bytes: real_sig.bytes,
+                                registry_sig: reg_sig_str.clone(),
+                                oem_id: oem_str.clone(),
+                                table_id: tab_str.clone(),
+                                revision: rev_val,
+                                registry_path: Some(full_path),
+                                physical_address,
+                            },
+                            hash,
+                        ));
                     }
-                    oem_idx += 1;
                 }
-                let _ = RegCloseKey(h_sig);
             }
-            sig_idx += 1;
         }
-        let _ = RegCloseKey(h_root);
     }
 
     Ok(tables)
 }
 
-/// Loads ACPI tables by combining Registry enumeration and the Windows System Firmware API.
+/// Loads ACPI tables from the Registry, the Windows System Firmware API, or both.
 ///
-/// This provides a comprehensive list by prioritizing Registry results (for duplicates)
-/// and supplementing them with API results (for system-managed tables like UEFI which might not be in the registry).
+/// With [`AcpiSource::Both`] the Registry results come first (they list duplicates such as
+/// SSDTs separately) and are supplemented with API results (for system-managed tables like
+/// UEFI which might not be in the registry). An API table is only skipped when a registry
+/// table has identical contents, so two different tables sharing a signature both survive.
+///
+/// Failures are logged and otherwise ignored; use [`load_acpi_tables_checked`] to learn why
+/// no tables were found.
+///
+/// # Arguments
+/// * `source` - Which discovery sources to use.
 ///
 /// # Returns
 /// A vector of `AcpiTableInfo`.
-pub fn load_acpi_tables_combined() -> Vec<AcpiTableInfo> {
+pub fn load_acpi_tables(source: AcpiSource) -> Vec<AcpiTableInfo> {
+    load_acpi_tables_checked(source).unwrap_or_default()
+}
+
+/// Loads ACPI tables like [`load_acpi_tables`], reporting why none were found.
+///
+/// A source that fails is skipped as long as another one lists tables. When nothing is listed
+/// the failure is returned, so callers can tell access denied or a specific API error from a
+/// firmware that really exposes no tables (`Ok` with an empty vector). Tables that
+/// exist but are empty are logged and left out, as they have no header to list.
+///
+/// # Arguments
+/// * `source` - Which discovery sources to use.
+///
+/// # Returns
+/// A `Result` containing the discovered tables, or the failure if there are none.
+pub fn load_acpi_tables_checked(source: AcpiSource) -> Result<Vec<AcpiTableInfo>, DumperError> {
     let mut combined = Vec::new();
+    let mut failure: Option<DumperError> = None;
+
+    // Content hashes of the registry tables, taken while listing them
+    let mut known: HashSet<u64> = HashSet::new();
 
     // 1. Load from Registry (Priority for duplicates)
-    if let Ok(reg_tables) = enum_acpi_tables_registry() {
-        combined.extend(reg_tables);
+    if source != AcpiSource::Api {
+        match enum_acpi_tables_registry() {
+            Ok(reg_tables) => {
+                log::info!("Registry lists {} ACPI tables", reg_tables.len());
+                for (table, hash) in reg_tables {
+                    known.extend(hash);
+                    combined.push(table);
+                }
+            }
+            Err(e) => {
+                log::warn!("Registry ACPI enumeration failed: {}", e);
+                note_failure(&mut failure, e);
+            }
+        }
+    }
+    if source == AcpiSource::Registry {
+        return checked_tables(combined, failure);
     }
 
     // 2. Load from API (Fallback for missing tables like UEFI)
-    if let Ok(api_sigs) = enum_system_firmware_tables(SIG_ACPI) {
-        for sig in api_sigs {
-            // If already present in registry collection, skip
-            if combined.iter().any(|t| t.signature == sig) {
-                continue;
-            }
-
-            // Try to fetch table data to get header info
-            if let Ok(data) = get_system_firmware_table(SIG_ACPI, &sig) {
+    match enum_system_firmware_tables(SIG_ACPI) {
+        Err(e) => {
+            log::warn!("EnumSystemFirmwareTables(ACPI) failed: {}", e);
+            note_failure(&mut failure, e);
+        }
+        Ok(api_sigs) => {
+            for sig in api_sigs {
+                // Try to fetch table data to get header info
+                let id = u32::from_le_bytes(sig.bytes);
+                let data = match get_system_firmware_table_by_id(SIG_ACPI, id) {
+                    Ok(data) => data,
+                    Err(e) => {
+                        note_failure(&mut failure, e);
+                        continue;
+                    }
+                };
+                if data.is_empty() {
+                    log::info!("API table {} exists but is empty", sig.text);
+                    continue;
+                }
+                // Already listed from the registry with the same contents
+                if known.contains(&acpi_content_hash(&data)) {
+                    log::debug!("API table {} is already listed from the registry", sig.text);
+                    continue;
+                }
                 if data.len() >= 36 {
                     let oem_id = String::from_utf8_lossy(&data[10..16]).trim().to_string();
                     let table_id = String::from_utf8_lossy(&data[16..24]).trim().to_string();
                     let revision = data[8] as u32;
 
                     combined.push(AcpiTableInfo {
-                        signature: sig.clone(),
-                        registry_sig: sig.clone(),
+                        signature: sig.text.clone(),
+                        signature_valid: sig.valid,
+                        signature_bytes: sig.bytes,
+                        registry_sig: sig.text,
                         oem_id,
                         table_id,
                         revision,
@@ -289,7 +396,62 @@ pub fn load_acpi_tables_combined() -> Vec<AcpiTableInfo> {
         }
     }
 
-    combined
+    log::info!("Loaded {} ACPI tables ({})", combined.len(), source.label());
+    checked_tables(combined, failure)
+}
+
+/// Records a loader failure, keeping the first one unless a later one is access denied.
+///
+/// Access denied is the most actionable cause, so it wins over e.g. a missing registry key.
+fn note_failure(failure: &mut Option<DumperError>, e: DumperError) {
+    if failure.is_none() || e == DumperError::NotAdmin {
+        *failure = Some(e);
+    }
+}
+
+/// Returns the loaded tables, or `failure` when there are none to return.
+fn checked_tables(
+    tables: Vec<AcpiTableInfo>,
+    failure: Option<DumperError>,
+) -> Result<Vec<AcpiTableInfo>, DumperError> {
+    match failure {
+        Some(e) if tables.is_empty() => Err(e),
+        _ => Ok(tables),
+    }
+}
+
+/// Reads an ACPI table from wherever it was discovered.
+///
+/// See [`AcpiTableInfo::location`] for which source is used.
+///
+/// # Arguments
+/// * `info` - The table to read.
+///
+/// # Returns
+/// A `Result` containing the table bytes on success.
+pub fn get_acpi_table(info: &AcpiTableInfo) -> Result<Vec<u8>, DumperError> {
+    get_acpi_table_with_value(info).map(|(_, data)| data)
+}
+
+/// Reads an ACPI table like [`get_acpi_table`], also returning the registry value it came from.
+///
+/// # Arguments
+/// * `info` - The table to read.
+///
+/// # Returns
+/// A `Result` containing the registry value name (`None` for firmware API reads) and the
+/// table bytes on success.
+pub fn get_acpi_table_with_value(
+    info: &AcpiTableInfo,
+) -> Result<(Option<String>, Vec<u8>), DumperError> {
+    match info.location() {
+        AcpiTableLocation::Registry(path) => {
+            get_acpi_table_value_by_path(path).map(|(name, data)| (Some(name), data))
+        }
+        AcpiTableLocation::Firmware(id) => {
+            get_system_firmware_table_by_id(SIG_ACPI, id).map(|data| (None, data))
+        }
+    }
 }
 
 /// Retrieves the raw binary content of an ACPI table from the Windows Registry using its full path.
@@ -299,21 +461,38 @@ pub fn load_acpi_tables_combined() -> Vec<AcpiTableInfo> {
 ///
 /// # Returns
 /// A `Result` containing the binary data as a `Vec<u8>` on success, or an error string on failure.
-pub fn get_acpi_table_by_path(path: &str) -> Result<Vec<u8>, String> {
+pub fn get_acpi_table_by_path(path: &str) -> Result<Vec<u8>, DumperError> {
+    get_acpi_table_value_by_path(path).map(|(_, data)| data)
+}
+
+/// Retrieves an ACPI table from the Windows Registry along with the value name it was read from.
+///
+/// Value "0" is tried first; otherwise the first `REG_BINARY` value in the key is used.
+///
+/// # Arguments
+/// * `path` - Full path to the registry key containing the table binary.
+///
+/// # Returns
+/// A `Result` containing the value name and binary data on success, or an error string on failure.
+pub fn get_acpi_table_value_by_path(path: &str) -> Result<(String, Vec<u8>), DumperError> {
     unsafe {
         let mut h_key = HKEY::default();
         let path_null = format!("{}\0", path);
-        if RegOpenKeyExA(
+        let status = RegOpenKeyExA(
             HKEY_LOCAL_MACHINE,
             windows::core::PCSTR(path_null.as_ptr()),
             0,
             KEY_READ,
             &mut h_key,
-        )
-        .is_err()
-        {
-            return Err(format!("Key open fail: {}", path));
+        );
+        if status.is_err() {
+            log::warn!("RegOpenKeyExA({}) failed: error {}", path, status.0);
+            return Err(match status.0 {
+                ERROR_ACCESS_DENIED | ERROR_PRIVILEGE_NOT_HELD => DumperError::NotAdmin,
+                _ => DumperError::KeyOpenFailed(path.to_string()),
+            });
         }
+        let key = RegKey(h_key);
 
         // Try value name "0" first (common location for ACPI binary data)
         let mut size = 0u32;
@@ -338,55 +517,23 @@ pub fn get_acpi_table_by_path(path: &str) -> Result<Vec<u8>, String> {
             )
             .is_ok()
             {
-                let _ = RegCloseKey(h_key);
-                return Ok(buffer);
+                log::debug!("Read {}\\0: {} bytes", path, buffer.len());
+                return Ok(("0".to_string(), buffer));
             }
         }
 
-        // Fallback: enumerate all values in the key and find the first binary one
-        let mut val_idx = 0;
-        loop {
-            let mut val_name = [0u8; 256];
-            let mut val_name_len = val_name.len() as u32;
-            let mut val_type = 0u32;
-            let mut val_size = 0u32;
-
-            if RegEnumValueA(
-                h_key,
-                val_idx,
-                windows::core::PSTR(val_name.as_mut_ptr()),
-                &mut val_name_len,
-                None,
-                Some(&mut val_type),
-                None,
-                Some(&mut val_size),
-            )
-            .is_err()
-            {
-                break;
-            }
-
-            if val_type == REG_BINARY.0 {
-                let mut buffer = vec![0u8; val_size as usize];
-                if RegQueryValueExA(
-                    h_key,
-                    windows::core::PCSTR(val_name.as_ptr()),
-                    None,
-                    None,
-                    Some(buffer.as_mut_ptr()),
-                    Some(&mut val_size),
-                )
-                .is_ok()
-                {
-                    let _ = RegCloseKey(h_key);
-                    return Ok(buffer);
-                }
-            }
-            val_idx += 1;
+        // Fallback: the first binary value in the key, whatever it is named
+        if let Some((name, buffer)) = key.first_binary_value() {
+            let name = String::from_utf8_lossy(&name).into_owned();
+            log::debug!("Read {}\\{}: {} bytes", path, name, buffer.len());
+            return Ok((name, buffer));
         }
 
-        let _ = RegCloseKey(h_key);
-        Err(format!("No binary value found in registry key: {}", path))
+        log::warn!("No binary value in registry key {}", path);
+        Err(DumperError::TableNotFound(format!(
+            "Binary value in registry key {}",
+            path
+        )))
     }
 }
 
@@ -400,50 +547,41 @@ pub fn get_acpi_table_by_path(path: &str) -> Result<Vec<u8>, String> {
 /// * `provider` - The firmware table provider signature (e.g., `SIG_ACPI`).
 ///
 /// # Returns
-/// A `Result` containing a vector of table signature strings on success.
-pub fn enum_system_firmware_tables(provider: u32) -> Result<Vec<String>, String> {
+/// A `Result` containing the decoded table signatures on success.
+pub fn enum_system_firmware_tables(provider: u32) -> Result<Vec<TableSignature>, DumperError> {
+    log::debug!("EnumSystemFirmwareTables({})", provider_name(provider));
     let provider = FIRMWARE_TABLE_PROVIDER(provider);
     unsafe {
         let size = EnumSystemFirmwareTables(provider, None);
         if size == 0 {
-            let err = GetLastError();
-            return Err(format!("EnumSystemFirmwareTables failed. Code: {:?}", err));
+            return Err(DumperError::from_win32(GetLastError().0));
         }
 
         let mut buffer = vec![0u8; size as usize];
         let ret = EnumSystemFirmwareTables(provider, Some(&mut buffer));
         if ret == 0 {
-            let err = GetLastError();
-            return Err(format!(
-                "EnumSystemFirmwareTables (2nd call) failed. Code: {:?}",
-                err
-            ));
+            return Err(DumperError::from_win32(GetLastError().0));
         }
 
         let count = (ret as usize) / 4;
-        let mut tables = Vec::new();
-        for i in 0..count {
-            let start = i * 4;
-            let end = start + 4;
-            let chunk = &buffer[start..end];
-            match std::str::from_utf8(chunk) {
-                Ok(s) => tables.push(s.to_string()),
-                Err(_) => tables.push(hex::encode(chunk).to_uppercase()),
-            }
-        }
+        let mut tables: Vec<TableSignature> = buffer[..count * 4]
+            .chunks_exact(4)
+            .map(|chunk| decode_table_signature([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect();
 
         // Proactively check for "hidden" or standard ACPI tables
         let hidden_tables = ["DSDT", "RSDT", "XSDT", "RSDP", "UEFI"];
         for &sig in &hidden_tables {
-            if !tables.contains(&sig.to_string()) {
-                let id_int = u32::from_le_bytes(sig.as_bytes().try_into().unwrap());
-                let size = GetSystemFirmwareTable(provider, id_int, None);
+            if !tables.iter().any(|t| t.text == sig) {
+                let bytes: [u8; 4] = sig.as_bytes().try_into().unwrap();
+                let size = GetSystemFirmwareTable(provider, u32::from_le_bytes(bytes), None);
                 if size > 0 {
-                    tables.push(sig.to_string());
+                    tables.push(decode_table_signature(bytes));
                 }
             }
         }
 
+        log::info!("EnumSystemFirmwareTables found {} tables", tables.len());
         Ok(tables)
     }
 }
@@ -456,44 +594,105 @@ pub fn enum_system_firmware_tables(provider: u32) -> Result<Vec<String>, String>
 ///
 /// # Returns
 /// A `Result` containing the binary data as `Vec<u8>`.
-pub fn get_system_firmware_table(provider: u32, table_id: &str) -> Result<Vec<u8>, String> {
-    let provider_u32 = provider;
-    let provider_type = FIRMWARE_TABLE_PROVIDER(provider);
-
-    let id_int = if provider_u32 == SIG_RSMB {
+pub fn get_system_firmware_table(provider: u32, table_id: &str) -> Result<Vec<u8>, DumperError> {
+    let id_int = if provider == SIG_RSMB {
         0
     } else if table_id.len() != 4 {
         if table_id == "0" {
             0
         } else {
-            return Err("Table ID must be 4 characters for ACPI".into());
+            return Err(DumperError::BadSignature {
+                expected: "a 4-character ACPI signature".to_string(),
+                got: table_id.to_string(),
+            });
         }
     } else {
         let bytes = table_id.as_bytes();
         u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
     };
+    get_system_firmware_table_by_id(provider, id_int)
+}
+
+/// Retrieves raw binary data for a firmware table by its numeric table ID.
+///
+/// Unlike [`get_system_firmware_table`] this also reaches tables whose signature is not
+/// printable ASCII.
+///
+/// # Arguments
+/// * `provider` - The firmware table provider signature (`SIG_ACPI` or `SIG_RSMB`).
+/// * `id_int` - The table signature bytes read as a little-endian `u32`, or 0 for SMBIOS.
+///
+/// # Returns
+/// A `Result` containing the binary data as `Vec<u8>`.
+pub fn get_system_firmware_table_by_id(provider: u32, id_int: u32) -> Result<Vec<u8>, DumperError> {
+    let result = read_system_firmware_table(provider, id_int);
+    let call = format!(
+        "GetSystemFirmwareTable({}, {})",
+        provider_name(provider),
+        decode_table_signature(id_int.to_le_bytes()).text
+    );
+    match &result {
+        Ok(data) => log::debug!("{}: {} bytes", call, data.len()),
+        Err(e) => log::warn!("{} failed: {}", call, e),
+    }
+    result
+}
+
+/// Returns a firmware table provider signature as text, e.g. "ACPI".
+fn provider_name(provider: u32) -> String {
+    decode_table_signature(provider.to_be_bytes()).text
+}
+
+/// Performs the `GetSystemFirmwareTable` calls behind [`get_system_firmware_table_by_id`].
+///
+/// A size of 0 means one of three things, told apart by the last error: the table does not
+/// exist ([`DumperError::TableNotFound`]), the call was refused ([`DumperError::NotAdmin`] or
+/// [`DumperError::WinApi`]), or the table exists but is empty (`Ok` with no bytes).
+fn read_system_firmware_table(provider: u32, id_int: u32) -> Result<Vec<u8>, DumperError> {
+    let provider_type = FIRMWARE_TABLE_PROVIDER(provider);
 
     unsafe {
+        // Clear any stale error so a size of 0 with no error really means an empty table
+        SetLastError(WIN32_ERROR(0));
         let size = GetSystemFirmwareTable(provider_type, id_int, None);
         if size == 0 {
             let err = GetLastError();
+            if err == ERROR_NOT_FOUND {
+                return Err(DumperError::TableNotFound(format!(
+                    "{} table {}",
+                    provider_name(provider),
+                    decode_table_signature(id_int.to_le_bytes()).text
+                )));
+            }
             if err.is_err() {
-                return Err(format!("GetSystemFirmwareTable failed. Code: {:?}", err));
+                return Err(DumperError::from_win32(err.0));
             }
             return Ok(Vec::new());
         }
 
-        let mut buffer = vec![0u8; size as usize];
-        let ret = GetSystemFirmwareTable(provider_type, id_int, Some(&mut buffer));
-        if ret == 0 {
-            let err = GetLastError();
-            return Err(format!(
-                "GetSystemFirmwareTable (2nd call) failed. Code: {:?}",
-                err
-            ));
+        // The table can grow between the sizing call and the fetch, in which case the fetch
+        // returns the new required size instead of the data; retry with a larger buffer.
+        let mut size = size as usize;
+        for _ in 0..FIRMWARE_TABLE_READ_ATTEMPTS {
+            let mut buffer = vec![0u8; size];
+            let ret = GetSystemFirmwareTable(provider_type, id_int, Some(&mut buffer)) as usize;
+            if ret == 0 {
+                return Err(DumperError::from_win32(GetLastError().0));
+            }
+            if ret > buffer.len() {
+                size = ret;
+                continue;
+            }
+
+            // A shorter read would otherwise leave trailing zeros for the parsers to trip on
+            buffer.truncate(ret);
+            return Ok(buffer);
         }
 
-        Ok(buffer)
+        Err(DumperError::Other(format!(
+            "GetSystemFirmwareTable: table size kept changing after {} attempts",
+            FIRMWARE_TABLE_READ_ATTEMPTS
+        )))
     }
 }
 
@@ -501,6 +700,626 @@ pub fn get_system_firmware_table(provider: u32, table_id: &str) -> Result<Vec<u8
 ///
 /// # Returns
 /// A `Result` containing the raw SMBIOS binary data.
-pub fn get_smbios_data() -> Result<Vec<u8>, String> {
-    get_system_firmware_table(SIG_RSMB, "0")
+pub fn get_smbios_data() -> Result<Vec<u8>, DumperError> {
+    get_smbios_data_with_source().map(|(data, _)| data)
+}
+
+/// Where the SMBIOS data was read from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SmbiosSource {
+    /// `GetSystemFirmwareTable('RSMB')`.
+    FirmwareTable,
+    /// The WMI class `MSSMBios_RawSMBIOSTables` under `root\WMI`.
+    Wmi,
+}
+
+impl SmbiosSource {
+    /// Returns a short human-readable name of the source.
+    pub fn label(self) -> &'static str {
+        match self {
+            SmbiosSource::FirmwareTable => "firmware table API",
+            SmbiosSource::Wmi => "WMI",
+        }
+    }
+}
+
+/// Retrieves the raw SMBIOS data, falling back to WMI when the firmware table API fails.
+///
+/// # Returns
+/// A `Result` containing the raw SMBIOS binary data and the source it was read from.
+pub fn get_smbios_data_with_source() -> Result<(Vec<u8>, SmbiosSource), DumperError> {
+    let api_err = match get_system_firmware_table(SIG_RSMB, "0") {
+        Ok(data) if !data.is_empty() => {
+            log::info!(
+                "Read {} bytes of SMBIOS data from the firmware table API",
+                data.len()
+            );
+            return Ok((data, SmbiosSource::FirmwareTable));
+        }
+        Ok(_) => DumperError::TableNotFound("SMBIOS table".to_string()),
+        Err(e) => e,
+    };
+    log::warn!(
+        "Firmware table API has no SMBIOS data ({}); trying WMI",
+        api_err
+    );
+    // Report the firmware table API error; a denied API call stays NotAdmin so callers can
+    // offer elevation, while other failures mention why WMI did not help either
+    let wmi = get_smbios_data_wmi();
+    match &wmi {
+        Ok(data) => log::info!("WMI returned {} bytes of SMBIOS data", data.len()),
+        Err(e) => log::warn!("WMI SMBIOS query failed: {}", e),
+    }
+    match wmi {
+        Ok(data) if !data.is_empty() => Ok((data, SmbiosSource::Wmi)),
+        _ if api_err == DumperError::NotAdmin => Err(api_err),
+        Ok(_) => Err(DumperError::Other(format!(
+            "{}; WMI: empty result",
+            api_err
+        ))),
+        Err(wmi_err) => Err(DumperError::Other(format!("{}; WMI: {}", api_err, wmi_err))),
+    }
+}
+
+/// Closes a registry key on drop, so every early return and unwind releases the handle.
+struct RegKey(HKEY);
+
+/// Initial buffer size for a subkey or value name; key names are limited to 255 characters.
+const REG_KEY_NAME_INITIAL: usize = 256;
+/// Upper bound for growing a subkey or value name buffer after `ERROR_MORE_DATA`; value
+/// names may be up to 16,383 characters.
+const REG_KEY_NAME_MAX: usize = 32 * 1024;
+/// Initial buffer size for registry value data; larger tables grow it after `ERROR_MORE_DATA`.
+const REG_VALUE_DATA_INITIAL: usize = 64 * 1024;
+
+impl RegKey {
+    /// Opens a subkey for reading.
+    ///
+    /// # Arguments
+    /// * `name` - Raw subkey name as returned by `subkey_names`, without a terminator.
+    ///
+    /// # Returns
+    /// The opened key, or `None` if the name contains a NUL byte or the key cannot be opened.
+    fn open_subkey(&self, name: &[u8]) -> Option<RegKey> {
+        if name.contains(&0) {
+            return None;
+        }
+        let mut name_null = name.to_vec();
+        name_null.push(0);
+        let mut h_key = HKEY::default();
+        unsafe {
+            let status = RegOpenKeyExA(
+                self.0,
+                windows::core::PCSTR(name_null.as_ptr()),
+                0,
+                KEY_READ,
+                &mut h_key,
+            );
+            if status.is_err() {
+                log::warn!(
+                    "RegOpenKeyExA({}) failed: error {}",
+                    String::from_utf8_lossy(name),
+                    status.0
+                );
+                return None;
+            }
+        }
+        Some(RegKey(h_key))
+    }
+
+    /// Enumerates the names of this key's subkeys.
+    ///
+    /// Each call passes the real buffer capacity, grows the buffer on `ERROR_MORE_DATA`, and
+    /// slices the name by the returned length, so long or unusual names are never truncated.
+    /// Empty names and names with embedded NUL bytes are skipped because they cannot be opened.
+    ///
+    /// # Returns
+    /// The raw (ANSI) subkey names in enumeration order.
+    fn subkey_names(&self) -> Vec<Vec<u8>> {
+        let mut names = Vec::new();
+        let mut buf = vec![0u8; REG_KEY_NAME_INITIAL];
+        let mut index = 0;
+        loop {
+            let mut len = buf.len() as u32;
+            let status = unsafe {
+                RegEnumKeyExA(
+                    self.0,
+                    index,
+                    windows::core::PSTR(buf.as_mut_ptr()),
+                    &mut len,
+                    None,
+                    windows::core::PSTR(std::ptr::null_mut()),
+                    None,
+                    None,
+                )
+            };
+            if status == ERROR_MORE_DATA && buf.len() < REG_KEY_NAME_MAX {
+                buf.resize(buf.len() * 2, 0);
+                continue;
+            }
+            if status == ERROR_NO_MORE_ITEMS {
+                break;
+            }
+            if status.is_err() {
+                log::warn!("RegEnumKeyExA(index {}) failed: error {}", index, status.0);
+                break;
+            }
+            let name = &buf[..(len as usize).min(buf.len())];
+            if !name.is_empty() && !name.contains(&0) {
+                names.push(name.to_vec());
+            }
+            index += 1;
+        }
+        names
+    }
+
+    /// Reads the first `REG_BINARY` value of this key.
+    ///
+    /// As in `subkey_names`, each call passes the real buffer capacities and a buffer that
+    /// is too small for the name or the data is grown and the same index enumerated again.
+    /// The data comes from the enumeration call itself and the name is sliced by the
+    /// returned length, so a value whose name is long or does not survive the ANSI
+    /// conversion is still read.
+    ///
+    /// # Returns
+    /// The raw (ANSI) value name and the value's bytes, or `None` if no binary value is found.
+    fn first_binary_value(&self) -> Option<(Vec<u8>, Vec<u8>)> {
+        let mut name = vec![0u8; REG_KEY_NAME_INITIAL];
+        let mut data = vec![0u8; REG_VALUE_DATA_INITIAL];
+        let mut index = 0;
+        loop {
+            let mut name_len = name.len() as u32;
+            let mut data_len = data.len() as u32;
+            let mut value_type = 0u32;
+            let status = unsafe {
+                RegEnumValueA(
+                    self.0,
+                    index,
+                    windows::core::PSTR(name.as_mut_ptr()),
+                    &mut name_len,
+                    None,
+                    Some(&mut value_type),
+                    Some(data.as_mut_ptr()),
+                    Some(&mut data_len),
+                )
+            };
+            if status == ERROR_MORE_DATA {
+                // The data size is reported when the data did not fit; otherwise the name did not
+                if data_len as usize > data.len() {
+                    data.resize(data_len as usize, 0);
+                    continue;
+                }
+                if name.len() < REG_KEY_NAME_MAX {
+                    name.resize(name.len() * 2, 0);
+                    continue;
+                }
+                log::warn!(
+                    "RegEnumValueA(index {}): value name too long, skipped",
+                    index
+                );
+                index += 1;
+                continue;
+            }
+            if status == ERROR_NO_MORE_ITEMS {
+                return None;
+            }
+            if status.is_err() {
+                log::warn!("RegEnumValueA(index {}) failed: error {}", index, status.0);
+                return None;
+            }
+            if value_type == REG_BINARY.0 {
+                name.truncate((name_len as usize).min(name.len()));
+                data.truncate((data_len as usize).min(data.len()));
+                return Some((name, data));
+            }
+            index += 1;
+        }
+    }
+}
+
+impl Drop for RegKey {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = RegCloseKey(self.0);
+        }
+    }
+}
+
+/// Uninitializes COM on drop if this thread's `CoInitializeEx` call succeeded.
+struct ComGuard(bool);
+
+impl Drop for ComGuard {
+    fn drop(&mut self) {
+        if self.0 {
+            unsafe { CoUninitialize() };
+        }
+    }
+}
+
+/// Reads an integer-valued property from a WMI object.
+///
+/// The `VARIANT` wrapper clears itself on drop, so no explicit `VariantClear` is needed.
+fn wmi_get_u32(obj: &IWbemClassObject, name: PCWSTR) -> Result<u32, DumperError> {
+    unsafe {
+        let mut value = VARIANT::default();
+        obj.Get(name, 0, &mut value, None, None)
+            .map_err(|e| DumperError::Other(format!("Get property failed: {}", e)))?;
+        let inner = &value.as_raw().Anonymous.Anonymous;
+        let result = match VARENUM(inner.vt) {
+            VT_UI1 => Some(inner.Anonymous.bVal as u32),
+            VT_I4 => Some(inner.Anonymous.lVal as u32),
+            VT_UI4 => Some(inner.Anonymous.ulVal),
+            VT_BOOL => Some((inner.Anonymous.boolVal != 0) as u32),
+            _ => None,
+        };
+        result.ok_or_else(|| DumperError::Other("Unexpected property type".to_string()))
+    }
+}
+
+/// Reads a `uint8[]` property from a WMI object.
+fn wmi_get_bytes(obj: &IWbemClassObject, name: PCWSTR) -> Result<Vec<u8>, DumperError> {
+    unsafe {
+        let mut value = VARIANT::default();
+        obj.Get(name, 0, &mut value, None, None)
+            .map_err(|e| DumperError::Other(format!("Get property failed: {}", e)))?;
+        let inner = &value.as_raw().Anonymous.Anonymous;
+        if VARENUM(inner.vt) != VARENUM(VT_ARRAY.0 | VT_UI1.0) {
+            return Err(DumperError::Other("Unexpected property type".to_string()));
+        }
+
+        // The raw binding has its own SAFEARRAY type with the same layout
+        safe_array_bytes(inner.Anonymous.parray as *mut SAFEARRAY)
+    }
+}
+
+/// Copies the contents of a one-dimensional `SAFEARRAY` of bytes.
+unsafe fn safe_array_bytes(array: *mut SAFEARRAY) -> Result<Vec<u8>, DumperError> {
+    let lower = SafeArrayGetLBound(array, 1).map_err(|e| DumperError::WinApi(e.code().0 as u32))?;
+    let upper = SafeArrayGetUBound(array, 1).map_err(|e| DumperError::WinApi(e.code().0 as u32))?;
+    let len = (upper - lower + 1).max(0) as usize;
+    let mut ptr = std::ptr::null_mut();
+    SafeArrayAccessData(array, &mut ptr).map_err(|e| DumperError::WinApi(e.code().0 as u32))?;
+    let bytes = std::slice::from_raw_parts(ptr as *const u8, len).to_vec();
+    let _ = SafeArrayUnaccessData(array);
+    Ok(bytes)
+}
+
+/// Retrieves the raw SMBIOS data through WMI (`root\WMI:MSSMBios_RawSMBIOSTables`).
+///
+/// The result is wrapped in the same 8-byte header that `GetSystemFirmwareTable` returns,
+/// so the existing parsers apply unchanged.
+///
+/// # Returns
+/// A `Result` containing the raw SMBIOS binary data.
+pub fn get_smbios_data_wmi() -> Result<Vec<u8>, DumperError> {
+    unsafe {
+        // S_FALSE means COM was already initialized on this thread and still needs a matching
+        // CoUninitialize; RPC_E_CHANGED_MODE means it is usable but must not be uninitialized.
+        let hr = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+        let _guard = ComGuard(hr.is_ok());
+        if hr != RPC_E_CHANGED_MODE {
+            hr.ok()
+                .map_err(|e| DumperError::Other(format!("CoInitializeEx failed: {}", e)))?;
+        }
+        // Fails with RPC_E_TOO_LATE if the process already set security; the defaults still work.
+        let _ = CoInitializeSecurity(
+            PSECURITY_DESCRIPTOR::default(),
+            -1,
+            None,
+            None,
+            RPC_C_AUTHN_LEVEL_DEFAULT,
+            RPC_C_IMP_LEVEL_IMPERSONATE,
+            None,
+            EOAC_NONE,
+            None,
+        );
+
+        let locator: IWbemLocator = CoCreateInstance(&WbemLocator, None, CLSCTX_INPROC_SERVER)
+            .map_err(|e| DumperError::Other(format!("Failed to create WbemLocator: {}", e)))?;
+        let services = locator
+            .ConnectServer(
+                &BSTR::from("root\\WMI"),
+                &BSTR::new(),
+                &BSTR::new(),
+                &BSTR::new(),
+                0,
+                &BSTR::new(),
+                None,
+            )
+            .map_err(|e| DumperError::Other(format!("Failed to connect to root\\WMI: {}", e)))?;
+        let enumerator = services
+            .ExecQuery(
+                &BSTR::from("WQL"),
+                &BSTR::from("SELECT * FROM MSSMBios_RawSMBIOSTables"),
+                WBEM_GENERIC_FLAG_TYPE(WBEM_FLAG_FORWARD_ONLY.0 | WBEM_FLAG_RETURN_IMMEDIATELY.0),
+                None,
+            )
+            .map_err(|e| DumperError::Other(format!("WMI query failed: {}", e)))?;
+
+        let mut row: [Option<IWbemClassObject>; 1] = Default::default();
+        let mut returned = 0u32;
+        enumerator
+            .Next(WBEM_INFINITE, &mut row, &mut returned)
+            .ok()
+            .map_err(|e| DumperError::Other(format!("WMI enumeration failed: {}", e)))?;
+        let obj = match row[0].take() {
+            Some(obj) if returned > 0 => obj,
+            _ => {
+                return Err(DumperError::TableNotFound(
+                    "MSSMBios_RawSMBIOSTables instance".to_string(),
+                ))
+            }
+        };
+
+        let table = wmi_get_bytes(&obj, w!("SMBiosData"))?;
+        let calling_method = wmi_get_u32(&obj, w!("Used20CallingMethod")).unwrap_or(0);
+        let major = wmi_get_u32(&obj, w!("SmbiosMajorVersion"))?;
+        let minor = wmi_get_u32(&obj, w!("SmbiosMinorVersion"))?;
+        let dmi_revision = wmi_get_u32(&obj, w!("DmiRevision")).unwrap_or(0);
+
+        // Rebuild the RawSMBIOSData header expected by parse_raw_smbios_data_header
+        let mut data = Vec::with_capacity(8 + table.len());
+        data.push(calling_method as u8);
+        data.push(major as u8);
+        data.push(minor as u8);
+        data.push(dmi_revision as u8);
+        data.extend_from_slice(&(table.len() as u32).to_le_bytes());
+        data.extend_from_slice(&table);
+        Ok(data)
+    }
+}
+
+/// Reads physical memory, such as the PCI configuration space mapped by an ECAM window.
+///
+/// Windows offers no user-mode API for this: `\Device\PhysicalMemory` has been closed to
+/// applications since Windows Server 2003 SP1, and the firmware table provider only returns
+/// whole ACPI and SMBIOS tables. The read needs a kernel-mode driver, which this tool does
+/// not ship, so it always fails with a message naming the address and the alternative.
+///
+/// # Arguments
+/// * `address` - The physical address to read from.
+/// * `_length` - The number of bytes to read.
+///
+/// # Returns
+/// An error explaining why the read is not available.
+pub fn read_physical_memory(address: u64, _length: usize) -> Result<Vec<u8>, DumperError> {
+    Err(DumperError::Other(format!(
+        "reading physical memory at 0x{:X} needs a kernel-mode driver, which standard Windows \
+         does not provide to applications; dump the configuration space with a driver-based \
+         tool and open the dump instead",
+        address
+    )))
+}
+
+/// Quotes a single command-line argument following the Windows `CommandLineToArgvW` rules.
+fn quote_arg(arg: &str) -> String {
+    if !arg.is_empty() && !arg.contains([' ', '\t', '"']) {
+        return arg.to_string();
+    }
+
+    let mut quoted = String::from('"');
+    let mut backslashes = 0;
+    for c in arg.chars() {
+        match c {
+            '\\' => backslashes += 1,
+            '"' => {
+                // Escape all preceding backslashes and the quote itself
+                quoted.push_str(&"\\".repeat(backslashes * 2 + 1));
+                quoted.push('"');
+                backslashes = 0;
+            }
+            _ => {
+                quoted.push_str(&"\\".repeat(backslashes));
+                quoted.push(c);
+                backslashes = 0;
+            }
+        }
+    }
+    // Backslashes before the closing quote must be doubled
+    quoted.push_str(&"\\".repeat(backslashes * 2));
+    quoted.push('"');
+    quoted
+}
+
+/// Re-launches the current executable with the `runas` verb so Windows prompts for elevation.
+///
+/// The original command-line arguments are passed through unchanged. The caller is expected
+/// to exit the current process once this returns `Ok`.
+///
+/// # Returns
+/// `Ok(())` if the elevated process was started, or an error string (e.g. the UAC prompt was declined).
+pub fn relaunch_as_admin() -> Result<(), DumperError> {
+    let exe = std::env::current_exe()
+        .map_err(|e| DumperError::Other(format!("Cannot locate executable: {}", e)))?;
+    let params = std::env::args()
+        .skip(1)
+        .map(|a| quote_arg(&a))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let exe_wide: Vec<u16> = exe
+        .to_string_lossy()
+        .encode_utf16()
+        .chain(std::iter::once(0))
+        .collect();
+    let params_wide: Vec<u16> = params.encode_utf16().chain(std::iter::once(0)).collect();
+
+    unsafe {
+        let result = ShellExecuteW(
+            HWND::default(),
+            windows::core::w!("runas"),
+            PCWSTR(exe_wide.as_ptr()),
+            PCWSTR(params_wide.as_ptr()),
+            PCWSTR::null(),
+            SW_SHOWNORMAL,
+        );
+        // ShellExecuteW returns a value greater than 32 on success
+        if result.0 as usize <= 32 {
+            return Err(DumperError::from_win32(GetLastError().0));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns today's local date as `YYYY-MM-DD`, for the `{date}` export filename placeholder.
+pub fn local_date() -> String {
+    let now = unsafe { GetLocalTime() };
+    format!("{:04}-{:02}-{:02}", now.wYear, now.wMonth, now.wDay)
+}
+
+/// Returns the local time of day as `HH:MM:SS`, for timestamping `--watch` output.
+pub fn local_time() -> String {
+    let now = unsafe { GetLocalTime() };
+    format!("{:02}:{:02}:{:02}", now.wHour, now.wMinute, now.wSecond)
+}
+
+/// Reads the "Choose your default app mode" setting (Settings > Personalization > Colors).
+///
+/// # Returns
+/// `Some(true)` if apps should use the light theme, `Some(false)` for dark, or `None` if
+/// the `AppsUseLightTheme` value is missing, as on Windows versions before it existed.
+pub fn apps_use_light_theme() -> Option<bool> {
+    unsafe {
+        let mut h_key = HKEY::default();
+        let status = RegOpenKeyExA(
+            HKEY_CURRENT_USER,
+            windows::core::PCSTR(
+                c"Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize".as_ptr()
+                    as *const u8,
+            ),
+            0,
+            KEY_READ,
+            &mut h_key,
+        );
+        if status.is_err() {
+            return None;
+        }
+        let _key_guard = RegKey(h_key);
+        let mut value = 0u32;
+        let mut size = std::mem::size_of::<u32>() as u32;
+        let status = RegQueryValueExA(
+            h_key,
+            windows::core::PCSTR(c"AppsUseLightTheme".as_ptr() as *const u8),
+            None,
+            None,
+            Some(&mut value as *mut u32 as *mut u8),
+            Some(&mut size),
+        );
+        if status.is_err() {
+            return None;
+        }
+        Some(value != 0)
+    }
+}
+
+/// Opens a file in an external program via `ShellExecuteW`.
+///
+/// # Arguments
+/// * `path` - The file to open.
+/// * `program` - The program to launch with `path` as its argument, or `None` to use the
+///   handler registered for the file's extension.
+///
+/// # Returns
+/// `Ok(())` if the program was started, or an error string (e.g. no handler is registered).
+pub fn open_with_program(path: &std::path::Path, program: Option<&str>) -> Result<(), DumperError> {
+    let path_str = path.to_string_lossy();
+    let (file, params) = match program {
+        Some(program) => (program.to_string(), quote_arg(&path_str)),
+        None => (path_str.to_string(), String::new()),
+    };
+
+    let file_wide: Vec<u16> = file.encode_utf16().chain(std::iter::once(0)).collect();
+    let params_wide: Vec<u16> = params.encode_utf16().chain(std::iter::once(0)).collect();
+
+    unsafe {
+        let result = ShellExecuteW(
+            HWND::default(),
+            w!("open"),
+            PCWSTR(file_wide.as_ptr()),
+            PCWSTR(params_wide.as_ptr()),
+            PCWSTR::null(),
+            SW_SHOWNORMAL,
+        );
+        // ShellExecuteW returns a value greater than 32 on success
+        if result.0 as usize <= 32 {
+            return Err(DumperError::from_win32(GetLastError().0));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A table discovered through the firmware API with the given signature bytes.
+    fn api_table(bytes: [u8; 4]) -> AcpiTableInfo {
+        let sig = decode_table_signature(bytes);
+        AcpiTableInfo {
+            signature: sig.text.clone(),
+            signature_valid: sig.valid,
+            signature_bytes: sig.bytes,
+            registry_sig: sig.text,
+            oem_id: String::new(),
+            table_id: String::new(),
+            revision: 0,
+            registry_path: None,
+            physical_address: None,
+        }
+    }
+
+    #[test]
+    fn control_byte_signature_round_trips() {
+        let raw = [b'S', 0x01, b'D', b'T'];
+        let sig = decode_table_signature(raw);
+        assert_eq!(sig.text, "S.DT");
+        assert!(!sig.valid);
+        assert_eq!(sig.bytes, raw);
+
+        let info = api_table(raw);
+        // The lookup uses the raw bytes, not the `.`-substituted display text
+        assert_eq!(info.firmware_table_id(), u32::from_le_bytes(raw));
+        assert_ne!(info.firmware_table_id(), u32::from_le_bytes(*b"S.DT"));
+        assert_eq!(
+            decode_table_signature(info.firmware_table_id().to_le_bytes()),
+            decode_table_signature(raw)
+        );
+    }
+
+    #[test]
+    fn printable_signature_is_valid() {
+        let sig = decode_table_signature(*b"SSDT");
+        assert_eq!(sig.text, "SSDT");
+        assert!(sig.valid);
+    }
+
+    #[test]
+    fn location_prefers_registry_path() {
+        let mut info = api_table(*b"SSDT");
+        info.registry_path = Some("HARDWARE\\ACPI\\SSDT\\OEM\\TABLE\\00001000".to_string());
+        assert_eq!(
+            info.location(),
+            AcpiTableLocation::Registry("HARDWARE\\ACPI\\SSDT\\OEM\\TABLE\\00001000")
+        );
+        assert_eq!(info.source(), AcpiSource::Registry);
+    }
+
+    #[test]
+    fn location_falls_back_to_firmware_api() {
+        let info = api_table(*b"UEFI");
+        assert_eq!(
+            info.location(),
+            AcpiTableLocation::Firmware(u32::from_le_bytes(*b"UEFI"))
+        );
+        assert_eq!(info.source(), AcpiSource::Api);
+
+        // The raw bytes are used even when the display signature substitutes them
+        let info = api_table([b'S', 0x01, b'D', b'T']);
+        assert_eq!(
+            info.location(),
+            AcpiTableLocation::Firmware(u32::from_le_bytes([b'S', 0x01, b'D', b'T']))
+        );
+    }
 }
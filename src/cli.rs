@@ -0,0 +1,1007 @@
+use crate::api;
+use crate::logging;
+use crate::parsers;
+use crate::report;
+use std::collections::BTreeMap;
+use std::io::{IsTerminal, Write};
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+
+/// Exit code for a command that succeeded.
+pub const EXIT_OK: i32 = 0;
+/// Exit code for a failed read, a missing table, or a parse error.
+pub const EXIT_FAILURE: i32 = 1;
+/// Exit code for invalid command-line usage.
+pub const EXIT_USAGE: i32 = 2;
+
+/// Prints command-line usage information.
+fn print_usage() {
+    println!("Usage: acpi-smbios-dumper [OPTIONS]");
+    println!();
+    println!("Without options the graphical interface is launched.");
+    println!();
+    println!("Options:");
+    println!("  --report <dir>    Write every ACPI table, the SMBIOS blob, and summaries to <dir>");
+    println!("  --table <SIG>     Print the hex dump and parsed fields of an ACPI table");
+    println!("                    (SIG@N picks the Nth table of a signature, e.g. SSDT@2)");
+    println!("  --raw             Write only the --table bytes to stdout, e.g. > DSDT.aml");
+    println!("  --smbios          Print every SMBIOS structure with its parsed fields");
+    println!("  --type <N,...>    Limit --smbios to the given structure types (e.g. 17 or 0,1,4)");
+    println!("  --acpi            List every ACPI table's signature, IDs, revision, length,");
+    println!("                    physical address, and discovery source");
+    println!(
+        "  --format <FMT>    Output format: text (default), xml for --smbios, or csv for --acpi"
+    );
+    println!("                    hexdump prints the bytes of --acpi, --smbios, or --table as");
+    println!("                    offset/hex/ASCII lines");
+    println!("  --source <SRC>    Where ACPI tables are found: registry, api, or both (default)");
+    println!(
+        "  --strip-wrapper   Write the --report SMBIOS dump without the Windows 8-byte header"
+    );
+    println!("  --redact          Replace serial numbers, UUIDs, asset tags, and the product key");
+    println!("                    with REDACTED in --report, --smbios, --table, --system-info,");
+    println!("                    and --compare output");
+    println!("  --smbios-summary  List the SMBIOS structure types present with their counts");
+    println!("  --cpu-summary     Print the socket count and total cores/threads of all CPUs");
+    println!(
+        "  --system-info     Print BIOS, system, board, CPU, memory, and TPM inventory as JSON"
+    );
+    println!("  --verify          Check every ACPI checksum and the SMBIOS structure table");
+    println!("  --pci             Enumerate PCI functions through the MCFG ECAM windows");
+    println!("  --pci-config <FILE>");
+    println!("                    Decode a PCI configuration space or ECAM window dump");
+    println!(
+        "  --compare <FILE>  Diff this system against a report.json (exit 1 on any difference)"
+    );
+    println!("  --smbios-diff <A> <B>");
+    println!("                    Diff two SMBIOS dumps (or `live`) by structure and field");
+    println!("                    (exit 1 on any difference)");
+    println!(
+        "  --detect-vm       Report whether the firmware looks virtual (exit 0 if so, 1 if not)"
+    );
+    println!("  --watch <SECONDS> Re-read the ACPI tables and SMBIOS structures every SECONDS and");
+    println!("                    print timestamped changes until Ctrl+C");
+    println!("  -v, --verbose     With --watch, also print a line for reads with no change");
+    println!("  --log-level <LEVEL>");
+    println!("                    Log API calls and parse warnings to stderr: off (default),");
+    println!("                    error, warn, info, debug, or trace");
+    println!("  --log-file <FILE> Also write the log to <FILE> (level info unless --log-level)");
+    println!("  -q, --quiet       Print only parsed fields or errors, no progress or hex dumps");
+    println!("  -h, --help        Show this help");
+    println!();
+    println!("Exit codes: 0 success, 1 read/lookup/parse failure, 2 usage error.");
+}
+
+/// Writes a full system report into `dir`, creating the folder if needed.
+fn run_report(dir: &str, options: report::ReportOptions, quiet: bool) -> i32 {
+    let path = Path::new(dir);
+    if let Err(e) = std::fs::create_dir_all(path) {
+        eprintln!("Failed to create {}: {}", dir, e);
+        return EXIT_FAILURE;
+    }
+
+    let outcome = report::write_full_report(path, options, &AtomicBool::new(false), |_, _, _| {});
+    if !quiet {
+        for name in &outcome.written {
+            println!("Wrote {}", path.join(name).display());
+        }
+    }
+    for failure in &outcome.failed {
+        eprintln!("FAILED {}", failure);
+    }
+    if !quiet {
+        println!(
+            "{} files written, {} failed",
+            outcome.written.len(),
+            outcome.failed.len()
+        );
+    }
+
+    if outcome.failed.is_empty() {
+        EXIT_OK
+    } else {
+        EXIT_FAILURE
+    }
+}
+
+/// Splits a `--table` argument such as `SSDT` or `SSDT@2` into the signature and a 1-based
+/// index among the tables with that signature.
+fn parse_table_selector(arg: &str) -> Result<(String, usize), String> {
+    let (signature, index) = match arg.split_once('@') {
+        Some((signature, index)) => match index.parse::<usize>() {
+            Ok(n) if n > 0 => (signature, n),
+            _ => {
+                return Err(format!(
+                    "Invalid table index '{}' (expected 1 or higher, e.g. SSDT@2)",
+                    index
+                ))
+            }
+        },
+        None => (arg, 1),
+    };
+    if signature.is_empty() {
+        return Err("--table requires a table signature before '@'".to_string());
+    }
+    Ok((signature.to_uppercase(), index))
+}
+
+/// Prints the hex dump and parsed fields of an ACPI table selected by `SIG` or `SIG@N`.
+///
+/// Tables are numbered in the order [`api::load_acpi_tables`] lists them, the same order as
+/// the GUI sidebar, so `SSDT@2` reaches the second SSDT even when only the registry has it.
+///
+/// With `redact` the MSDM product key reads `REDACTED` and the table's hex dump, which holds
+/// the key, is not printed. `output` picks the parsed fields, only the hex dump, or the raw
+/// bytes.
+fn run_table(
+    selector: &str,
+    source: api::AcpiSource,
+    redact: bool,
+    quiet: bool,
+    output: TableOutput,
+) -> i32 {
+    let (signature, index) = match parse_table_selector(selector) {
+        Ok(selected) => selected,
+        Err(e) => {
+            eprintln!("{}", e);
+            return EXIT_USAGE;
+        }
+    };
+    let tables = api::load_acpi_tables(source);
+    let matching: Vec<&api::AcpiTableInfo> =
+        tables.iter().filter(|t| t.signature == signature).collect();
+    let Some(info) = matching.get(index - 1) else {
+        if matching.is_empty() {
+            eprintln!("Table {} not found", signature);
+        } else {
+            eprintln!(
+                "Table {}@{} not found: only {} {} table(s) (use {}@1 to {}@{})",
+                signature,
+                index,
+                matching.len(),
+                signature,
+                signature,
+                signature,
+                matching.len()
+            );
+        }
+        return EXIT_FAILURE;
+    };
+    // Raw output is only the table, so it can be redirected to a .aml file
+    if !quiet && matching.len() > 1 && output != TableOutput::Raw {
+        println!(
+            "{}@{} of {} (OEM table ID {})",
+            signature,
+            index,
+            matching.len(),
+            info.table_id.trim()
+        );
+    }
+
+    let data = match api::get_acpi_table(info) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("Failed to read {}: {}", signature, e);
+            return EXIT_FAILURE;
+        }
+    };
+    // The MSDM bytes hold the product key in plain text
+    let hide_bytes = redact && signature == "MSDM";
+    if output != TableOutput::Parsed && hide_bytes {
+        eprintln!("--redact leaves out the MSDM bytes, which hold the product key");
+        return EXIT_FAILURE;
+    }
+    match output {
+        TableOutput::Hexdump => {
+            print!("{}", report::hex_dump_str(&data, 0));
+            return EXIT_OK;
+        }
+        TableOutput::Raw => return write_raw(&signature, &data),
+        TableOutput::Parsed => {}
+    }
+    let Some(mut fields) = report::acpi_table_fields(&data) else {
+        eprintln!("Failed to parse {}: invalid ACPI header", signature);
+        return EXIT_FAILURE;
+    };
+    if redact {
+        report::redact_acpi_fields(&signature, &mut fields);
+    }
+
+    if !quiet && !hide_bytes {
+        print!("{}", report::hex_dump_str(&data, 0));
+        println!();
+    }
+    let width = fields.iter().map(|(k, _)| k.len()).max().unwrap_or(0) + 2;
+    for (name, value) in fields {
+        println!("{:<width$}{}", format!("{}:", name), value);
+    }
+    EXIT_OK
+}
+
+/// What `--table` prints.
+#[derive(Clone, Copy, PartialEq)]
+enum TableOutput {
+    /// The hex dump (unless `--quiet`) and the parsed header and detail fields.
+    Parsed,
+    /// Only the hex dump (`--format hexdump`).
+    Hexdump,
+    /// Only the table bytes, unformatted (`--raw`).
+    Raw,
+}
+
+/// Writes a table's bytes to stdout unchanged, for redirecting to a file or piping into
+/// `iasl` and similar tools.
+///
+/// Rust writes stdout bytes as they are, with no newline translation, when it is a file or
+/// pipe. A console would instead try to show the bytes as text, so that is refused.
+///
+/// # Returns
+/// 0 once the bytes are written, 1 if writing fails, 2 if stdout is a console.
+fn write_raw(signature: &str, data: &[u8]) -> i32 {
+    let mut stdout = std::io::stdout().lock();
+    if stdout.is_terminal() {
+        eprintln!(
+            "--raw writes binary data; redirect it to a file (e.g. > {}.aml) or a pipe",
+            signature
+        );
+        return EXIT_USAGE;
+    }
+    if let Err(e) = stdout.write_all(data).and_then(|_| stdout.flush()) {
+        eprintln!("Failed to write {}: {}", signature, e);
+        return EXIT_FAILURE;
+    }
+    EXIT_OK
+}
+
+/// Parses a comma-separated list of SMBIOS structure types such as `0,1,4`.
+fn parse_type_list(list: &str) -> Result<Vec<u8>, String> {
+    list.split(',')
+        .map(|part| {
+            let part = part.trim();
+            part.parse::<u32>()
+                .map_err(|_| format!("Invalid SMBIOS type '{}'", part))
+                .and_then(|n| {
+                    u8::try_from(n)
+                        .map_err(|_| format!("SMBIOS type {} is out of range (0-255)", n))
+                })
+        })
+        .collect()
+}
+
+/// Output format of the `--smbios` dump and the `--acpi` list.
+#[derive(Clone, Copy)]
+enum DumpFormat {
+    /// `dmidecode`-style text, or aligned columns for `--acpi`.
+    Text,
+    /// XML as produced by [`report::smbios_xml`]; `--smbios` only.
+    Xml,
+    /// CSV as produced by [`report::acpi_manifest_csv`]; `--acpi` only.
+    Csv,
+    /// Offset/hex/ASCII dumps of the bytes instead of the parsed fields.
+    Hexdump,
+}
+
+/// Prints the ACPI table manifest: one line per table without its contents.
+///
+/// With [`DumpFormat::Hexdump`] every table's bytes are dumped instead; `redact` then
+/// leaves out the MSDM, which holds the product key.
+fn run_acpi(source: api::AcpiSource, format: DumpFormat, redact: bool) -> i32 {
+    let tables = match api::load_acpi_tables_checked(source) {
+        Ok(tables) if tables.is_empty() => {
+            eprintln!("No ACPI tables found");
+            return EXIT_FAILURE;
+        }
+        Ok(tables) => tables,
+        Err(e) => {
+            eprintln!("ACPI enumeration failed: {}", e);
+            return EXIT_FAILURE;
+        }
+    };
+    let rows = report::acpi_manifest_rows(&tables, api::get_acpi_table);
+    match format {
+        DumpFormat::Text => print!("{}", report::acpi_manifest_text(&rows)),
+        DumpFormat::Csv => print!("{}", report::acpi_manifest_csv(&rows)),
+        DumpFormat::Hexdump => print!(
+            "{}",
+            report::acpi_hex_dump_text(&tables, redact, api::get_acpi_table)
+        ),
+        DumpFormat::Xml => {
+            eprintln!("--acpi supports --format text, csv, or hexdump");
+            return EXIT_USAGE;
+        }
+    }
+    EXIT_OK
+}
+
+/// Prints the parsed SMBIOS structures, limited to `types` unless it is empty.
+///
+/// With [`DumpFormat::Hexdump`] the structures' bytes are dumped instead, which `redact`
+/// cannot mask.
+fn run_smbios(types: &[u8], format: DumpFormat, redact: bool) -> i32 {
+    if redact && matches!(format, DumpFormat::Hexdump) {
+        eprintln!("--redact cannot mask the raw bytes of --format hexdump");
+        return EXIT_USAGE;
+    }
+    match api::get_smbios_data() {
+        Ok(data) => {
+            let walk = parsers::try_walk_smbios_structures(&data);
+            if let Err(e) = &walk {
+                eprintln!("{}", e);
+            }
+            let encoding = parsers::StringEncoding::default();
+            match format {
+                DumpFormat::Text => print!(
+                    "{}",
+                    report::smbios_dump_text_filtered(&data, encoding, types, redact)
+                ),
+                DumpFormat::Xml => print!("{}", report::smbios_xml(&data, encoding, types, redact)),
+                DumpFormat::Hexdump => print!("{}", report::smbios_hex_dump_text(&data, types)),
+                DumpFormat::Csv => {
+                    eprintln!("--smbios supports --format text, xml, or hexdump");
+                    return EXIT_USAGE;
+                }
+            }
+            if walk.is_ok() {
+                EXIT_OK
+            } else {
+                EXIT_FAILURE
+            }
+        }
+        Err(e) => {
+            eprintln!("SMBIOS read failed: {}", e);
+            EXIT_FAILURE
+        }
+    }
+}
+
+/// Prints one line per SMBIOS structure type present, with its count.
+fn run_smbios_summary() -> i32 {
+    match api::get_smbios_data() {
+        Ok(data) => {
+            let walk = parsers::try_walk_smbios_structures(&data);
+            if let Err(e) = &walk {
+                eprintln!("{}", e);
+            }
+            print!("{}", report::smbios_type_summary(&data));
+            if walk.is_ok() {
+                EXIT_OK
+            } else {
+                EXIT_FAILURE
+            }
+        }
+        Err(e) => {
+            eprintln!("SMBIOS read failed: {}", e);
+            EXIT_FAILURE
+        }
+    }
+}
+
+/// Prints the processor package summary, e.g. `2 sockets, 2x 24C/48T = 48C/96T`.
+///
+/// Unless `quiet`, one line per Type 4 structure follows with its designator, status,
+/// core and thread counts, and model.
+///
+/// # Returns
+/// 0 on success, 1 if SMBIOS cannot be read or has no processor structures.
+fn run_cpu_summary(quiet: bool) -> i32 {
+    let data = match api::get_smbios_data() {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("SMBIOS read failed: {}", e);
+            return EXIT_FAILURE;
+        }
+    };
+    let encoding = parsers::StringEncoding::default();
+    let Some(summary) = report::cpu_summary(&data, encoding) else {
+        eprintln!("No processor (Type 4) structures found");
+        return EXIT_FAILURE;
+    };
+    println!("{}", summary);
+    if !quiet {
+        for package in report::cpu_packages(&data, encoding) {
+            let status = if package.populated {
+                "Populated"
+            } else {
+                "Empty"
+            };
+            let counts = match package.counts {
+                Some((cores, threads)) => format!("{}C/{}T", cores, threads),
+                None => "-".to_string(),
+            };
+            let line = format!(
+                "  {:<12}{:<11}{:<12}{}",
+                package.designator, status, counts, package.version
+            );
+            println!("{}", line.trim_end());
+        }
+    }
+    EXIT_OK
+}
+
+/// Checks the firmware for virtual machine markers.
+///
+/// # Returns
+/// 0 if markers were found, 1 otherwise. In quiet mode only the hypervisor name is printed.
+/// [`run`] only uses the result as the final exit status, so bare metal does not stop the
+/// commands after it.
+fn run_detect_vm(source: api::AcpiSource, quiet: bool) -> i32 {
+    let tables = api::load_acpi_tables(source);
+    let smbios = api::get_smbios_data().unwrap_or_default();
+    match report::detect_vm(&tables, &smbios) {
+        Some(vm) => {
+            if quiet {
+                println!("{}", vm.hypervisor);
+            } else {
+                println!("Likely virtual: {} ({})", vm.hypervisor, vm.evidence);
+            }
+            EXIT_OK
+        }
+        None => {
+            if !quiet {
+                println!("No virtual machine markers found");
+            }
+            EXIT_FAILURE
+        }
+    }
+}
+
+/// Prints the flattened inventory record of this machine as pretty-printed JSON.
+fn run_system_info(redact: bool) -> i32 {
+    let mut info = report::collect_system_info();
+    if redact {
+        info.redact();
+    }
+    match serde_json::to_string_pretty(&info) {
+        Ok(text) => {
+            println!("{}", text);
+            EXIT_OK
+        }
+        Err(e) => {
+            eprintln!("Failed to serialize system info: {}", e);
+            EXIT_FAILURE
+        }
+    }
+}
+
+/// Enumerates the PCI functions of every ECAM window listed in the MCFG table.
+///
+/// Reading ECAM needs physical memory access, which standard Windows does not give
+/// applications, so this reports why it is unavailable unless [`api::read_physical_memory`]
+/// succeeds.
+///
+/// # Returns
+/// 0 if the windows were enumerated, 1 if MCFG is missing or the memory cannot be read.
+fn run_pci(source: api::AcpiSource, quiet: bool) -> i32 {
+    let tables = api::load_acpi_tables(source);
+    let Some(info) = tables.iter().find(|t| t.signature == "MCFG") else {
+        eprintln!("Table MCFG not found; this system does not describe any ECAM windows");
+        return EXIT_FAILURE;
+    };
+    let data = match api::get_acpi_table(info) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("Failed to read MCFG: {}", e);
+            return EXIT_FAILURE;
+        }
+    };
+    let regions = parsers::parse_mcfg_regions(&data);
+    if regions.is_empty() {
+        eprintln!("MCFG lists no ECAM windows");
+        return EXIT_FAILURE;
+    }
+
+    let smbios = api::get_smbios_data().unwrap_or_default();
+    let slots = report::smbios_pci_locations(&smbios, parsers::StringEncoding::default());
+    for region in &regions {
+        if !quiet {
+            println!(
+                "Segment {:04X}, Buses {:02X}-{:02X}",
+                region.segment, region.start_bus, region.end_bus
+            );
+        }
+        for bus in region.start_bus..=region.end_bus {
+            for device in 0..32 {
+                for function in 0..8 {
+                    let Some(address) = region.config_address(bus, device, function) else {
+                        continue;
+                    };
+                    let config = match api::read_physical_memory(address, 4096) {
+                        Ok(config) => config,
+                        Err(e) => {
+                            eprintln!("PCI enumeration unavailable: {}", e);
+                            return EXIT_FAILURE;
+                        }
+                    };
+                    if parsers::pci_config_functions(&config, bus).is_empty() {
+                        continue;
+                    }
+                    // Only segment 0 can be matched against SMBIOS slot addresses below
+                    let slots = if region.segment == 0 { &slots[..] } else { &[] };
+                    println!(
+                        "{}",
+                        report::pci_function_text(Some((bus, device, function)), &config, slots)
+                    );
+                }
+            }
+        }
+    }
+    EXIT_OK
+}
+
+/// Decodes a PCI configuration space dump, cross-referenced with this machine's SMBIOS slots.
+///
+/// # Returns
+/// 0 if the dump held at least one PCI function, 1 otherwise.
+fn run_pci_config(path: &str) -> i32 {
+    let data = match std::fs::read(path) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("Failed to read {}: {}", path, e);
+            return EXIT_FAILURE;
+        }
+    };
+    if parsers::pci_config_functions(&data, 0).is_empty() {
+        eprintln!(
+            "No PCI functions found in {} (expected a 64, 256, or 4096-byte configuration \
+             space dump or a 4 KB-per-function ECAM window dump)",
+            path
+        );
+        return EXIT_FAILURE;
+    }
+    let smbios = api::get_smbios_data().unwrap_or_default();
+    print!(
+        "{}",
+        report::pci_config_text(&data, &smbios, parsers::StringEncoding::default())
+    );
+    EXIT_OK
+}
+
+/// Checks every ACPI table checksum, including the RSDP and XSDT, and walks SMBIOS.
+///
+/// Prints one `PASS`, `FAIL`, or `SKIP` line per check followed by a summary line; in quiet
+/// mode only failures and the summary are printed.
+///
+/// # Returns
+/// 0 if nothing failed, 1 otherwise.
+fn run_verify(source: api::AcpiSource, quiet: bool) -> i32 {
+    let mut results = Vec::new();
+    let tables = api::load_acpi_tables(source);
+    for sig in ["RSDP", "XSDT"] {
+        if !tables.iter().any(|t| t.signature == sig) {
+            results.push((
+                format!("ACPI {}", sig),
+                report::VerifyOutcome::Skip("not exposed by the firmware table API".to_string()),
+            ));
+        }
+    }
+    for info in &tables {
+        let name = match info.table_id.trim() {
+            "" => format!("ACPI {}", info.signature),
+            id => format!("ACPI {} {}", info.signature, id),
+        };
+        let outcome = match api::get_acpi_table(info) {
+            Ok(data) => report::verify_acpi_checksum(&info.signature, &data),
+            Err(e) => report::VerifyOutcome::Fail(format!("read failed: {}", e)),
+        };
+        results.push((name, outcome));
+    }
+    let smbios = match api::get_smbios_data() {
+        Ok(data) => report::verify_smbios(&data),
+        Err(e) => report::VerifyOutcome::Fail(format!("read failed: {}", e)),
+    };
+    results.push(("SMBIOS".to_string(), smbios));
+
+    let (mut passed, mut failed, mut skipped) = (0, 0, 0);
+    for (name, outcome) in &results {
+        let (status, detail) = match outcome {
+            report::VerifyOutcome::Pass(d) => {
+                passed += 1;
+                ("PASS", d)
+            }
+            report::VerifyOutcome::Fail(d) => {
+                failed += 1;
+                ("FAIL", d)
+            }
+            report::VerifyOutcome::Skip(d) => {
+                skipped += 1;
+                ("SKIP", d)
+            }
+        };
+        if !quiet || status == "FAIL" {
+            println!("{}  {}: {}", status, name, detail);
+        }
+    }
+    println!(
+        "verify: {} passed, {} failed, {} skipped",
+        passed, failed, skipped
+    );
+
+    if failed == 0 {
+        EXIT_OK
+    } else {
+        EXIT_FAILURE
+    }
+}
+
+/// Compares the current system against a reference `report.json`.
+///
+/// The system is serialized with the same exporter as `--report`, and the two documents are
+/// diffed field by field. Each difference is printed as a `+` (added), `-` (removed), or
+/// `~` (changed) line followed by a summary line. With `redact` the current system is
+/// redacted too, so it can be checked against a reference captured with `--redact`.
+///
+/// # Returns
+/// 0 if the reports match, 1 if they differ or the reference cannot be read.
+fn run_compare(reference_path: &str, source: api::AcpiSource, redact: bool, quiet: bool) -> i32 {
+    let reference: serde_json::Value = match std::fs::read_to_string(reference_path)
+        .map_err(|e| e.to_string())
+        .and_then(|text| serde_json::from_str(&text).map_err(|e| e.to_string()))
+    {
+        Ok(value) => value,
+        Err(e) => {
+            eprintln!("Failed to read {}: {}", reference_path, e);
+            return EXIT_FAILURE;
+        }
+    };
+
+    let tables = api::load_acpi_tables(source);
+    let smbios = match api::get_smbios_data() {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("Failed to read SMBIOS data: {}", e);
+            Vec::new()
+        }
+    };
+    let current = report::report_json(
+        &tables,
+        &smbios,
+        parsers::StringEncoding::default(),
+        redact,
+        &BTreeMap::new(),
+    );
+
+    let differences = report::diff_json(&reference, &current);
+    for difference in &differences {
+        println!("{}", difference.describe());
+    }
+    if !quiet || !differences.is_empty() {
+        let count = |f: fn(&report::JsonChange) -> bool| {
+            differences.iter().filter(|d| f(&d.change)).count()
+        };
+        println!(
+            "compare: {} added, {} removed, {} changed",
+            count(|c| matches!(c, report::JsonChange::Added(_))),
+            count(|c| matches!(c, report::JsonChange::Removed(_))),
+            count(|c| matches!(c, report::JsonChange::Changed(..))),
+        );
+    }
+
+    if differences.is_empty() {
+        EXIT_OK
+    } else {
+        EXIT_FAILURE
+    }
+}
+
+/// Reads one side of `--smbios-diff`: `live` for this system, otherwise a dump file.
+///
+/// # Returns
+/// The blob in the Windows `RawSMBIOSData` layout, or a message saying why it is unusable.
+fn load_smbios_side(arg: &str) -> Result<Vec<u8>, String> {
+    if arg == "live" {
+        return api::get_smbios_data().map_err(|e| format!("Failed to read SMBIOS data: {}", e));
+    }
+    let data = std::fs::read(arg).map_err(|e| format!("Failed to read {}: {}", arg, e))?;
+    match parsers::detect_firmware_file(&data) {
+        parsers::FirmwareFileKind::Smbios(blob) => Ok(blob),
+        _ => Err(format!("{} is not an SMBIOS dump", arg)),
+    }
+}
+
+/// Diffs two SMBIOS blobs structure by structure and prints the result grouped by type.
+///
+/// Each side is `live` or a dump file (`smbios_raw.bin`, `smbios_table.bin`, or
+/// `dmidecode --dump-bin` output); see [`report::diff_smbios`] for how structures are matched.
+///
+/// # Returns
+/// 0 if no structure differs, 1 if any does or a side cannot be read.
+fn run_smbios_diff(a: &str, b: &str, quiet: bool) -> i32 {
+    let (data_a, data_b) = match (load_smbios_side(a), load_smbios_side(b)) {
+        (Ok(data_a), Ok(data_b)) => (data_a, data_b),
+        (Err(e), _) | (_, Err(e)) => {
+            eprintln!("{}", e);
+            return EXIT_FAILURE;
+        }
+    };
+    let diffs = report::diff_smbios(&data_a, &data_b, parsers::StringEncoding::default());
+    if quiet && diffs.is_empty() {
+        return EXIT_OK;
+    }
+    if !quiet {
+        println!("A: {}", a);
+        println!("B: {}", b);
+    }
+    print!("{}", report::smbios_diff_text(&diffs));
+    if diffs.is_empty() {
+        EXIT_OK
+    } else {
+        EXIT_FAILURE
+    }
+}
+
+/// Re-reads the firmware every `interval` and prints what changed since the previous read.
+///
+/// Each read is a [`report::watch_snapshot`], diffed against the previous one with
+/// [`report::diff_json`]; every difference is printed as a timestamped `+`, `-`, or `~` line.
+/// A read that fails is reported and skipped. Runs until the process is stopped with Ctrl+C.
+///
+/// # Returns
+/// 1 if the first read fails; otherwise it does not return.
+fn run_watch(
+    interval: Duration,
+    source: api::AcpiSource,
+    redact: bool,
+    verbose: bool,
+    quiet: bool,
+) -> i32 {
+    let read = || -> Result<serde_json::Value, String> {
+        let smbios =
+            api::get_smbios_data().map_err(|e| format!("Failed to read SMBIOS data: {}", e))?;
+        let tables = api::load_acpi_tables(source);
+        Ok(report::watch_snapshot(
+            &tables,
+            &smbios,
+            parsers::StringEncoding::default(),
+            redact,
+            api::get_acpi_table,
+        ))
+    };
+    let mut previous = match read() {
+        Ok(snapshot) => snapshot,
+        Err(e) => {
+            eprintln!("{}", e);
+            return EXIT_FAILURE;
+        }
+    };
+    if !quiet {
+        eprintln!(
+            "[{}] Watching every {}s; press Ctrl+C to stop",
+            api::local_time(),
+            interval.as_secs()
+        );
+    }
+    loop {
+        std::thread::sleep(interval);
+        let time = api::local_time();
+        let current = match read() {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                eprintln!("[{}] {}", time, e);
+                continue;
+            }
+        };
+        let differences = report::diff_json(&previous, &current);
+        if differences.is_empty() && verbose {
+            println!("[{}] No changes", time);
+        }
+        for difference in &differences {
+            println!("[{}] {}", time, difference.describe());
+        }
+        previous = current;
+    }
+}
+
+/// Runs the command-line interface.
+///
+/// Commands run in the order given; the first one that fails stops the run. `--detect-vm`
+/// finding no virtual machine is not a failure and only sets the final exit status.
+///
+/// # Arguments
+/// * `args` - The command-line arguments, excluding the program name.
+///
+/// # Returns
+/// The process exit code: [`EXIT_OK`], [`EXIT_FAILURE`], or [`EXIT_USAGE`].
+pub fn run(args: &[String]) -> i32 {
+    let quiet = args.iter().any(|a| a == "-q" || a == "--quiet");
+    let strip_wrapper = args.iter().any(|a| a == "--strip-wrapper");
+    let redact = args.iter().any(|a| a == "--redact");
+    let types = match args.iter().position(|a| a == "--type") {
+        Some(i) => match args.get(i + 1).map(|list| parse_type_list(list)) {
+            Some(Ok(types)) => types,
+            Some(Err(e)) => {
+                eprintln!("{}", e);
+                return EXIT_USAGE;
+            }
+            None => {
+                eprintln!("--type requires a comma-separated list of SMBIOS types");
+                return EXIT_USAGE;
+            }
+        },
+        None => Vec::new(),
+    };
+    let format = match args.iter().position(|a| a == "--format") {
+        Some(i) => match args.get(i + 1).map(String::as_str) {
+            Some("text") => DumpFormat::Text,
+            Some("xml") => DumpFormat::Xml,
+            Some("csv") => DumpFormat::Csv,
+            Some("hexdump") => DumpFormat::Hexdump,
+            Some(other) => {
+                eprintln!(
+                    "Unknown format '{}' (expected text, xml, csv, or hexdump)",
+                    other
+                );
+                return EXIT_USAGE;
+            }
+            None => {
+                eprintln!("--format requires text, xml, csv, or hexdump");
+                return EXIT_USAGE;
+            }
+        },
+        None => DumpFormat::Text,
+    };
+    let source = match args.iter().position(|a| a == "--source") {
+        Some(i) => match args.get(i + 1).map(|key| api::AcpiSource::from_key(key)) {
+            Some(Some(source)) => source,
+            Some(None) => {
+                eprintln!(
+                    "Unknown source '{}' (expected registry, api, or both)",
+                    args[i + 1]
+                );
+                return EXIT_USAGE;
+            }
+            None => {
+                eprintln!("--source requires registry, api, or both");
+                return EXIT_USAGE;
+            }
+        },
+        None => api::AcpiSource::default(),
+    };
+    let verbose = args.iter().any(|a| a == "-v" || a == "--verbose");
+    let raw = args.iter().any(|a| a == "--raw");
+    if raw && !args.iter().any(|a| a == "--table") {
+        eprintln!("--raw requires --table");
+        return EXIT_USAGE;
+    }
+    let table_output = match (raw, format) {
+        (true, DumpFormat::Text) => TableOutput::Raw,
+        (true, _) => {
+            eprintln!("--raw cannot be combined with --format");
+            return EXIT_USAGE;
+        }
+        (false, DumpFormat::Hexdump) => TableOutput::Hexdump,
+        (false, _) => TableOutput::Parsed,
+    };
+    let log_file = match args.iter().position(|a| a == "--log-file") {
+        Some(i) => match args.get(i + 1) {
+            Some(path) => Some(Path::new(path)),
+            None => {
+                eprintln!("--log-file requires a file argument");
+                return EXIT_USAGE;
+            }
+        },
+        None => None,
+    };
+    let log_level = match args.iter().position(|a| a == "--log-level") {
+        Some(i) => match args.get(i + 1).map(|level| logging::parse_level(level)) {
+            Some(Some(level)) => level,
+            Some(None) => {
+                eprintln!(
+                    "Unknown log level '{}' (expected off, error, warn, info, debug, or trace)",
+                    args[i + 1]
+                );
+                return EXIT_USAGE;
+            }
+            None => {
+                eprintln!("--log-level requires off, error, warn, info, debug, or trace");
+                return EXIT_USAGE;
+            }
+        },
+        None if log_file.is_some() => log::LevelFilter::Info,
+        None => log::LevelFilter::Off,
+    };
+    if log_level != log::LevelFilter::Off {
+        if let Err(e) = logging::init(log_level, true, log_file) {
+            eprintln!("Failed to create log file: {}", e);
+            return EXIT_FAILURE;
+        }
+        log::info!("Command line: {}", args.join(" "));
+    }
+    // Not finding a VM is an answer rather than a failure, so it only sets the final status
+    let mut detect_vm_code = EXIT_OK;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let code = match arg.as_str() {
+            "--report" => match iter.next() {
+                Some(dir) => run_report(
+                    dir,
+                    report::ReportOptions {
+                        source,
+                        encoding: parsers::StringEncoding::default(),
+                        strip_wrapper,
+                        redact,
+                        notes: BTreeMap::new(),
+                    },
+                    quiet,
+                ),
+                None => {
+                    eprintln!("--report requires a directory argument");
+                    EXIT_USAGE
+                }
+            },
+            "--table" => match iter.next() {
+                Some(signature) => run_table(signature, source, redact, quiet, table_output),
+                None => {
+                    eprintln!("--table requires a table signature argument");
+                    EXIT_USAGE
+                }
+            },
+            "--compare" => match iter.next() {
+                Some(path) => run_compare(path, source, redact, quiet),
+                None => {
+                    eprintln!("--compare requires a reference report.json argument");
+                    EXIT_USAGE
+                }
+            },
+            "--smbios-diff" => match (iter.next(), iter.next()) {
+                (Some(a), Some(b)) => run_smbios_diff(a, b, quiet),
+                _ => {
+                    eprintln!("--smbios-diff requires two arguments: files or `live`");
+                    EXIT_USAGE
+                }
+            },
+            "--watch" => match iter.next().map(|secs| secs.parse::<u64>()) {
+                Some(Ok(secs)) if secs > 0 => {
+                    run_watch(Duration::from_secs(secs), source, redact, verbose, quiet)
+                }
+                Some(_) => {
+                    eprintln!("--watch requires a whole number of seconds greater than 0");
+                    EXIT_USAGE
+                }
+                None => {
+                    eprintln!("--watch requires an interval in seconds");
+                    EXIT_USAGE
+                }
+            },
+            "--pci-config" => match iter.next() {
+                Some(path) => run_pci_config(path),
+                None => {
+                    eprintln!("--pci-config requires a dump file argument");
+                    EXIT_USAGE
+                }
+            },
+            "--smbios" => run_smbios(&types, format, redact),
+            "--acpi" => run_acpi(source, format, redact),
+            "--smbios-summary" => run_smbios_summary(),
+            "--cpu-summary" => run_cpu_summary(quiet),
+            "--system-info" => run_system_info(redact),
+            "--detect-vm" => {
+                detect_vm_code = run_detect_vm(source, quiet);
+                EXIT_OK
+            }
+            "--verify" => run_verify(source, quiet),
+            "--pci" => run_pci(source, quiet),
+            "-q" | "--quiet" | "-v" | "--verbose" | "--strip-wrapper" | "--redact" | "--raw" => {
+                EXIT_OK
+            }
+            // Already parsed above; skip its value
+            "--type" | "--format" | "--source" | "--log-level" | "--log-file" => {
+                iter.next();
+                EXIT_OK
+            }
+            "-h" | "--help" => {
+                print_usage();
+                EXIT_OK
+            }
+            other => {
+                eprintln!("Unknown argument: {}", other);
+                print_usage();
+                EXIT_USAGE
+            }
+        };
+        if code != EXIT_OK {
+            return code;
+        }
+    }
+    detect_vm_code
+}
@@ -0,0 +1,84 @@
+use std::fmt;
+
+/// Win32 `ERROR_ACCESS_DENIED`, reported by the firmware and registry APIs without elevation.
+pub const ERROR_ACCESS_DENIED: u32 = 5;
+/// Win32 `ERROR_PRIVILEGE_NOT_HELD`, reported instead of access denied by some firmware
+/// table providers when the process lacks the required privilege.
+pub const ERROR_PRIVILEGE_NOT_HELD: u32 = 1314;
+
+/// Errors returned by the firmware table readers in `api` and the parsers in `parsers`.
+///
+/// The GUI and CLI show these through `Display`; callers that need to react to a specific
+/// failure, such as offering to restart elevated on [`DumperError::NotAdmin`], can match on
+/// the variant instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DumperError {
+    /// The operation was denied because the process is not running as Administrator.
+    NotAdmin,
+    /// A registry key could not be opened; holds the path under `HKEY_LOCAL_MACHINE`.
+    KeyOpenFailed(String),
+    /// The requested table, registry value, or object does not exist.
+    TableNotFound(String),
+    /// The data is shorter than the structure being read.
+    TooShort {
+        /// Number of bytes the structure needs.
+        need: usize,
+        /// Number of bytes available.
+        got: usize,
+    },
+    /// The data does not carry the expected signature.
+    BadSignature {
+        /// The signature that was expected.
+        expected: String,
+        /// The signature that was found.
+        got: String,
+    },
+    /// A Windows API call failed with this Win32 error code or `HRESULT`.
+    WinApi(u32),
+    /// The data is structurally invalid, e.g. an SMBIOS table that exceeds the walk limits.
+    Malformed(String),
+    /// The user cancelled the operation before this item was reached.
+    Cancelled,
+    /// Any other failure, described in the message.
+    Other(String),
+}
+
+impl DumperError {
+    /// Maps a Win32 error code to an error, turning access denied and a missing privilege
+    /// into [`DumperError::NotAdmin`].
+    pub fn from_win32(code: u32) -> Self {
+        if code == ERROR_ACCESS_DENIED || code == ERROR_PRIVILEGE_NOT_HELD {
+            DumperError::NotAdmin
+        } else {
+            DumperError::WinApi(code)
+        }
+    }
+}
+
+impl fmt::Display for DumperError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DumperError::NotAdmin => write!(
+                f,
+                "Access denied. Verify the tool is running as Administrator."
+            ),
+            DumperError::KeyOpenFailed(path) => write!(f, "Key open fail: {}", path),
+            DumperError::TableNotFound(name) => write!(f, "{} not found", name),
+            DumperError::TooShort { need, got } => {
+                write!(f, "Data too short: need {} bytes, got {}", need, got)
+            }
+            DumperError::BadSignature { expected, got } => {
+                write!(f, "Bad signature: expected {}, got {}", expected, got)
+            }
+            DumperError::WinApi(code) => {
+                write!(f, "Windows API error {} (0x{:08X})", code, code)
+            }
+            DumperError::Cancelled => write!(f, "Cancelled"),
+            DumperError::Malformed(message) | DumperError::Other(message) => {
+                write!(f, "{}", message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DumperError {}
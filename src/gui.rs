@@ -1,15 +1,31 @@
 use crate::api;
+use crate::error::DumperError;
+use crate::i18n;
 use crate::parsers;
+use crate::report;
+use base64::Engine;
 use eframe::egui;
 use eframe::egui::{Color32, FontId, Galley, TextFormat};
 use eframe::Storage;
-use serde_json::json;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::io::Write;
-use std::sync::Arc;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
 use windows::Win32::UI::Shell::IsUserAnAdmin;
 
 const STATUS_OK: &str = "Ready";
+/// How many paths the File > Recent menu remembers.
+const RECENT_FILES_LIMIT: usize = 10;
+/// Default monospace font size of the hex and parsed views, restored by Ctrl+0.
+const DATA_FONT_SIZE: f32 = 14.0;
+/// Smallest and largest data view font size reachable by zooming.
+const DATA_FONT_RANGE: std::ops::RangeInclusive<f32> = 8.0..=32.0;
+/// How often the UI polls a background load or export while one is in flight.
+const BACKGROUND_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+/// How long the System theme trusts its last registry read before reading the setting again.
+const SYSTEM_THEME_RECHECK: std::time::Duration = std::time::Duration::from_secs(2);
 
 /// Entry point for launching the GUI version of the BIOS Dump Tool.
 ///
@@ -53,6 +69,758 @@ enum Tab {
     Parsed,
 }
 
+/// Color theme choices.
+#[derive(Clone, Copy, PartialEq)]
+enum Theme {
+    /// Follow the Windows app mode (light or dark).
+    System,
+    /// Always light.
+    Light,
+    /// Always dark.
+    Dark,
+}
+
+impl Theme {
+    /// All themes, in the order they are offered in the UI.
+    const ALL: [Theme; 3] = [Theme::System, Theme::Light, Theme::Dark];
+
+    /// Returns the human-readable name of the theme.
+    fn label(self) -> &'static str {
+        match self {
+            Theme::System => i18n::t("System"),
+            Theme::Light => i18n::t("Light"),
+            Theme::Dark => i18n::t("Dark"),
+        }
+    }
+
+    /// Returns the stable identifier used to persist the setting.
+    fn key(self) -> &'static str {
+        match self {
+            Theme::System => "system",
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+        }
+    }
+
+    /// Parses an identifier produced by [`Theme::key`].
+    fn from_key(key: &str) -> Option<Theme> {
+        Theme::ALL.into_iter().find(|t| t.key() == key)
+    }
+}
+
+/// Columns of the SMBIOS field table, each of which it can be sorted by.
+#[derive(Clone, Copy, PartialEq)]
+enum SmbiosFieldColumn {
+    /// Structure type, then table order.
+    Type,
+    /// Structure handle.
+    Handle,
+    /// Field name.
+    Field,
+    /// Field value.
+    Value,
+}
+
+impl SmbiosFieldColumn {
+    /// All columns, left to right.
+    const ALL: [SmbiosFieldColumn; 4] = [
+        SmbiosFieldColumn::Type,
+        SmbiosFieldColumn::Handle,
+        SmbiosFieldColumn::Field,
+        SmbiosFieldColumn::Value,
+    ];
+
+    /// Returns the column header.
+    fn label(self) -> &'static str {
+        match self {
+            SmbiosFieldColumn::Type => i18n::t("Type"),
+            SmbiosFieldColumn::Handle => i18n::t("Handle"),
+            SmbiosFieldColumn::Field => i18n::t("Field"),
+            SmbiosFieldColumn::Value => i18n::t("Value"),
+        }
+    }
+
+    /// Returns the column width in points; the value column takes the rest.
+    fn width(self) -> f32 {
+        match self {
+            SmbiosFieldColumn::Type => 180.0,
+            SmbiosFieldColumn::Handle => 60.0,
+            SmbiosFieldColumn::Field => 200.0,
+            SmbiosFieldColumn::Value => 320.0,
+        }
+    }
+
+    /// Returns the key rows are ordered by in this column; text compares case-insensitively.
+    fn sort_key(self, row: &report::SmbiosFieldRow) -> (u16, String) {
+        match self {
+            SmbiosFieldColumn::Type => (row.type_id as u16, String::new()),
+            SmbiosFieldColumn::Handle => (row.handle, String::new()),
+            SmbiosFieldColumn::Field => (0, row.field.to_lowercase()),
+            SmbiosFieldColumn::Value => (0, row.value.to_lowercase()),
+        }
+    }
+}
+
+/// Sort orders for the ACPI sidebar list.
+#[derive(Clone, Copy, PartialEq)]
+enum AcpiSort {
+    /// Alphabetical by signature, then table ID.
+    Signature,
+    /// Alphabetical by OEM table ID.
+    TableId,
+    /// Largest tables first.
+    Size,
+}
+
+impl AcpiSort {
+    /// All sort orders, in the order they are offered in the UI.
+    const ALL: [AcpiSort; 3] = [AcpiSort::Signature, AcpiSort::TableId, AcpiSort::Size];
+
+    /// Returns the human-readable name of the sort order.
+    fn label(self) -> &'static str {
+        match self {
+            AcpiSort::Signature => i18n::t("Signature"),
+            AcpiSort::TableId => i18n::t("Table ID"),
+            AcpiSort::Size => i18n::t("Size"),
+        }
+    }
+
+    /// Returns the stable identifier used to persist the setting.
+    fn key(self) -> &'static str {
+        match self {
+            AcpiSort::Signature => "signature",
+            AcpiSort::TableId => "table_id",
+            AcpiSort::Size => "size",
+        }
+    }
+
+    /// Parses an identifier produced by [`AcpiSort::key`].
+    fn from_key(key: &str) -> Option<AcpiSort> {
+        AcpiSort::ALL.into_iter().find(|s| s.key() == key)
+    }
+}
+
+/// Raw ACPI table bytes already read this session, keyed by [`table_cache_key`].
+type TableCache = Arc<Mutex<HashMap<String, Vec<u8>>>>;
+
+/// Returns the key identifying a table in the [`TableCache`].
+fn table_cache_key(info: &api::AcpiTableInfo) -> String {
+    match &info.registry_path {
+        Some(path) => path.clone(),
+        None => format!("API:{}", info.signature),
+    }
+}
+
+/// Reads an ACPI table, serving it from `cache` when it was read before.
+///
+/// # Arguments
+/// * `cache` - Shared table cache, filled on a successful read.
+/// * `info` - The table to read.
+///
+/// # Returns
+/// The raw table bytes or the read error.
+fn read_acpi_cached(cache: &TableCache, info: &api::AcpiTableInfo) -> Result<Vec<u8>, DumperError> {
+    let key = table_cache_key(info);
+    if let Some(data) = cache.lock().unwrap().get(&key) {
+        return Ok(data.clone());
+    }
+    let data = api::get_acpi_table(info)?;
+    cache.lock().unwrap().insert(key, data.clone());
+    Ok(data)
+}
+
+/// Returns the filename template placeholders that describe an ACPI table.
+fn acpi_name_fields(info: &api::AcpiTableInfo) -> Vec<(&'static str, String)> {
+    vec![
+        ("sig", info.signature.clone()),
+        ("oem", info.oem_id.clone()),
+        ("table_id", info.table_id.clone()),
+    ]
+}
+
+/// Names each table written by "Export All", one name per table.
+///
+/// The names come from `template` when it is set (see [`report::apply_filename_template`]),
+/// otherwise they are `{sig}_{table_id}.aml`. Tables that would share a name, such as SSDTs
+/// with the same table ID, get a `_2`, `_3`, ... suffix.
+fn acpi_export_file_names(tables: &[api::AcpiTableInfo], template: &str) -> Vec<String> {
+    let date = if template.trim().is_empty() {
+        String::new()
+    } else {
+        api::local_date()
+    };
+    let mut used_names = HashSet::new();
+    tables
+        .iter()
+        .map(|info| {
+            let mut fields = acpi_name_fields(info);
+            fields.push(("date", date.clone()));
+            let fields: Vec<(&str, &str)> = fields.iter().map(|(k, v)| (*k, v.as_str())).collect();
+            let name =
+                report::apply_filename_template(template, &fields, "aml").unwrap_or_else(|| {
+                    format!(
+                        "{}_{}.aml",
+                        report::clean_filename_fragment(&info.signature),
+                        report::clean_filename_fragment(info.table_id.trim())
+                    )
+                });
+            let (stem, extension) = name.rsplit_once('.').unwrap_or((&name, "aml"));
+            let mut unique = name.clone();
+            let mut n = 1;
+            while !used_names.insert(unique.to_lowercase()) {
+                n += 1;
+                unique = format!("{}_{}.{}", stem, n, extension);
+            }
+            unique
+        })
+        .collect()
+}
+
+/// Creates a file dialog for an export, opening in `dir` when one is configured.
+fn export_dialog(dir: &str) -> rfd::FileDialog {
+    let dialog = rfd::FileDialog::new();
+    if dir.is_empty() {
+        dialog
+    } else {
+        dialog.set_directory(dir)
+    }
+}
+
+/// Asks for a destination with a save dialog and writes `text` there, reporting failures.
+///
+/// The dialog opens in `dir` when it is not empty.
+fn save_text_file(dir: &str, default_name: &str, text: &str) {
+    let (filter_name, extension) = if default_name.ends_with(".html") {
+        (i18n::t("HTML Files"), "html")
+    } else if default_name.ends_with(".csv") {
+        ("CSV", "csv")
+    } else {
+        (i18n::t("Text Files"), "txt")
+    };
+    if let Some(path) = export_dialog(dir)
+        .set_file_name(default_name)
+        .add_filter(filter_name, &[extension])
+        .save_file()
+    {
+        match std::fs::File::create(&path) {
+            Ok(mut file) => {
+                if let Err(e) = file.write_all(text.as_bytes()) {
+                    rfd::MessageDialog::new()
+                        .set_title(i18n::t("Export Error"))
+                        .set_description(i18n::tf("Failed to write file: {}", &[&e]))
+                        .set_level(rfd::MessageLevel::Error)
+                        .show();
+                }
+            }
+            Err(e) => {
+                rfd::MessageDialog::new()
+                    .set_title(i18n::t("Export Error"))
+                    .set_description(i18n::tf("Failed to create file: {}", &[&e]))
+                    .set_level(rfd::MessageLevel::Error)
+                    .show();
+            }
+        }
+    }
+}
+
+/// Asks for a destination with a save dialog and writes `data` there, reporting failures.
+///
+/// The dialog opens in `dir` when it is not empty.
+fn save_binary_file(dir: &str, default_name: &str, data: &[u8]) {
+    if let Some(path) = export_dialog(dir).set_file_name(default_name).save_file() {
+        match std::fs::File::create(&path) {
+            Ok(mut file) => {
+                if let Err(e) = file.write_all(data) {
+                    rfd::MessageDialog::new()
+                        .set_title(i18n::t("Export Error"))
+                        .set_description(i18n::tf("Failed to write file: {}", &[&e]))
+                        .set_level(rfd::MessageLevel::Error)
+                        .show();
+                }
+            }
+            Err(e) => {
+                rfd::MessageDialog::new()
+                    .set_title(i18n::t("Export Error"))
+                    .set_description(i18n::tf("Failed to create file: {}", &[&e]))
+                    .set_level(rfd::MessageLevel::Error)
+                    .show();
+            }
+        }
+    }
+}
+
+/// Messages sent from the export-all and full report worker threads to the UI.
+enum ExportMessage {
+    /// Table `index` of `total` (1-based) is being exported.
+    Progress {
+        index: usize,
+        total: usize,
+        signature: String,
+    },
+    /// The export finished; `errors` lists the tables that failed.
+    Finished {
+        success_count: usize,
+        errors: Vec<String>,
+        cancelled: bool,
+    },
+    /// The full system report into `folder` finished.
+    ReportFinished {
+        folder: PathBuf,
+        outcome: report::ReportOutcome,
+    },
+}
+
+/// ACPI and SMBIOS data read on a worker thread for the startup load.
+struct StartupLoad {
+    /// The discovered ACPI tables, or why none were found.
+    tables: Result<Vec<api::AcpiTableInfo>, DumperError>,
+    /// The bytes of each entry in `tables`, in the same order.
+    contents: Vec<Result<Vec<u8>, DumperError>>,
+    /// The SMBIOS blob and where it came from.
+    smbios: Result<(Vec<u8>, api::SmbiosSource), DumperError>,
+}
+
+/// Reads every ACPI table and the SMBIOS blob for the startup load and sends them to the UI.
+///
+/// Runs on a worker thread so the window appears before the firmware reads finish; `ctx` is
+/// used to wake the UI once the data is sent.
+fn startup_load_worker(
+    source: api::AcpiSource,
+    sender: mpsc::Sender<StartupLoad>,
+    ctx: egui::Context,
+) {
+    let tables = api::load_acpi_tables_checked(source);
+    let contents = report::read_tables_concurrently(
+        tables.as_deref().unwrap_or_default(),
+        api::get_acpi_table,
+        &AtomicBool::new(false),
+        |_, _| {},
+    );
+    let smbios = api::get_smbios_data_with_source();
+    let _ = sender.send(StartupLoad {
+        tables,
+        contents,
+        smbios,
+    });
+    ctx.request_repaint();
+}
+
+/// State of a running export-all job.
+struct ExportJob {
+    /// Receives progress from the worker thread.
+    receiver: Receiver<ExportMessage>,
+    /// Number of tables handled so far.
+    index: usize,
+    /// Total number of tables to export.
+    total: usize,
+    /// Signature of the table currently being written.
+    signature: String,
+    /// Set by the Cancel button; the worker stops before its next table or file.
+    cancel: Arc<AtomicBool>,
+}
+
+/// Writes each table in `tables` to the matching path in `paths`, reporting progress over
+/// `sender`.
+///
+/// Runs on a worker thread; `ctx` is used to wake the UI after each message. Tables are
+/// read on a bounded pool first, then written one after another in table order. Once
+/// `cancel` is set no new reads start, and only the tables already read are written.
+fn export_tables_worker(
+    tables: Vec<api::AcpiTableInfo>,
+    paths: Vec<PathBuf>,
+    cache: TableCache,
+    cancel: Arc<AtomicBool>,
+    sender: mpsc::Sender<ExportMessage>,
+    ctx: egui::Context,
+) {
+    let total = tables.len();
+    let mut success_count = 0;
+    let mut errors: Vec<String> = Vec::new();
+
+    let contents = report::read_tables_concurrently(
+        &tables,
+        |info| read_acpi_cached(&cache, info),
+        &cancel,
+        |index, done| {
+            let _ = sender.send(ExportMessage::Progress {
+                index: done,
+                total,
+                signature: tables[index].signature.clone(),
+            });
+            ctx.request_repaint();
+        },
+    );
+
+    for ((info, content), path) in tables.iter().zip(contents).zip(paths) {
+        match content {
+            Ok(data) => match std::fs::File::create(&path) {
+                Ok(mut file) => {
+                    if file.write_all(&data).is_ok() {
+                        success_count += 1;
+                    } else {
+                        errors.push(format!("{}: write failed", info.signature));
+                    }
+                }
+                Err(_) => errors.push(format!("{}: create failed", info.signature)),
+            },
+            Err(DumperError::Cancelled) => {}
+            Err(_) => errors.push(format!("{}: read failed", info.signature)),
+        }
+    }
+
+    let _ = sender.send(ExportMessage::Finished {
+        success_count,
+        errors,
+        cancelled: cancel.load(Ordering::Relaxed),
+    });
+    ctx.request_repaint();
+}
+
+/// Writes a full system report to `folder`, reporting each step over `sender`.
+///
+/// Runs on a worker thread so the UI stays responsive; `cancel` stops the report between
+/// tables and files, keeping what was already written.
+fn full_report_worker(
+    folder: PathBuf,
+    options: report::ReportOptions,
+    cancel: Arc<AtomicBool>,
+    sender: mpsc::Sender<ExportMessage>,
+    ctx: egui::Context,
+) {
+    let outcome = report::write_full_report(&folder, options, &cancel, |index, total, name| {
+        let _ = sender.send(ExportMessage::Progress {
+            index,
+            total,
+            signature: name.to_string(),
+        });
+        ctx.request_repaint();
+    });
+    let _ = sender.send(ExportMessage::ReportFinished { folder, outcome });
+    ctx.request_repaint();
+}
+
+/// A parsed sidebar filter: `type:N`/`#N` and `sig:XXXX` tokens plus free text.
+///
+/// Type tokens narrow the SMBIOS list and signature tokens the ACPI list; once any token
+/// is present, a list without tokens of its own kind is hidden entirely.
+#[derive(Default)]
+struct SidebarFilter {
+    /// Lowercased free text that row labels must contain.
+    text: String,
+    /// SMBIOS structure types from `type:N` or `#N` tokens.
+    types: Vec<u8>,
+    /// Uppercased ACPI signatures from `sig:XXXX` tokens.
+    signatures: Vec<String>,
+}
+
+impl SidebarFilter {
+    /// Splits the filter box text into tokens and free text.
+    fn parse(input: &str) -> Self {
+        let mut filter = SidebarFilter::default();
+        let mut words = Vec::new();
+        for word in input.split_whitespace() {
+            let lower = word.to_lowercase();
+            let type_id = lower
+                .strip_prefix("type:")
+                .or_else(|| lower.strip_prefix('#'))
+                .and_then(|n| n.parse::<u8>().ok());
+            if let Some(type_id) = type_id {
+                filter.types.push(type_id);
+            } else if let Some(sig) = lower.strip_prefix("sig:").filter(|s| !s.is_empty()) {
+                filter.signatures.push(sig.to_uppercase());
+            } else {
+                words.push(lower);
+            }
+        }
+        filter.text = words.join(" ");
+        filter
+    }
+
+    /// Returns true if the filter contains any `type:` or `sig:` token.
+    fn has_tokens(&self) -> bool {
+        !self.types.is_empty() || !self.signatures.is_empty()
+    }
+
+    /// Returns true if a row label contains the free text.
+    fn matches_text(&self, label: &str) -> bool {
+        self.text.is_empty() || label.to_lowercase().contains(&self.text)
+    }
+
+    /// Returns true if an ACPI table row should be shown.
+    fn matches_acpi(&self, signature: &str, label: &str) -> bool {
+        if self.has_tokens() && !self.signatures.iter().any(|s| s == signature) {
+            return false;
+        }
+        self.matches_text(label)
+    }
+
+    /// Returns true if an SMBIOS structure row should be shown.
+    fn matches_smbios(&self, type_id: u8, label: &str) -> bool {
+        if self.has_tokens() && !self.types.contains(&type_id) {
+            return false;
+        }
+        self.matches_text(label)
+    }
+}
+
+/// A selectable sidebar row, in the order rows are drawn.
+#[derive(Clone)]
+enum SidebarEntry {
+    /// An ACPI table row.
+    Acpi(api::AcpiTableInfo),
+    /// An SMBIOS structure row (offset, type_id).
+    Smbios(usize, u8),
+    /// A dropped file row (index into `loaded_files`).
+    File(usize),
+}
+
+/// A sidebar entry pinned to the Pinned section, persisted across runs.
+#[derive(Clone, PartialEq)]
+enum Pin {
+    /// Every ACPI table with this signature.
+    Acpi(String),
+    /// The SMBIOS structure with this type and handle.
+    Smbios(u8, u16),
+}
+
+impl Pin {
+    /// Returns the stable identifier used to persist the pin.
+    fn key(&self) -> String {
+        match self {
+            Pin::Acpi(signature) => format!("acpi:{}", signature),
+            Pin::Smbios(type_id, handle) => format!("smbios:{}:{:04X}", type_id, handle),
+        }
+    }
+
+    /// Parses an identifier produced by [`Pin::key`].
+    fn from_key(key: &str) -> Option<Pin> {
+        if let Some(signature) = key.strip_prefix("acpi:") {
+            return (!signature.is_empty()).then(|| Pin::Acpi(signature.to_string()));
+        }
+        let (type_id, handle) = key.strip_prefix("smbios:")?.split_once(':')?;
+        Some(Pin::Smbios(
+            type_id.parse().ok()?,
+            u16::from_str_radix(handle, 16).ok()?,
+        ))
+    }
+
+    /// Returns the label shown when the pinned entry is not in the loaded data.
+    fn missing_label(&self) -> String {
+        match self {
+            Pin::Acpi(signature) => signature.clone(),
+            Pin::Smbios(type_id, handle) => i18n::tf(
+                "Type {}, Handle 0x{}",
+                &[type_id, &format!("{:04X}", handle)],
+            ),
+        }
+    }
+}
+
+/// Keyboard navigation state for the sidebar.
+#[derive(Default)]
+struct SidebarNav {
+    /// Rows visible in the last frame, ACPI first, skipping filtered and collapsed rows.
+    entries: Vec<SidebarEntry>,
+    /// Index into `entries` of the keyboard-focused row.
+    cursor: Option<usize>,
+    /// Whether the cursor row should be scrolled into view when next drawn.
+    scroll_to_cursor: bool,
+}
+
+impl SidebarNav {
+    /// Records a drawn row and outlines it if it holds the keyboard cursor.
+    fn track(&mut self, ui: &egui::Ui, response: &egui::Response, entry: SidebarEntry) {
+        let index = self.entries.len();
+        self.entries.push(entry);
+        if response.clicked() {
+            self.cursor = Some(index);
+        }
+        if self.cursor == Some(index) {
+            ui.painter().rect_stroke(
+                response.rect.expand(1.0),
+                2.0,
+                egui::Stroke::new(1.5, ui.visuals().warn_fg_color),
+            );
+            if self.scroll_to_cursor {
+                response.scroll_to_me(None);
+                self.scroll_to_cursor = false;
+            }
+        }
+    }
+}
+
+/// An action that can be run from the command palette (Ctrl+K).
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PaletteAction {
+    /// Enumerates the ACPI tables.
+    LoadAcpi,
+    /// Reads the SMBIOS blob.
+    LoadSmbios,
+    /// Re-reads both ACPI and SMBIOS data.
+    Reload,
+    /// Saves the selected item's bytes.
+    ExportRaw,
+    /// Saves the selected item's parsed view.
+    ExportParsed,
+    /// Saves the selected item's hex dump as text.
+    ExportHexDump,
+    /// Saves the bytes selected in the hex view.
+    ExportSelection,
+    /// Opens the selected ACPI table in the external hex editor.
+    OpenInHexEditor,
+    /// Saves the selected SMBIOS structure's fields as JSON.
+    ExportSmbiosJson,
+    /// Saves the selected SMBIOS structure's fields as CSV.
+    ExportSmbiosCsv,
+    /// Saves the whole raw SMBIOS blob.
+    ExportFullSmbios,
+    /// Copies every parsed SMBIOS structure as XML.
+    CopySmbiosXml,
+    /// Copies the selected item's bytes as Base64.
+    CopyBase64,
+    /// Opens the Load from Base64 dialog.
+    LoadBase64,
+    /// Exports every ACPI table as `.aml` into a folder.
+    ExportAllAcpi,
+    /// Saves every SMBIOS structure's parsed view to one file.
+    ExportAllParsedSmbios,
+    /// Saves every ACPI table's parsed view to one file.
+    ExportAllParsedAcpi,
+    /// Saves the ACPI table list as a CSV manifest.
+    ExportAcpiManifest,
+    /// Saves the single-page HTML report.
+    ExportHtmlReport,
+    /// Writes the full capture into a folder.
+    FullSystemReport,
+    /// Saves the HTML report with identifying fields redacted.
+    ExportHtmlReportRedacted,
+    /// Writes the full capture with identifying fields redacted.
+    FullSystemReportRedacted,
+    /// Switches between dark and light mode.
+    ToggleTheme,
+    /// Re-runs the virtual machine heuristic and reports the result.
+    DetectVm,
+    /// Opens or closes the SMBIOS Diff window.
+    ToggleSmbiosDiff,
+    /// Shows or hides the search panel.
+    ToggleSearch,
+    /// Shows or hides the byte statistics panel.
+    ToggleByteStats,
+    /// Opens or closes the Tables by Size window.
+    ToggleFootprint,
+    /// Opens or closes the Table Advisory window.
+    ToggleAdvisory,
+    /// Opens or closes the SMBIOS field table.
+    ToggleSmbiosFields,
+    /// Switches to the hex view.
+    HexView,
+    /// Switches to the parsed view.
+    ParsedView,
+    /// Focuses the sidebar filter box.
+    FilterSidebar,
+}
+
+/// Command palette entries in display order, with the label that filtering matches against.
+const PALETTE_ACTIONS: &[(PaletteAction, &str)] = &[
+    (PaletteAction::LoadAcpi, "Load ACPI Tables"),
+    (PaletteAction::LoadSmbios, "Load SMBIOS Data"),
+    (PaletteAction::Reload, "Reload ACPI and SMBIOS"),
+    (PaletteAction::ExportRaw, "Export Raw Binary"),
+    (PaletteAction::ExportParsed, "Export Parsed"),
+    (PaletteAction::ExportHexDump, "Export Hex Dump"),
+    (PaletteAction::ExportSelection, "Export Selected Bytes"),
+    (PaletteAction::OpenInHexEditor, "Open in Hex Editor"),
+    (
+        PaletteAction::ExportSmbiosJson,
+        "Export Selected SMBIOS as JSON",
+    ),
+    (
+        PaletteAction::ExportSmbiosCsv,
+        "Export Selected SMBIOS as CSV",
+    ),
+    (PaletteAction::ExportFullSmbios, "Export Full SMBIOS Blob"),
+    (PaletteAction::CopySmbiosXml, "Copy SMBIOS as XML"),
+    (PaletteAction::CopyBase64, "Copy as Base64"),
+    (PaletteAction::LoadBase64, "Load from Base64"),
+    (
+        PaletteAction::ExportAllAcpi,
+        "Export All ACPI Tables to AML",
+    ),
+    (
+        PaletteAction::ExportAllParsedSmbios,
+        "Export All Parsed (SMBIOS)",
+    ),
+    (
+        PaletteAction::ExportAllParsedAcpi,
+        "Export All Parsed (ACPI)",
+    ),
+    (PaletteAction::ExportAcpiManifest, "Export ACPI Manifest"),
+    (PaletteAction::ExportHtmlReport, "Export HTML Report"),
+    (PaletteAction::FullSystemReport, "Full System Report"),
+    (
+        PaletteAction::ExportHtmlReportRedacted,
+        "Export HTML Report (Redacted)",
+    ),
+    (
+        PaletteAction::FullSystemReportRedacted,
+        "Full System Report (Redacted)",
+    ),
+    (PaletteAction::ToggleTheme, "Toggle Theme"),
+    (PaletteAction::DetectVm, "Detect Virtual Machine"),
+    (PaletteAction::ToggleSearch, "Toggle Search"),
+    (PaletteAction::ToggleByteStats, "Toggle Byte Stats"),
+    (PaletteAction::ToggleFootprint, "Tables by Size"),
+    (PaletteAction::ToggleAdvisory, "Table Advisory"),
+    (PaletteAction::ToggleSmbiosFields, "All SMBIOS Fields"),
+    (PaletteAction::ToggleSmbiosDiff, "SMBIOS Diff"),
+    (PaletteAction::HexView, "Switch to Hex View"),
+    (PaletteAction::ParsedView, "Switch to Parsed View"),
+    (PaletteAction::FilterSidebar, "Filter Sidebar"),
+];
+
+/// Adds a Windows font with CJK glyphs as a fallback to the egui fonts.
+///
+/// The default egui fonts have no Chinese glyphs, so translated labels would render as
+/// boxes. The font is only loaded once a language that needs it is selected.
+///
+/// # Returns
+/// Whether a font was found and installed.
+fn install_cjk_font(ctx: &egui::Context) -> bool {
+    let fonts_dir = std::env::var("WINDIR").unwrap_or_else(|_| "C:\\Windows".to_string());
+    let Some(data) = ["msjh.ttc", "mingliu.ttc"]
+        .iter()
+        .find_map(|name| std::fs::read(PathBuf::from(&fonts_dir).join("Fonts").join(name)).ok())
+    else {
+        return false;
+    };
+    let mut fonts = egui::FontDefinitions::default();
+    fonts
+        .font_data
+        .insert("cjk".to_string(), egui::FontData::from_owned(data));
+    for family in [egui::FontFamily::Proportional, egui::FontFamily::Monospace] {
+        fonts
+            .families
+            .entry(family)
+            .or_default()
+            .push("cjk".to_string());
+    }
+    ctx.set_fonts(fonts);
+    true
+}
+
+/// Returns the palette entries whose label contains every word of `query`, case-insensitively.
+fn palette_matches(query: &str) -> Vec<(PaletteAction, &'static str)> {
+    let query = query.to_lowercase();
+    PALETTE_ACTIONS
+        .iter()
+        .map(|&(action, label)| (action, i18n::t(label)))
+        .filter(|(_, label)| {
+            let label = label.to_lowercase();
+            query.split_whitespace().all(|word| label.contains(word))
+        })
+        .collect()
+}
+
 /// Tracks the currently selected item in the sidebar.
 enum Selection {
     /// Nothing is selected.
@@ -61,6 +829,165 @@ enum Selection {
     Acpi(api::AcpiTableInfo),
     /// An SMBIOS structure is selected (offset, type_id).
     Smbios(usize, u8),
+    /// A file dropped onto the window is selected (index into `loaded_files`).
+    File(usize),
+}
+
+/// A firmware dump dropped onto the window.
+struct LoadedFile {
+    /// File name shown in the sidebar.
+    name: String,
+    /// The file contents.
+    data: Vec<u8>,
+    /// What the contents were detected as.
+    kind: parsers::FirmwareFileKind,
+}
+
+impl LoadedFile {
+    /// Returns the sidebar label of the file, naming what it was detected as.
+    fn label(&self) -> String {
+        match self.kind {
+            parsers::FirmwareFileKind::AcpiTable => {
+                format!(
+                    "{} (ACPI {})",
+                    self.name,
+                    String::from_utf8_lossy(&self.data[0..4])
+                )
+            }
+            parsers::FirmwareFileKind::Smbios(_) => format!("{} (SMBIOS)", self.name),
+            parsers::FirmwareFileKind::PciConfig => format!("{} (PCI config)", self.name),
+            parsers::FirmwareFileKind::Unknown => format!("{} (unknown)", self.name),
+        }
+    }
+}
+
+/// One side of the SMBIOS Diff window.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DiffSide {
+    /// The SMBIOS data read from this system.
+    Live,
+    /// An SMBIOS dump among the loaded files (index into `loaded_files`).
+    File(usize),
+}
+
+/// Explains in a sidebar section why its data could not be loaded.
+///
+/// Access denied without elevation gets an inline "Restart as Administrator" button, so the
+/// fix sits next to the empty list instead of only in the top banner.
+///
+/// # Returns
+/// Whether the restart button was clicked.
+fn load_failure_notice(ui: &mut egui::Ui, error: &DumperError, is_admin: bool) -> bool {
+    let red = Color32::from_rgb(200, 50, 50);
+    match error {
+        DumperError::NotAdmin if !is_admin => {
+            ui.colored_label(
+                red,
+                i18n::t(
+                    "Access denied: reading firmware tables requires Administrator privileges.",
+                ),
+            );
+            ui.button(i18n::t("🛡 Restart as Administrator")).clicked()
+        }
+        DumperError::NotAdmin => {
+            ui.colored_label(
+                red,
+                i18n::t("Access denied even though the tool is running as Administrator."),
+            );
+            false
+        }
+        e => {
+            ui.colored_label(red, i18n::tf("Load failed: {}", &[e]));
+            false
+        }
+    }
+}
+
+/// Draws one structure of the SMBIOS Diff tree: a `-`/`+` line for a structure on one side
+/// only, or a collapsible field table for a changed one.
+fn show_smbios_structure_diff(ui: &mut egui::Ui, diff: &report::SmbiosStructureDiff) {
+    let title = format!("{} ({})", diff.handle_str(), diff.label);
+    match &diff.change {
+        report::SmbiosStructureChange::OnlyInA => {
+            ui.colored_label(
+                Color32::from_rgb(200, 50, 50),
+                i18n::tf("- {} only in A", &[&title]),
+            );
+        }
+        report::SmbiosStructureChange::OnlyInB => {
+            ui.colored_label(
+                Color32::from_rgb(60, 160, 60),
+                i18n::tf("+ {} only in B", &[&title]),
+            );
+        }
+        report::SmbiosStructureChange::Changed(fields) => {
+            egui::CollapsingHeader::new(format!("~ {}", title))
+                .id_salt(("smbios_diff", diff.type_id, diff.handle_a, diff.handle_b))
+                .show(ui, |ui| {
+                    egui::Grid::new(("smbios_diff_fields", diff.handle_a, diff.handle_b))
+                        .num_columns(3)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.strong(i18n::t("Field"));
+                            ui.strong("A");
+                            ui.strong("B");
+                            ui.end_row();
+                            let absent = || i18n::t("(absent)").to_string();
+                            for field in fields {
+                                ui.label(&field.field);
+                                ui.monospace(field.a.clone().unwrap_or_else(absent));
+                                ui.monospace(field.b.clone().unwrap_or_else(absent));
+                                ui.end_row();
+                            }
+                        });
+                });
+        }
+    }
+}
+
+/// Decodes pasted Base64 text into an ACPI table or SMBIOS dump.
+///
+/// Whitespace is ignored so that text wrapped by a mail or chat client still decodes.
+///
+/// # Returns
+/// The decoded bytes and what they were detected as, or a message describing why the text
+/// was rejected.
+fn decode_base64_dump(text: &str) -> Result<(Vec<u8>, parsers::FirmwareFileKind), String> {
+    let compact: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+    if compact.is_empty() {
+        return Err(
+            i18n::t("Paste the Base64 text of an ACPI table or SMBIOS dump first.").to_string(),
+        );
+    }
+    let data = base64::engine::general_purpose::STANDARD
+        .decode(compact)
+        .map_err(|e| i18n::tf("Not valid Base64: {}", &[&e]))?;
+    match parsers::detect_firmware_file(&data) {
+        parsers::FirmwareFileKind::Unknown => Err(i18n::tf(
+            "The decoded {} bytes are neither an ACPI table nor an SMBIOS dump.",
+            &[&data.len()],
+        )),
+        kind => Ok((data, kind)),
+    }
+}
+
+/// Renders the parsed view of a dropped file's (possibly edited) contents.
+///
+/// PCI configuration space dumps are cross-referenced with the slots in `smbios`.
+fn file_parsed_text(
+    data: &[u8],
+    tables: &[api::AcpiTableInfo],
+    smbios: &[u8],
+    encoding: parsers::StringEncoding,
+) -> String {
+    match parsers::detect_firmware_file(data) {
+        parsers::FirmwareFileKind::AcpiTable => report::acpi_table_text(data, tables, false),
+        parsers::FirmwareFileKind::Smbios(blob) => report::smbios_dump_text(&blob, encoding, false),
+        parsers::FirmwareFileKind::PciConfig => report::pci_config_text(data, smbios, encoding),
+        parsers::FirmwareFileKind::Unknown => {
+            i18n::t("Unrecognized file: not an ACPI table or SMBIOS dump.").to_string()
+        }
+    }
 }
 
 #[allow(dead_code)]
@@ -77,20 +1004,156 @@ impl Selection {
 struct DumpApp {
     /// List of discovered ACPI tables.
     acpi_tables: Option<Vec<api::AcpiTableInfo>>,
+    /// Why the last ACPI load found no tables, if it failed.
+    acpi_load_error: Option<DumperError>,
+    /// Why the last SMBIOS load failed, if it did.
+    smbios_load_error: Option<DumperError>,
+    /// ACPI tables arranged by how they reference each other, built on load.
+    acpi_tree: Vec<report::AcpiTreeNode>,
+    /// Whether the ACPI sidebar shows the reference tree instead of signature groups.
+    acpi_tree_view: bool,
+    /// Size in bytes of each entry in `acpi_tables`, read on load.
+    acpi_sizes: Vec<usize>,
+    /// Content hash of each entry in `acpi_tables`, or `None` if it could not be read.
+    acpi_hashes: Vec<Option<u64>>,
+    /// Spec compliance warnings for each entry in `acpi_tables`, checked on load.
+    acpi_warnings: Vec<Vec<String>>,
+    /// Sort order of the ACPI sidebar list.
+    acpi_sort: AcpiSort,
+    /// Whether tables sharing a signature are collapsed into one sidebar entry.
+    acpi_group_duplicates: bool,
     /// Raw SMBIOS data buffer.
     smbios_data: Option<Vec<u8>>,
     /// List of parsed SMBIOS structures for the sidebar.
     smbios_list: Vec<(usize, u8, u8, u16, String)>, // offset, type, length, handle, label
+    /// Probes and cooling devices (Types 26-29) for the Sensors panel.
+    sensors: Vec<report::SensorReading>,
+    /// Processor sockets (Type 4, grouped by designator) for the Sockets panel.
+    sockets: Vec<report::ProcessorSocket>,
+    /// Socket and core/thread totals shown above the Sockets panel list.
+    cpu_summary: Option<String>,
+    /// NUMA nodes from the SRAT and SLIT for the NUMA panel.
+    numa: Option<report::NumaTopology>,
+    /// Memory slot population summary shown under the SMBIOS version.
+    memory_summary: Option<String>,
+    /// Serial numbers and UUIDs that look like unfilled placeholders.
+    placeholders: Vec<report::PlaceholderField>,
+    /// Offset and type of each SMBIOS structure, keyed by handle, for jump-to-handle links.
+    smbios_handles: HashMap<u16, (usize, u8)>,
+    /// Cache keys of the ACPI tables that changed on the last reload.
+    changed_acpi: HashSet<String>,
+    /// Handles of the SMBIOS structures that changed on the last reload.
+    changed_smbios: HashSet<u16>,
+    /// Summary of the last reload's changes and the time at which it stops being shown.
+    reload_toast: Option<(String, f64)>,
+    /// Message of the brief toast shown after copying a field value, and when it expires.
+    copy_toast: Option<(String, f64)>,
+    /// Files dropped onto the window or pasted as Base64, in the order they were added.
+    loaded_files: Vec<LoadedFile>,
+    /// Paths of the most recently opened dump files, newest first.
+    recent_files: Vec<PathBuf>,
+    /// Entries pinned to the top of the sidebar, in the order they were pinned.
+    pins: Vec<Pin>,
+    /// Free-text notes on tables and structures, keyed by [`report::acpi_note_key`] and
+    /// [`report::smbios_note_key`] and persisted across runs.
+    notes: BTreeMap<String, String>,
+    /// Whether ACPI and SMBIOS data is read in the background when the window opens.
+    load_on_startup: bool,
+    /// Receives the startup load's data while it is being read.
+    startup_load: Option<Receiver<StartupLoad>>,
+    /// Text of the Load from Base64 dialog; `Some` while the dialog is open.
+    base64_input: Option<String>,
+    /// Whether the MSDM product key is shown unmasked in the parsed view.
+    reveal_product_key: bool,
 
     /// The currently selected table or structure.
     selected_item: Selection,
     /// The active view tab (Hex or Parsed).
     active_tab: Tab,
 
-    /// Cached hex dump string of the selected item.
-    cached_hex: String,
+    /// Where each line of the selected item's hex dump starts, as returned by
+    /// [`report::hex_dump_line_starts`]; the lines themselves are formatted as they scroll
+    /// into view.
+    hex_line_starts: Vec<usize>,
+    /// Error shown in place of the hex view when the selected item could not be read.
+    hex_error: Option<String>,
     /// Cached parsed/interpreted string of the selected item.
     cached_parsed: String,
+    /// Raw bytes of the selected item, backing the hex view.
+    cached_bytes: Vec<u8>,
+    /// Byte range (inclusive) selected in the hex view.
+    hex_selection: Option<(usize, usize)>,
+    /// Byte at which the selection being dragged in the hex view started.
+    hex_drag_anchor: Option<usize>,
+    /// Byte distribution of `cached_bytes`.
+    cached_stats: Option<report::ByteStats>,
+    /// Handle and resolved fields of the selected SMBIOS structure, drawn as a grid with
+    /// clickable handle references in the parsed view.
+    smbios_field_view: Option<(u16, Vec<(String, String)>)>,
+    /// Type whose parser is forced onto the selected OEM structure, or `None` for its own.
+    smbios_interpret_as: Option<u8>,
+    /// SMBIOS version whose parsing rules replace the table's own, for comparing decodings.
+    smbios_version_override: Option<parsers::SmbiosVersion>,
+    /// Distance matrix of the selected SLIT, drawn as a grid above the parsed text.
+    slit_matrix: Option<Vec<Vec<u8>>>,
+    /// Namespace scope tree of the selected DSDT or SSDT, drawn above the parsed text.
+    aml_tree: Option<Vec<parsers::AmlNode>>,
+    /// Physical addresses the selected RSDT, XSDT or FADT points at, drawn as links above the
+    /// parsed text.
+    acpi_address_refs: Vec<report::AcpiAddressRef>,
+    /// Whether the parsed view shows the ACPI header as an offset/size/raw-bytes table.
+    show_header_layout: bool,
+    /// Whether the byte statistics panel is visible.
+    byte_stats_open: bool,
+    /// Monospace font size of the hex and parsed views, changed with Ctrl+scroll and
+    /// Ctrl+Plus/Minus.
+    data_font_size: f32,
+    /// Whether the Tables by Size window is open.
+    footprint_open: bool,
+    /// Whether the Tables by Size list is sorted by name instead of by descending size.
+    footprint_by_name: bool,
+    /// Whether the Table Advisory window is open.
+    advisory_open: bool,
+    /// Whether the All SMBIOS Fields window is open.
+    smbios_fields_open: bool,
+    /// Every parsed SMBIOS field, built when the window needs it.
+    smbios_field_rows: Vec<report::SmbiosFieldRow>,
+    /// The encoding and version `smbios_field_rows` was parsed with; `None` after a load.
+    smbios_field_rows_key: Option<(parsers::StringEncoding, Option<parsers::SmbiosVersion>)>,
+    /// Text the SMBIOS field table is filtered by.
+    smbios_fields_filter: String,
+    /// Column the SMBIOS field table is sorted by, and whether it is descending.
+    smbios_fields_sort: (SmbiosFieldColumn, bool),
+    /// Indices into `smbios_field_rows` in display order.
+    smbios_field_order: Vec<usize>,
+    /// The sort `smbios_field_order` was built with; `None` when the rows were rebuilt.
+    smbios_field_order_key: Option<(SmbiosFieldColumn, bool)>,
+    /// Whether the SMBIOS Diff window is open.
+    smbios_diff_open: bool,
+    /// The blobs the SMBIOS Diff window compares, as A and B.
+    smbios_diff_sides: (DiffSide, DiffSide),
+    /// The last structured SMBIOS diff, with the names of the sides it compared.
+    smbios_diff: Option<(String, String, Vec<report::SmbiosStructureDiff>)>,
+    /// Whether the hex view accepts edits to `cached_bytes`.
+    hex_edit_mode: bool,
+    /// Whether SMBIOS structures are dumped with offsets into the whole blob.
+    hex_absolute_offsets: bool,
+    /// Whether hovering the ASCII column names the byte under the pointer.
+    hex_byte_names: bool,
+    /// Whether lines of one repeated byte, e.g. zero padding, are collapsed to one line.
+    hex_collapse_runs: bool,
+    /// Number of leading bytes Export Trimmed Copy keeps; 0 until first set.
+    trim_length: usize,
+    /// Whether Export Trimmed Copy rewrites an ACPI table's length field and checksum.
+    trim_rewrite_header: bool,
+    /// The collapsible runs of `cached_bytes`, from [`report::hex_runs`].
+    hex_runs: Vec<report::HexRun>,
+    /// Editable hex digits of `cached_bytes`, shown while in edit mode.
+    hex_edit_text: String,
+    /// Why the last edit was rejected, if it was.
+    hex_edit_error: Option<String>,
+    /// Whether `cached_bytes` differs from what was read from firmware.
+    bytes_modified: bool,
 
     /// Text used to filter the sidebar table list.
     sidebar_filter: String,
@@ -98,8 +1161,30 @@ struct DumpApp {
     search_query: String,
     /// Whether the search panel (Ctrl+F) is currently visible.
     search_panel_open: bool,
-    /// Whether dark mode is enabled.
+    /// Whether "Copy matching lines" drops lines identical to one already copied.
+    search_unique_lines: bool,
+    /// The chosen color theme.
+    theme: Theme,
+    /// Whether dark visuals are in effect, resolved from `theme` every frame.
     dark_mode: bool,
+    /// When the Windows app mode was last read for [`Theme::System`].
+    system_theme_checked: Option<std::time::Instant>,
+    /// The window's reported system theme at that read; a change triggers a new read.
+    system_theme_signal: Option<egui::Theme>,
+    /// Where ACPI tables are discovered.
+    acpi_source: api::AcpiSource,
+    /// Whether SMBIOS exports drop the Windows `RawSMBIOSData` header.
+    smbios_strip_wrapper: bool,
+    /// Whether empty memory slots and unused system slots are hidden from the sidebar.
+    populated_only: bool,
+    /// Whether End-of-Table (Type 127) and Inactive (Type 126) structures are hidden from the sidebar.
+    hide_end_of_table: bool,
+    /// Language of the interface strings.
+    language: i18n::Language,
+    /// Whether a font with CJK glyphs has been added to the egui fonts.
+    cjk_font_installed: bool,
+    /// Encoding used to decode SMBIOS strings.
+    string_encoding: parsers::StringEncoding,
 
     /// Status message for load/operations.
     status: String,
@@ -107,23 +1192,85 @@ struct DumpApp {
     is_admin: bool,
     /// Parsed SMBIOS header for version info.
     smbios_header: Option<parsers::RawSMBIOSData>,
+    /// Virtual machine heuristic result, refreshed whenever tables are loaded.
+    vm_detection: Option<report::VmDetection>,
+    /// Raw ACPI table bytes read so far, shared with the export worker.
+    table_cache: TableCache,
+    /// The export-all job in progress, if any.
+    export_job: Option<ExportJob>,
+
+    /// Keyboard navigation through the sidebar rows.
+    sidebar_nav: SidebarNav,
+    /// Whether the sidebar was the last panel clicked, so arrow keys navigate it.
+    sidebar_active: bool,
+    /// Whether the sidebar filter box should take keyboard focus on the next frame.
+    focus_sidebar_filter: bool,
+
+    /// Whether the command palette (Ctrl+K) is open.
+    palette_open: bool,
+    /// Text filtering the command palette entries.
+    palette_query: String,
+    /// Index of the highlighted entry among the filtered palette entries.
+    palette_cursor: usize,
+
+    /// Program used by "Open in Hex Editor"; empty means the registered `.bin` handler.
+    hex_editor_path: String,
+    /// Temporary files written for the external hex editor, removed on exit.
+    hex_editor_temp_files: Vec<PathBuf>,
+    /// Template for raw, parsed, and export-all file names, e.g. `{date}_{sig}_{table_id}`;
+    /// empty means the built-in names.
+    export_name_template: String,
+    /// Folder the export dialogs open in; empty means the dialog's own default.
+    export_dir: String,
 
     /// Cached match positions for search.
     search_matches: Vec<usize>,
     search_current: usize,
+    /// Whether the hex view should scroll to the current search match on the next frame.
+    scroll_to_match: bool,
 }
 
 impl DumpApp {
     /// Creates a new instance of the application with default state.
     fn new(cc: &eframe::CreationContext<'_>) -> Self {
-        // Default to dark mode (persist if available)
-        let mut dark_mode = cc.egui_ctx.style().visuals.dark_mode;
+        let dark_mode = cc.egui_ctx.style().visuals.dark_mode;
+        let mut theme = Theme::System;
         let mut sidebar_filter = String::new();
         let mut search_query = String::new();
         let mut active_tab = Tab::Hex;
+        let mut string_encoding = parsers::StringEncoding::default();
+        let mut acpi_tree_view = false;
+        let mut acpi_sort = AcpiSort::Signature;
+        let mut acpi_group_duplicates = true;
+        let mut hex_editor_path = String::new();
+        let mut export_name_template = String::new();
+        let mut export_dir = String::new();
+        let mut language = i18n::Language::default();
+        let mut recent_files = Vec::new();
+        let mut pins = Vec::new();
+        let mut notes = BTreeMap::new();
+        let mut load_on_startup = true;
+        let mut acpi_source = api::AcpiSource::default();
+        let mut smbios_strip_wrapper = false;
+        let mut populated_only = false;
+        let mut hide_end_of_table = false;
+        let mut hex_absolute_offsets = false;
+        let mut hex_byte_names = false;
+        let mut hex_collapse_runs = false;
+        let mut search_panel_open = false;
+        let mut search_unique_lines = false;
+        let mut byte_stats_open = false;
+        let mut data_font_size = DATA_FONT_SIZE;
         if let Some(storage) = cc.storage {
-            if let Some(stored) = storage.get_string("ui.dark_mode") {
-                dark_mode = stored == "1";
+            if let Some(stored) = storage.get_string("ui.theme") {
+                theme = Theme::from_key(&stored).unwrap_or(theme);
+            } else if let Some(stored) = storage.get_string("ui.dark_mode") {
+                // Settings saved before the System theme existed
+                theme = if stored == "1" {
+                    Theme::Dark
+                } else {
+                    Theme::Light
+                };
             }
             if let Some(stored) = storage.get_string("ui.sidebar_filter") {
                 sidebar_filter = stored;
@@ -136,214 +1283,765 @@ impl DumpApp {
                     active_tab = Tab::Parsed;
                 }
             }
+            if let Some(stored) = storage.get_string("ui.acpi_tree_view") {
+                acpi_tree_view = stored == "1";
+            }
+            if let Some(stored) = storage.get_string("ui.acpi_sort") {
+                acpi_sort = AcpiSort::from_key(&stored).unwrap_or(acpi_sort);
+            }
+            if let Some(stored) = storage.get_string("ui.acpi_group") {
+                acpi_group_duplicates = stored == "1";
+            }
+            if let Some(stored) = storage.get_string("ui.smbios_strip_wrapper") {
+                smbios_strip_wrapper = stored == "1";
+            }
+            if let Some(stored) = storage.get_string("ui.populated_only") {
+                populated_only = stored == "1";
+            }
+            if let Some(stored) = storage.get_string("ui.hide_end_of_table") {
+                hide_end_of_table = stored == "1";
+            }
+            if let Some(stored) = storage.get_string("ui.hex_absolute_offsets") {
+                hex_absolute_offsets = stored == "1";
+            }
+            if let Some(stored) = storage.get_string("ui.hex_byte_names") {
+                hex_byte_names = stored == "1";
+            }
+            if let Some(stored) = storage.get_string("ui.hex_collapse_runs") {
+                hex_collapse_runs = stored == "1";
+            }
+            if let Some(stored) = storage.get_string("ui.search_panel_open") {
+                search_panel_open = stored == "1";
+            }
+            if let Some(stored) = storage.get_string("ui.search_unique_lines") {
+                search_unique_lines = stored == "1";
+            }
+            if let Some(stored) = storage.get_string("ui.byte_stats_open") {
+                byte_stats_open = stored == "1";
+            }
+            if let Some(size) = storage
+                .get_string("ui.data_font_size")
+                .and_then(|stored| stored.parse::<f32>().ok())
+            {
+                data_font_size = size.clamp(*DATA_FONT_RANGE.start(), *DATA_FONT_RANGE.end());
+            }
+            if let Some(stored) = storage.get_string("ui.string_encoding") {
+                string_encoding = parsers::StringEncoding::from_key(&stored).unwrap_or_default();
+            }
+            if let Some(stored) = storage.get_string("ui.hex_editor_path") {
+                hex_editor_path = stored;
+            }
+            if let Some(stored) = storage.get_string("ui.export_name_template") {
+                export_name_template = stored;
+            }
+            if let Some(stored) = storage.get_string("ui.export_dir") {
+                export_dir = stored;
+            }
+            if let Some(stored) = storage.get_string("ui.language") {
+                language = i18n::Language::from_key(&stored).unwrap_or_default();
+            }
+            if let Some(stored) = storage.get_string("ui.acpi_source") {
+                acpi_source = api::AcpiSource::from_key(&stored).unwrap_or_default();
+            }
+            if let Some(stored) = storage.get_string("ui.recent_files") {
+                recent_files = stored
+                    .lines()
+                    .filter(|line| !line.is_empty())
+                    .take(RECENT_FILES_LIMIT)
+                    .map(PathBuf::from)
+                    .collect();
+            }
+            if let Some(stored) = storage.get_string("ui.pins") {
+                pins = stored.lines().filter_map(Pin::from_key).collect();
+            }
+            if let Some(stored) = storage.get_string("ui.notes") {
+                notes = serde_json::from_str(&stored).unwrap_or_default();
+            }
+            if let Some(stored) = storage.get_string("ui.load_on_startup") {
+                load_on_startup = stored == "1";
+            }
         }
+        i18n::set_language(language);
+        let cjk_font_installed =
+            language != i18n::Language::English && install_cjk_font(&cc.egui_ctx);
         let is_admin = unsafe { IsUserAnAdmin().as_bool() };
 
-        Self {
+        let mut app = Self {
             acpi_tables: None,
+            acpi_load_error: None,
+            smbios_load_error: None,
+            acpi_tree: Vec::new(),
+            acpi_tree_view,
+            acpi_sizes: Vec::new(),
+            acpi_hashes: Vec::new(),
+            acpi_warnings: Vec::new(),
+            acpi_sort,
+            acpi_group_duplicates,
             smbios_data: None,
             smbios_list: Vec::new(),
+            sensors: Vec::new(),
+            sockets: Vec::new(),
+            cpu_summary: None,
+            numa: None,
+            memory_summary: None,
+            placeholders: Vec::new(),
+            smbios_handles: HashMap::new(),
+            changed_acpi: HashSet::new(),
+            changed_smbios: HashSet::new(),
+            reload_toast: None,
+            copy_toast: None,
+            loaded_files: Vec::new(),
+            recent_files,
+            pins,
+            notes,
+            load_on_startup,
+            startup_load: None,
+            base64_input: None,
+            reveal_product_key: false,
             selected_item: Selection::None,
             active_tab,
-            cached_hex: String::new(),
+            hex_line_starts: vec![0],
+            hex_error: None,
             cached_parsed: String::new(),
+            cached_bytes: Vec::new(),
+            hex_selection: None,
+            hex_drag_anchor: None,
+            cached_stats: None,
+            smbios_field_view: None,
+            smbios_interpret_as: None,
+            smbios_version_override: None,
+            slit_matrix: None,
+            aml_tree: None,
+            acpi_address_refs: Vec::new(),
+            show_header_layout: false,
+            byte_stats_open,
+            data_font_size,
+            footprint_open: false,
+            footprint_by_name: false,
+            advisory_open: false,
+            smbios_fields_open: false,
+            smbios_field_rows: Vec::new(),
+            smbios_field_rows_key: None,
+            smbios_fields_filter: String::new(),
+            smbios_fields_sort: (SmbiosFieldColumn::Type, false),
+            smbios_field_order: Vec::new(),
+            smbios_field_order_key: None,
+            smbios_diff_open: false,
+            smbios_diff_sides: (DiffSide::Live, DiffSide::Live),
+            smbios_diff: None,
+            hex_edit_mode: false,
+            hex_absolute_offsets,
+            hex_byte_names,
+            hex_collapse_runs,
+            trim_length: 0,
+            trim_rewrite_header: true,
+            hex_runs: Vec::new(),
+            hex_edit_text: String::new(),
+            hex_edit_error: None,
+            bytes_modified: false,
             sidebar_filter,
             search_query,
-            search_panel_open: false,
+            search_panel_open,
+            search_unique_lines,
+            theme,
             dark_mode,
-            status: STATUS_OK.to_string(),
+            system_theme_checked: None,
+            system_theme_signal: None,
+            acpi_source,
+            smbios_strip_wrapper,
+            populated_only,
+            hide_end_of_table,
+            language,
+            cjk_font_installed,
+            string_encoding,
+            status: i18n::t(STATUS_OK).to_string(),
             is_admin,
             smbios_header: None,
+            vm_detection: None,
+            table_cache: TableCache::default(),
+            export_job: None,
+            sidebar_nav: SidebarNav::default(),
+            sidebar_active: false,
+            focus_sidebar_filter: false,
+            palette_open: false,
+            palette_query: String::new(),
+            palette_cursor: 0,
+            hex_editor_path,
+            hex_editor_temp_files: Vec::new(),
+            export_name_template,
+            export_dir,
             search_matches: Vec::new(),
             search_current: 0,
+            scroll_to_match: false,
+        };
+        // Without elevation the reads fail anyway; the admin banner explains why
+        if app.load_on_startup && app.is_admin {
+            app.start_startup_load(&cc.egui_ctx);
         }
+        app
     }
 
-    /// Copies the given text to the system clipboard.
-    fn copy_to_clipboard(&self, ctx: &egui::Context, text: &str) {
-        ctx.copy_text(text.to_string());
+    /// Starts reading ACPI and SMBIOS data on a worker thread; see [`startup_load_worker`].
+    fn start_startup_load(&mut self, ctx: &egui::Context) {
+        let (sender, receiver) = mpsc::channel();
+        let source = self.acpi_source;
+        let ctx = ctx.clone();
+        std::thread::spawn(move || startup_load_worker(source, sender, ctx));
+        self.startup_load = Some(receiver);
+        self.status = i18n::t("Loading firmware tables…").to_string();
     }
 
-    /// Retrieve parsed key/value pairs for the selected SMBIOS structure.
-    fn current_smbios_fields(&self) -> Option<Vec<(String, String)>> {
-        if let Selection::Smbios(offset, _) = self.selected_item {
-            if let Some(data) = &self.smbios_data {
-                if let Ok((header, next_off)) = parsers::parse_smbios_structure(data, offset) {
-                    let strings = parsers::get_smbios_strings(data, offset, header.length);
-                    if let Some(details) = parsers::parse_smbios_details(
-                        header.type_id,
-                        data,
-                        offset,
-                        header.length,
-                        &strings,
-                    ) {
-                        return Some(details);
-                    }
-                    if !strings.is_empty() {
-                        let mut out = Vec::new();
-                        for (i, s) in strings.iter().enumerate() {
-                            out.push((format!("String {}", i + 1), s.clone()));
-                        }
-                        return Some(out);
-                    }
-                    // fallback to show raw slice length
-                    let raw_len = next_off.saturating_sub(offset);
-                    return Some(vec![
-                        ("Handle".to_string(), format!("0x{:04X}", header.handle)),
-                        ("Length".to_string(), format!("{} bytes", raw_len)),
-                    ]);
-                }
+    /// Resolves `theme` to light or dark visuals and applies them.
+    ///
+    /// For [`Theme::System`] the Windows app mode is read from the registry again once
+    /// [`SYSTEM_THEME_RECHECK`] has passed, or at once when the window reports a theme change,
+    /// so switching the Windows setting while the tool runs takes effect on the next repaint.
+    fn apply_theme(&mut self, ctx: &egui::Context) {
+        if self.theme == Theme::System {
+            let signal = ctx.input(|i| i.raw.system_theme);
+            let fresh = matches!(
+                self.system_theme_checked,
+                Some(at) if at.elapsed() < SYSTEM_THEME_RECHECK
+            );
+            if !fresh || signal != self.system_theme_signal {
+                self.system_theme_checked = Some(std::time::Instant::now());
+                self.system_theme_signal = signal;
+                self.dark_mode = match api::apps_use_light_theme() {
+                    Some(light) => !light,
+                    None => signal.map_or(self.dark_mode, |t| t == egui::Theme::Dark),
+                };
+            }
+        } else {
+            self.dark_mode = self.theme == Theme::Dark;
+        }
+        if self.dark_mode {
+            ctx.set_visuals(egui::Visuals::dark());
+        } else {
+            ctx.set_visuals(egui::Visuals::light());
+        }
+    }
+
+    /// Keeps the window polling while a background load or export is in flight.
+    ///
+    /// egui only repaints on input or when asked, which keeps an idle window at no CPU.
+    /// Workers wake the UI after each message, but one that exits without sending (e.g. a
+    /// panic) would leave the progress stale until the mouse moves, so in-flight work is
+    /// also checked on a short timer. Nothing is scheduled once it finishes.
+    fn schedule_background_repaint(&self, ctx: &egui::Context) {
+        if self.startup_load.is_some() || self.export_job.is_some() {
+            ctx.request_repaint_after(BACKGROUND_POLL_INTERVAL);
+        }
+    }
+
+    /// Shows the startup load's data once the worker has sent it.
+    ///
+    /// Data the user already loaded by hand while the worker ran is kept.
+    fn poll_startup_load(&mut self) {
+        let Some(receiver) = &self.startup_load else {
+            return;
+        };
+        let load = match receiver.try_recv() {
+            Ok(load) => load,
+            Err(mpsc::TryRecvError::Empty) => return,
+            Err(mpsc::TryRecvError::Disconnected) => {
+                self.startup_load = None;
+                return;
+            }
+        };
+        self.startup_load = None;
+        if self.acpi_tables.is_none() {
+            self.apply_acpi_tables(load.tables, load.contents);
+        }
+        if self.smbios_data.is_none() {
+            self.apply_smbios(load.smbios);
+        }
+    }
+
+    /// Copies the given text to the system clipboard.
+    fn copy_to_clipboard(&self, ctx: &egui::Context, text: &str) {
+        ctx.copy_text(text.to_string());
+    }
+
+    /// Summarizes what is loaded for the right side of the status bar, e.g.
+    /// `SMBIOS 3.4 | 38 structures | ACPI: 22 tables | Admin: yes`.
+    fn status_segments(&self) -> String {
+        let smbios = match (&self.smbios_header, &self.smbios_data) {
+            (Some(h), Some(_)) => i18n::tf(
+                "SMBIOS {}.{} | {} structures",
+                &[
+                    &h._major_version,
+                    &h._minor_version,
+                    &self.smbios_list.len(),
+                ],
+            ),
+            _ if self.smbios_load_error == Some(DumperError::NotAdmin) => {
+                i18n::t("SMBIOS: access denied").to_string()
+            }
+            _ => i18n::t("SMBIOS: not loaded").to_string(),
+        };
+        let acpi = match &self.acpi_tables {
+            _ if self.acpi_load_error == Some(DumperError::NotAdmin) => {
+                i18n::t("ACPI: access denied").to_string()
+            }
+            Some(tables) => i18n::tf("ACPI: {} tables", &[&tables.len()]),
+            None => i18n::t("ACPI: not loaded").to_string(),
+        };
+        i18n::tf(
+            "{} | {} | Admin: {}",
+            &[
+                &smbios,
+                &acpi,
+                &if self.is_admin {
+                    i18n::t("yes")
+                } else {
+                    i18n::t("no")
+                },
+            ],
+        )
+    }
+
+    /// Copies every parsed SMBIOS structure to the clipboard as XML.
+    fn copy_smbios_xml(&mut self, ctx: &egui::Context) {
+        match &self.smbios_data {
+            Some(data) => {
+                self.copy_to_clipboard(
+                    ctx,
+                    &report::smbios_xml(data, self.string_encoding, &[], false),
+                );
+                self.status = i18n::t("Copied SMBIOS structures as XML").to_string();
+            }
+            None => self.status = i18n::t("Load SMBIOS data first").to_string(),
+        }
+    }
+
+    /// Copies the selected item's bytes, including unsaved hex edits, to the clipboard as
+    /// Base64.
+    fn copy_base64(&mut self, ctx: &egui::Context) {
+        if self.selected_item.is_none() {
+            self.status = i18n::t("Select an item first").to_string();
+            return;
+        }
+        let text = base64::engine::general_purpose::STANDARD.encode(&self.cached_bytes);
+        self.copy_to_clipboard(ctx, &text);
+        self.status = i18n::tf("Copied {} bytes as Base64", &[&self.cached_bytes.len()]);
+    }
+
+    /// Returns the SMBIOS version whose rules the parsed views follow: the override if one
+    /// is chosen, otherwise the version the table declares.
+    fn smbios_parse_version(&self) -> Option<parsers::SmbiosVersion> {
+        self.smbios_version_override
+            .or_else(|| self.smbios_header.as_ref().map(|h| h.version()))
+    }
+
+    /// Builds the parsed text of an SMBIOS structure, noting a version override at the top.
+    fn smbios_parsed_text(&self, structure: &[u8]) -> String {
+        let text = report::smbios_structure_text(
+            structure,
+            self.smbios_data.as_deref().unwrap_or(&[]),
+            self.string_encoding,
+            self.smbios_parse_version(),
+        );
+        match (self.smbios_version_override, &self.smbios_header) {
+            (Some(version), Some(header)) => format!(
+                "Parsed with SMBIOS {} rules (override; the table declares {})\n{}",
+                version,
+                header.version(),
+                text
+            ),
+            (Some(version), None) => {
+                format!("Parsed with SMBIOS {} rules (override)\n{}", version, text)
+            }
+            (None, _) => text,
+        }
+    }
+
+    /// Retrieve parsed key/value pairs for the selected SMBIOS structure.
+    fn current_smbios_fields(&self) -> Option<Vec<(String, String)>> {
+        if let Selection::Smbios(offset, _) = self.selected_item {
+            if let Some(data) = &self.smbios_data {
+                if let Ok((_, next_off)) = parsers::parse_smbios_structure(data, offset) {
+                    return report::smbios_fields_with_version(
+                        &data[offset..next_off],
+                        self.string_encoding,
+                        self.smbios_parse_version(),
+                    );
+                }
             }
         }
         None
     }
 
+    /// Retrieve parsed key/value pairs for the selected item (ACPI header and details, or SMBIOS fields).
+    fn current_fields(&self) -> Option<Vec<(String, String)>> {
+        match self.selected_item {
+            Selection::Acpi(_) => report::acpi_table_fields(&self.cached_bytes),
+            Selection::Smbios(..) => self.current_smbios_fields(),
+            Selection::File(i) => match self.loaded_files.get(i)?.kind {
+                parsers::FirmwareFileKind::AcpiTable => {
+                    report::acpi_table_fields(&self.cached_bytes)
+                }
+                _ => None,
+            },
+            Selection::None => None,
+        }
+    }
+
     /// Triggers the combined discovery of ACPI tables and updates the state.
     fn load_acpi(&mut self) {
-        let tables = api::load_acpi_tables_combined();
-        if tables.is_empty() {
-            self.status = "No ACPI tables found (admin required?)".to_string();
-        } else {
-            self.status = format!("Loaded {} ACPI tables", tables.len());
+        let tables = api::load_acpi_tables_checked(self.acpi_source);
+        let contents = report::read_tables_concurrently(
+            tables.as_deref().unwrap_or_default(),
+            api::get_acpi_table,
+            &AtomicBool::new(false),
+            |_, _| {},
+        );
+        self.apply_acpi_tables(tables, contents);
+    }
+
+    /// Shows freshly read ACPI tables, marking the ones whose bytes changed since the last load.
+    ///
+    /// The status line tells an empty firmware apart from access denied and other API errors.
+    ///
+    /// # Arguments
+    /// * `tables` - The discovered tables, or why none were found.
+    /// * `contents` - The bytes of each table, in the same order; they replace the table cache.
+    fn apply_acpi_tables(
+        &mut self,
+        tables: Result<Vec<api::AcpiTableInfo>, DumperError>,
+        contents: Vec<Result<Vec<u8>, DumperError>>,
+    ) {
+        self.status = match &tables {
+            Ok(tables) if tables.is_empty() => i18n::t("No ACPI tables found").to_string(),
+            Ok(tables) => i18n::tf("Loaded {} ACPI tables", &[&tables.len()]),
+            Err(DumperError::NotAdmin) => {
+                i18n::t("ACPI tables could not be read: access denied. Restart as Administrator.")
+                    .to_string()
+            }
+            Err(DumperError::TableNotFound(_)) => {
+                i18n::t("No ACPI tables found: the firmware reports none").to_string()
+            }
+            Err(e) => i18n::tf("ACPI enumeration failed: {}", &[e]),
+        };
+        self.acpi_load_error = tables.as_ref().err().cloned();
+        let tables = tables.unwrap_or_default();
+        self.acpi_tree = report::build_acpi_tree(&tables);
+        // Keep the previous bytes to mark the tables this load changed
+        let previous = std::mem::take(&mut *self.table_cache.lock().unwrap());
+        self.acpi_sizes = contents
+            .iter()
+            .map(|content| content.as_ref().map_or(0, |d| d.len()))
+            .collect();
+        self.acpi_hashes = contents
+            .iter()
+            .map(|content| content.as_ref().ok().map(|d| report::acpi_content_hash(d)))
+            .collect();
+        self.acpi_warnings = contents
+            .iter()
+            .map(|content| {
+                content
+                    .as_ref()
+                    .map_or_else(|_| Vec::new(), |d| report::acpi_table_warnings(d, &tables))
+            })
+            .collect();
+        self.table_cache.lock().unwrap().extend(
+            tables
+                .iter()
+                .zip(contents)
+                .filter_map(|(t, content)| Some((table_cache_key(t), content.ok()?))),
+        );
+        self.changed_acpi.clear();
+        if !previous.is_empty() {
+            let cache = self.table_cache.lock().unwrap();
+            for t in &tables {
+                let key = table_cache_key(t);
+                if cache.get(&key) != previous.get(&key) {
+                    self.changed_acpi.insert(key);
+                }
+            }
+        }
+        {
+            let cache = self.table_cache.lock().unwrap();
+            let bytes = |signature: &str| {
+                let t = tables.iter().find(|t| t.signature == signature)?;
+                cache.get(&table_cache_key(t)).map(Vec::as_slice)
+            };
+            self.numa = report::numa_topology(bytes("SRAT"), bytes("SLIT"));
         }
         self.acpi_tables = Some(tables);
+        self.refresh_vm_detection();
     }
 
     /// Triggers the retrieval and parsing of SMBIOS data and updates the state.
     fn load_smbios(&mut self) {
-        let smbios_data = match api::get_smbios_data() {
-            Ok(data) => {
-                self.status = "Loaded SMBIOS data".to_string();
+        self.apply_smbios(api::get_smbios_data_with_source());
+    }
+
+    /// Parses a freshly read SMBIOS blob, or shows why it could not be read.
+    fn apply_smbios(&mut self, result: Result<(Vec<u8>, api::SmbiosSource), DumperError>) {
+        self.smbios_load_error = result.as_ref().err().cloned();
+        let smbios_data = match result {
+            Ok((data, source)) => {
+                self.status = i18n::tf("Loaded SMBIOS data via {}", &[&source.label()]);
                 data
             }
+            Err(DumperError::NotAdmin) => {
+                self.status = i18n::t(
+                    "SMBIOS data could not be read: access denied. Restart as Administrator.",
+                )
+                .to_string();
+                Vec::new()
+            }
             Err(e) => {
-                self.status = format!("SMBIOS load failed: {}", e);
+                self.status = i18n::tf("SMBIOS load failed: {}", &[&e]);
                 Vec::new()
             }
         };
 
         let mut smbios_list = Vec::new();
-        self.smbios_header = None;
-        if !smbios_data.is_empty() {
-            if let Some((hdr, off)) = parsers::parse_raw_smbios_data_header(&smbios_data) {
-                self.smbios_header = Some(hdr);
-                let mut current_off = off;
-
-                while current_off < smbios_data.len() {
-                    if let Ok((header, next_off)) =
-                        parsers::parse_smbios_structure(&smbios_data, current_off)
-                    {
-                        let mut label =
-                            format!("Type {} (Handle 0x{:04X})", header.type_id, header.handle);
-                        let type_name = match header.type_id {
-                            0 => "BIOS Info",
-                            1 => "System Info",
-                            2 => "Baseboard",
-                            3 => "Chassis",
-                            4 => "Processor",
-                            7 => "Cache Info",
-                            8 => "Port Connector",
-                            9 => "System Slots",
-                            11 => "OEM Strings",
-                            13 => "BIOS Language",
-                            16 => "Memory Array",
-                            17 => "Memory Device",
-                            19 => "Memory Mapped",
-                            32 => "Boot Info",
-                            127 => "End-of-Table",
-                            _ => "",
-                        };
-                        if !type_name.is_empty() {
-                            label.push_str(" - ");
-                            label.push_str(type_name);
-                        }
-
-                        smbios_list.push((
-                            current_off,
-                            header.type_id,
-                            header.length,
-                            header.handle,
-                            label,
-                        ));
-
-                        if next_off <= current_off {
-                            break;
-                        }
-                        current_off = next_off;
-                    } else {
-                        break;
-                    }
-                }
+        let memory_labels: HashMap<usize, String> =
+            report::memory_slots(&smbios_data, self.string_encoding)
+                .into_iter()
+                .map(|slot| (slot.offset, slot.label()))
+                .collect();
+        self.smbios_handles.clear();
+        self.smbios_header = parsers::parse_raw_smbios_data_header(&smbios_data).map(|(h, _)| h);
+        let structures = match parsers::try_walk_smbios_structures(&smbios_data) {
+            Ok(structures) => structures,
+            Err(e) => {
+                self.status = e.to_string();
+                parsers::walk_smbios_structures(&smbios_data)
             }
+        };
+        for (offset, header, _) in structures {
+            let mut label = format!("Type {} (Handle 0x{:04X})", header.type_id, header.handle);
+            let type_name = match header.type_id {
+                7 => parsers::cache_display_name(&smbios_data, offset),
+                17 => memory_labels.get(&offset).cloned(),
+                _ => None,
+            }
+            .unwrap_or_else(|| parsers::smbios_type_name(header.type_id).to_string());
+            if !type_name.is_empty() {
+                label.push_str(" - ");
+                label.push_str(&type_name);
+            }
+
+            self.smbios_handles
+                .insert(header.handle, (offset, header.type_id));
+            smbios_list.push((offset, header.type_id, header.length, header.handle, label));
         }
+        self.sensors = report::smbios_sensors(&smbios_data, self.string_encoding);
+        self.sockets = report::smbios_sockets(&smbios_data, self.string_encoding);
+        self.cpu_summary = report::cpu_summary(&smbios_data, self.string_encoding);
+        self.memory_summary =
+            report::memory_summary(&smbios_data, self.string_encoding, self.populated_only);
+        self.placeholders = report::smbios_placeholders(&smbios_data, self.string_encoding);
+        self.changed_smbios = match &self.smbios_data {
+            Some(previous) if !previous.is_empty() => {
+                report::changed_smbios_handles(previous, &smbios_data)
+            }
+            _ => HashSet::new(),
+        };
         self.smbios_data = Some(smbios_data);
         self.smbios_list = smbios_list;
+        self.smbios_field_rows_key = None;
+        self.refresh_vm_detection();
     }
 
-    /// Handles the selection of an ACPI table and updates the detail views.
-    fn select_acpi(&mut self, info: api::AcpiTableInfo) {
-        self.selected_item = Selection::Acpi(info.clone());
+    /// Re-reads ACPI and SMBIOS data and summarizes what changed since the previous load.
+    fn reload(&mut self, ctx: &egui::Context) {
+        self.load_acpi();
+        self.load_smbios();
 
-        let result = if let Some(ref path) = info.registry_path {
-            api::get_acpi_table_by_path(path)
+        let changed: BTreeSet<&str> = self
+            .acpi_tables
+            .iter()
+            .flatten()
+            .filter(|t| self.changed_acpi.contains(&table_cache_key(t)))
+            .map(|t| t.signature.as_str())
+            .collect();
+        let mut summary = if changed.is_empty() {
+            i18n::t("No ACPI tables changed").to_string()
         } else {
-            api::get_system_firmware_table(api::SIG_ACPI, &info.signature)
+            let changed: Vec<&str> = changed.into_iter().collect();
+            i18n::tf("Changed ACPI tables: {}", &[&changed.join(", ")])
+        };
+        if !self.changed_smbios.is_empty() {
+            summary.push('\n');
+            summary.push_str(&i18n::tf(
+                "{} SMBIOS structures changed",
+                &[&self.changed_smbios.len()],
+            ));
+        }
+        self.reload_toast = Some((summary, ctx.input(|i| i.time) + 8.0));
+    }
+
+    /// Shows the summary of the last reload in the bottom-right corner until it expires.
+    fn show_reload_toast(&mut self, ctx: &egui::Context) {
+        let now = ctx.input(|i| i.time);
+        let Some((summary, until)) = &self.reload_toast else {
+            return;
+        };
+        if now >= *until {
+            self.reload_toast = None;
+            return;
+        }
+        ctx.request_repaint_after(std::time::Duration::from_secs_f64(until - now));
+
+        let mut dismissed = false;
+        egui::Window::new("Reload")
+            .collapsible(false)
+            .resizable(false)
+            .title_bar(false)
+            .anchor(egui::Align2::RIGHT_BOTTOM, [-12.0, -40.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(summary.as_str());
+                    if ui.small_button("✖").clicked() {
+                        dismissed = true;
+                    }
+                });
+            });
+        if dismissed {
+            self.reload_toast = None;
+        }
+    }
+
+    /// Copies one parsed field value and confirms it with a brief toast.
+    fn copy_field_value(&mut self, ctx: &egui::Context, key: &str, value: &str) {
+        self.copy_to_clipboard(ctx, value);
+        let message = i18n::tf("Copied {}", &[&key]);
+        self.status = message.clone();
+        self.copy_toast = Some((message, ctx.input(|i| i.time) + 1.5));
+    }
+
+    /// Shows the toast from [`Self::copy_field_value`] at the bottom of the window until it
+    /// expires.
+    fn show_copy_toast(&mut self, ctx: &egui::Context) {
+        let now = ctx.input(|i| i.time);
+        let Some((message, until)) = &self.copy_toast else {
+            return;
         };
+        if now >= *until {
+            self.copy_toast = None;
+            return;
+        }
+        ctx.request_repaint_after(std::time::Duration::from_secs_f64(until - now));
+        egui::Area::new(egui::Id::new("copy_toast"))
+            .anchor(egui::Align2::CENTER_BOTTOM, [0.0, -40.0])
+            .interactable(false)
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.label(format!("📋 {}", message));
+                });
+            });
+    }
+
+    /// Re-runs the virtual machine heuristic over whatever ACPI and SMBIOS data is loaded.
+    fn refresh_vm_detection(&mut self) {
+        self.vm_detection = report::detect_vm(
+            self.acpi_tables.as_deref().unwrap_or(&[]),
+            self.smbios_data.as_deref().unwrap_or(&[]),
+        );
+    }
 
-        match result {
-            Ok(data) => {
-                self.status = format!("Loaded ACPI {}", info.signature);
-                self.update_cache(&data, "ACPI", &info.signature)
+    /// Handles the selection of an ACPI table and updates the detail views.
+    fn select_acpi(&mut self, info: api::AcpiTableInfo) {
+        self.selected_item = Selection::Acpi(info.clone());
+
+        match api::get_acpi_table_with_value(&info) {
+            Ok((value_name, data)) => {
+                self.status = i18n::tf("Loaded ACPI {}", &[&info.signature]);
+                self.table_cache
+                    .lock()
+                    .unwrap()
+                    .insert(table_cache_key(&info), data.clone());
+                let mut parsed = String::new();
+                if let Some(name) = value_name {
+                    parsed.push_str(&format!("Registry value: {}\n", name));
+                }
+                parsed.push_str(&report::acpi_table_text(
+                    &data,
+                    self.acpi_tables.as_deref().unwrap_or(&[]),
+                    false,
+                ));
+                let parsed = self.reveal_secrets(&data, parsed);
+                self.update_cache(&data, parsed)
             }
             Err(e) => {
-                self.cached_hex = format!("Error: {}", e);
-                self.cached_parsed = format!("Error: {}", e);
-                self.status = format!("ACPI load failed: {}", e);
+                self.hex_error = Some(i18n::tf("Error: {}", &[&e]));
+                self.cached_parsed = i18n::tf("Error: {}", &[&e]);
+                self.status = i18n::tf("ACPI load failed: {}", &[&e]);
             }
         }
     }
 
+    /// Replaces the masked MSDM product key in `parsed` with the real one when revealed.
+    fn reveal_secrets(&self, data: &[u8], parsed: String) -> String {
+        match parsers::msdm_product_key(data).filter(|_| self.reveal_product_key) {
+            Some(key) => parsed.replace(&parsers::mask_product_key(&key), &key),
+            None => parsed,
+        }
+    }
+
     /// Export selected SMBIOS structure as JSON of parsed fields.
-    fn export_smbios_json(&self) {
-        let (_tid, default_name) = match self.selected_item {
+    ///
+    /// With `redact` the identifying fields read `REDACTED`, for sharing the file publicly.
+    fn export_smbios_json(&self, redact: bool) {
+        let (tid, default_name) = match self.selected_item {
+            Selection::Smbios(_, tid) if redact => {
+                (tid, format!("smbios_type_{}_redacted.json", tid))
+            }
             Selection::Smbios(_, tid) => (tid, format!("smbios_type_{}.json", tid)),
             _ => return,
         };
 
-        let fields = match self.current_smbios_fields() {
+        let mut fields = match self.current_smbios_fields() {
             Some(f) => f,
             None => {
                 rfd::MessageDialog::new()
-                    .set_title("Export Error")
-                    .set_description("No parsed fields available for this SMBIOS structure.")
+                    .set_title(i18n::t("Export Error"))
+                    .set_description(i18n::t(
+                        "No parsed fields available for this SMBIOS structure.",
+                    ))
                     .set_level(rfd::MessageLevel::Error)
                     .show();
                 return;
             }
         };
 
-        let map: std::collections::BTreeMap<_, _> = fields.into_iter().collect();
-        let json_str = match serde_json::to_string_pretty(&json!(map)) {
+        if redact {
+            // A forced interpretation names its fields after the parser that was applied
+            report::redact_smbios_fields(self.smbios_interpret_as.unwrap_or(tid), &mut fields);
+        }
+
+        let mut json = report::fields_json(fields);
+        if let Some(note) = self
+            .selection_note_key()
+            .and_then(|key| self.notes.get(&key))
+        {
+            json["notes"] = serde_json::json!(note);
+        }
+        let json_str = match serde_json::to_string_pretty(&json) {
             Ok(s) => s,
             Err(e) => {
                 rfd::MessageDialog::new()
-                    .set_title("Export Error")
-                    .set_description(format!("Failed to serialize JSON: {}", e))
+                    .set_title(i18n::t("Export Error"))
+                    .set_description(i18n::tf("Failed to serialize JSON: {}", &[&e]))
                     .set_level(rfd::MessageLevel::Error)
                     .show();
                 return;
             }
         };
 
-        if let Some(path) = rfd::FileDialog::new()
+        if let Some(path) = export_dialog(&self.export_dir)
             .set_file_name(&default_name)
             .add_filter("JSON", &['j', 's', 'o', 'n'])
             .save_file()
         {
             if let Err(e) = std::fs::write(&path, json_str) {
                 rfd::MessageDialog::new()
-                    .set_title("Export Error")
-                    .set_description(format!("Failed to write file: {}", e))
+                    .set_title(i18n::t("Export Error"))
+                    .set_description(i18n::tf("Failed to write file: {}", &[&e]))
                     .set_level(rfd::MessageLevel::Error)
                     .show();
             }
@@ -361,8 +2059,10 @@ impl DumpApp {
             Some(f) => f,
             None => {
                 rfd::MessageDialog::new()
-                    .set_title("Export Error")
-                    .set_description("No parsed fields available for this SMBIOS structure.")
+                    .set_title(i18n::t("Export Error"))
+                    .set_description(i18n::t(
+                        "No parsed fields available for this SMBIOS structure.",
+                    ))
                     .set_level(rfd::MessageLevel::Error)
                     .show();
                 return;
@@ -375,15 +2075,15 @@ impl DumpApp {
             csv_data.push_str(&format!("\"{}\",\"{}\"\n", k, escaped));
         }
 
-        if let Some(path) = rfd::FileDialog::new()
+        if let Some(path) = export_dialog(&self.export_dir)
             .set_file_name(&default_name)
             .add_filter("CSV", &['c', 's', 'v'])
             .save_file()
         {
             if let Err(e) = std::fs::write(&path, csv_data) {
                 rfd::MessageDialog::new()
-                    .set_title("Export Error")
-                    .set_description(format!("Failed to write file: {}", e))
+                    .set_title(i18n::t("Export Error"))
+                    .set_description(i18n::tf("Failed to write file: {}", &[&e]))
                     .set_level(rfd::MessageLevel::Error)
                     .show();
             }
@@ -393,177 +2093,1568 @@ impl DumpApp {
     /// Handles the selection of an SMBIOS structure and updates the detail views.
     fn select_smbios(&mut self, offset: usize, type_id: u8) {
         self.selected_item = Selection::Smbios(offset, type_id);
+        self.smbios_interpret_as = None;
         if let Some(ref data) = self.smbios_data {
             if let Ok((_, next_off)) = parsers::parse_smbios_structure(data, offset) {
                 let data_vec = data[offset..next_off].to_vec();
-                self.status = format!("Loaded SMBIOS type {}", type_id);
-                self.update_cache(&data_vec, "SMBIOS", &format!("Type {}", type_id));
+                self.status = i18n::tf("Loaded SMBIOS type {}", &[&type_id]);
+                let parsed = self.smbios_parsed_text(&data_vec);
+                self.update_cache(&data_vec, parsed);
             } else {
-                self.status = "SMBIOS parse failed".to_string();
+                self.status = i18n::t("SMBIOS parse failed").to_string();
             }
         }
     }
 
-    /// Updates the internal hex and parsed text caches for the selected data block.
-    fn update_cache(&mut self, data: &[u8], cat: &str, _id: &str) {
-        // Hex Dump
-        self.cached_hex = hex_dump_str(data);
-
-        // Parsed
-        let mut out = String::new();
-        if cat == "ACPI" {
-            if let Ok(header) = parsers::parse_acpi_header(data) {
-                out.push_str(&format!("Signature: {}\n", header.signature));
-                out.push_str(&format!("Length:    {}\n", header.length));
-                out.push_str(&format!("OEM ID:    {}\n", header.oem_id));
-                out.push_str(&format!("Table ID:  {}\n", header.oem_table_id));
-                out.push_str(&format!("Revision:  {}\n", header._revision));
-
-                if header.signature == "XSDT" {
-                    out.push_str("\n====================\nXSDT Entries:\n");
-                    let mut addr_map = std::collections::HashMap::new();
-                    if let Some(ref all_tables) = self.acpi_tables {
-                        if let Some(fadt_info) = all_tables
-                            .iter()
-                            .find(|t| t.signature == "FACP" || t.signature == "FADT")
-                        {
-                            let data = if let Some(ref path) = fadt_info.registry_path {
-                                api::get_acpi_table_by_path(path).ok()
-                            } else {
-                                api::get_system_firmware_table(api::SIG_ACPI, &fadt_info.signature)
-                                    .ok()
-                            };
-
-                            if let Some(d) = data {
-                                let refs = parsers::parse_fadt_references(&d);
-                                for (a, s) in refs {
-                                    addr_map.insert(a, s);
-                                }
-                            }
-                        }
-                    }
+    /// Handles the selection of a dropped file and updates the detail views.
+    fn select_file(&mut self, index: usize) {
+        let Some(file) = self.loaded_files.get(index) else {
+            return;
+        };
+        self.selected_item = Selection::File(index);
+        self.status = i18n::tf("Loaded from file: {}", &[&file.name]);
+        let data = file.data.clone();
+        let parsed = file_parsed_text(
+            &data,
+            self.acpi_tables.as_deref().unwrap_or(&[]),
+            self.smbios_data.as_deref().unwrap_or(&[]),
+            self.string_encoding,
+        );
+        self.update_cache(&data, parsed);
+    }
 
-                    let empty_lookup = std::collections::HashMap::new();
-                    if let Some(entries) = parsers::parse_xsdt_entries(data, &empty_lookup) {
-                        for (i, addr, _) in entries {
-                            let label = addr_map.get(&addr).cloned();
-                            if let Some(sig) = label {
-                                out.push_str(&format!("Entry{:<12}0x{:016X} ({})\n", i, addr, sig));
-                            } else {
-                                out.push_str(&format!("Entry{:<12}0x{:016X}\n", i, addr));
-                            }
-                        }
+    /// Adds the files dropped onto the window to the sidebar and selects the last one.
+    fn load_dropped_files(&mut self, files: Vec<egui::DroppedFile>) {
+        let mut names = Vec::new();
+        for file in files {
+            let name = file
+                .path
+                .as_ref()
+                .and_then(|p| p.file_name())
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| file.name.clone());
+            let data = match (&file.bytes, &file.path) {
+                (Some(bytes), _) => bytes.to_vec(),
+                (None, Some(path)) => match std::fs::read(path) {
+                    Ok(data) => data,
+                    Err(e) => {
+                        self.status = i18n::tf("Failed to read {}: {}", &[&name, &e]);
+                        continue;
                     }
-                }
-            } else {
-                out.push_str("Error parsing ACPI Header\n");
+                },
+                (None, None) => continue,
+            };
+            if let Some(path) = file.path {
+                self.remember_recent_file(path);
             }
-        } else if cat == "SMBIOS" {
-            if let Ok((header, _)) = parsers::parse_smbios_structure(data, 0) {
-                let strings = parsers::get_smbios_strings(data, 0, header.length);
+            let kind = parsers::detect_firmware_file(&data);
+            self.loaded_files.push(LoadedFile {
+                name: name.clone(),
+                data,
+                kind,
+            });
+            names.push(name);
+        }
+        if names.is_empty() {
+            return;
+        }
+        self.select_file(self.loaded_files.len() - 1);
+        self.status = i18n::tf("Loaded from file: {}", &[&names.join(", ")]);
+    }
 
-                out.push_str(&format!(
-                    "Type {} (Handle 0x{:04X})\n",
-                    header.type_id, header.handle
-                ));
-                out.push_str(&format!("Length: {}\n", header.length));
-                out.push_str("====================\n");
+    /// Loads dump files from disk as if they had been dropped onto the window.
+    fn open_files(&mut self, paths: Vec<PathBuf>) {
+        let files = paths
+            .into_iter()
+            .map(|path| egui::DroppedFile {
+                path: Some(path),
+                ..Default::default()
+            })
+            .collect();
+        self.load_dropped_files(files);
+    }
 
-                if let Some(details) =
-                    parsers::parse_smbios_details(header.type_id, data, 0, header.length, &strings)
-                {
-                    for (k, v) in details {
-                        out.push_str(&format!("{:25}: {}\n", k, v));
-                    }
-                } else if !strings.is_empty() {
-                    out.push_str("Strings:\n");
-                    for (i, s) in strings.iter().enumerate() {
-                        out.push_str(&format!("  {}: {}\n", i + 1, s));
-                    }
-                } else {
-                    out.push_str("No strings.\n");
-                }
+    /// Pins `pin` to the top of the sidebar, or unpins it if it is already pinned.
+    fn toggle_pin(&mut self, pin: Pin) {
+        match self.pins.iter().position(|p| *p == pin) {
+            Some(index) => {
+                self.pins.remove(index);
             }
+            None => self.pins.push(pin),
         }
-        self.cached_parsed = out;
+    }
 
-        // Refresh search matches if query exists
-        if !self.search_query.is_empty() {
-            self.recompute_search_matches();
-        }
+    /// Moves `path` to the front of the recent files, dropping the oldest past the limit.
+    fn remember_recent_file(&mut self, path: PathBuf) {
+        self.recent_files.retain(|p| *p != path);
+        self.recent_files.insert(0, path);
+        self.recent_files.truncate(RECENT_FILES_LIMIT);
     }
 
-    /// Recomputes search match positions for the active text.
-    fn recompute_search_matches(&mut self) {
-        let text = match self.active_tab {
-            Tab::Hex => &self.cached_hex,
-            Tab::Parsed => &self.cached_parsed,
-        }
-        .to_lowercase();
+    /// Draws the File menu with Open and the recent files list.
+    fn show_menu_bar(&mut self, ctx: &egui::Context) {
+        let mut open_dialog = false;
+        let mut open_recent = None;
+        let mut clear_recent = false;
+        let mut choose_export_dir = false;
+        let mut export_trimmed = false;
+        egui::TopBottomPanel::top("menu_bar").show(ctx, |ui| {
+            egui::menu::bar(ui, |ui| {
+                ui.menu_button(i18n::t("File"), |ui| {
+                    if ui.button(i18n::t("Open Dump…")).clicked() {
+                        open_dialog = true;
+                        ui.close_menu();
+                    }
+                    ui.menu_button(i18n::t("Recent"), |ui| {
+                        if self.recent_files.is_empty() {
+                            ui.label(i18n::t("No recent files"));
+                            return;
+                        }
+                        for path in &self.recent_files {
+                            let name = path
+                                .file_name()
+                                .map(|n| n.to_string_lossy().to_string())
+                                .unwrap_or_else(|| path.to_string_lossy().to_string());
+                            // Missing files stay listed so a reconnected drive brings them back
+                            let exists = path.exists();
+                            let label = if exists {
+                                name
+                            } else {
+                                i18n::tf("⚠ {} (missing)", &[&name])
+                            };
+                            if ui
+                                .add_enabled(exists, egui::Button::new(label))
+                                .on_hover_text(path.to_string_lossy())
+                                .on_disabled_hover_text(path.to_string_lossy())
+                                .clicked()
+                            {
+                                open_recent = Some(path.clone());
+                                ui.close_menu();
+                            }
+                        }
+                        ui.separator();
+                        if ui.button(i18n::t("Clear Recent")).clicked() {
+                            clear_recent = true;
+                            ui.close_menu();
+                        }
+                    });
+                    ui.menu_button(i18n::t("Export Naming"), |ui| {
+                        ui.label(i18n::t("Filename template"));
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.export_name_template)
+                                .hint_text("{date}_{sig}_{table_id}")
+                                .desired_width(240.0),
+                        );
+                        let placeholders = report::FILENAME_PLACEHOLDERS
+                            .iter()
+                            .map(|p| format!("{{{}}}", p))
+                            .collect::<Vec<_>>()
+                            .join(" ");
+                        ui.weak(placeholders);
+                        ui.weak(i18n::t(
+                            "Used by Export Raw, Export Parsed, and Export All; leave empty for the built-in names",
+                        ));
+                        ui.separator();
+                        ui.label(i18n::t("Output folder"));
+                        if self.export_dir.is_empty() {
+                            ui.weak(i18n::t("Not set"));
+                        } else {
+                            ui.monospace(&self.export_dir);
+                        }
+                        ui.horizontal(|ui| {
+                            if ui.button(i18n::t("Choose…")).clicked() {
+                                choose_export_dir = true;
+                                ui.close_menu();
+                            }
+                            if ui
+                                .add_enabled(
+                                    !self.export_dir.is_empty(),
+                                    egui::Button::new(i18n::t("Clear")),
+                                )
+                                .clicked()
+                            {
+                                self.export_dir.clear();
+                            }
+                        });
+                    });
+                    ui.menu_button(i18n::t("Export Trimmed Copy"), |ui| {
+                        export_trimmed = self.show_trim_menu(ui);
+                    });
+                    ui.separator();
+                    ui.checkbox(&mut self.load_on_startup, i18n::t("Load tables on startup"))
+                        .on_hover_text(i18n::t(
+                            "Read ACPI and SMBIOS data in the background when the window opens (administrator only)",
+                        ));
+                });
+            });
+        });
 
-        let query = self.search_query.to_lowercase();
-        self.search_matches.clear();
-        self.search_current = 0;
-        if query.is_empty() {
-            return;
+        if open_dialog {
+            if let Some(paths) = rfd::FileDialog::new()
+                .set_title(i18n::t("Open Dump Files"))
+                .add_filter(i18n::t("Firmware Dumps"), &["aml", "bin", "dat"])
+                .add_filter(i18n::t("All Files"), &["*"])
+                .pick_files()
+            {
+                self.open_files(paths);
+            }
         }
-
-        let mut start = 0;
-        while let Some(pos) = text[start..].find(&query) {
-            self.search_matches.push(start + pos);
-            start += pos + query.len().max(1);
+        if let Some(path) = open_recent {
+            self.open_files(vec![path]);
         }
-    }
-
-    /// Advances search selection.
-    fn step_search(&mut self, delta: isize) {
-        if self.search_matches.is_empty() {
-            return;
+        if clear_recent {
+            self.recent_files.clear();
+        }
+        if export_trimmed {
+            self.export_trimmed();
+        }
+        if choose_export_dir {
+            if let Some(folder) = export_dialog(&self.export_dir)
+                .set_title(i18n::t("Select Default Export Folder"))
+                .pick_folder()
+            {
+                self.export_dir = folder.to_string_lossy().to_string();
+            }
         }
-        let len = self.search_matches.len();
-        let cur = self.search_current as isize;
-        let next = (cur + delta).rem_euclid(len as isize);
-        self.search_current = next as usize;
-    }
-
-    /// Sanitizes a filename fragment for Windows.
-    fn clean_filename_fragment(fragment: &str) -> String {
-        let invalid = ['<', '>', ':', '"', '/', '\\', '|', '?', '*'];
-        fragment
-            .chars()
-            .map(|c| if invalid.contains(&c) { '_' } else { c })
-            .collect()
     }
 
-    /// Opens a save file dialog to export the currently selected item as a raw binary file.
-    fn export_raw(&self) {
-        let (data, default_name) = match &self.selected_item {
-            Selection::Acpi(info) => {
-                let result = if let Some(ref path) = info.registry_path {
-                    api::get_acpi_table_by_path(path)
+    /// Draws the Load from Base64 dialog and adds the decoded data under "Loaded from File".
+    fn show_base64_dialog(&mut self, ctx: &egui::Context) {
+        let Some(input) = &mut self.base64_input else {
+            return;
+        };
+        let mut open = true;
+        let mut load = false;
+        let mut cancel = false;
+        egui::Window::new(i18n::t("Load from Base64"))
+            .open(&mut open)
+            .collapsible(false)
+            .default_width(420.0)
+            .show(ctx, |ui| {
+                ui.label(i18n::t(
+                    "Paste the Base64 text of an ACPI table or SMBIOS dump:",
+                ));
+                egui::ScrollArea::vertical()
+                    .max_height(240.0)
+                    .show(ui, |ui| {
+                        ui.add(
+                            egui::TextEdit::multiline(input)
+                                .font(egui::TextStyle::Monospace)
+                                .desired_width(f32::INFINITY)
+                                .desired_rows(8),
+                        );
+                    });
+                ui.horizontal(|ui| {
+                    load = ui.button(i18n::t("Load")).clicked();
+                    cancel = ui.button(i18n::t("Cancel")).clicked();
+                });
+            });
+        if !open || cancel {
+            self.base64_input = None;
+            return;
+        }
+        if !load {
+            return;
+        }
+
+        match decode_base64_dump(input) {
+            Ok((data, kind)) => {
+                let pasted = self
+                    .loaded_files
+                    .iter()
+                    .filter(|f| f.name.starts_with("base64_"))
+                    .count();
+                let name = format!("base64_{}.bin", pasted + 1);
+                self.loaded_files.push(LoadedFile {
+                    name: name.clone(),
+                    data,
+                    kind,
+                });
+                self.base64_input = None;
+                self.select_file(self.loaded_files.len() - 1);
+                self.status = i18n::tf("Loaded from Base64: {}", &[&name]);
+            }
+            Err(e) => {
+                rfd::MessageDialog::new()
+                    .set_title(i18n::t("Load from Base64"))
+                    .set_description(e)
+                    .set_level(rfd::MessageLevel::Error)
+                    .show();
+            }
+        }
+    }
+
+    /// Adjusts the data view font size from Ctrl+scroll, Ctrl+Plus/Minus, and Ctrl+0.
+    ///
+    /// The shortcuts are consumed before egui's own keyboard zoom sees them, so the rest of
+    /// the interface keeps its scale.
+    fn handle_zoom_keys(&mut self, ctx: &egui::Context) {
+        let (scroll, zoom_in, zoom_out, reset) = ctx.input_mut(|i| {
+            (
+                i.zoom_delta(),
+                i.consume_key(egui::Modifiers::COMMAND, egui::Key::Plus)
+                    | i.consume_key(egui::Modifiers::COMMAND, egui::Key::Equals),
+                i.consume_key(egui::Modifiers::COMMAND, egui::Key::Minus),
+                i.consume_key(egui::Modifiers::COMMAND, egui::Key::Num0),
+            )
+        });
+        // Scrolling scales smoothly; the keys step by whole points
+        let mut size = self.data_font_size * scroll;
+        if zoom_in {
+            size = size.round() + 1.0;
+        }
+        if zoom_out {
+            size = size.round() - 1.0;
+        }
+        if reset {
+            size = DATA_FONT_SIZE;
+        }
+        let size = size.clamp(*DATA_FONT_RANGE.start(), *DATA_FONT_RANGE.end());
+        if size != self.data_font_size {
+            self.data_font_size = size;
+            self.status = i18n::tf("Data font size: {} pt", &[&format!("{:.0}", size)]);
+        }
+    }
+
+    /// Draws the Tables by Size window: each ACPI table's size, the ACPI and SMBIOS totals,
+    /// and the largest table. Clicking a table selects it.
+    fn show_footprint_window(&mut self, ctx: &egui::Context) {
+        if !self.footprint_open {
+            return;
+        }
+        // Sizes come from `acpi_sizes`, filled through the table cache when the tables load
+        let footprint = report::firmware_footprint(
+            self.acpi_tables.as_deref().unwrap_or(&[]),
+            &self.acpi_sizes,
+            self.smbios_data.as_deref(),
+        );
+        let mut rows: Vec<&(api::AcpiTableInfo, usize)> = footprint.tables.iter().collect();
+        if self.footprint_by_name {
+            rows.sort_by_key(|(t, _)| acpi_entry_label(t));
+        }
+
+        let mut open = true;
+        let mut select = None;
+        egui::Window::new(i18n::t("Tables by Size"))
+            .open(&mut open)
+            .default_width(360.0)
+            .show(ctx, |ui| {
+                if rows.is_empty() && footprint.smbios_size.is_none() {
+                    ui.label(i18n::t("Load the ACPI tables or SMBIOS data first."));
+                    return;
+                }
+                egui::ScrollArea::vertical()
+                    .max_height(320.0)
+                    .show(ui, |ui| {
+                        egui::Grid::new("footprint_grid")
+                            .num_columns(3)
+                            .striped(true)
+                            .show(ui, |ui| {
+                                let name_header = if self.footprint_by_name {
+                                    i18n::t("Table ⏶")
+                                } else {
+                                    i18n::t("Table")
+                                };
+                                let size_header = if self.footprint_by_name {
+                                    i18n::t("Size")
+                                } else {
+                                    i18n::t("Size ⏷")
+                                };
+                                if ui.button(name_header).clicked() {
+                                    self.footprint_by_name = true;
+                                }
+                                if ui.button(size_header).clicked() {
+                                    self.footprint_by_name = false;
+                                }
+                                ui.strong("%");
+                                ui.end_row();
+
+                                for (t, size) in &rows {
+                                    let label = acpi_entry_label(t);
+                                    let selected =
+                                        matches!(&self.selected_item, Selection::Acpi(s) if s == t);
+                                    if ui.selectable_label(selected, label).clicked() {
+                                        select = Some(t.clone());
+                                    }
+                                    ui.monospace(format!("{:>9}", size));
+                                    let share =
+                                        *size as f64 * 100.0 / footprint.acpi_total.max(1) as f64;
+                                    ui.monospace(format!("{:5.1}", share));
+                                    ui.end_row();
+                                }
+                            });
+                    });
+                ui.separator();
+                egui::Grid::new("footprint_totals")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.strong(i18n::tf("ACPI ({} tables)", &[&footprint.tables.len()]));
+                        ui.monospace(i18n::tf("{} bytes", &[&footprint.acpi_total]));
+                        ui.end_row();
+                        ui.strong(i18n::t("SMBIOS"));
+                        match footprint.smbios_size {
+                            Some(size) => ui.monospace(i18n::tf("{} bytes", &[&size])),
+                            None => ui.label(i18n::t("Not loaded")),
+                        };
+                        ui.end_row();
+                        ui.strong(i18n::t("Total"));
+                        ui.monospace(i18n::tf("{} bytes", &[&footprint.total()]));
+                        ui.end_row();
+                        if let Some((t, size)) = footprint.largest() {
+                            ui.strong(i18n::t("Largest"));
+                            ui.label(i18n::tf("{}, {} bytes", &[&acpi_entry_label(t), size]));
+                            ui.end_row();
+                        }
+                    });
+            });
+        self.footprint_open = open;
+        if let Some(t) = select {
+            self.run_acpi_entry_action(ctx, AcpiEntryAction::Select(t));
+        }
+    }
+
+    /// Draws the Table Advisory window: the commonly expected ACPI tables that are missing
+    /// and the unrecognized tables that are present. Clicking a table selects it.
+    fn show_advisory_window(&mut self, ctx: &egui::Context) {
+        if !self.advisory_open {
+            return;
+        }
+        let advisory = self.acpi_tables.as_deref().map(|tables| {
+            report::acpi_table_advisory(tables, |t| read_acpi_cached(&self.table_cache, t))
+        });
+
+        let mut open = true;
+        let mut select = None;
+        egui::Window::new(i18n::t("Table Advisory"))
+            .open(&mut open)
+            .default_width(360.0)
+            .show(ctx, |ui| {
+                let Some(advisory) = &advisory else {
+                    ui.label(i18n::t("Load the ACPI tables first."));
+                    return;
+                };
+                ui.strong(i18n::t("Expected tables"));
+                if advisory.missing.is_empty() {
+                    ui.label(i18n::t("All commonly expected tables are present."));
+                }
+                for (sigs, purpose) in &advisory.missing {
+                    ui.colored_label(
+                        Color32::from_rgb(210, 150, 40),
+                        i18n::tf("⚠ {} missing: {}", &[sigs, &i18n::t(purpose)]),
+                    );
+                }
+                ui.separator();
+                ui.strong(i18n::t("Unusual tables"));
+                if advisory.unusual.is_empty() {
+                    ui.label(i18n::t("None"));
+                }
+                for t in &advisory.unusual {
+                    let selected = matches!(&self.selected_item, Selection::Acpi(s) if s == t);
+                    if ui
+                        .selectable_label(selected, acpi_entry_label(t))
+                        .on_hover_text(i18n::t(
+                            "Not a standard ACPI signature; usually an OEM-specific table",
+                        ))
+                        .clicked()
+                    {
+                        select = Some(t.clone());
+                    }
+                }
+            });
+        self.advisory_open = open;
+        if let Some(t) = select {
+            self.run_acpi_entry_action(ctx, AcpiEntryAction::Select(t));
+        }
+    }
+
+    /// Draws the All SMBIOS Fields window: every parsed field of every structure in one
+    /// table that can be filtered and sorted by column. Clicking a row selects its structure.
+    fn show_smbios_fields_window(&mut self, ctx: &egui::Context) {
+        if !self.smbios_fields_open {
+            return;
+        }
+        let key = (self.string_encoding, self.smbios_parse_version());
+        if self.smbios_field_rows_key != Some(key) {
+            self.smbios_field_rows = self
+                .smbios_data
+                .as_deref()
+                .map(|data| report::smbios_field_rows(data, key.0, key.1))
+                .unwrap_or_default();
+            self.smbios_field_rows_key = Some(key);
+            self.smbios_field_order_key = None;
+        }
+        // Sorted only when the rows or the sort change; the stable sort keeps table order
+        // among equal keys in both directions
+        if self.smbios_field_order_key != Some(self.smbios_fields_sort) {
+            let (column, descending) = self.smbios_fields_sort;
+            let rows = &self.smbios_field_rows;
+            let mut order: Vec<usize> = (0..rows.len()).collect();
+            if descending {
+                order.sort_by_cached_key(|&i| std::cmp::Reverse(column.sort_key(&rows[i])));
+            } else {
+                order.sort_by_cached_key(|&i| column.sort_key(&rows[i]));
+            }
+            self.smbios_field_order = order;
+            self.smbios_field_order_key = Some(self.smbios_fields_sort);
+        }
+
+        let mut open = true;
+        let mut select = None;
+        let mut export = false;
+        egui::Window::new(i18n::t("All SMBIOS Fields"))
+            .open(&mut open)
+            .default_width(780.0)
+            .default_height(420.0)
+            .show(ctx, |ui| {
+                if self.smbios_data.is_none() {
+                    ui.label(i18n::t("Load the SMBIOS data first."));
+                    return;
+                }
+                let needle = self.smbios_fields_filter.to_lowercase();
+                let (column, descending) = self.smbios_fields_sort;
+                let rows: Vec<&report::SmbiosFieldRow> = self
+                    .smbios_field_order
+                    .iter()
+                    .map(|&i| &self.smbios_field_rows[i])
+                    .filter(|row| row.matches(&needle))
+                    .collect();
+
+                ui.horizontal(|ui| {
+                    ui.label(i18n::t("Filter:"));
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.smbios_fields_filter)
+                            .hint_text(i18n::t("Type, handle, field, or value"))
+                            .desired_width(240.0),
+                    );
+                    ui.label(i18n::tf(
+                        "{} of {} fields",
+                        &[&rows.len(), &self.smbios_field_rows.len()],
+                    ));
+                    if ui
+                        .button(i18n::t("Export CSV"))
+                        .on_hover_text(i18n::t("Save the rows shown, in this order"))
+                        .clicked()
+                    {
+                        export = true;
+                    }
+                });
+                ui.separator();
+
+                ui.horizontal(|ui| {
+                    for c in SmbiosFieldColumn::ALL {
+                        let arrow = match (c == column, descending) {
+                            (false, _) => "",
+                            (true, false) => " ⏶",
+                            (true, true) => " ⏷",
+                        };
+                        let header = egui::Button::new(format!("{}{}", c.label(), arrow));
+                        if ui.add_sized([c.width(), 20.0], header).clicked() {
+                            self.smbios_fields_sort = (c, c == column && !descending);
+                        }
+                    }
+                });
+
+                // Only the visible rows are laid out, so thousands of fields stay cheap
+                let row_height = ui.text_style_height(&egui::TextStyle::Body) + 4.0;
+                egui::ScrollArea::both().auto_shrink(false).show_rows(
+                    ui,
+                    row_height,
+                    rows.len(),
+                    |ui, range| {
+                        for row in &rows[range] {
+                            let selected = matches!(
+                                self.selected_item,
+                                Selection::Smbios(off, _) if off == row.offset
+                            );
+                            let cells = [
+                                row.type_label(),
+                                format!("0x{:04X}", row.handle),
+                                row.field.clone(),
+                                row.value.clone(),
+                            ];
+                            let response = ui
+                                .horizontal(|ui| {
+                                    for (c, text) in SmbiosFieldColumn::ALL.iter().zip(cells) {
+                                        ui.add_sized(
+                                            [c.width(), row_height],
+                                            egui::SelectableLabel::new(selected, text),
+                                        );
+                                    }
+                                })
+                                .response
+                                .interact(egui::Sense::click());
+                            if response.clicked() {
+                                select = Some((row.offset, row.type_id));
+                            }
+                        }
+                    },
+                );
+                if export {
+                    save_text_file(
+                        &self.export_dir,
+                        "smbios_fields.csv",
+                        &report::smbios_field_rows_csv(&rows),
+                    );
+                }
+            });
+        self.smbios_fields_open = open;
+        if let Some((offset, type_id)) = select {
+            self.select_smbios(offset, type_id);
+        }
+    }
+
+    /// Returns the SMBIOS blob behind one side of the SMBIOS Diff window, if it is loaded.
+    fn diff_side_data(&self, side: DiffSide) -> Option<&[u8]> {
+        match side {
+            DiffSide::Live => self.smbios_data.as_deref().filter(|d| !d.is_empty()),
+            DiffSide::File(i) => match &self.loaded_files.get(i)?.kind {
+                parsers::FirmwareFileKind::Smbios(blob) => Some(blob),
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns the name of one side of the SMBIOS Diff window.
+    fn diff_side_label(&self, side: DiffSide) -> String {
+        match side {
+            DiffSide::Live => i18n::t("This system").to_string(),
+            DiffSide::File(i) => self
+                .loaded_files
+                .get(i)
+                .map_or_else(String::new, |f| f.name.clone()),
+        }
+    }
+
+    /// Draws the SMBIOS Diff window: pick two SMBIOS blobs, this system's or loaded dumps,
+    /// and list the structures only in A, only in B, or changed, grouped by type.
+    fn show_smbios_diff_window(&mut self, ctx: &egui::Context) {
+        if !self.smbios_diff_open {
+            return;
+        }
+        let candidates: Vec<DiffSide> = std::iter::once(DiffSide::Live)
+            .chain((0..self.loaded_files.len()).map(DiffSide::File))
+            .filter(|&side| self.diff_side_data(side).is_some())
+            .collect();
+        // Fall back to the first two available blobs when a side is gone or was never picked
+        let (mut a, mut b) = self.smbios_diff_sides;
+        if !candidates.contains(&a) {
+            a = candidates.first().copied().unwrap_or(DiffSide::Live);
+        }
+        if !candidates.contains(&b) || (a == b && candidates.len() > 1) {
+            b = candidates
+                .iter()
+                .copied()
+                .find(|&side| side != a)
+                .unwrap_or(a);
+        }
+
+        let mut open = true;
+        let mut compare = false;
+        let mut copy = None;
+        egui::Window::new(i18n::t("SMBIOS Diff"))
+            .open(&mut open)
+            .default_width(520.0)
+            .show(ctx, |ui| {
+                if candidates.len() < 2 {
+                    ui.label(i18n::t(
+                        "Load this system's SMBIOS data or open SMBIOS dumps so there are two to compare.",
+                    ));
+                    return;
+                }
+                egui::Grid::new("smbios_diff_sides")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        for (name, side) in [("A", &mut a), ("B", &mut b)] {
+                            ui.strong(name);
+                            egui::ComboBox::from_id_salt(("smbios_diff_side", name))
+                                .selected_text(self.diff_side_label(*side))
+                                .width(300.0)
+                                .show_ui(ui, |ui| {
+                                    for &candidate in &candidates {
+                                        ui.selectable_value(
+                                            side,
+                                            candidate,
+                                            self.diff_side_label(candidate),
+                                        );
+                                    }
+                                });
+                            ui.end_row();
+                        }
+                    });
+                ui.horizontal(|ui| {
+                    compare = ui.button(i18n::t("Compare")).clicked();
+                    if let Some((_, _, diffs)) = &self.smbios_diff {
+                        if ui.button(i18n::t("📋 Copy")).clicked() {
+                            copy = Some(report::smbios_diff_text(diffs));
+                        }
+                    }
+                });
+                let Some((name_a, name_b, diffs)) = &self.smbios_diff else {
+                    return;
+                };
+                ui.separator();
+                ui.label(i18n::tf("A: {}, B: {}", &[name_a, name_b]));
+                ui.strong(report::smbios_diff_summary(diffs));
+                if diffs.is_empty() {
+                    ui.label(i18n::t("No structure differs."));
+                    return;
+                }
+                egui::ScrollArea::vertical()
+                    .max_height(420.0)
+                    .show(ui, |ui| {
+                        for group in diffs.chunk_by(|x, y| x.type_id == y.type_id) {
+                            let type_id = group[0].type_id;
+                            let name = match parsers::smbios_type_name(type_id) {
+                                "" => format!("Type {}", type_id),
+                                name => format!("Type {} - {}", type_id, name),
+                            };
+                            egui::CollapsingHeader::new(format!("{} ({})", name, group.len()))
+                                .id_salt(("smbios_diff_type", type_id))
+                                .default_open(true)
+                                .show(ui, |ui| {
+                                    for diff in group {
+                                        show_smbios_structure_diff(ui, diff);
+                                    }
+                                });
+                        }
+                    });
+            });
+        self.smbios_diff_open = open;
+        self.smbios_diff_sides = (a, b);
+        if compare {
+            if let (Some(data_a), Some(data_b)) = (self.diff_side_data(a), self.diff_side_data(b)) {
+                let diffs = report::diff_smbios(data_a, data_b, self.string_encoding);
+                self.status = i18n::tf("SMBIOS diff: {}", &[&report::smbios_diff_summary(&diffs)]);
+                self.smbios_diff = Some((self.diff_side_label(a), self.diff_side_label(b), diffs));
+            }
+        }
+        if let Some(text) = copy {
+            self.copy_to_clipboard(ctx, &text);
+            self.status = i18n::t("Copied SMBIOS diff").to_string();
+        }
+    }
+
+    /// Returns the value added to hex view offsets for the current selection.
+    ///
+    /// SMBIOS structures are numbered from their position in the blob when absolute offsets
+    /// are enabled; ACPI tables and loaded files always start at 0.
+    fn hex_base_offset(&self) -> usize {
+        match self.selected_item {
+            Selection::Smbios(offset, _) if self.hex_absolute_offsets => offset,
+            _ => 0,
+        }
+    }
+
+    /// Updates the internal hex and parsed text caches for the selected data block.
+    fn update_cache(&mut self, data: &[u8], parsed: String) {
+        // Hex Dump
+        self.hex_line_starts = report::hex_dump_line_starts(data.len(), self.hex_base_offset());
+        self.hex_error = None;
+        self.cached_bytes = data.to_vec();
+        self.hex_runs = report::hex_runs(data);
+        self.hex_selection = None;
+        self.cached_stats = Some(report::byte_stats(data));
+        self.hex_edit_text = hex_edit_str(data);
+        self.hex_edit_error = None;
+        self.bytes_modified = false;
+        self.refresh_structured_views();
+
+        // Parsed
+        self.cached_parsed = parsed;
+
+        // Refresh search matches if query exists
+        if !self.search_query.is_empty() {
+            self.recompute_search_matches();
+        }
+    }
+
+    /// Recomputes search match positions for the active text.
+    ///
+    /// Hex view positions index into the text [`report::hex_dump_str`] would produce; the
+    /// dump is searched one line at a time so it never has to be built in full.
+    fn recompute_search_matches(&mut self) {
+        let query = self.search_query.to_lowercase();
+        self.search_matches.clear();
+        self.search_current = 0;
+        if query.is_empty() {
+            return;
+        }
+
+        let base_offset = self.hex_base_offset();
+        let mut find_all = |text: &str, base: usize| {
+            let text = text.to_lowercase();
+            let mut start = 0;
+            while let Some(pos) = text[start..].find(&query) {
+                self.search_matches.push(base + start + pos);
+                start += pos + query.len().max(1);
+            }
+        };
+        match self.active_tab {
+            Tab::Hex => {
+                for (row, &base) in self.hex_line_starts.iter().enumerate() {
+                    find_all(
+                        &report::hex_dump_line(&self.cached_bytes, row, base_offset),
+                        base,
+                    );
+                }
+            }
+            Tab::Parsed => find_all(&self.cached_parsed, 0),
+        }
+    }
+
+    /// Collects the lines of the active text that contain a search match.
+    ///
+    /// A line with several matches is collected once. Hex view matches map to the dump row
+    /// they fall in, using the same offsets as the visible dump.
+    ///
+    /// # Arguments
+    /// * `unique` - Whether to drop lines identical to one collected earlier.
+    fn matching_lines(&self, unique: bool) -> Vec<String> {
+        let base_offset = self.hex_base_offset();
+        let mut lines = Vec::new();
+        let mut seen = HashSet::new();
+        let mut last_line = None;
+        for &pos in &self.search_matches {
+            let (line_start, line) = match self.active_tab {
+                Tab::Hex => {
+                    let row = self
+                        .hex_line_starts
+                        .partition_point(|&start| start <= pos)
+                        .saturating_sub(1);
+                    (
+                        row,
+                        report::hex_dump_line(&self.cached_bytes, row, base_offset),
+                    )
+                }
+                Tab::Parsed => {
+                    let text = &self.cached_parsed;
+                    // Lowercasing can shift non-ASCII text, so the offset may not be a boundary
+                    if !text.is_char_boundary(pos) {
+                        continue;
+                    }
+                    let start = text[..pos].rfind('\n').map_or(0, |i| i + 1);
+                    let end = text[pos..].find('\n').map_or(text.len(), |i| pos + i);
+                    (start, text[start..end].to_string())
+                }
+            };
+            if last_line == Some(line_start) {
+                continue;
+            }
+            last_line = Some(line_start);
+            let line = line.trim_end_matches(['\r', '\n']).to_string();
+            if unique && !seen.insert(line.clone()) {
+                continue;
+            }
+            lines.push(line);
+        }
+        lines
+    }
+
+    /// Validates the edited hex digits and, if they are well-formed, applies them to the buffer.
+    fn apply_hex_edit(&mut self) {
+        match parse_hex_edit(&self.hex_edit_text, self.cached_bytes.len()) {
+            Ok(bytes) => {
+                self.hex_edit_error = None;
+                if bytes != self.cached_bytes {
+                    self.set_edited_bytes(bytes);
+                }
+            }
+            Err(e) => self.hex_edit_error = Some(e),
+        }
+    }
+
+    /// Replaces the selected item's bytes with an edited copy and refreshes the views.
+    fn set_edited_bytes(&mut self, bytes: Vec<u8>) {
+        self.hex_line_starts = report::hex_dump_line_starts(bytes.len(), self.hex_base_offset());
+        self.hex_runs = report::hex_runs(&bytes);
+        self.cached_stats = Some(report::byte_stats(&bytes));
+        self.cached_parsed = match self.selected_item {
+            Selection::Acpi(_) => self.reveal_secrets(
+                &bytes,
+                report::acpi_table_text(&bytes, self.acpi_tables.as_deref().unwrap_or(&[]), false),
+            ),
+            Selection::Smbios(..) => self.smbios_parsed_text(&bytes),
+            Selection::File(_) => file_parsed_text(
+                &bytes,
+                self.acpi_tables.as_deref().unwrap_or(&[]),
+                self.smbios_data.as_deref().unwrap_or(&[]),
+                self.string_encoding,
+            ),
+            Selection::None => String::new(),
+        };
+        self.cached_bytes = bytes;
+        self.bytes_modified = true;
+        self.refresh_structured_views();
+        if !self.search_query.is_empty() {
+            self.recompute_search_matches();
+        }
+    }
+
+    /// Rebuilds the widget-based parsed views (SMBIOS field grid, SLIT matrix, AML scope
+    /// tree) from `cached_bytes`.
+    fn refresh_structured_views(&mut self) {
+        self.slit_matrix = match &self.selected_item {
+            Selection::Acpi(info) if info.signature == "SLIT" => {
+                parsers::slit_matrix(&self.cached_bytes)
+            }
+            _ => None,
+        };
+        self.aml_tree = match &self.selected_item {
+            Selection::Acpi(_) | Selection::File(_) => {
+                parsers::parse_acpi_header(&self.cached_bytes)
+                    .ok()
+                    .filter(|h| h.signature == "DSDT" || h.signature == "SSDT")
+                    .map(|_| parsers::aml_scope_tree(&self.cached_bytes))
+                    .filter(|tree| !tree.is_empty())
+            }
+            _ => None,
+        };
+        self.acpi_address_refs = match &self.selected_item {
+            Selection::Acpi(_) => report::acpi_address_refs(
+                &self.cached_bytes,
+                self.acpi_tables.as_deref().unwrap_or(&[]),
+            ),
+            _ => Vec::new(),
+        };
+        self.smbios_field_view = match self.selected_item {
+            Selection::Smbios(..) => parsers::parse_smbios_structure(&self.cached_bytes, 0)
+                .ok()
+                .and_then(|(header, _)| {
+                    let mut fields = match self.smbios_interpret_as {
+                        Some(type_id) => report::smbios_fields_as(
+                            &self.cached_bytes,
+                            self.string_encoding,
+                            type_id,
+                            self.smbios_parse_version(),
+                        )?,
+                        None => report::smbios_fields_with_version(
+                            &self.cached_bytes,
+                            self.string_encoding,
+                            self.smbios_parse_version(),
+                        )?,
+                    };
+                    report::resolve_smbios_handles(
+                        self.smbios_data.as_deref().unwrap_or(&[]),
+                        &mut fields,
+                        self.string_encoding,
+                    );
+                    Some((header.handle, fields))
+                }),
+            _ => None,
+        };
+    }
+
+    /// Draws one line naming the selected item above the tabs, so its identity stays in
+    /// view while the hex or parsed view scrolls.
+    fn show_selection_header(&self, ui: &mut egui::Ui) {
+        let size = self.cached_bytes.len();
+        match &self.selected_item {
+            Selection::None => {}
+            Selection::Acpi(t) => {
+                ui.horizontal_wrapped(|ui| {
+                    ui.strong(&t.signature);
+                    ui.label(i18n::tf(
+                        "OEM {} | Table ID {} | {} bytes",
+                        &[&t.oem_id, &t.table_id, &size],
+                    ));
+                    match acpi_checksum_status(t, &self.cached_bytes) {
+                        Some(ChecksumStatus::Ok) => {
+                            ui.label(i18n::t("Checksum: OK"));
+                        }
+                        Some(ChecksumStatus::Invalid(sum)) => {
+                            ui.colored_label(
+                                Color32::from_rgb(200, 50, 50),
+                                i18n::tf(
+                                    "Checksum: invalid (sum {})",
+                                    &[&format!("0x{:02X}", sum)],
+                                ),
+                            );
+                        }
+                        Some(ChecksumStatus::Truncated) => {
+                            ui.colored_label(
+                                Color32::from_rgb(200, 50, 50),
+                                i18n::t("Checksum: not checked (table truncated)"),
+                            );
+                        }
+                        None => {}
+                    }
+                });
+            }
+            Selection::Smbios(offset, type_id) => {
+                let handle = self
+                    .smbios_list
+                    .iter()
+                    .find(|(off, ..)| off == offset)
+                    .map(|(_, _, _, handle, _)| *handle);
+                ui.horizontal_wrapped(|ui| {
+                    ui.strong(match parsers::smbios_type_name(*type_id) {
+                        "" => format!("Type {}", type_id),
+                        name => format!("Type {} - {}", type_id, name),
+                    });
+                    match handle {
+                        Some(handle) => ui.label(i18n::tf(
+                            "Handle 0x{} | {} bytes",
+                            &[&format!("{:04X}", handle), &size],
+                        )),
+                        None => ui.label(i18n::tf("{} bytes", &[&size])),
+                    };
+                });
+            }
+            Selection::File(i) => {
+                if let Some(file) = self.loaded_files.get(*i) {
+                    ui.horizontal_wrapped(|ui| {
+                        ui.strong(&file.name);
+                        ui.label(i18n::tf("{} bytes", &[&size]));
+                    });
+                }
+            }
+        }
+    }
+
+    /// Returns the key the selected table's or structure's note is stored under, or `None`
+    /// when nothing that can be annotated is selected.
+    fn selection_note_key(&self) -> Option<String> {
+        match &self.selected_item {
+            Selection::Acpi(t) => Some(report::acpi_note_key(&t.signature)),
+            Selection::Smbios(offset, type_id) => self
+                .smbios_list
+                .iter()
+                .find(|(off, ..)| off == offset)
+                .map(|(_, _, _, handle, _)| report::smbios_note_key(*type_id, *handle)),
+            Selection::None | Selection::File(_) => None,
+        }
+    }
+
+    /// Shows the free-text notes box for the selected table or structure. Clearing the text
+    /// removes the note.
+    fn show_notes_box(&mut self, ui: &mut egui::Ui) {
+        let Some(key) = self.selection_note_key() else {
+            return;
+        };
+        let mut text = self.notes.get(&key).cloned().unwrap_or_default();
+        egui::CollapsingHeader::new(i18n::t("📝 Notes"))
+            .id_salt(("notes", &key))
+            .default_open(!text.is_empty())
+            .show(ui, |ui| {
+                let edit = egui::TextEdit::multiline(&mut text)
+                    .desired_rows(3)
+                    .desired_width(f32::INFINITY)
+                    .hint_text(i18n::t(
+                        "Notes are saved with the settings and included in JSON and HTML exports",
+                    ));
+                if ui.add(edit).changed() {
+                    if text.trim().is_empty() {
+                        self.notes.remove(&key);
+                    } else {
+                        self.notes.insert(key.clone(), text.clone());
+                    }
+                }
+            });
+    }
+
+    /// Rewrites the ACPI checksum byte of the edited table so it validates again.
+    fn fix_checksum(&mut self) {
+        let mut bytes = self.cached_bytes.clone();
+        if parsers::fix_acpi_checksum(&mut bytes) {
+            self.hex_edit_text = hex_edit_str(&bytes);
+            self.hex_edit_error = None;
+            self.set_edited_bytes(bytes);
+            self.status = i18n::t("Checksum fixed").to_string();
+        }
+    }
+
+    /// Handles sidebar keyboard navigation: Up/Down move the cursor, Enter loads the row,
+    /// and typed text goes to the filter box.
+    fn handle_sidebar_keys(&mut self, ctx: &egui::Context) {
+        if !self.sidebar_active || ctx.wants_keyboard_input() {
+            return;
+        }
+        let (up, down, enter, typed) = ctx.input_mut(|i| {
+            let typed: String = i
+                .events
+                .iter()
+                .filter_map(|e| match e {
+                    egui::Event::Text(t) => Some(t.as_str()),
+                    _ => None,
+                })
+                .collect();
+            (
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::Enter),
+                typed,
+            )
+        });
+        if !typed.is_empty() {
+            self.sidebar_filter.push_str(&typed);
+            self.focus_sidebar_filter = true;
+            return;
+        }
+
+        let nav = &mut self.sidebar_nav;
+        let count = nav.entries.len();
+        if count == 0 {
+            return;
+        }
+        if up || down {
+            // Start from the selected row if the cursor has not been placed yet
+            let current = nav.cursor.or_else(|| {
+                nav.entries
+                    .iter()
+                    .position(|e| match (e, &self.selected_item) {
+                        (SidebarEntry::Acpi(t), Selection::Acpi(s)) => t == s,
+                        (SidebarEntry::Smbios(o, _), Selection::Smbios(off, _)) => o == off,
+                        (SidebarEntry::File(i), Selection::File(j)) => i == j,
+                        _ => false,
+                    })
+            });
+            nav.cursor = Some(match current {
+                None => 0,
+                Some(c) if down => (c + 1).min(count - 1),
+                Some(c) => c.saturating_sub(1),
+            });
+            nav.scroll_to_cursor = true;
+        }
+        if enter {
+            match nav.cursor.and_then(|c| nav.entries.get(c)).cloned() {
+                Some(SidebarEntry::Acpi(t)) => self.select_acpi(t),
+                Some(SidebarEntry::Smbios(off, tid)) => self.select_smbios(off, tid),
+                Some(SidebarEntry::File(index)) => self.select_file(index),
+                None => {}
+            }
+        }
+    }
+
+    /// Draws the command palette window and runs the chosen action.
+    ///
+    /// Up/Down move the highlight, Enter runs it, and Escape closes the palette.
+    fn show_palette(&mut self, ctx: &egui::Context) {
+        if !self.palette_open {
+            return;
+        }
+        let (up, down, enter, escape) = ctx.input_mut(|i| {
+            (
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::Enter),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::Escape),
+            )
+        });
+        if escape {
+            self.palette_open = false;
+            return;
+        }
+
+        let matches = palette_matches(&self.palette_query);
+        if up {
+            self.palette_cursor = self.palette_cursor.saturating_sub(1);
+        }
+        if down {
+            self.palette_cursor += 1;
+        }
+        self.palette_cursor = self.palette_cursor.min(matches.len().saturating_sub(1));
+
+        let mut chosen = if enter {
+            matches.get(self.palette_cursor).map(|(action, _)| *action)
+        } else {
+            None
+        };
+        egui::Window::new(i18n::t("Command Palette"))
+            .collapsible(false)
+            .resizable(false)
+            .title_bar(false)
+            .anchor(egui::Align2::CENTER_TOP, [0.0, 60.0])
+            .fixed_size([360.0, 0.0])
+            .show(ctx, |ui| {
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut self.palette_query)
+                        .hint_text(i18n::t("Type a command..."))
+                        .desired_width(f32::INFINITY),
+                );
+                response.request_focus();
+                if response.changed() {
+                    self.palette_cursor = 0;
+                }
+                ui.separator();
+                egui::ScrollArea::vertical()
+                    .max_height(300.0)
+                    .show(ui, |ui| {
+                        if matches.is_empty() {
+                            ui.label(i18n::t("No matching commands"));
+                        }
+                        for (i, (action, label)) in matches.iter().enumerate() {
+                            let row = ui.selectable_label(i == self.palette_cursor, *label);
+                            if i == self.palette_cursor && (up || down) {
+                                row.scroll_to_me(None);
+                            }
+                            if row.clicked() {
+                                chosen = Some(*action);
+                            }
+                        }
+                    });
+            });
+
+        if let Some(action) = chosen {
+            self.palette_open = false;
+            self.run_palette_action(action, ctx);
+        }
+    }
+
+    /// Runs a command palette action by calling the matching toolbar or sidebar method.
+    fn run_palette_action(&mut self, action: PaletteAction, ctx: &egui::Context) {
+        let has_selection = !self.selected_item.is_none();
+        let has_smbios_selection = matches!(self.selected_item, Selection::Smbios(..));
+        match action {
+            PaletteAction::LoadAcpi => self.load_acpi(),
+            PaletteAction::LoadSmbios => self.load_smbios(),
+            PaletteAction::Reload => self.reload(ctx),
+            PaletteAction::ExportRaw if has_selection => self.export_raw(),
+            PaletteAction::ExportParsed if has_selection => self.export_parsed(),
+            PaletteAction::ExportHexDump if has_selection => self.export_hex_dump(),
+            PaletteAction::ExportSelection => self.export_selection(),
+            PaletteAction::OpenInHexEditor => match &self.selected_item {
+                Selection::Acpi(info) => {
+                    let info = info.clone();
+                    self.open_in_hex_editor(&info);
+                }
+                _ => self.status = i18n::t("Select an ACPI table first").to_string(),
+            },
+            PaletteAction::ExportSmbiosJson if has_smbios_selection => {
+                self.export_smbios_json(false)
+            }
+            PaletteAction::ExportSmbiosCsv if has_smbios_selection => self.export_smbios_csv(),
+            PaletteAction::ExportFullSmbios => self.export_full_smbios(),
+            PaletteAction::CopySmbiosXml => self.copy_smbios_xml(ctx),
+            PaletteAction::CopyBase64 => self.copy_base64(ctx),
+            PaletteAction::LoadBase64 => {
+                self.base64_input.get_or_insert_with(String::new);
+            }
+            PaletteAction::ExportAllAcpi => self.export_all_acpi(ctx),
+            PaletteAction::ExportAllParsedSmbios => self.export_all_parsed_smbios(false),
+            PaletteAction::ExportAllParsedAcpi => self.export_all_parsed_acpi(false),
+            PaletteAction::ExportAcpiManifest => self.export_acpi_manifest(),
+            PaletteAction::ExportHtmlReport => self.export_html_report(false),
+            PaletteAction::FullSystemReport => self.export_full_report(ctx, false),
+            PaletteAction::ExportHtmlReportRedacted => self.export_html_report(true),
+            PaletteAction::FullSystemReportRedacted => self.export_full_report(ctx, true),
+            PaletteAction::ToggleTheme => {
+                self.theme = if self.dark_mode {
+                    Theme::Light
                 } else {
-                    api::get_system_firmware_table(api::SIG_ACPI, &info.signature)
+                    Theme::Dark
                 };
+            }
+            PaletteAction::DetectVm => {
+                self.refresh_vm_detection();
+                self.status = match &self.vm_detection {
+                    Some(vm) => {
+                        i18n::tf("Likely virtual: {} ({})", &[&vm.hypervisor, &vm.evidence])
+                    }
+                    None => i18n::t("No virtual machine markers found").to_string(),
+                };
+            }
+            PaletteAction::ToggleSearch => self.search_panel_open = !self.search_panel_open,
+            PaletteAction::ToggleByteStats => self.byte_stats_open = !self.byte_stats_open,
+            PaletteAction::ToggleFootprint => self.footprint_open = !self.footprint_open,
+            PaletteAction::ToggleAdvisory => self.advisory_open = !self.advisory_open,
+            PaletteAction::ToggleSmbiosFields => self.smbios_fields_open = !self.smbios_fields_open,
+            PaletteAction::ToggleSmbiosDiff => self.smbios_diff_open = !self.smbios_diff_open,
+            PaletteAction::HexView => self.switch_tab(Tab::Hex),
+            PaletteAction::ParsedView => self.switch_tab(Tab::Parsed),
+            PaletteAction::FilterSidebar => self.focus_sidebar_filter = true,
+            _ => self.status = i18n::t("Select an item first").to_string(),
+        }
+    }
 
-                match result {
-                    Ok(data) => (
-                        data,
-                        format!(
-                            "{}_{}.aml",
-                            info.signature,
-                            Self::clean_filename_fragment(info.table_id.trim())
-                        ),
-                    ),
-                    Err(e) => {
-                        rfd::MessageDialog::new()
-                            .set_title("Export Error")
-                            .set_description(format!("Failed to read table data: {}", e))
-                            .set_level(rfd::MessageLevel::Error)
-                            .show();
-                        return;
+    /// Switches the data view tab, keeping the search near the match that was current.
+    ///
+    /// The current match is remembered as a fraction of the way through the old text,
+    /// and the closest match at the same relative position in the new text is selected.
+    fn switch_tab(&mut self, tab: Tab) {
+        if self.active_tab == tab {
+            return;
+        }
+        let anchor = self.search_matches.get(self.search_current).map(|&pos| {
+            let len = match self.active_tab {
+                Tab::Hex => self.hex_dump_len(),
+                Tab::Parsed => self.cached_parsed.len(),
+            };
+            pos as f64 / len.max(1) as f64
+        });
+
+        self.active_tab = tab;
+        self.recompute_search_matches();
+
+        if let Some(anchor) = anchor {
+            let len = match self.active_tab {
+                Tab::Hex => self.hex_dump_len(),
+                Tab::Parsed => self.cached_parsed.len(),
+            }
+            .max(1) as f64;
+            let distance = |pos: usize| (pos as f64 / len - anchor).abs();
+            if let Some((index, _)) = self
+                .search_matches
+                .iter()
+                .enumerate()
+                .min_by(|a, b| distance(*a.1).total_cmp(&distance(*b.1)))
+            {
+                self.search_current = index;
+            }
+        }
+    }
+
+    /// Draws the hex view, formatting only the lines that are scrolled into view.
+    ///
+    /// Search matches and the selected byte range are highlighted. Pressing on a byte
+    /// starts a selection, dragging extends it, and Shift+click extends the current one.
+    fn show_hex_rows(&mut self, ui: &mut egui::Ui) {
+        if let Some(error) = &self.hex_error {
+            ui.label(error.as_str());
+            return;
+        }
+        let font = FontId::monospace(self.data_font_size);
+        let row_height = ui.fonts(|f| f.row_height(&font));
+        let glyph_width = ui.fonts(|f| f.glyph_width(&font, '0'));
+        let runs: &[report::HexRun] = if self.hex_collapse_runs {
+            &self.hex_runs
+        } else {
+            &[]
+        };
+        let rows = report::collapsed_row_count(self.hex_line_starts.len() - 1, runs);
+        let (pointer, pressed, down, shift) = ui.input(|i| {
+            (
+                i.pointer.interact_pos(),
+                i.pointer.primary_pressed(),
+                i.pointer.primary_down(),
+                i.modifiers.shift,
+            )
+        });
+
+        let mut scroll = egui::ScrollArea::both()
+            .auto_shrink([false, false])
+            .drag_to_scroll(false);
+        if std::mem::take(&mut self.scroll_to_match) {
+            if let Some(&pos) = self.search_matches.get(self.search_current) {
+                let row = self.hex_line_starts.partition_point(|&s| s <= pos) - 1;
+                let row = report::collapsed_row_of(runs, row);
+                let spacing = ui.spacing().item_spacing.y;
+                scroll = scroll.vertical_scroll_offset(row as f32 * (row_height + spacing));
+            }
+        }
+
+        let base_offset = self.hex_base_offset();
+        let bytes = &self.cached_bytes;
+        let starts = &self.hex_line_starts;
+        let matches = &self.search_matches;
+        let query_len = self.search_query.len();
+        let current = self.search_current;
+        let selection = self.hex_selection;
+        let text_color = ui.visuals().text_color();
+        let selection_color = ui.visuals().selection.bg_fill;
+        let byte_names = self.hex_byte_names;
+        let mut hovered_byte = None;
+        let mut pressed_byte = None;
+        let weak_color = ui.visuals().weak_text_color();
+        scroll.show_rows(ui, row_height, rows, |ui, visible| {
+            for view_row in visible {
+                let row = match report::collapsed_row_at(runs, view_row) {
+                    report::HexViewRow::Line(row) => row,
+                    report::HexViewRow::Run(index) => {
+                        let run = runs[index];
+                        let response = ui.add(
+                            egui::Label::new(
+                                egui::RichText::new(run.describe(base_offset))
+                                    .font(font.clone())
+                                    .color(weak_color)
+                                    .italics(),
+                            )
+                            .selectable(false)
+                            .extend(),
+                        );
+                        // Dragging across the marker extends the selection to the run's edge
+                        if pointer.is_some_and(|p| response.rect.y_range().contains(p.y)) {
+                            hovered_byte = Some(run.first_row * 16);
+                        }
+                        continue;
+                    }
+                };
+                let line = report::hex_dump_line(bytes, row, base_offset);
+                let line_start = row * 16;
+                let line_bytes = bytes.len().saturating_sub(line_start).min(16);
+                let hex_start = line.find(' ').unwrap_or(0) + 2;
+                let ascii_start = hex_start + 48 + 2;
+                let mut highlight = vec![None; line.len()];
+
+                if let Some((first, last)) = selection {
+                    let colors = Some((selection_color, text_color));
+                    for i in 0..line_bytes {
+                        if (first..=last).contains(&(line_start + i)) {
+                            // Include the separating space unless the selection ends here
+                            let width = if line_start + i < last && i < 15 {
+                                3
+                            } else {
+                                2
+                            };
+                            let hex = hex_start + i * 3;
+                            highlight[hex..hex + width].fill(colors);
+                            highlight[ascii_start + i] = colors;
+                        }
+                    }
+                }
+
+                let text_start = starts[row];
+                let text_end = text_start + line.len();
+                let first_match = matches.partition_point(|&m| m + query_len <= text_start);
+                for (index, &pos) in matches.iter().enumerate().skip(first_match) {
+                    if pos >= text_end {
+                        break;
+                    }
+                    let background = if index == current {
+                        Color32::from_rgb(80, 130, 210)
+                    } else {
+                        Color32::from_rgb(70, 70, 70)
+                    };
+                    let from = pos.max(text_start) - text_start;
+                    let to = (pos + query_len).min(text_end) - text_start;
+                    highlight[from..to].fill(Some((background, Color32::WHITE)));
+                }
+
+                let response = ui.add(
+                    egui::Label::new(hex_line_job(&line, &highlight, text_color, &font))
+                        .selectable(false)
+                        .extend(),
+                );
+                if let Some(pos) = pointer.filter(|p| response.rect.y_range().contains(p.y)) {
+                    let column = ((pos.x - response.rect.left()).max(0.0) / glyph_width) as usize;
+                    let byte = line_start + hex_line_byte_at(&line, column).min(line_bytes - 1);
+                    hovered_byte = Some(byte);
+                    if response.rect.contains(pos) {
+                        pressed_byte = Some(byte);
+                        if byte_names && (ascii_start..ascii_start + line_bytes).contains(&column) {
+                            response.on_hover_text_at_pointer(report::hex_byte_description(
+                                bytes[byte],
+                            ));
+                        }
                     }
                 }
             }
+        });
+
+        if pressed {
+            if let Some(byte) = pressed_byte {
+                let anchor = match self.hex_selection {
+                    Some((first, _)) if shift => first,
+                    _ => byte,
+                };
+                self.hex_drag_anchor = Some(anchor);
+                self.hex_selection = Some((anchor.min(byte), anchor.max(byte)));
+            }
+        } else if down {
+            if let (Some(anchor), Some(byte)) = (self.hex_drag_anchor, hovered_byte) {
+                self.hex_selection = Some((anchor.min(byte), anchor.max(byte)));
+            }
+        }
+        if !down {
+            self.hex_drag_anchor = None;
+        }
+    }
+
+    /// Returns the length of the selected item's hex dump text.
+    fn hex_dump_len(&self) -> usize {
+        self.hex_line_starts.last().copied().unwrap_or(0)
+    }
+
+    /// Advances search selection.
+    fn step_search(&mut self, delta: isize) {
+        if self.search_matches.is_empty() {
+            return;
+        }
+        let len = self.search_matches.len();
+        let cur = self.search_current as isize;
+        let next = (cur + delta).rem_euclid(len as isize);
+        self.search_current = next as usize;
+        self.scroll_to_match = true;
+    }
+
+    /// Handles search panel keys: Enter/F3 jump to the next match, Shift+Enter/Shift+F3 to
+    /// the previous one, and Escape closes the panel.
+    ///
+    /// The keys are consumed so the find box does not also react to them.
+    fn handle_search_keys(&mut self, ctx: &egui::Context) {
+        if !self.search_panel_open || self.palette_open {
+            return;
+        }
+        // Shifted keys first: a plain-key pattern also matches when Shift is held
+        let (prev, next, escape) = ctx.input_mut(|i| {
+            let prev = i.consume_key(egui::Modifiers::SHIFT, egui::Key::Enter)
+                | i.consume_key(egui::Modifiers::SHIFT, egui::Key::F3);
+            let next = i.consume_key(egui::Modifiers::NONE, egui::Key::Enter)
+                | i.consume_key(egui::Modifiers::NONE, egui::Key::F3);
+            (
+                prev,
+                next,
+                i.consume_key(egui::Modifiers::NONE, egui::Key::Escape),
+            )
+        });
+        if escape {
+            self.search_panel_open = false;
+        } else if prev {
+            self.step_search(-1);
+        } else if next {
+            self.step_search(1);
+        }
+    }
+
+    /// Names an export of the selected ACPI table or SMBIOS structure with the filename
+    /// template.
+    ///
+    /// # Returns
+    /// The templated name, or `default_name` when no template is set, the selection is a
+    /// loaded file (which keeps its own name), or the template expands to nothing.
+    fn templated_name(&self, extension: &str, default_name: String) -> String {
+        if self.export_name_template.trim().is_empty() {
+            return default_name;
+        }
+        let mut fields = match &self.selected_item {
+            Selection::Acpi(info) => acpi_name_fields(info),
+            Selection::Smbios(off, tid) => {
+                let handle = self
+                    .smbios_data
+                    .as_deref()
+                    .and_then(|data| parsers::parse_smbios_structure(data, *off).ok())
+                    .map(|(header, _)| format!("0x{:04X}", header.handle))
+                    .unwrap_or_default();
+                vec![("type", tid.to_string()), ("handle", handle)]
+            }
+            Selection::File(_) | Selection::None => return default_name,
+        };
+        fields.push(("date", api::local_date()));
+        let fields: Vec<(&str, &str)> = fields.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        report::apply_filename_template(&self.export_name_template, &fields, extension)
+            .unwrap_or(default_name)
+    }
+
+    /// Opens a save file dialog to export the currently selected item as a raw binary file.
+    fn export_raw(&self) {
+        let (data, default_name) = match &self.selected_item {
+            Selection::Acpi(info) => match read_acpi_cached(&self.table_cache, info) {
+                Ok(data) => (
+                    data,
+                    format!(
+                        "{}_{}.aml",
+                        info.signature,
+                        report::clean_filename_fragment(info.table_id.trim())
+                    ),
+                ),
+                Err(e) => {
+                    rfd::MessageDialog::new()
+                        .set_title(i18n::t("Export Error"))
+                        .set_description(i18n::tf("Failed to read table data: {}", &[&e]))
+                        .set_level(rfd::MessageLevel::Error)
+                        .show();
+                    return;
+                }
+            },
             Selection::Smbios(off, tid) => {
                 if let Some(ref smbios_data) = self.smbios_data {
                     if let Ok((_, next_off)) = parsers::parse_smbios_structure(smbios_data, *off) {
@@ -573,8 +3664,8 @@ impl DumpApp {
                         )
                     } else {
                         rfd::MessageDialog::new()
-                            .set_title("Export Error")
-                            .set_description("Failed to parse SMBIOS structure.")
+                            .set_title(i18n::t("Export Error"))
+                            .set_description(i18n::t("Failed to parse SMBIOS structure."))
                             .set_level(rfd::MessageLevel::Error)
                             .show();
                         return;
@@ -583,31 +3674,253 @@ impl DumpApp {
                     return;
                 }
             }
+            Selection::File(i) => match self.loaded_files.get(*i) {
+                Some(file) => (file.data.clone(), file.name.clone()),
+                None => return,
+            },
             Selection::None => return,
         };
+        // Edits made in the hex view take precedence over the firmware copy
+        let data = if self.bytes_modified {
+            self.cached_bytes.clone()
+        } else {
+            data
+        };
+        let extension = match self.selected_item {
+            Selection::Smbios(..) => "bin",
+            _ => "aml",
+        };
+        let default_name = self.templated_name(extension, default_name);
+        save_binary_file(&self.export_dir, &default_name, &data);
+    }
+
+    /// Opens a save dialog to export only the bytes selected in the hex view, named after
+    /// the item and the range, e.g. `DSDT_0x24-0x1FF.bin`.
+    fn export_selection(&mut self) {
+        let Some((start, end)) = self.hex_selection else {
+            self.status = i18n::t("Select bytes in the hex view first").to_string();
+            return;
+        };
+        let Some(data) = self.cached_bytes.get(start..=end) else {
+            return;
+        };
+        let Some(prefix) = self.export_prefix() else {
+            return;
+        };
+        save_binary_file(
+            &self.export_dir,
+            &format!("{}_0x{:X}-0x{:X}.bin", prefix, start, end),
+            data,
+        );
+    }
+
+    /// Returns the start of a partial export's file name: the ACPI signature, the SMBIOS
+    /// type, or a loaded file's name without its extension.
+    fn export_prefix(&self) -> Option<String> {
+        match &self.selected_item {
+            Selection::Acpi(info) => Some(info.signature.clone()),
+            Selection::Smbios(_, tid) => Some(format!("smbios_type_{}", tid)),
+            Selection::File(i) => self.loaded_files.get(*i).map(|file| {
+                report::clean_filename_fragment(
+                    file.name
+                        .rsplit_once('.')
+                        .map_or(file.name.as_str(), |(stem, _)| stem),
+                )
+            }),
+            Selection::None => None,
+        }
+    }
+
+    /// Returns whether the selected item is an ACPI table, including a loaded `.aml` file.
+    fn selection_is_acpi_table(&self) -> bool {
+        match &self.selected_item {
+            Selection::Acpi(_) => true,
+            Selection::File(i) => self
+                .loaded_files
+                .get(*i)
+                .is_some_and(|file| matches!(file.kind, parsers::FirmwareFileKind::AcpiTable)),
+            Selection::Smbios(..) | Selection::None => false,
+        }
+    }
+
+    /// Draws the Export Trimmed Copy menu: how many bytes to keep and whether to rewrite the
+    /// ACPI header to match.
+    ///
+    /// # Returns
+    /// `true` if Export was clicked.
+    fn show_trim_menu(&mut self, ui: &mut egui::Ui) -> bool {
+        let len = self.cached_bytes.len();
+        if len == 0 {
+            ui.label(i18n::t("Select an item first"));
+            return false;
+        }
+        if self.trim_length == 0 || self.trim_length > len {
+            self.trim_length = len;
+        }
+        ui.horizontal(|ui| {
+            ui.label(i18n::t("Keep the first"));
+            ui.add(
+                egui::DragValue::new(&mut self.trim_length)
+                    .range(1..=len)
+                    .suffix(i18n::t(" bytes")),
+            );
+            ui.weak(format!("(0x{:X})", self.trim_length));
+        });
+        if let Some((_, end)) = self.hex_selection {
+            if ui
+                .button(i18n::t("Up to the end of the hex selection"))
+                .clicked()
+            {
+                self.trim_length = (end + 1).min(len);
+            }
+        }
+        let acpi = self.selection_is_acpi_table();
+        ui.add_enabled(
+            acpi,
+            egui::Checkbox::new(
+                &mut self.trim_rewrite_header,
+                i18n::t("Rewrite the length field and checksum"),
+            ),
+        )
+        .on_hover_text(i18n::t(
+            "Set the header length to the new size and recompute the checksum so the copy still parses",
+        ))
+        .on_disabled_hover_text(i18n::t("Only ACPI tables have a length field to rewrite"));
+        ui.weak(i18n::t("Unsaved hex edits are included"));
+        if ui.button(i18n::t("Export…")).clicked() {
+            ui.close_menu();
+            return true;
+        }
+        false
+    }
+
+    /// Saves the first `trim_length` bytes of the selected item, with the ACPI header
+    /// rewritten to match when that is enabled, as a small sample for a bug report.
+    fn export_trimmed(&mut self) {
+        let Some(prefix) = self.export_prefix() else {
+            return;
+        };
+        let acpi = self.selection_is_acpi_table();
+        let data = parsers::trim_acpi_table(
+            &self.cached_bytes,
+            self.trim_length,
+            acpi && self.trim_rewrite_header,
+        );
+        let extension = if acpi { "aml" } else { "bin" };
+        save_binary_file(
+            &self.export_dir,
+            &format!("{}_first_0x{:X}.{}", prefix, data.len(), extension),
+            &data,
+        );
+    }
+
+    /// Writes an ACPI table to a temporary file and opens it in the external hex editor.
+    ///
+    /// Uses [`Self::hex_editor_path`] when set, otherwise the program registered for `.bin`
+    /// files. Unsaved hex view edits of the selected table are written instead of the
+    /// firmware copy.
+    fn open_in_hex_editor(&mut self, info: &api::AcpiTableInfo) {
+        let data = if self.bytes_modified
+            && matches!(&self.selected_item, Selection::Acpi(s) if s == info)
+        {
+            self.cached_bytes.clone()
+        } else {
+            match read_acpi_cached(&self.table_cache, info) {
+                Ok(data) => data,
+                Err(e) => {
+                    self.status = i18n::tf("Failed to read {}: {}", &[&info.signature, &e]);
+                    return;
+                }
+            }
+        };
+
+        let dir = std::env::temp_dir().join("acpi-smbios-dumper");
+        let path = dir.join(format!(
+            "{}_{}.bin",
+            info.signature,
+            report::clean_filename_fragment(info.table_id.trim())
+        ));
+        if let Err(e) = std::fs::create_dir_all(&dir).and_then(|_| std::fs::write(&path, &data)) {
+            self.status = i18n::tf("Failed to write {}: {}", &[&path.display(), &e]);
+            return;
+        }
+        if !self.hex_editor_temp_files.contains(&path) {
+            self.hex_editor_temp_files.push(path.clone());
+        }
+
+        let program = Some(self.hex_editor_path.as_str()).filter(|p| !p.is_empty());
+        match api::open_with_program(&path, program) {
+            Ok(()) => {
+                self.status = i18n::tf("Opened {} in external hex editor", &[&info.signature])
+            }
+            Err(e) => {
+                rfd::MessageDialog::new()
+                    .set_title(i18n::t("Hex Editor Error"))
+                    .set_description(i18n::tf(
+                        "{}\n\nChoose a hex editor from the table's context menu.",
+                        &[&e],
+                    ))
+                    .set_level(rfd::MessageLevel::Error)
+                    .show();
+            }
+        }
+    }
+
+    /// Runs an action chosen on an ACPI table's sidebar entry.
+    fn run_acpi_entry_action(&mut self, ctx: &egui::Context, action: AcpiEntryAction) {
+        match action {
+            AcpiEntryAction::Command(info, command) => {
+                // Reselecting would drop unsaved hex edits of the selected table
+                if !matches!(&self.selected_item, Selection::Acpi(s) if *s == info) {
+                    self.select_acpi(info);
+                }
+                self.run_entry_command(ctx, command);
+            }
+            AcpiEntryAction::Select(info) => self.select_acpi(info),
+            AcpiEntryAction::OpenInHexEditor(info) => self.open_in_hex_editor(&info),
+            AcpiEntryAction::ChooseHexEditor => self.choose_hex_editor(),
+            AcpiEntryAction::UseDefaultHexEditor => {
+                self.hex_editor_path.clear();
+                self.status = i18n::t("Hex editor reset to the .bin file handler").to_string();
+            }
+        }
+    }
+
+    /// Runs a sidebar context menu command on the SMBIOS structure at `offset`, selecting it
+    /// first if needed.
+    fn run_smbios_entry_command(
+        &mut self,
+        ctx: &egui::Context,
+        offset: usize,
+        type_id: u8,
+        command: EntryCommand,
+    ) {
+        if !matches!(self.selected_item, Selection::Smbios(off, _) if off == offset) {
+            self.select_smbios(offset, type_id);
+        }
+        self.run_entry_command(ctx, command);
+    }
+
+    /// Runs a sidebar context menu command on the selected item.
+    fn run_entry_command(&mut self, ctx: &egui::Context, command: EntryCommand) {
+        match command {
+            EntryCommand::ExportRaw => self.export_raw(),
+            EntryCommand::ExportParsed => self.export_parsed(),
+            EntryCommand::ExportHexDump => self.export_hex_dump(),
+            EntryCommand::CopyParsed => {
+                self.copy_to_clipboard(ctx, &self.cached_parsed);
+                self.status = i18n::t("Copied parsed text").to_string();
+            }
+        }
+    }
 
+    /// Asks for the program used by "Open in Hex Editor".
+    fn choose_hex_editor(&mut self) {
         if let Some(path) = rfd::FileDialog::new()
-            .set_file_name(&default_name)
-            .save_file()
+            .add_filter(i18n::t("Programs"), &["exe"])
+            .pick_file()
         {
-            match std::fs::File::create(&path) {
-                Ok(mut file) => {
-                    if let Err(e) = file.write_all(&data) {
-                        rfd::MessageDialog::new()
-                            .set_title("Export Error")
-                            .set_description(format!("Failed to write file: {}", e))
-                            .set_level(rfd::MessageLevel::Error)
-                            .show();
-                    }
-                }
-                Err(e) => {
-                    rfd::MessageDialog::new()
-                        .set_title("Export Error")
-                        .set_description(format!("Failed to create file: {}", e))
-                        .set_level(rfd::MessageLevel::Error)
-                        .show();
-                }
-            }
+            self.hex_editor_path = path.to_string_lossy().to_string();
         }
     }
 
@@ -617,166 +3930,1031 @@ impl DumpApp {
             Selection::Acpi(info) => format!(
                 "{}_{}_parsed.txt",
                 info.signature,
-                Self::clean_filename_fragment(info.table_id.trim())
+                report::clean_filename_fragment(info.table_id.trim())
             ),
             Selection::Smbios(_, tid) => format!("smbios_type_{}_parsed.txt", tid),
+            Selection::File(i) => match self.loaded_files.get(*i) {
+                Some(file) => format!("{}_parsed.txt", report::clean_filename_fragment(&file.name)),
+                None => return,
+            },
             Selection::None => return,
         };
 
-        if let Some(path) = rfd::FileDialog::new()
-            .set_file_name(&default_name)
-            .add_filter("Text Files", &["txt"])
-            .save_file()
+        let default_name = self.templated_name("txt", default_name);
+        save_text_file(&self.export_dir, &default_name, &self.cached_parsed);
+    }
+
+    /// Opens a save file dialog to export the selected item's hex dump as a text file.
+    ///
+    /// The dump matches the hex view: it holds any unsaved edits, uses blob offsets when
+    /// those are shown, and collapses repeated-byte runs when **Collapse runs** is on.
+    fn export_hex_dump(&self) {
+        let default_name = match &self.selected_item {
+            Selection::Acpi(info) => format!(
+                "{}_{}_hexdump.txt",
+                info.signature,
+                report::clean_filename_fragment(info.table_id.trim())
+            ),
+            Selection::Smbios(_, tid) => format!("smbios_type_{}_hexdump.txt", tid),
+            Selection::File(i) => match self.loaded_files.get(*i) {
+                Some(file) => {
+                    format!(
+                        "{}_hexdump.txt",
+                        report::clean_filename_fragment(&file.name)
+                    )
+                }
+                None => return,
+            },
+            Selection::None => return,
+        };
+        let text = if self.hex_collapse_runs {
+            report::hex_dump_str_collapsed(
+                &self.cached_bytes,
+                self.hex_base_offset(),
+                &self.hex_runs,
+            )
+        } else {
+            report::hex_dump_str(&self.cached_bytes, self.hex_base_offset())
+        };
+        save_text_file(&self.export_dir, &default_name, &text);
+    }
+
+    /// Exports the parsed view of every SMBIOS structure into one `dmidecode`-style text file.
+    ///
+    /// With `redact` the identifying fields read `REDACTED`.
+    fn export_all_parsed_smbios(&self, redact: bool) {
+        if let Some(data) = &self.smbios_data {
+            save_text_file(
+                &self.export_dir,
+                if redact {
+                    "smbios_all_parsed_redacted.txt"
+                } else {
+                    "smbios_all_parsed.txt"
+                },
+                &report::smbios_dump_text(data, self.string_encoding, redact),
+            );
+        }
+    }
+
+    /// Exports the parsed view of every ACPI table into one text file.
+    ///
+    /// With `redact` the MSDM product key reads `REDACTED`.
+    fn export_all_parsed_acpi(&self, redact: bool) {
+        if let Some(tables) = &self.acpi_tables {
+            let text =
+                report::acpi_dump_text(tables, redact, |t| read_acpi_cached(&self.table_cache, t));
+            save_text_file(
+                &self.export_dir,
+                if redact {
+                    "acpi_all_parsed_redacted.txt"
+                } else {
+                    "acpi_all_parsed.txt"
+                },
+                &text,
+            );
+        }
+    }
+
+    /// Exports the ACPI table list, without the table contents, as `acpi_manifest.csv`.
+    fn export_acpi_manifest(&self) {
+        if let Some(tables) = &self.acpi_tables {
+            let rows =
+                report::acpi_manifest_rows(tables, |t| read_acpi_cached(&self.table_cache, t));
+            save_text_file(
+                &self.export_dir,
+                "acpi_manifest.csv",
+                &report::acpi_manifest_csv(&rows),
+            );
+        }
+    }
+
+    /// Exports the summary, every ACPI table, and every SMBIOS structure as one HTML page.
+    ///
+    /// With `redact` the identifying fields read `REDACTED`.
+    fn export_html_report(&self, redact: bool) {
+        let tables = self.acpi_tables.as_deref().unwrap_or(&[]);
+        let smbios = self.smbios_data.as_deref().unwrap_or(&[]);
+        let html = report::report_html(
+            tables,
+            smbios,
+            self.string_encoding,
+            redact,
+            &self.notes,
+            |t| read_acpi_cached(&self.table_cache, t),
+        );
+        save_text_file(
+            &self.export_dir,
+            if redact {
+                "report_redacted.html"
+            } else {
+                "report.html"
+            },
+            &html,
+        );
+    }
+
+    /// Opens a folder picker and exports all discovered ACPI tables on a worker thread.
+    fn export_all_acpi(&mut self, ctx: &egui::Context) {
+        if self.export_job.is_some() {
+            return;
+        }
+        if let Some(tables) = &self.acpi_tables {
+            if let Some(folder) = export_dialog(&self.export_dir)
+                .set_title(i18n::t("Select Folder to Export All ACPI Tables"))
+                .pick_folder()
+            {
+                let (sender, receiver) = mpsc::channel();
+                let tables = tables.clone();
+                let total = tables.len();
+                let paths = acpi_export_file_names(&tables, &self.export_name_template)
+                    .into_iter()
+                    .map(|name| folder.join(name))
+                    .collect();
+                let cache = Arc::clone(&self.table_cache);
+                let cancel = Arc::new(AtomicBool::new(false));
+                let worker_cancel = Arc::clone(&cancel);
+                let ctx = ctx.clone();
+                std::thread::spawn(move || {
+                    export_tables_worker(tables, paths, cache, worker_cancel, sender, ctx)
+                });
+                self.export_job = Some(ExportJob {
+                    receiver,
+                    index: 0,
+                    total,
+                    signature: String::new(),
+                    cancel,
+                });
+            }
+        }
+    }
+
+    /// Drains progress messages from the export-all worker and shows the summary when it ends.
+    fn poll_export_job(&mut self) {
+        let Some(job) = &mut self.export_job else {
+            return;
+        };
+        let mut finished = None;
+        let mut report_finished = None;
+        while let Ok(message) = job.receiver.try_recv() {
+            match message {
+                ExportMessage::Progress {
+                    index,
+                    total,
+                    signature,
+                } => {
+                    job.index = index;
+                    job.total = total;
+                    job.signature = signature;
+                }
+                ExportMessage::Finished {
+                    success_count,
+                    errors,
+                    cancelled,
+                } => finished = Some((success_count, errors, cancelled)),
+                ExportMessage::ReportFinished { folder, outcome } => {
+                    report_finished = Some((folder, outcome))
+                }
+            }
+        }
+        if let Some((folder, outcome)) = report_finished {
+            self.export_job = None;
+            self.show_report_outcome(&folder, &outcome);
+            return;
+        }
+        let Some((success_count, errors, cancelled)) = finished else {
+            return;
+        };
+        self.export_job = None;
+
+        let fail_count = errors.len();
+        self.status = i18n::tf(
+            "Exported {} tables, {} failed",
+            &[&success_count, &fail_count],
+        );
+        if cancelled {
+            self.status = i18n::tf(
+                "Export cancelled: {} tables exported, {} failed",
+                &[&success_count, &fail_count],
+            );
+            return;
+        }
+        let message = if fail_count == 0 {
+            i18n::tf("Successfully exported {} tables.", &[&success_count])
+        } else {
+            i18n::tf(
+                "Exported {} tables, {} failed.\n\nErrors:\n{}",
+                &[&success_count, &fail_count, &errors.join("\n")],
+            )
+        };
+
+        rfd::MessageDialog::new()
+            .set_title(i18n::t("Export Complete"))
+            .set_description(&message)
+            .set_level(if fail_count == 0 {
+                rfd::MessageLevel::Info
+            } else {
+                rfd::MessageLevel::Warning
+            })
+            .show();
+    }
+
+    /// Opens a folder picker and writes a complete system capture (tables, SMBIOS, summaries)
+    /// into it on a worker thread.
+    ///
+    /// With `redact` the text, JSON, and HTML files are redacted and the raw files that hold
+    /// the identifying values are left out.
+    fn export_full_report(&mut self, ctx: &egui::Context, redact: bool) {
+        if self.export_job.is_some() {
+            return;
+        }
+        if let Some(folder) = export_dialog(&self.export_dir)
+            .set_title(i18n::t("Select Folder for Full System Report"))
+            .pick_folder()
         {
-            match std::fs::File::create(&path) {
-                Ok(mut file) => {
-                    if let Err(e) = file.write_all(self.cached_parsed.as_bytes()) {
+            let (sender, receiver) = mpsc::channel();
+            let options = report::ReportOptions {
+                source: self.acpi_source,
+                encoding: self.string_encoding,
+                strip_wrapper: self.smbios_strip_wrapper,
+                redact,
+                notes: self.notes.clone(),
+            };
+            let cancel = Arc::new(AtomicBool::new(false));
+            let worker_cancel = Arc::clone(&cancel);
+            let ctx = ctx.clone();
+            std::thread::spawn(move || {
+                full_report_worker(folder, options, worker_cancel, sender, ctx)
+            });
+            self.export_job = Some(ExportJob {
+                receiver,
+                index: 0,
+                total: 0,
+                signature: String::new(),
+                cancel,
+            });
+        }
+    }
+
+    /// Shows the result of a full system report in the status bar and a message box.
+    fn show_report_outcome(&mut self, folder: &std::path::Path, outcome: &report::ReportOutcome) {
+        self.status = i18n::tf(
+            "Full report: {} files written, {} failed",
+            &[&outcome.written.len(), &outcome.failed.len()],
+        );
+        if outcome.cancelled {
+            self.status = i18n::tf(
+                "Full report cancelled: {} files written, {} failed",
+                &[&outcome.written.len(), &outcome.failed.len()],
+            );
+        }
+
+        let message = if outcome.failed.is_empty() {
+            i18n::tf(
+                "Wrote {} files to {}.",
+                &[&outcome.written.len(), &folder.display()],
+            )
+        } else {
+            i18n::tf(
+                "Wrote {} files to {}, {} failed.\n\nErrors:\n{}",
+                &[
+                    &outcome.written.len(),
+                    &folder.display(),
+                    &outcome.failed.len(),
+                    &outcome.failed.join("\n"),
+                ],
+            )
+        };
+        let (title, message) = if outcome.cancelled {
+            (
+                i18n::t("Report Cancelled"),
+                format!(
+                    "{}\n\n{}",
+                    message,
+                    i18n::t("The report was cancelled; manifest.txt lists what was skipped.")
+                ),
+            )
+        } else {
+            (i18n::t("Report Complete"), message)
+        };
+
+        rfd::MessageDialog::new()
+            .set_title(title)
+            .set_description(&message)
+            .set_level(if outcome.failed.is_empty() && !outcome.cancelled {
+                rfd::MessageLevel::Info
+            } else {
+                rfd::MessageLevel::Warning
+            })
+            .show();
+    }
+
+    /// Opens a save file dialog to export the entire raw SMBIOS information blob.
+    fn export_full_smbios(&self) {
+        if let Some(ref data) = self.smbios_data {
+            let (data, file_name, filter_name) = if self.smbios_strip_wrapper {
+                (
+                    parsers::strip_raw_smbios_header(data),
+                    "smbios_table.bin",
+                    i18n::t("SMBIOS structure table (dmidecode)"),
+                )
+            } else {
+                (
+                    data.as_slice(),
+                    "smbios_raw.bin",
+                    i18n::t("Windows RawSMBIOSData (8-byte header + table)"),
+                )
+            };
+            if let Some(path) = export_dialog(&self.export_dir)
+                .set_title(i18n::t("Save Full SMBIOS Data"))
+                .set_file_name(file_name)
+                .add_filter(filter_name, &["bin"])
+                .save_file()
+            {
+                match std::fs::File::create(&path) {
+                    Ok(mut file) => {
+                        if let Err(e) = file.write_all(data) {
+                            rfd::MessageDialog::new()
+                                .set_title(i18n::t("Export Error"))
+                                .set_description(i18n::tf("Failed to write file: {}", &[&e]))
+                                .set_level(rfd::MessageLevel::Error)
+                                .show();
+                        }
+                    }
+                    Err(e) => {
                         rfd::MessageDialog::new()
-                            .set_title("Export Error")
-                            .set_description(format!("Failed to write file: {}", e))
+                            .set_title(i18n::t("Export Error"))
+                            .set_description(i18n::tf("Failed to create file: {}", &[&e]))
                             .set_level(rfd::MessageLevel::Error)
                             .show();
                     }
                 }
-                Err(e) => {
-                    rfd::MessageDialog::new()
-                        .set_title("Export Error")
-                        .set_description(format!("Failed to create file: {}", e))
-                        .set_level(rfd::MessageLevel::Error)
-                        .show();
+            }
+        }
+    }
+}
+
+/// Formats bytes as editable hex: 16 space-separated pairs per line, without offsets or ASCII.
+fn hex_edit_str(data: &[u8]) -> String {
+    let mut out = String::new();
+    for chunk in data.chunks(16) {
+        let hex_part: Vec<String> = chunk.iter().map(|b| format!("{:02X}", b)).collect();
+        out.push_str(&hex_part.join(" "));
+        out.push('\n');
+    }
+    out
+}
+
+/// Parses edited hex text back into bytes.
+///
+/// # Arguments
+/// * `text` - Hex digit pairs separated by any whitespace.
+/// * `expected_len` - The number of bytes the buffer must keep.
+///
+/// # Returns
+/// The bytes, or an error if the text has non-hex characters or a different length.
+fn parse_hex_edit(text: &str, expected_len: usize) -> Result<Vec<u8>, String> {
+    if let Some(c) = text
+        .chars()
+        .find(|c| !c.is_ascii_hexdigit() && !c.is_whitespace())
+    {
+        return Err(i18n::tf("Invalid character '{}'", &[&c]));
+    }
+    let digits: Vec<u8> = text.bytes().filter(|b| b.is_ascii_hexdigit()).collect();
+    if digits.len() != expected_len * 2 {
+        return Err(i18n::tf(
+            "Expected {} hex digits ({} bytes), found {}",
+            &[&(expected_len * 2), &expected_len, &digits.len()],
+        ));
+    }
+    digits
+        .chunks(2)
+        .map(|pair| {
+            u8::from_str_radix(std::str::from_utf8(pair).unwrap_or(""), 16)
+                .map_err(|e| e.to_string())
+        })
+        .collect()
+}
+
+/// Returns the sidebar label of an ACPI table.
+fn acpi_entry_label(t: &api::AcpiTableInfo) -> String {
+    format!("{} ({})", t.signature, t.table_id.trim())
+}
+
+/// The checksum state of an ACPI table's bytes.
+enum ChecksumStatus {
+    /// The bytes sum to zero.
+    Ok,
+    /// The bytes sum to the given non-zero value.
+    Invalid(u8),
+    /// The table is shorter than its header says, so the sum was not checked.
+    Truncated,
+}
+
+/// Checks the checksum of a table's (possibly edited) bytes.
+///
+/// # Returns
+/// `None` for the FACS, which has no checksum, the RSDP, which uses its own layout, and
+/// data too short to hold a header.
+fn acpi_checksum_status(t: &api::AcpiTableInfo, data: &[u8]) -> Option<ChecksumStatus> {
+    if t.signature == "FACS" || t.signature == "RSDP" {
+        return None;
+    }
+    if parsers::acpi_truncation(data).is_some() {
+        return Some(ChecksumStatus::Truncated);
+    }
+    if data.len() < 36 {
+        return None;
+    }
+    match parsers::acpi_checksum_sum(data) {
+        0 => Some(ChecksumStatus::Ok),
+        sum => Some(ChecksumStatus::Invalid(sum)),
+    }
+}
+
+/// Returns a sidebar label, highlighted with a marker when the item changed on the last reload.
+fn sidebar_label(label: &str, changed: bool) -> egui::RichText {
+    if changed {
+        egui::RichText::new(format!("● {}", label)).color(Color32::from_rgb(210, 150, 40))
+    } else {
+        egui::RichText::new(label)
+    }
+}
+
+/// Draws a marker after an annotated sidebar entry that shows the note on hover.
+fn note_marker(ui: &mut egui::Ui, note: Option<&String>) {
+    if let Some(note) = note {
+        ui.label(egui::RichText::new("📝").small())
+            .on_hover_text(note);
+    }
+}
+
+/// Draws the star that pins or unpins a sidebar entry, recording a click in `toggle`.
+fn pin_button(ui: &mut egui::Ui, pins: &[Pin], pin: &Pin, toggle: &mut Option<Pin>) {
+    let (color, tooltip) = if pins.contains(pin) {
+        (Color32::from_rgb(230, 180, 40), i18n::t("Unpin"))
+    } else {
+        (
+            ui.visuals().weak_text_color(),
+            i18n::t("Pin to the top of the sidebar"),
+        )
+    };
+    let star = egui::Button::new(egui::RichText::new("★").color(color)).frame(false);
+    if ui.add(star).on_hover_text(tooltip).clicked() {
+        *toggle = Some(pin.clone());
+    }
+}
+
+/// Renders one ACPI table entry in the sidebar, returning the row's response.
+///
+/// Tables with spec compliance warnings get a badge after the label that lists them on hover.
+fn show_acpi_entry(
+    ui: &mut egui::Ui,
+    t: &api::AcpiTableInfo,
+    size: usize,
+    warnings: &[String],
+    is_selected: bool,
+    changed: bool,
+) -> egui::Response {
+    let mut hover = i18n::tf(
+        "OEM: {}\nTable ID: {}\nRevision: {}\nSize: {} bytes\nRegistry: {}",
+        &[
+            &t.oem_id,
+            &t.table_id,
+            &t.revision,
+            &size,
+            &t.registry_path.as_deref().unwrap_or("<API>"),
+        ],
+    );
+    if let Some(desc) = parsers::acpi_signature_description(&t.signature) {
+        hover = format!("{} = {}\n{}", t.signature, desc, hover);
+    }
+    hover.push('\n');
+    hover.push_str(&i18n::tf("Source: {}", &[&i18n::t(t.source().label())]));
+    if !t.signature_valid {
+        hover.push('\n');
+        hover.push_str(i18n::t(
+            "Signature bytes are not printable ASCII; they are shown as '.'",
+        ));
+    }
+    if changed {
+        hover.push_str("\nChanged since the previous load");
+    }
+    let response = ui
+        .selectable_label(is_selected, sidebar_label(&acpi_entry_label(t), changed))
+        .on_hover_text(hover);
+    if !warnings.is_empty() {
+        let badge = match warnings.len() {
+            1 => i18n::t("⚠ 1 warning").to_string(),
+            n => i18n::tf("⚠ {} warnings", &[&n]),
+        };
+        ui.label(
+            egui::RichText::new(badge)
+                .small()
+                .color(Color32::from_rgb(210, 150, 40)),
+        )
+        .on_hover_text(warnings.join("\n"));
+    }
+    response
+}
+
+/// An action chosen on an ACPI table's sidebar entry.
+enum AcpiEntryAction {
+    /// Selects the table for the detail views.
+    Select(api::AcpiTableInfo),
+    /// Opens the table's bytes in the external hex editor.
+    OpenInHexEditor(api::AcpiTableInfo),
+    /// Asks for the program used as the external hex editor.
+    ChooseHexEditor,
+    /// Goes back to the program registered for `.bin` files.
+    UseDefaultHexEditor,
+    /// Selects the table if needed, then runs a command on it.
+    Command(api::AcpiTableInfo, EntryCommand),
+}
+
+/// A sidebar context menu command that acts on the selected item.
+#[derive(Clone, Copy)]
+enum EntryCommand {
+    /// Saves the raw bytes, as Export Raw Binary does.
+    ExportRaw,
+    /// Saves the parsed text, as Export Parsed Text does.
+    ExportParsed,
+    /// Saves the hex dump as text, as Export Hex Dump does.
+    ExportHexDump,
+    /// Copies the parsed text to the clipboard.
+    CopyParsed,
+}
+
+/// Adds the commands shared by the ACPI and SMBIOS entry menus, recording the choice in
+/// `command`.
+fn entry_command_buttons(ui: &mut egui::Ui, command: &mut Option<EntryCommand>) {
+    if ui.button(i18n::t("💾 Export Raw")).clicked() {
+        *command = Some(EntryCommand::ExportRaw);
+        ui.close_menu();
+    }
+    if ui.button(i18n::t("💾 Export Parsed")).clicked() {
+        *command = Some(EntryCommand::ExportParsed);
+        ui.close_menu();
+    }
+    if ui.button(i18n::t("💾 Export Hex Dump")).clicked() {
+        *command = Some(EntryCommand::ExportHexDump);
+        ui.close_menu();
+    }
+    if ui.button(i18n::t("📋 Copy Parsed")).clicked() {
+        *command = Some(EntryCommand::CopyParsed);
+        ui.close_menu();
+    }
+}
+
+/// Attaches the right-click menu of an ACPI table entry to `response`.
+fn acpi_entry_context_menu(
+    response: &egui::Response,
+    t: &api::AcpiTableInfo,
+    action: &mut Option<AcpiEntryAction>,
+) {
+    response.context_menu(|ui| {
+        let mut command = None;
+        entry_command_buttons(ui, &mut command);
+        if let Some(command) = command {
+            *action = Some(AcpiEntryAction::Command(t.clone(), command));
+        }
+        if ui.button(i18n::t("📋 Copy Signature")).clicked() {
+            ui.ctx().copy_text(t.signature.clone());
+            ui.close_menu();
+        }
+        if let Some(address) = t.physical_address {
+            if ui.button(i18n::t("📋 Copy Physical Address")).clicked() {
+                ui.ctx().copy_text(format!("0x{:X}", address));
+                ui.close_menu();
+            }
+        }
+        ui.separator();
+        if ui.button(i18n::t("🔧 Open in Hex Editor")).clicked() {
+            *action = Some(AcpiEntryAction::OpenInHexEditor(t.clone()));
+            ui.close_menu();
+        }
+        if ui.button(i18n::t("Choose Hex Editor...")).clicked() {
+            *action = Some(AcpiEntryAction::ChooseHexEditor);
+            ui.close_menu();
+        }
+        if ui.button(i18n::t("Use Default .bin Handler")).clicked() {
+            *action = Some(AcpiEntryAction::UseDefaultHexEditor);
+            ui.close_menu();
+        }
+    });
+}
+
+/// Attaches the right-click menu of an SMBIOS structure entry to `response`, recording a
+/// command for the structure at `offset` in `command`.
+fn smbios_entry_context_menu(
+    response: &egui::Response,
+    offset: usize,
+    type_id: u8,
+    handle: u16,
+    command: &mut Option<(usize, u8, EntryCommand)>,
+) {
+    response.context_menu(|ui| {
+        let mut chosen = None;
+        entry_command_buttons(ui, &mut chosen);
+        if let Some(chosen) = chosen {
+            *command = Some((offset, type_id, chosen));
+        }
+        if ui.button(i18n::t("📋 Copy Handle")).clicked() {
+            ui.ctx().copy_text(format!("0x{:04X}", handle));
+            ui.close_menu();
+        }
+    });
+}
+
+/// Recursively renders ACPI tree nodes, recording a click or context menu choice in `action`.
+///
+/// Leaf tables that do not match `filter` are hidden; grouping nodes are always shown.
+/// Tables whose [`table_cache_key`] is in `changed` are marked as changed.
+fn show_acpi_tree(
+    ui: &mut egui::Ui,
+    nodes: &[report::AcpiTreeNode],
+    selected: &Selection,
+    filter: &SidebarFilter,
+    changed: &HashSet<String>,
+    action: &mut Option<AcpiEntryAction>,
+    nav: &mut SidebarNav,
+) {
+    for node in nodes {
+        let is_selected =
+            matches!((selected, &node.table), (Selection::Acpi(s), Some(t)) if s == t);
+        let is_changed = node
+            .table
+            .as_ref()
+            .is_some_and(|t| changed.contains(&table_cache_key(t)));
+        if node.children.is_empty() {
+            let signature = node.table.as_ref().map_or("", |t| t.signature.as_str());
+            if !filter.matches_acpi(signature, &node.label) {
+                continue;
+            }
+            let response = ui.selectable_label(is_selected, sidebar_label(&node.label, is_changed));
+            if let Some(t) = &node.table {
+                nav.track(ui, &response, SidebarEntry::Acpi(t.clone()));
+                acpi_entry_context_menu(&response, t, action);
+                if response.clicked() {
+                    *action = Some(AcpiEntryAction::Select(t.clone()));
                 }
             }
+            continue;
         }
+
+        let id = ui.make_persistent_id(("acpi_tree", &node.label));
+        egui::collapsing_header::CollapsingState::load_with_default_open(ui.ctx(), id, true)
+            .show_header(ui, |ui| {
+                let response =
+                    ui.selectable_label(is_selected, sidebar_label(&node.label, is_changed));
+                if let Some(t) = &node.table {
+                    nav.track(ui, &response, SidebarEntry::Acpi(t.clone()));
+                    acpi_entry_context_menu(&response, t, action);
+                    if response.clicked() {
+                        *action = Some(AcpiEntryAction::Select(t.clone()));
+                    }
+                }
+            })
+            .body(|ui| show_acpi_tree(ui, &node.children, selected, filter, changed, action, nav));
     }
+}
 
-    /// Opens a folder picker to export all discovered ACPI tables as individual binary files.
-    fn export_all_acpi(&self) {
-        if let Some(tables) = &self.acpi_tables {
-            if let Some(folder) = rfd::FileDialog::new()
-                .set_title("Select Folder to Export All ACPI Tables")
-                .pick_folder()
-            {
-                let mut success_count = 0;
-                let mut fail_count = 0;
-                let mut errors: Vec<String> = Vec::new();
+/// Maps a column in one line of [`report::hex_dump_str`] to the byte it represents.
+///
+/// Positions in the offset column map to the first byte of the line; positions in the
+/// hex or ASCII columns map to the byte under the cursor.
+///
+/// # Returns
+/// The index of the byte within the line (0-15).
+fn hex_line_byte_at(line: &str, column: usize) -> usize {
+    // Layout per line: offset, 2 spaces, 48-char hex column, 2 spaces, ASCII column
+    let hex_start = line.find(' ').unwrap_or(0) + 2;
+    let ascii_start = hex_start + 48 + 2;
+    if column < hex_start {
+        0
+    } else if column < ascii_start {
+        ((column - hex_start) / 3).min(15)
+    } else {
+        (column - ascii_start).min(15)
+    }
+}
 
-                for info in tables {
-                    let result = if let Some(ref path) = info.registry_path {
-                        api::get_acpi_table_by_path(path)
-                    } else {
-                        api::get_system_firmware_table(api::SIG_ACPI, &info.signature)
-                    };
+/// Lays out one hex dump line, giving highlighted characters their own colors.
+///
+/// # Arguments
+/// * `line` - The line as returned by [`report::hex_dump_line`].
+/// * `highlight` - Optional (background, text) colors for each character of `line`.
+/// * `color` - Text color of characters without a highlight.
+/// * `font` - Monospace font of the hex view.
+fn hex_line_job(
+    line: &str,
+    highlight: &[Option<(Color32, Color32)>],
+    color: Color32,
+    font: &FontId,
+) -> egui::text::LayoutJob {
+    let mut job = egui::text::LayoutJob::default();
+    let mut start = 0;
+    for end in 1..=line.len() {
+        if end < line.len() && highlight[end] == highlight[start] {
+            continue;
+        }
+        let mut format = TextFormat {
+            font_id: font.clone(),
+            color,
+            ..Default::default()
+        };
+        if let Some((background, text)) = highlight[start] {
+            format.background = background;
+            format.color = text;
+        }
+        job.append(&line[start..end], 0.0, format);
+        start = end;
+    }
+    job
+}
 
-                    match result {
-                        Ok(data) => {
-                            let path = folder.join(format!(
-                                "{}_{}.aml",
-                                info.signature,
-                                info.table_id.trim()
-                            ));
-                            match std::fs::File::create(&path) {
-                                Ok(mut file) => {
-                                    if file.write_all(&data).is_ok() {
-                                        success_count += 1;
-                                    } else {
-                                        fail_count += 1;
-                                        errors.push(format!("{}: write failed", info.signature));
-                                    }
-                                }
-                                Err(_) => {
-                                    fail_count += 1;
-                                    errors.push(format!("{}: create failed", info.signature));
-                                }
+/// Extracts the structure handle a parsed field refers to, if it holds a handle reference.
+///
+/// Matches "... Handle" fields whose value starts with "0xNNNN" and Group Association items
+/// of the form "Handle 0xNNNN (...)".
+fn smbios_handle_ref(key: &str, value: &str) -> Option<u16> {
+    let hex = if key.ends_with("Handle") {
+        value.strip_prefix("0x")?
+    } else {
+        value.strip_prefix("Handle 0x")?
+    };
+    u16::from_str_radix(hex.get(..4)?, 16).ok()
+}
+
+/// Draws an SMBIOS structure's parsed fields as a grid, with handle references as links.
+///
+/// Rows matching `query` are highlighted, placeholder values get a warning icon, and
+/// right-clicking a link offers to copy the handle. Each row has a copy button; the row whose
+/// button was clicked is stored in `copied` as `(key, value)`.
+///
+/// # Returns
+/// The `(offset, type_id)` of the structure whose link was clicked, if any.
+fn show_smbios_fields(
+    ui: &mut egui::Ui,
+    type_id: u8,
+    fields: &[(String, String)],
+    handles: &HashMap<u16, (usize, u8)>,
+    query: &str,
+    copied: &mut Option<(String, String)>,
+) -> Option<(usize, u8)> {
+    let query = query.to_lowercase();
+    let mut jump = None;
+    egui::Grid::new("smbios_fields")
+        .num_columns(3)
+        .striped(true)
+        .show(ui, |ui| {
+            for (key, value) in fields {
+                if ui
+                    .small_button("📋")
+                    .on_hover_text(i18n::t("Copy this value"))
+                    .clicked()
+                {
+                    *copied = Some((key.clone(), value.clone()));
+                }
+                let hit = !query.is_empty()
+                    && (key.to_lowercase().contains(&query)
+                        || value.to_lowercase().contains(&query));
+                let mut key_text = egui::RichText::new(key).monospace();
+                if hit {
+                    key_text = key_text
+                        .background_color(Color32::from_rgb(80, 130, 210))
+                        .color(Color32::WHITE);
+                }
+                ui.label(key_text);
+
+                let target = smbios_handle_ref(key, value)
+                    .and_then(|handle| handles.get(&handle).map(|&t| (handle, t)));
+                match target {
+                    Some((handle, target)) => {
+                        let link = ui
+                            .link(egui::RichText::new(value).monospace())
+                            .on_hover_text(i18n::t("Go to this structure"));
+                        if link.clicked() {
+                            jump = Some(target);
+                        }
+                        link.context_menu(|ui| {
+                            if ui.button(i18n::t("📋 Copy Handle")).clicked() {
+                                ui.ctx().copy_text(format!("0x{:04X}", handle));
+                                ui.close_menu();
                             }
+                        });
+                    }
+                    None => match report::placeholder_reason(type_id, key, value) {
+                        Some(reason) => {
+                            ui.horizontal(|ui| {
+                                ui.label(egui::RichText::new(value).monospace());
+                                ui.colored_label(Color32::from_rgb(210, 150, 40), "⚠")
+                                    .on_hover_text(reason);
+                            });
+                        }
+                        None => {
+                            ui.label(egui::RichText::new(value).monospace());
+                        }
+                    },
+                }
+                ui.end_row();
+            }
+        });
+    jump
+}
+
+/// Draws the physical addresses an ACPI table points at, linking each to the table it
+/// resolves to.
+///
+/// # Returns
+/// The table whose link was clicked, if any.
+fn show_acpi_address_refs(
+    ui: &mut egui::Ui,
+    refs: &[report::AcpiAddressRef],
+) -> Option<api::AcpiTableInfo> {
+    let mut follow = None;
+    egui::ScrollArea::vertical()
+        .id_salt("acpi_address_refs")
+        .max_height(200.0)
+        .show(ui, |ui| {
+            egui::Grid::new("acpi_address_refs_grid")
+                .striped(true)
+                .show(ui, |ui| {
+                    for r in refs {
+                        ui.label(&r.label);
+                        ui.label(egui::RichText::new(format!("0x{:X}", r.address)).monospace());
+                        let Some(info) = r.target.table() else {
+                            ui.weak(i18n::t("not directly retrievable"))
+                                .on_hover_text(i18n::t(
+                                    "The firmware API reads tables by signature, not by address",
+                                ));
+                            ui.end_row();
+                            continue;
+                        };
+                        let hover = match r.target {
+                            report::AcpiAddressTarget::Fetched(_) => i18n::t(
+                                "Not in the table list; read from the firmware by its signature",
+                            ),
+                            _ => i18n::t("Go to this table"),
+                        };
+                        if ui.link(&info.signature).on_hover_text(hover).clicked() {
+                            follow = Some(info.clone());
                         }
-                        Err(_) => {
-                            fail_count += 1;
-                            errors.push(format!("{}: read failed", info.signature));
+                        ui.end_row();
+                    }
+                });
+        });
+    follow
+}
+
+/// Draws a SLIT distance matrix as a grid with locality row and column headers.
+fn show_slit_matrix(ui: &mut egui::Ui, matrix: &[Vec<u8>]) {
+    ui.strong(i18n::t("Locality Distances"));
+    egui::ScrollArea::both()
+        .id_salt("slit_matrix")
+        .max_height(240.0)
+        .show(ui, |ui| {
+            egui::Grid::new("slit_grid").striped(true).show(ui, |ui| {
+                ui.label("");
+                for col in 0..matrix.len() {
+                    ui.strong(format!("{}", col));
+                }
+                ui.end_row();
+                for (row, distances) in matrix.iter().enumerate() {
+                    ui.strong(format!("{}", row));
+                    for &distance in distances {
+                        // 10 is the normalized distance of a locality to itself
+                        let text = egui::RichText::new(format!("{}", distance)).monospace();
+                        if distance == 10 {
+                            ui.label(text.strong());
+                        } else {
+                            ui.label(text);
                         }
                     }
+                    ui.end_row();
                 }
+            });
+        });
+}
 
-                let message = if fail_count == 0 {
-                    format!("Successfully exported {} tables.", success_count)
-                } else {
-                    format!(
-                        "Exported {} tables, {} failed.\n\nErrors:\n{}",
-                        success_count,
-                        fail_count,
-                        errors.join("\n")
-                    )
-                };
-
-                rfd::MessageDialog::new()
-                    .set_title("Export Complete")
-                    .set_description(&message)
-                    .set_level(if fail_count == 0 {
-                        rfd::MessageLevel::Info
-                    } else {
-                        rfd::MessageLevel::Warning
-                    })
-                    .show();
+/// Recursively draws an AML namespace scope tree, opening the first two levels by default.
+///
+/// Hovering a node shows its absolute path and table offset; right-clicking copies the path.
+fn show_aml_tree(ui: &mut egui::Ui, nodes: &[parsers::AmlNode], depth: usize) {
+    for node in nodes {
+        let label = egui::RichText::new(format!("{}  {}", node.name, node.kind)).monospace();
+        let hover = match node.offset {
+            Some(offset) => format!(
+                "{}\n{}",
+                node.path,
+                i18n::tf("Offset {}", &[&format!("0x{:X}", offset)])
+            ),
+            None => node.path.clone(),
+        };
+        let response = if node.children.is_empty() {
+            ui.label(label)
+        } else {
+            let id = ui.make_persistent_id(("aml_tree", &node.path));
+            egui::collapsing_header::CollapsingState::load_with_default_open(
+                ui.ctx(),
+                id,
+                depth < 2,
+            )
+            .show_header(ui, |ui| ui.label(label))
+            .body(|ui| show_aml_tree(ui, &node.children, depth + 1))
+            .1
+            .inner
+        };
+        response.on_hover_text(hover).context_menu(|ui| {
+            if ui.button(i18n::t("📋 Copy Path")).clicked() {
+                ui.ctx().copy_text(node.path.clone());
+                ui.close_menu();
             }
-        }
+        });
     }
+}
 
-    /// Opens a save file dialog to export the entire raw SMBIOS information blob.
-    fn export_full_smbios(&self) {
-        if let Some(ref data) = self.smbios_data {
-            if let Some(path) = rfd::FileDialog::new()
-                .set_title("Save Full SMBIOS Data")
-                .set_file_name("smbios_raw.bin")
-                .save_file()
-            {
-                match std::fs::File::create(&path) {
-                    Ok(mut file) => {
-                        if let Err(e) = file.write_all(data) {
-                            rfd::MessageDialog::new()
-                                .set_title("Export Error")
-                                .set_description(format!("Failed to write file: {}", e))
-                                .set_level(rfd::MessageLevel::Error)
-                                .show();
-                        }
-                    }
-                    Err(e) => {
-                        rfd::MessageDialog::new()
-                            .set_title("Export Error")
-                            .set_description(format!("Failed to create file: {}", e))
-                            .set_level(rfd::MessageLevel::Error)
-                            .show();
-                    }
-                }
+/// Draws the ACPI header fields as a table of offset, size, raw bytes, and decoded value.
+fn show_header_layout(ui: &mut egui::Ui, layout: &[report::HeaderFieldLayout]) {
+    egui::Grid::new("acpi_header_layout")
+        .striped(true)
+        .show(ui, |ui| {
+            for heading in ["Field", "Offset", "Size", "Raw Bytes", "Value"] {
+                ui.strong(i18n::t(heading));
             }
-        }
-    }
+            ui.end_row();
+            for field in layout {
+                ui.label(field.name);
+                ui.monospace(format!("0x{:02X}", field.offset));
+                ui.label(i18n::tf("{} B", &[&field.size]));
+                ui.monospace(&field.raw);
+                ui.label(&field.value);
+                ui.end_row();
+            }
+        });
 }
 
-/// Generates a standardized hex dump string from a byte slice.
-///
-/// Each line includes the offset, 16 hex bytes, and the corresponding ASCII representation.
-fn hex_dump_str(data: &[u8]) -> String {
-    let mut out = String::new();
-    let length = 16;
-    for (i, chunk) in data.chunks(length).enumerate() {
-        let offset = i * length;
-        let hex_part: Vec<String> = chunk.iter().map(|b| format!("{:02X}", b)).collect();
-        let hex_str = hex_part.join(" ");
-        let ascii_part: String = chunk
-            .iter()
-            .map(|&b| {
-                if (32..127).contains(&b) {
-                    b as char
-                } else {
-                    '.'
-                }
-            })
-            .collect();
-        out.push_str(&format!(
-            "{:04X}  {:<48}  {}\n",
-            offset, hex_str, ascii_part
+/// Draws the byte statistics panel: summary counts and a 16-bucket histogram.
+fn show_byte_stats(ui: &mut egui::Ui, stats: &report::ByteStats) {
+    let percent = |count: usize| {
+        if stats.length == 0 {
+            0.0
+        } else {
+            count as f64 * 100.0 / stats.length as f64
+        }
+    };
+    ui.horizontal_wrapped(|ui| {
+        ui.label(i18n::tf("Length: {} bytes", &[&stats.length]));
+        ui.separator();
+        ui.label(i18n::tf(
+            "Zero: {} ({}%)",
+            &[
+                &stats.zero_count,
+                &format!("{:.1}", percent(stats.zero_count)),
+            ],
+        ));
+        ui.separator();
+        ui.label(i18n::tf(
+            "Printable ASCII: {} ({}%)",
+            &[
+                &stats.printable_count,
+                &format!("{:.1}", percent(stats.printable_count)),
+            ],
+        ));
+        if let Some((value, count)) = stats.most_common {
+            ui.separator();
+            ui.label(i18n::tf(
+                "Most common: {} x{}",
+                &[&format!("0x{:02X}", value), &count],
+            ));
+        }
+    });
+
+    let size = egui::vec2(ui.available_width().min(480.0), 60.0);
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 2.0, ui.visuals().extreme_bg_color);
+    let max = stats.histogram.iter().copied().max().unwrap_or(0).max(1);
+    let bar_width = rect.width() / 16.0;
+    for (i, &count) in stats.histogram.iter().enumerate() {
+        let height = rect.height() * count as f32 / max as f32;
+        let x = rect.left() + i as f32 * bar_width;
+        let bar = egui::Rect::from_min_max(
+            egui::pos2(x + 1.0, rect.bottom() - height),
+            egui::pos2(x + bar_width - 1.0, rect.bottom()),
+        );
+        painter.rect_filled(bar, 0.0, ui.visuals().selection.bg_fill);
+    }
+    if let Some(pos) = response.hover_pos() {
+        let i = (((pos.x - rect.left()) / bar_width) as usize).min(15);
+        response.on_hover_text(i18n::tf(
+            "{}-{}: {} bytes",
+            &[
+                &format!("0x{:02X}", i * 16),
+                &format!("0x{:02X}", i * 16 + 15),
+                &stats.histogram[i],
+            ],
         ));
     }
-    out
 }
 
 impl eframe::App for DumpApp {
@@ -784,38 +4962,115 @@ impl eframe::App for DumpApp {
     ///
     /// Defines the sidebar (table list), central panel (data view), search panel, and top toolbar.
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        // Apply theme
-        if self.dark_mode {
-            ctx.set_visuals(egui::Visuals::dark());
-        } else {
-            ctx.set_visuals(egui::Visuals::light());
+        self.apply_theme(ctx);
+        self.poll_export_job();
+        self.poll_startup_load();
+        self.schedule_background_repaint(ctx);
+        let dropped = ctx.input(|i| i.raw.dropped_files.clone());
+        if !dropped.is_empty() {
+            self.load_dropped_files(dropped);
+        }
+        if ctx.input_mut(|i| i.consume_key(egui::Modifiers::CTRL, egui::Key::K)) {
+            self.palette_open = !self.palette_open;
+            self.palette_query.clear();
+            self.palette_cursor = 0;
         }
+        self.handle_zoom_keys(ctx);
+        self.show_palette(ctx);
+        self.show_reload_toast(ctx);
+        self.show_copy_toast(ctx);
+        self.show_base64_dialog(ctx);
+        self.show_footprint_window(ctx);
+        self.show_advisory_window(ctx);
+        self.show_smbios_fields_window(ctx);
+        self.show_smbios_diff_window(ctx);
+        self.handle_sidebar_keys(ctx);
+        self.show_menu_bar(ctx);
+        let mut export_all_clicked = false;
+        let mut reload_clicked = false;
+        let mut restart_clicked = false;
+        let mut source_changed = false;
+        let mut toggle_pin = None;
+        let mut nav = std::mem::take(&mut self.sidebar_nav);
+        nav.entries.clear();
 
         egui::TopBottomPanel::top("admin_banner").show(ctx, |ui| {
             if !self.is_admin {
+                ui.horizontal(|ui| {
+                    ui.colored_label(
+                        Color32::from_rgb(200, 50, 50),
+                        i18n::t("Running without Administrator privileges. Some firmware reads may fail."),
+                    );
+                    if ui.button(i18n::t("🛡 Restart as Administrator")).clicked() {
+                        restart_clicked = true;
+                    }
+                });
+            }
+            if let Some(vm) = &self.vm_detection {
                 ui.colored_label(
-                    Color32::from_rgb(200, 50, 50),
-                    "Running without Administrator privileges. Some firmware reads may fail.",
-                );
+                    Color32::from_rgb(210, 150, 40),
+                    i18n::tf("Likely virtual: {}", &[&vm.hypervisor]),
+                )
+                .on_hover_text(&vm.evidence);
+            }
+            if !self.placeholders.is_empty() {
+                let details: Vec<String> = self
+                    .placeholders
+                    .iter()
+                    .map(|p| {
+                        format!(
+                            "Type {} (Handle 0x{:04X}) {}: {} - {}",
+                            p.type_id, p.handle, p.key, p.value, p.reason
+                        )
+                    })
+                    .collect();
+                ui.colored_label(
+                    Color32::from_rgb(210, 150, 40),
+                    i18n::tf("⚠ {} placeholder values detected", &[&self.placeholders.len()]),
+                )
+                .on_hover_text(details.join("\n"));
             }
         });
 
-        egui::SidePanel::left("sidebar_panel")
+        let sidebar = egui::SidePanel::left("sidebar_panel")
             .resizable(true)
             .default_width(320.0)
             .width_range(200.0..=500.0)
             .show(ctx, |ui| {
                 ui.horizontal(|ui| {
-                    ui.heading("Firmware Tables");
+                    ui.heading(i18n::t("Firmware Tables"));
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                        let theme_icon = if self.dark_mode { "☀" } else { "🌙" };
-                        let theme_tooltip = if self.dark_mode {
-                            "Switch to Light Mode"
-                        } else {
-                            "Switch to Dark Mode"
-                        };
-                        if ui.button(theme_icon).on_hover_text(theme_tooltip).clicked() {
-                            self.dark_mode = !self.dark_mode;
+                        let theme_icon = if self.dark_mode { "🌙" } else { "☀" };
+                        egui::ComboBox::from_id_salt("theme")
+                            .selected_text(format!("{} {}", theme_icon, self.theme.label()))
+                            .show_ui(ui, |ui| {
+                                for theme in Theme::ALL {
+                                    ui.selectable_value(&mut self.theme, theme, theme.label());
+                                }
+                            })
+                            .response
+                            .on_hover_text(i18n::t(
+                                "Color theme; System follows the Windows app mode",
+                            ));
+                        let previous_language = self.language;
+                        egui::ComboBox::from_id_salt("language")
+                            .selected_text(format!("🌐 {}", self.language.label()))
+                            .show_ui(ui, |ui| {
+                                for language in i18n::Language::ALL {
+                                    ui.selectable_value(
+                                        &mut self.language,
+                                        language,
+                                        language.label(),
+                                    );
+                                }
+                            })
+                            .response
+                            .on_hover_text(i18n::t("Language"));
+                        if self.language != previous_language {
+                            i18n::set_language(self.language);
+                            if !self.cjk_font_installed {
+                                self.cjk_font_installed = install_cjk_font(ui.ctx());
+                            }
                         }
                     });
                 });
@@ -823,132 +5078,699 @@ impl eframe::App for DumpApp {
 
                 ui.horizontal(|ui| {
                     ui.label("🔍");
-                    ui.add(
+                    let response = ui.add(
                         egui::TextEdit::singleline(&mut self.sidebar_filter)
-                            .hint_text("Filter tables...")
+                            .hint_text(i18n::t("Filter tables..."))
                             .desired_width(ui.available_width()),
-                    );
+                    )
+                    .on_hover_text(i18n::t(
+                        "Filter by label text, or narrow with tokens:\n\
+                         type:17 or #17 - SMBIOS structures of type 17\n\
+                         sig:SSDT - ACPI tables with signature SSDT\n\
+                         Tokens and text can be combined, e.g. \"#17 DIMM\".",
+                    ));
+                    if self.focus_sidebar_filter {
+                        response.request_focus();
+                        self.focus_sidebar_filter = false;
+                    }
                 });
                 ui.separator();
 
                 egui::ScrollArea::vertical().show(ui, |ui| {
-                    let filter = self.sidebar_filter.to_lowercase();
+                    let filter = SidebarFilter::parse(&self.sidebar_filter);
+
+                    if !self.pins.is_empty() {
+                        egui::CollapsingHeader::new(i18n::tf("★ Pinned ({})", &[&self.pins.len()]))
+                            .default_open(true)
+                            .show(ui, |ui| {
+                                let mut entry_action = None;
+                                let mut clicked_smbios = None;
+                                let mut smbios_command = None;
+                                for pin in &self.pins {
+                                    let loaded = match pin {
+                                        Pin::Acpi(_) => self.acpi_tables.is_some(),
+                                        Pin::Smbios(..) => self.smbios_data.is_some(),
+                                    };
+                                    let mut present = false;
+                                    match pin {
+                                        Pin::Acpi(signature) => {
+                                            let tables = self.acpi_tables.iter().flatten().enumerate();
+                                            for (i, t) in tables.filter(|(_, t)| t.signature == *signature) {
+                                                present = true;
+                                                if !filter.matches_acpi(&t.signature, &acpi_entry_label(t)) {
+                                                    continue;
+                                                }
+                                                ui.horizontal(|ui| {
+                                                    pin_button(ui, &self.pins, pin, &mut toggle_pin);
+                                                    let is_selected = matches!(&self.selected_item, Selection::Acpi(s) if s == t);
+                                                    let is_changed = self.changed_acpi.contains(&table_cache_key(t));
+                                                    let size = self.acpi_sizes.get(i).copied().unwrap_or(0);
+                                                    let warnings = self.acpi_warnings.get(i).map_or(&[][..], |w| w.as_slice());
+                                                    let response = show_acpi_entry(ui, t, size, warnings, is_selected, is_changed);
+                                                    note_marker(ui, self.notes.get(&report::acpi_note_key(&t.signature)));
+                                                    nav.track(ui, &response, SidebarEntry::Acpi(t.clone()));
+                                                    acpi_entry_context_menu(&response, t, &mut entry_action);
+                                                    if response.clicked() {
+                                                        entry_action = Some(AcpiEntryAction::Select(t.clone()));
+                                                    }
+                                                });
+                                            }
+                                        }
+                                        Pin::Smbios(pin_type, pin_handle) => {
+                                            let entry = self.smbios_list.iter().find(|(_, type_id, _, handle, _)| {
+                                                type_id == pin_type && handle == pin_handle
+                                            });
+                                            if let Some((offset, type_id, _, handle, label)) = entry {
+                                                present = true;
+                                                if filter.matches_smbios(*type_id, label) {
+                                                    ui.horizontal(|ui| {
+                                                        pin_button(ui, &self.pins, pin, &mut toggle_pin);
+                                                        let is_selected = matches!(&self.selected_item, Selection::Smbios(off, _) if off == offset);
+                                                        let is_changed = self.changed_smbios.contains(handle);
+                                                        let response = ui.selectable_label(is_selected, sidebar_label(label, is_changed));
+                                                        note_marker(ui, self.notes.get(&report::smbios_note_key(*type_id, *handle)));
+                                                        nav.track(ui, &response, SidebarEntry::Smbios(*offset, *type_id));
+                                                        smbios_entry_context_menu(&response, *offset, *type_id, *handle, &mut smbios_command);
+                                                        if response.clicked() {
+                                                            clicked_smbios = Some((*offset, *type_id));
+                                                        }
+                                                    });
+                                                }
+                                            }
+                                        }
+                                    }
+                                    if present || !filter.matches_text(&pin.missing_label()) {
+                                        continue;
+                                    }
+                                    let (note, hover) = if loaded {
+                                        (
+                                            i18n::tf("{} (not present)", &[&pin.missing_label()]),
+                                            i18n::t("The pinned entry is not in the currently loaded tables"),
+                                        )
+                                    } else {
+                                        (
+                                            i18n::tf("{} (not loaded)", &[&pin.missing_label()]),
+                                            i18n::t("Load the tables to resolve this pin"),
+                                        )
+                                    };
+                                    ui.horizontal(|ui| {
+                                        pin_button(ui, &self.pins, pin, &mut toggle_pin);
+                                        ui.label(egui::RichText::new(note).weak().italics())
+                                            .on_hover_text(hover);
+                                    });
+                                }
+                                if let Some(action) = entry_action {
+                                    self.run_acpi_entry_action(ctx, action);
+                                }
+                                if let Some((off, tid)) = clicked_smbios {
+                                    self.select_smbios(off, tid);
+                                }
+                                if let Some((off, tid, command)) = smbios_command {
+                                    self.run_smbios_entry_command(ctx, off, tid, command);
+                                }
+                            });
+                    }
 
-                    egui::CollapsingHeader::new("ACPI Tables")
+                    egui::CollapsingHeader::new(i18n::t("ACPI Tables"))
                         .default_open(true)
                         .show(ui, |ui| {
+                            if let Some(error) = &self.acpi_load_error {
+                                restart_clicked |= load_failure_notice(ui, error, self.is_admin);
+                            }
                             if let Some(tables) = &self.acpi_tables {
                                 ui.horizontal(|ui| {
-                                    if ui.button("💾 Export All to AML").clicked() {
-                                        self.export_all_acpi();
+                                    let idle = self.export_job.is_none();
+                                    if ui
+                                        .add_enabled(idle, egui::Button::new(i18n::t("💾 Export All to AML")))
+                                        .clicked()
+                                    {
+                                        export_all_clicked = true;
                                     }
+                                    if ui
+                                        .button(i18n::t("🔄 Reload"))
+                                        .on_hover_text(
+                                            i18n::t("Re-read ACPI and SMBIOS and mark what changed"),
+                                        )
+                                        .clicked()
+                                    {
+                                        reload_clicked = true;
+                                    }
+                                });
+                                ui.horizontal(|ui| {
+                                    ui.checkbox(&mut self.acpi_tree_view, i18n::t("Tree view"))
+                                        .on_hover_text(i18n::t("Show RSDP -> XSDT -> tables -> FADT -> DSDT/FACS"));
+                                    let previous_source = self.acpi_source;
+                                    egui::ComboBox::from_label(i18n::t("Source"))
+                                        .selected_text(i18n::t(self.acpi_source.label()))
+                                        .show_ui(ui, |ui| {
+                                            for source in api::AcpiSource::ALL {
+                                                ui.selectable_value(
+                                                    &mut self.acpi_source,
+                                                    source,
+                                                    i18n::t(source.label()),
+                                                );
+                                            }
+                                        })
+                                        .response
+                                        .on_hover_text(i18n::t("Where ACPI tables are discovered"));
+                                    source_changed = self.acpi_source != previous_source;
                                 });
                                 ui.separator();
 
-                                // Group by signature for easier browsing
-                                let mut grouped: BTreeMap<String, Vec<api::AcpiTableInfo>> =
-                                    BTreeMap::new();
-                                for t in tables.iter() {
-                                    grouped.entry(t.signature.clone()).or_default().push(t.clone());
+                                if self.acpi_tree_view {
+                                    let mut entry_action = None;
+                                    show_acpi_tree(
+                                        ui,
+                                        &self.acpi_tree,
+                                        &self.selected_item,
+                                        &filter,
+                                        &self.changed_acpi,
+                                        &mut entry_action,
+                                        &mut nav,
+                                    );
+                                    if let Some(action) = entry_action {
+                                        self.run_acpi_entry_action(ctx, action);
+                                    }
+                                    return;
                                 }
-                                for (_sig, list) in grouped.iter_mut() {
-                                    list.sort_by(|a, b| {
-                                        a.table_id
-                                            .cmp(&b.table_id)
+
+                                ui.horizontal(|ui| {
+                                    egui::ComboBox::from_label(i18n::t("Sort"))
+                                        .selected_text(self.acpi_sort.label())
+                                        .show_ui(ui, |ui| {
+                                            for sort in AcpiSort::ALL {
+                                                ui.selectable_value(
+                                                    &mut self.acpi_sort,
+                                                    sort,
+                                                    sort.label(),
+                                                );
+                                            }
+                                        });
+                                    ui.checkbox(
+                                        &mut self.acpi_group_duplicates,
+                                        i18n::t("Group duplicates"),
+                                    )
+                                    .on_hover_text(i18n::t("Collapse tables sharing a signature (e.g. SSDT)"));
+                                });
+
+                                // Sort a view of the tables; the loaded order is kept for export-all
+                                let size_of =
+                                    |i: usize| self.acpi_sizes.get(i).copied().unwrap_or(0);
+                                let warnings_of = |i: usize| {
+                                    self.acpi_warnings.get(i).map_or(&[][..], |w| w.as_slice())
+                                };
+                                let mut sorted: Vec<(usize, &api::AcpiTableInfo)> =
+                                    tables.iter().enumerate().collect();
+                                match self.acpi_sort {
+                                    AcpiSort::Signature => sorted.sort_by(|(_, a), (_, b)| {
+                                        a.signature
+                                            .cmp(&b.signature)
+                                            .then(a.table_id.cmp(&b.table_id))
                                             .then(a.revision.cmp(&b.revision))
-                                    });
+                                    }),
+                                    AcpiSort::TableId => sorted.sort_by(|(_, a), (_, b)| {
+                                        a.table_id
+                                            .trim()
+                                            .cmp(b.table_id.trim())
+                                            .then(a.signature.cmp(&b.signature))
+                                    }),
+                                    AcpiSort::Size => sorted.sort_by(|(i, a), (j, b)| {
+                                        size_of(*j)
+                                            .cmp(&size_of(*i))
+                                            .then(a.signature.cmp(&b.signature))
+                                    }),
                                 }
 
-                                let mut clicked_acpi = None;
-                                for (sig, list) in grouped {
-                                    ui.collapsing(format!("{} ({} tables)", sig, list.len()), |ui| {
-                                        for t in list {
-                                            let label = format!(
-                                                "{} ({})",
-                                                t.signature,
-                                                t.table_id.trim()
-                                            );
-                                            if !filter.is_empty()
-                                                && !label.to_lowercase().contains(&filter)
+                                // Group duplicate signatures at the position of their first table
+                                let mut groups: Vec<(String, Vec<(usize, &api::AcpiTableInfo)>)> =
+                                    Vec::new();
+                                for (i, t) in sorted {
+                                    if self.acpi_group_duplicates {
+                                        if let Some((_, list)) =
+                                            groups.iter_mut().find(|(sig, _)| *sig == t.signature)
+                                        {
+                                            list.push((i, t));
+                                            continue;
+                                        }
+                                    }
+                                    groups.push((t.signature.clone(), vec![(i, t)]));
+                                }
+
+                                let signatures: BTreeSet<&str> =
+                                    tables.iter().map(|t| t.signature.as_str()).collect();
+                                egui::CollapsingHeader::new(i18n::t("Signature Legend"))
+                                    .default_open(false)
+                                    .show(ui, |ui| {
+                                        for sig in signatures {
+                                            if let Some(desc) =
+                                                parsers::acpi_signature_description(sig)
                                             {
-                                                continue;
+                                                ui.label(format!("{} = {}", sig, desc));
                                             }
-                                            let is_selected = matches!(&self.selected_item, Selection::Acpi(s) if s == &t);
-                                            let hover = format!(
-                                                "OEM: {}\nTable ID: {}\nRevision: {}\nRegistry: {}",
-                                                t.oem_id,
-                                                t.table_id,
-                                                t.revision,
-                                                t.registry_path.as_deref().unwrap_or("<API>")
+                                        }
+                                    });
+
+                                let mut entry_action = None;
+                                let mut show_entries = |ui: &mut egui::Ui,
+                                                        entries: &[(usize, &api::AcpiTableInfo)]| {
+                                    for (i, t) in entries {
+                                        let is_selected = matches!(&self.selected_item, Selection::Acpi(s) if s == *t);
+                                        let is_changed = self.changed_acpi.contains(&table_cache_key(t));
+                                        ui.horizontal(|ui| {
+                                            let pin = Pin::Acpi(t.signature.clone());
+                                            pin_button(ui, &self.pins, &pin, &mut toggle_pin);
+                                            let response = show_acpi_entry(
+                                                ui,
+                                                t,
+                                                size_of(*i),
+                                                warnings_of(*i),
+                                                is_selected,
+                                                is_changed,
                                             );
-                                            if ui
-                                                .selectable_label(is_selected, &label)
-                                                .on_hover_text(hover)
-                                                .clicked()
-                                            {
-                                                clicked_acpi = Some(t.clone());
+                                            note_marker(ui, self.notes.get(&report::acpi_note_key(&t.signature)));
+                                            nav.track(ui, &response, SidebarEntry::Acpi((*t).clone()));
+                                            acpi_entry_context_menu(&response, t, &mut entry_action);
+                                            if response.clicked() {
+                                                entry_action = Some(AcpiEntryAction::Select((*t).clone()));
                                             }
+                                        });
+                                    }
+                                };
+                                let mut licensing = Vec::new();
+                                for (sig, list) in groups {
+                                    let duplicated = list.len() > 1;
+                                    let visible: Vec<(usize, &api::AcpiTableInfo)> = list
+                                        .into_iter()
+                                        .filter(|(_, t)| {
+                                            filter.matches_acpi(&t.signature, &acpi_entry_label(t))
+                                        })
+                                        .collect();
+                                    if visible.is_empty() {
+                                        continue;
+                                    }
+                                    if parsers::is_licensing_table(&sig) {
+                                        licensing.extend(visible);
+                                        continue;
+                                    }
+                                    if !duplicated {
+                                        show_entries(ui, &visible);
+                                        continue;
+                                    }
+
+                                    // Byte-identical copies go in a closed section under the originals
+                                    let hashes: Vec<Option<u64>> = visible
+                                        .iter()
+                                        .map(|(i, _)| self.acpi_hashes.get(*i).copied().flatten())
+                                        .collect();
+                                    let copies = report::identical_copies(&hashes);
+                                    let (copied, unique): (Vec<_>, Vec<_>) = visible
+                                        .into_iter()
+                                        .zip(copies)
+                                        .partition(|(_, copy)| *copy);
+                                    let unique: Vec<_> = unique.into_iter().map(|(e, _)| e).collect();
+                                    let copied: Vec<_> = copied.into_iter().map(|(e, _)| e).collect();
+                                    let header = if copied.is_empty() {
+                                        i18n::tf("{} ({} tables)", &[&sig, &unique.len()])
+                                    } else {
+                                        i18n::tf(
+                                            "{} ({} unique, {} duplicate)",
+                                            &[&sig, &unique.len(), &copied.len()],
+                                        )
+                                    };
+                                    let group = ui.collapsing(header, |ui| {
+                                        show_entries(ui, &unique);
+                                        if !copied.is_empty() {
+                                            egui::CollapsingHeader::new(i18n::tf(
+                                                "Identical copies ({})",
+                                                &[&copied.len()],
+                                            ))
+                                            .id_salt(("acpi_copies", &sig))
+                                            .default_open(false)
+                                            .show(ui, |ui| show_entries(ui, &copied))
+                                            .header_response
+                                            .on_hover_text(i18n::t(
+                                                "Tables whose bytes match an entry above",
+                                            ));
                                         }
                                     });
+                                    if let Some(desc) = parsers::acpi_signature_description(&sig) {
+                                        group.header_response.on_hover_text(desc);
+                                    }
+                                }
+                                if !licensing.is_empty() {
+                                    ui.collapsing(
+                                        i18n::tf("Licensing ({} tables)", &[&licensing.len()]),
+                                        |ui| show_entries(ui, &licensing),
+                                    )
+                                    .header_response
+                                    .on_hover_text(i18n::t("OEM activation tables (SLIC, MSDM)"));
                                 }
 
-                                if let Some(t) = clicked_acpi {
-                                    self.select_acpi(t);
+                                if let Some(action) = entry_action {
+                                    self.run_acpi_entry_action(ctx, action);
                                 }
-                            } else if ui.button("Load ACPI Tables").clicked() {
+                            } else if ui.button(i18n::t("Load ACPI Tables")).clicked() {
                                 self.load_acpi();
                             }
                         });
 
-                    egui::CollapsingHeader::new("SMBIOS Data")
+                    egui::CollapsingHeader::new(i18n::t("SMBIOS Data"))
                         .default_open(true)
                         .show(ui, |ui| {
+                            if let Some(error) = &self.smbios_load_error {
+                                restart_clicked |= load_failure_notice(ui, error, self.is_admin);
+                            }
                             if self.smbios_data.is_some() {
                                 if let Some(h) = &self.smbios_header {
-                                    ui.label(format!(
-                                        "Version {}.{} | DMI rev {}",
-                                        h._major_version, h._minor_version, h._dmi_revision
-                                    ));
+                                    ui.label(i18n::tf("Version {}.{} | DMI rev {}", &[&h._major_version, &h._minor_version, &h._dmi_revision]));
+                                }
+                                if let Some(summary) = &self.memory_summary {
+                                    ui.label(summary);
+                                }
+                                if ui
+                                    .checkbox(&mut self.populated_only, i18n::t("Only show populated"))
+                                    .on_hover_text(i18n::t(
+                                        "Hide empty memory slots and unused expansion slots",
+                                    ))
+                                    .changed()
+                                {
+                                    if let Some(data) = &self.smbios_data {
+                                        self.memory_summary = report::memory_summary(
+                                            data,
+                                            self.string_encoding,
+                                            self.populated_only,
+                                        );
+                                    }
                                 }
+                                ui.checkbox(&mut self.hide_end_of_table, i18n::t("Hide End-of-Table marker"))
+                                    .on_hover_text(i18n::t(
+                                        "Hide the End-of-Table (Type 127) and Inactive (Type 126) structures from this list; filter with #127 to show them",
+                                    ));
                                 ui.horizontal(|ui| {
-                                    if ui.button("💾 Export Full Blob").clicked() {
+                                    if ui.button(i18n::t("💾 Export Full Blob")).clicked() {
                                         self.export_full_smbios();
                                     }
+                                    ui.checkbox(
+                                        &mut self.smbios_strip_wrapper,
+                                        i18n::t("Strip header"),
+                                    )
+                                    .on_hover_text(i18n::t(
+                                        "Export the bare structure table that dmidecode and other SMBIOS tools expect, without the Windows 8-byte RawSMBIOSData header",
+                                    ));
+                                    if ui
+                                        .button(i18n::t("📋 Copy as XML"))
+                                        .on_hover_text(i18n::t("Copy every parsed structure as XML"))
+                                        .clicked()
+                                    {
+                                        self.copy_smbios_xml(ui.ctx());
+                                    }
                                 });
+                                let previous_encoding = self.string_encoding;
+                                egui::ComboBox::from_label(i18n::t("Strings"))
+                                    .selected_text(self.string_encoding.label())
+                                    .show_ui(ui, |ui| {
+                                        for encoding in parsers::StringEncoding::ALL {
+                                            ui.selectable_value(
+                                                &mut self.string_encoding,
+                                                encoding,
+                                                encoding.label(),
+                                            );
+                                        }
+                                    })
+                                    .response
+                                    .on_hover_text(i18n::t("Character encoding for SMBIOS strings"));
+                                if self.string_encoding != previous_encoding {
+                                    if let Some(data) = &self.smbios_data {
+                                        self.sensors =
+                                            report::smbios_sensors(data, self.string_encoding);
+                                        self.sockets =
+                                            report::smbios_sockets(data, self.string_encoding);
+                                        self.cpu_summary =
+                                            report::cpu_summary(data, self.string_encoding);
+                                    }
+                                    if let Selection::Smbios(off, tid) = self.selected_item {
+                                        self.select_smbios(off, tid);
+                                    }
+                                }
+                                let previous_override = self.smbios_version_override;
+                                let declared = self.smbios_header.as_ref().map(|h| h.version());
+                                let declared_label = match declared {
+                                    Some(version) => i18n::tf("SMBIOS {} (table)", &[&version]),
+                                    None => i18n::t("Table version").to_string(),
+                                };
+                                egui::ComboBox::from_label(i18n::t("Parse as"))
+                                    .selected_text(match self.smbios_version_override {
+                                        Some(version) => i18n::tf("SMBIOS {} (override)", &[&version]),
+                                        None => declared_label.clone(),
+                                    })
+                                    .show_ui(ui, |ui| {
+                                        ui.selectable_value(
+                                            &mut self.smbios_version_override,
+                                            None,
+                                            declared_label,
+                                        );
+                                        for version in parsers::SmbiosVersion::OVERRIDES {
+                                            ui.selectable_value(
+                                                &mut self.smbios_version_override,
+                                                Some(version),
+                                                format!("SMBIOS {}", version),
+                                            );
+                                        }
+                                    })
+                                    .response
+                                    .on_hover_text(i18n::t(
+                                        "Decode the UUID byte order, BIOS ROM Size, and memory device Size by another SMBIOS version's rules",
+                                    ));
+                                if self.smbios_version_override != previous_override {
+                                    if let Selection::Smbios(off, tid) = self.selected_item {
+                                        // Keep a forced interpretation while comparing versions
+                                        let interpret_as = self.smbios_interpret_as;
+                                        self.select_smbios(off, tid);
+                                        self.smbios_interpret_as = interpret_as;
+                                        self.refresh_structured_views();
+                                    }
+                                }
                                 ui.separator();
 
                                 let mut clicked_smbios = None;
+                                let mut smbios_command = None;
                                 let mut smbios_sorted = self.smbios_list.clone();
                                 smbios_sorted.sort_by(|a, b| a.1.cmp(&b.1).then(a.3.cmp(&b.3)));
 
-                                for (offset, type_id, _length, _handle, label) in smbios_sorted {
-                                    if !filter.is_empty() && !label.to_lowercase().contains(&filter)
+                                for (offset, type_id, _length, handle, label) in smbios_sorted {
+                                    if !filter.matches_smbios(type_id, &label) {
+                                        continue;
+                                    }
+                                    if self.hide_end_of_table
+                                        && matches!(type_id, 126 | 127)
+                                        && !filter.types.contains(&type_id)
                                     {
                                         continue;
                                     }
-                                    let is_selected = matches!(&self.selected_item, Selection::Smbios(off, _) if *off == offset);
-                                    if ui.selectable_label(is_selected, label).clicked() {
-                                        clicked_smbios = Some((offset, type_id));
+                                    if self.populated_only
+                                        && self
+                                            .smbios_data
+                                            .as_deref()
+                                            .is_some_and(|data| report::is_empty_slot(data, offset))
+                                    {
+                                        continue;
                                     }
+                                    let is_selected = matches!(&self.selected_item, Selection::Smbios(off, _) if *off == offset);
+                                    let is_changed = self.changed_smbios.contains(&handle);
+                                    ui.horizontal(|ui| {
+                                        let pin = Pin::Smbios(type_id, handle);
+                                        pin_button(ui, &self.pins, &pin, &mut toggle_pin);
+                                        let response =
+                                            ui.selectable_label(is_selected, sidebar_label(&label, is_changed));
+                                        note_marker(ui, self.notes.get(&report::smbios_note_key(type_id, handle)));
+                                        nav.track(ui, &response, SidebarEntry::Smbios(offset, type_id));
+                                        smbios_entry_context_menu(&response, offset, type_id, handle, &mut smbios_command);
+                                        if response.clicked() {
+                                            clicked_smbios = Some((offset, type_id));
+                                        }
+                                    });
                                 }
                                 if let Some((off, tid)) = clicked_smbios {
                                     self.select_smbios(off, tid);
                                 }
-                            } else if ui.button("Load SMBIOS Data").clicked() {
+                                if let Some((off, tid, command)) = smbios_command {
+                                    self.run_smbios_entry_command(ctx, off, tid, command);
+                                }
+                            } else if ui.button(i18n::t("Load SMBIOS Data")).clicked() {
                                 self.load_smbios();
                             }
                         });
+
+                    if !self.loaded_files.is_empty() {
+                        egui::CollapsingHeader::new(i18n::t("Loaded from File"))
+                            .default_open(true)
+                            .show(ui, |ui| {
+                                let mut clicked_file = None;
+                                for (index, file) in self.loaded_files.iter().enumerate() {
+                                    let label = file.label();
+                                    if !filter.matches_text(&label) {
+                                        continue;
+                                    }
+                                    let is_selected =
+                                        matches!(self.selected_item, Selection::File(i) if i == index);
+                                    let response = ui
+                                        .selectable_label(is_selected, label)
+                                        .on_hover_text(i18n::tf("{} bytes", &[&file.data.len()]));
+                                    nav.track(ui, &response, SidebarEntry::File(index));
+                                    if response.clicked() {
+                                        clicked_file = Some(index);
+                                    }
+                                }
+                                if let Some(index) = clicked_file {
+                                    self.select_file(index);
+                                }
+                            });
+                    }
+
+                    if !self.sockets.is_empty() {
+                        egui::CollapsingHeader::new(i18n::tf("Sockets ({})", &[&self.sockets.len()]))
+                            .default_open(false)
+                            .show(ui, |ui| {
+                                if let Some(summary) = &self.cpu_summary {
+                                    ui.label(summary);
+                                }
+                                let mut clicked_socket = None;
+                                for socket in &self.sockets {
+                                    let status = if socket.populated {
+                                        i18n::t("Populated")
+                                    } else {
+                                        i18n::t("Empty")
+                                    };
+                                    let is_selected = matches!(&self.selected_item, Selection::Smbios(off, _) if *off == socket.offset);
+                                    let handles: Vec<String> = socket
+                                        .handles
+                                        .iter()
+                                        .map(|h| format!("Handle 0x{:04X}", h))
+                                        .collect();
+                                    let mut hover = socket.processors.clone();
+                                    hover.push(handles.join(", "));
+                                    if ui
+                                        .selectable_label(
+                                            is_selected,
+                                            format!("{}: {}", socket.designator, status),
+                                        )
+                                        .on_hover_text(hover.join("\n"))
+                                        .clicked()
+                                    {
+                                        clicked_socket = Some(socket.offset);
+                                    }
+                                }
+                                if let Some(off) = clicked_socket {
+                                    self.select_smbios(off, 4);
+                                }
+                            });
+                    }
+
+                    let mut numa_source = None;
+                    if let Some(numa) = &self.numa {
+                        egui::CollapsingHeader::new(i18n::tf("NUMA ({} nodes)", &[&numa.nodes.len()]))
+                            .default_open(false)
+                            .show(ui, |ui| {
+                                if !numa.has_slit {
+                                    ui.weak(i18n::t("No SLIT: node distances are unknown"));
+                                }
+                                let source = if numa.has_srat { "SRAT" } else { "SLIT" };
+                                for node in &numa.nodes {
+                                    let ranges: Vec<String> = node
+                                        .memory
+                                        .iter()
+                                        .map(|(base, length)| {
+                                            format!(
+                                                "0x{:016X} - 0x{:016X} ({})",
+                                                base,
+                                                base.wrapping_add(*length).wrapping_sub(1),
+                                                parsers::memory_size_str(*length)
+                                            )
+                                        })
+                                        .collect();
+                                    let mut response = ui.selectable_label(false, numa.node_line(node));
+                                    if !ranges.is_empty() {
+                                        response = response.on_hover_text(ranges.join("\n"));
+                                    }
+                                    if response.clicked() {
+                                        numa_source = Some(source);
+                                    }
+                                }
+                            });
+                    }
+                    // Clicking a node selects the table its CPUs and memory came from
+                    let numa_table = numa_source.and_then(|source| {
+                        self.acpi_tables
+                            .iter()
+                            .flatten()
+                            .find(|t| t.signature == source)
+                            .cloned()
+                    });
+                    if let Some(t) = numa_table {
+                        self.run_acpi_entry_action(ctx, AcpiEntryAction::Select(t));
+                    }
+
+                    if !self.sensors.is_empty() {
+                        egui::CollapsingHeader::new(i18n::tf("Sensors ({})", &[&self.sensors.len()]))
+                            .default_open(false)
+                            .show(ui, |ui| {
+                                let mut clicked_sensor = None;
+                                for sensor in &self.sensors {
+                                    let name = match sensor.description.as_str() {
+                                        "" => parsers::smbios_type_name(sensor.type_id),
+                                        description => description,
+                                    };
+                                    let is_selected = matches!(&self.selected_item, Selection::Smbios(off, _) if *off == sensor.offset);
+                                    if ui
+                                        .selectable_label(
+                                            is_selected,
+                                            format!("{}: {}", name, sensor.nominal),
+                                        )
+                                        .on_hover_text(format!(
+                                            "{} (Handle 0x{:04X})\nLocation: {}\nStatus: {}",
+                                            parsers::smbios_type_name(sensor.type_id),
+                                            sensor.handle,
+                                            sensor.location,
+                                            sensor.status
+                                        ))
+                                        .clicked()
+                                    {
+                                        clicked_sensor = Some((sensor.offset, sensor.type_id));
+                                    }
+                                }
+                                if let Some((off, tid)) = clicked_sensor {
+                                    self.select_smbios(off, tid);
+                                }
+                            });
+                    }
                 });
             });
 
+        // Rows drawn this frame become the navigation list; clamp in case rows disappeared
+        if nav.cursor.is_some_and(|c| c >= nav.entries.len()) {
+            nav.cursor = nav.entries.len().checked_sub(1);
+        }
+        self.sidebar_nav = nav;
+        if let Some(pin) = toggle_pin {
+            self.toggle_pin(pin);
+        }
+        if let Some(pos) =
+            ctx.input(|i| i.pointer.interact_pos().filter(|_| i.pointer.any_pressed()))
+        {
+            self.sidebar_active = sidebar.response.rect.contains(pos);
+        }
+
         egui::CentralPanel::default().show(ctx, |ui| {
             // Monitor for Ctrl+F keyboard shortcut
             if ctx.input_mut(|i| i.consume_key(egui::Modifiers::CTRL, egui::Key::F)) {
                 self.search_panel_open = !self.search_panel_open;
             }
+            self.handle_search_keys(ctx);
 
             ui.vertical(|ui| {
                 let toolbar_width = ui.available_width();
+                self.show_selection_header(ui);
+                self.show_notes_box(ui);
 
                 // Row 1: tabs + search toggle (wrap to avoid overlap on narrow widths)
                 ui.horizontal_wrapped(|ui| {
@@ -957,52 +5779,130 @@ impl eframe::App for DumpApp {
                     ui.spacing_mut().item_spacing.y = 4.0;
 
                     if ui
-                        .selectable_label(self.active_tab == Tab::Hex, "Hex View")
+                        .selectable_label(self.active_tab == Tab::Hex, i18n::t("Hex View"))
+                        .clicked()
+                    {
+                        self.switch_tab(Tab::Hex);
+                    }
+                    if ui
+                        .selectable_label(self.active_tab == Tab::Parsed, i18n::t("Parsed View"))
+                        .clicked()
+                    {
+                        self.switch_tab(Tab::Parsed);
+                    }
+
+                    ui.add_space(8.0);
+                    ui.toggle_value(&mut self.search_panel_open, i18n::t("🔍 Search (Ctrl+F)"));
+                    ui.toggle_value(&mut self.byte_stats_open, i18n::t("📊 Byte Stats"))
+                        .on_hover_text(i18n::t("Show the byte distribution of the selected item"));
+                    ui.toggle_value(&mut self.footprint_open, i18n::t("📏 Tables by Size"))
+                        .on_hover_text(i18n::t(
+                            "List every ACPI table by size with the total firmware footprint",
+                        ));
+                    ui.toggle_value(&mut self.advisory_open, i18n::t("ℹ Table Advisory"))
+                        .on_hover_text(i18n::t(
+                            "Compare the ACPI tables found with the ones most PCs provide",
+                        ));
+                    ui.toggle_value(&mut self.smbios_fields_open, i18n::t("▦ All SMBIOS Fields"))
+                        .on_hover_text(i18n::t(
+                            "Search and sort every parsed SMBIOS field in one table",
+                        ));
+                    ui.toggle_value(&mut self.smbios_diff_open, i18n::t("🔀 SMBIOS Diff"))
+                        .on_hover_text(i18n::t(
+                            "Compare two SMBIOS dumps, or this system and a dump, field by field",
+                        ));
+                });
+
+                // Row 2: clipboard and exports (wrapped to avoid overlap)
+                ui.horizontal_wrapped(|ui| {
+                    ui.set_width(toolbar_width);
+                    ui.spacing_mut().item_spacing.x = 8.0;
+                    ui.spacing_mut().item_spacing.y = 4.0;
+
+                    let has_selection = !matches!(self.selected_item, Selection::None);
+
+                    let has_data = !self.cached_bytes.is_empty() || !self.cached_parsed.is_empty();
+                    if ui
+                        .add_enabled(has_data, egui::Button::new(i18n::t("📋 Copy")))
+                        .on_hover_text(i18n::t("Copy current view to clipboard"))
+                        .on_disabled_hover_text(i18n::t("Select an item first"))
+                        .clicked()
+                    {
+                        let text_to_copy = match self.active_tab {
+                            Tab::Hex => {
+                                report::hex_dump_str(&self.cached_bytes, self.hex_base_offset())
+                            }
+                            Tab::Parsed => self.cached_parsed.clone(),
+                        };
+                        self.copy_to_clipboard(ctx, &text_to_copy);
+                    }
+
+                    if ui
+                        .add_enabled(has_selection, egui::Button::new(i18n::t("📝 Copy as Markdown")))
+                        .on_hover_text(i18n::t("Copy the parsed fields as a Markdown table"))
+                        .on_disabled_hover_text(i18n::t("Select an item first"))
                         .clicked()
                     {
-                        self.active_tab = Tab::Hex;
-                        self.recompute_search_matches();
+                        match self.current_fields() {
+                            Some(fields) => {
+                                self.copy_to_clipboard(ctx, &report::markdown_table(&fields))
+                            }
+                            None => self.status = i18n::t("No parsed fields to copy").to_string(),
+                        }
                     }
+
+                    let selected_bytes = self
+                        .hex_selection
+                        .filter(|_| self.active_tab == Tab::Hex)
+                        .and_then(|(start, end)| self.cached_bytes.get(start..=end));
                     if ui
-                        .selectable_label(self.active_tab == Tab::Parsed, "Parsed View")
+                        .add_enabled(
+                            selected_bytes.is_some(),
+                            egui::Button::new(i18n::t("📋 Copy Selected Bytes")),
+                        )
+                        .on_hover_text(i18n::t("Copy the bytes selected in the hex view as hex"))
+                        .on_disabled_hover_text(i18n::t("Select bytes in the hex view first"))
                         .clicked()
                     {
-                        self.active_tab = Tab::Parsed;
-                        self.recompute_search_matches();
+                        if let Some(bytes) = selected_bytes {
+                            let hex: Vec<String> =
+                                bytes.iter().map(|b| format!("{:02X}", b)).collect();
+                            self.copy_to_clipboard(ctx, &hex.join(" "));
+                        }
                     }
 
-                    ui.add_space(8.0);
                     if ui
-                        .toggle_value(&mut self.search_panel_open, "🔍 Search (Ctrl+F)")
+                        .add_enabled(
+                            selected_bytes.is_some(),
+                            egui::Button::new(i18n::t("💾 Export Selection")),
+                        )
+                        .on_hover_text(i18n::t("Save the bytes selected in the hex view to a .bin file"))
+                        .on_disabled_hover_text(i18n::t("Select bytes in the hex view first"))
                         .clicked()
-                    {}
-                });
-
-                // Row 2: clipboard and exports (wrapped to avoid overlap)
-                ui.horizontal_wrapped(|ui| {
-                    ui.set_width(toolbar_width);
-                    ui.spacing_mut().item_spacing.x = 8.0;
-                    ui.spacing_mut().item_spacing.y = 4.0;
+                    {
+                        self.export_selection();
+                    }
 
-                    let has_selection = !matches!(self.selected_item, Selection::None);
+                    if ui
+                        .add_enabled(has_selection, egui::Button::new(i18n::t("📋 Copy as Base64")))
+                        .on_hover_text(i18n::t("Copy the selected item's bytes as Base64 for pasting"))
+                        .on_disabled_hover_text(i18n::t("Select an item to copy"))
+                        .clicked()
+                    {
+                        self.copy_base64(ctx);
+                    }
 
-                    let has_data = !self.cached_hex.is_empty() || !self.cached_parsed.is_empty();
                     if ui
-                        .add_enabled(has_data, egui::Button::new("📋 Copy"))
-                        .on_hover_text("Copy current view to clipboard")
-                        .on_disabled_hover_text("Select an item first")
+                        .button(i18n::t("📥 Load from Base64…"))
+                        .on_hover_text(i18n::t("Decode a pasted ACPI table or SMBIOS dump"))
                         .clicked()
                     {
-                        let text_to_copy = match self.active_tab {
-                            Tab::Hex => &self.cached_hex,
-                            Tab::Parsed => &self.cached_parsed,
-                        };
-                        self.copy_to_clipboard(ctx, text_to_copy);
+                        self.base64_input.get_or_insert_with(String::new);
                     }
 
                     if ui
-                        .add_enabled(has_selection, egui::Button::new("📦 Export Raw Binary"))
-                        .on_disabled_hover_text("Select an item to export")
+                        .add_enabled(has_selection, egui::Button::new(i18n::t("📦 Export Raw Binary")))
+                        .on_disabled_hover_text(i18n::t("Select an item to export"))
                         .clicked()
                     {
                         self.export_raw();
@@ -1013,7 +5913,7 @@ impl eframe::App for DumpApp {
                             matches!(self.selected_item, Selection::Smbios(..)),
                             egui::Button::new("� Export CSV"),
                         )
-                        .on_disabled_hover_text("Select an SMBIOS item")
+                        .on_disabled_hover_text(i18n::t("Select an SMBIOS item"))
                         .clicked()
                     {
                         self.export_smbios_csv();
@@ -1024,30 +5924,240 @@ impl eframe::App for DumpApp {
                             matches!(self.selected_item, Selection::Smbios(..)),
                             egui::Button::new("� Export JSON"),
                         )
-                        .on_disabled_hover_text("Select an SMBIOS item")
+                        .on_disabled_hover_text(i18n::t("Select an SMBIOS item"))
                         .clicked()
                     {
-                        self.export_smbios_json();
+                        self.export_smbios_json(false);
                     }
 
                     if ui
-                        .add_enabled(has_selection, egui::Button::new("📥 Export Parsed"))
-                        .on_disabled_hover_text("Select an item to export")
+                        .add_enabled(has_selection, egui::Button::new(i18n::t("📥 Export Parsed")))
+                        .on_disabled_hover_text(i18n::t("Select an item to export"))
                         .clicked()
                     {
                         self.export_parsed();
                     }
+
+                    if ui
+                        .add_enabled(has_selection, egui::Button::new(i18n::t("📥 Export Hex Dump")))
+                        .on_hover_text(i18n::t(
+                            "Save the hex view as text, with its offsets and collapsed runs",
+                        ))
+                        .on_disabled_hover_text(i18n::t("Select an item to export"))
+                        .clicked()
+                    {
+                        self.export_hex_dump();
+                    }
+
+                    if ui
+                        .add_enabled(
+                            self.smbios_data.is_some(),
+                            egui::Button::new(i18n::t("📥 Export All Parsed (SMBIOS)")),
+                        )
+                        .on_hover_text(i18n::t("Write every SMBIOS structure's parsed view to one file"))
+                        .on_disabled_hover_text(i18n::t("Load SMBIOS data first"))
+                        .clicked()
+                    {
+                        self.export_all_parsed_smbios(false);
+                    }
+
+                    if ui
+                        .add_enabled(
+                            self.acpi_tables.is_some(),
+                            egui::Button::new(i18n::t("📥 Export All Parsed (ACPI)")),
+                        )
+                        .on_hover_text(i18n::t("Write every ACPI table's parsed view to one file"))
+                        .on_disabled_hover_text(i18n::t("Load ACPI tables first"))
+                        .clicked()
+                    {
+                        self.export_all_parsed_acpi(false);
+                    }
+
+                    if ui
+                        .add_enabled(
+                            self.acpi_tables.is_some(),
+                            egui::Button::new(i18n::t("📄 Export ACPI Manifest")),
+                        )
+                        .on_hover_text(i18n::t(
+                            "Save each table's signature, OEM and table IDs, revision, length, address, and source as CSV",
+                        ))
+                        .on_disabled_hover_text(i18n::t("Load ACPI tables first"))
+                        .clicked()
+                    {
+                        self.export_acpi_manifest();
+                    }
+
+                    if ui
+                        .add_enabled(
+                            self.acpi_tables.is_some() || self.smbios_data.is_some(),
+                            egui::Button::new(i18n::t("🌐 Export HTML Report")),
+                        )
+                        .on_hover_text(i18n::t("Write the summary and every parsed table to one HTML page"))
+                        .on_disabled_hover_text(i18n::t("Load ACPI tables or SMBIOS data first"))
+                        .clicked()
+                    {
+                        self.export_html_report(false);
+                    }
+
+                    if ui
+                        .add_enabled(
+                            self.export_job.is_none(),
+                            egui::Button::new(i18n::t("📑 Full System Report")),
+                        )
+                        .on_hover_text(
+                            i18n::t("Write all tables, the SMBIOS blob, and summaries to a folder"),
+                        )
+                        .clicked()
+                    {
+                        self.export_full_report(ctx, false);
+                    }
+
+                    ui.menu_button(i18n::t("🔒 Export (redacted)"), |ui| {
+                        ui.label(i18n::t(
+                            "Serial numbers, UUIDs, asset tags, and the product key read REDACTED",
+                        ));
+                        ui.separator();
+                        if ui
+                            .add_enabled(
+                                matches!(self.selected_item, Selection::Smbios(..)),
+                                egui::Button::new(i18n::t("Selected SMBIOS as JSON")),
+                            )
+                            .on_disabled_hover_text(i18n::t("Select an SMBIOS item"))
+                            .clicked()
+                        {
+                            self.export_smbios_json(true);
+                            ui.close_menu();
+                        }
+                        if ui
+                            .add_enabled(
+                                self.smbios_data.is_some(),
+                                egui::Button::new(i18n::t("All Parsed (SMBIOS, dmidecode)")),
+                            )
+                            .on_disabled_hover_text(i18n::t("Load SMBIOS data first"))
+                            .clicked()
+                        {
+                            self.export_all_parsed_smbios(true);
+                            ui.close_menu();
+                        }
+                        if ui
+                            .add_enabled(
+                                self.acpi_tables.is_some(),
+                                egui::Button::new(i18n::t("All Parsed (ACPI)")),
+                            )
+                            .on_disabled_hover_text(i18n::t("Load ACPI tables first"))
+                            .clicked()
+                        {
+                            self.export_all_parsed_acpi(true);
+                            ui.close_menu();
+                        }
+                        if ui
+                            .add_enabled(
+                                self.acpi_tables.is_some() || self.smbios_data.is_some(),
+                                egui::Button::new(i18n::t("HTML Report")),
+                            )
+                            .on_disabled_hover_text(i18n::t("Load ACPI tables or SMBIOS data first"))
+                            .clicked()
+                        {
+                            self.export_html_report(true);
+                            ui.close_menu();
+                        }
+                        if ui
+                            .add_enabled(
+                                self.export_job.is_none(),
+                                egui::Button::new(i18n::t("Full System Report")),
+                            )
+                            .on_hover_text(i18n::t(
+                                "Leaves out the raw SMBIOS blob and MSDM table, which hold these values",
+                            ))
+                            .clicked()
+                        {
+                            self.export_full_report(ctx, true);
+                            ui.close_menu();
+                        }
+                    });
                 });
 
+                // Row 3: hex editing
+                if self.active_tab == Tab::Hex && !self.cached_bytes.is_empty() {
+                    ui.horizontal_wrapped(|ui| {
+                        ui.set_width(toolbar_width);
+                        ui.spacing_mut().item_spacing.x = 8.0;
+                        if ui
+                            .toggle_value(&mut self.hex_edit_mode, i18n::t("✏ Edit Mode"))
+                            .on_hover_text(i18n::t("Edit the hex digits; Export Raw Binary saves the edits"))
+                            .changed()
+                        {
+                            self.hex_edit_text = hex_edit_str(&self.cached_bytes);
+                            self.hex_edit_error = None;
+                        }
+                        if matches!(self.selected_item, Selection::Smbios(..))
+                            && ui
+                                .toggle_value(&mut self.hex_absolute_offsets, i18n::t("Blob offsets"))
+                                .on_hover_text(i18n::t(
+                                    "Number offsets from the start of the SMBIOS blob instead of the structure",
+                                ))
+                                .changed()
+                        {
+                            self.hex_line_starts = report::hex_dump_line_starts(
+                                self.cached_bytes.len(),
+                                self.hex_base_offset(),
+                            );
+                            if !self.search_query.is_empty() {
+                                self.recompute_search_matches();
+                            }
+                        }
+                        ui.toggle_value(&mut self.hex_byte_names, i18n::t("Byte names"))
+                            .on_hover_text(i18n::t(
+                                "Hover the ASCII column to see each byte's value and control character name",
+                            ));
+                        ui.toggle_value(&mut self.hex_collapse_runs, i18n::t("Collapse runs"))
+                            .on_hover_text(i18n::tf(
+                                "Show lines of one repeated byte, such as zero padding, as a single line ({} collapsible here)",
+                                &[&self.hex_runs.len()],
+                            ));
+                        if self.bytes_modified {
+                            ui.colored_label(Color32::from_rgb(210, 150, 40), i18n::t("Modified"));
+                        }
+                        if let Some(e) = &self.hex_edit_error {
+                            ui.colored_label(Color32::from_rgb(200, 50, 50), e);
+                        }
+                        let checksum = match &self.selected_item {
+                            Selection::Acpi(t) => acpi_checksum_status(t, &self.cached_bytes),
+                            _ => None,
+                        };
+                        match checksum {
+                            Some(ChecksumStatus::Truncated) => {
+                                ui.colored_label(
+                                    Color32::from_rgb(200, 50, 50),
+                                    i18n::t("Checksum: not checked (table truncated)"),
+                                );
+                            }
+                            Some(ChecksumStatus::Ok) => {
+                                ui.label(i18n::t("Checksum: OK"));
+                            }
+                            Some(ChecksumStatus::Invalid(sum)) => {
+                                ui.colored_label(
+                                    Color32::from_rgb(200, 50, 50),
+                                    i18n::tf("Checksum: invalid (sum {})", &[&format!("0x{:02X}", sum)]),
+                                );
+                                if ui.button(i18n::t("Fix Checksum")).clicked() {
+                                    self.fix_checksum();
+                                }
+                            }
+                            None => {}
+                        }
+                    });
+                }
+
                 ui.separator();
 
                 // Search Bar
                 if self.search_panel_open {
                     ui.horizontal(|ui| {
-                        ui.label("Find:");
+                        ui.label(i18n::t("Find:"));
                         let response = ui.add(
                             egui::TextEdit::singleline(&mut self.search_query)
-                                .hint_text("Enter text..."),
+                                .hint_text(i18n::t("Enter text...")),
                         );
                         if self.search_panel_open {
                             response.request_focus();
@@ -1063,40 +6173,256 @@ impl eframe::App for DumpApp {
                             } else {
                                 "".to_string()
                             };
-                            ui.label(format!("{} matches{}", total, current));
+                            ui.label(i18n::tf("{} matches{}", &[&total, &current]));
                             ui.add_enabled_ui(total > 0, |ui| {
-                                if ui.button("Prev").clicked() {
+                                if ui.button(i18n::t("Prev")).clicked() {
                                     self.step_search(-1);
                                 }
-                                if ui.button("Next").clicked() {
+                                if ui.button(i18n::t("Next")).clicked() {
                                     self.step_search(1);
                                 }
+                                if ui
+                                    .button(i18n::t("📋 Copy matching lines"))
+                                    .on_hover_text(i18n::t(
+                                        "Copy every line that contains a match to the clipboard",
+                                    ))
+                                    .clicked()
+                                {
+                                    let lines = self.matching_lines(self.search_unique_lines);
+                                    self.copy_to_clipboard(ui.ctx(), &lines.join("\n"));
+                                    self.status =
+                                        i18n::tf("Copied {} matching lines", &[&lines.len()]);
+                                }
                             });
+                            ui.checkbox(&mut self.search_unique_lines, i18n::t("Unique lines"))
+                                .on_hover_text(i18n::t(
+                                    "Copy identical lines only once",
+                                ));
                         }
 
-                        if ui.button("Close").clicked() {
+                        if ui.button(i18n::t("Close")).clicked() {
                             self.search_panel_open = false;
                         }
                     });
                     ui.separator();
                 }
 
+                if self.byte_stats_open {
+                    match &self.cached_stats {
+                        Some(stats) => show_byte_stats(ui, stats),
+                        None => {
+                            ui.label(i18n::t("Select an item to see its byte statistics."));
+                        }
+                    }
+                    ui.separator();
+                }
+
                 // Data Display Area
+                if self.hex_edit_mode && self.active_tab == Tab::Hex {
+                    egui::ScrollArea::vertical().show(ui, |ui| {
+                        let response = ui.add_sized(
+                            ui.available_size(),
+                            egui::TextEdit::multiline(&mut self.hex_edit_text)
+                                .font(FontId::monospace(self.data_font_size))
+                                .desired_width(f32::INFINITY),
+                        );
+                        if response.changed() {
+                            self.apply_hex_edit();
+                        }
+                    });
+                    return;
+                }
+                if self.active_tab == Tab::Parsed {
+                    if let (Some((handle, fields)), Selection::Smbios(_, type_id)) =
+                        (&self.smbios_field_view, &self.selected_item)
+                    {
+                        let mut jump = None;
+                        let mut copied = None;
+                        let mut interpret_as = self.smbios_interpret_as;
+                        egui::ScrollArea::vertical().show(ui, |ui| {
+                            ui.horizontal(|ui| {
+                                ui.strong(format!("Type {} (Handle 0x{:04X})", type_id, handle));
+                                if ui
+                                    .small_button(i18n::t("📋 Copy Handle"))
+                                    .on_hover_text(i18n::t("Copy this structure's handle"))
+                                    .clicked()
+                                {
+                                    ui.ctx().copy_text(format!("0x{:04X}", handle));
+                                }
+                                if let Some(reference) = report::smbios_spec_reference(
+                                    *type_id,
+                                    self.smbios_parse_version(),
+                                ) {
+                                    ui.weak(reference).on_hover_text(i18n::t(
+                                        "SMBIOS version that introduced this structure type",
+                                    ));
+                                }
+                                // OEM types have no parser of their own; trying a known
+                                // layout helps when reverse-engineering them
+                                if *type_id >= 128 {
+                                    ui.separator();
+                                    ui.label(i18n::t("Interpret as:"));
+                                    let type_label = |t: u8| match parsers::smbios_type_name(t) {
+                                        "" => format!("Type {}", t),
+                                        name => format!("Type {} ({})", t, name),
+                                    };
+                                    egui::ComboBox::from_id_salt("smbios_interpret_as")
+                                        .selected_text(match interpret_as {
+                                            Some(t) => type_label(t),
+                                            None => i18n::t("auto").to_string(),
+                                        })
+                                        .show_ui(ui, |ui| {
+                                            ui.selectable_value(
+                                                &mut interpret_as,
+                                                None,
+                                                i18n::t("auto"),
+                                            );
+                                            for &t in parsers::PARSED_SMBIOS_TYPES {
+                                                ui.selectable_value(
+                                                    &mut interpret_as,
+                                                    Some(t),
+                                                    type_label(t),
+                                                );
+                                            }
+                                        })
+                                        .response
+                                        .on_hover_text(i18n::t(
+                                            "Decode these bytes with another type's parser",
+                                        ));
+                                }
+                            });
+                            if interpret_as.is_some() {
+                                ui.colored_label(
+                                    Color32::from_rgb(210, 150, 40),
+                                    i18n::t("⚠ Forced interpretation: fields may not mean what their names say"),
+                                );
+                            }
+                            if let Some(version) = self.smbios_version_override {
+                                ui.colored_label(
+                                    Color32::from_rgb(210, 150, 40),
+                                    i18n::tf("⚠ Parsed with SMBIOS {} rules, not the table's own version", &[&version]),
+                                );
+                            }
+                            ui.separator();
+                            jump = show_smbios_fields(
+                                ui,
+                                *type_id,
+                                fields,
+                                &self.smbios_handles,
+                                &self.search_query,
+                                &mut copied,
+                            );
+                        });
+                        if let Some((key, value)) = copied {
+                            self.copy_field_value(ctx, &key, &value);
+                        }
+                        if interpret_as != self.smbios_interpret_as {
+                            self.smbios_interpret_as = interpret_as;
+                            self.refresh_structured_views();
+                        }
+                        if let Some((offset, type_id)) = jump {
+                            self.select_smbios(offset, type_id);
+                        }
+                        return;
+                    }
+                }
+                let msdm_selected =
+                    matches!(&self.selected_item, Selection::Acpi(info) if info.signature == "MSDM");
+                if msdm_selected && self.active_tab == Tab::Parsed {
+                    if ui
+                        .checkbox(&mut self.reveal_product_key, i18n::t("Reveal product key"))
+                        .on_hover_text(i18n::t("Reports and CLI output always mask the key"))
+                        .changed()
+                    {
+                        if let Selection::Acpi(info) = &self.selected_item {
+                            let info = info.clone();
+                            self.select_acpi(info);
+                        }
+                    }
+                    ui.separator();
+                }
+                if let Some(matrix) = self
+                    .slit_matrix
+                    .as_ref()
+                    .filter(|_| self.active_tab == Tab::Parsed)
+                {
+                    show_slit_matrix(ui, matrix);
+                    ui.separator();
+                }
+                if let Some(tree) = self
+                    .aml_tree
+                    .as_ref()
+                    .filter(|_| self.active_tab == Tab::Parsed)
+                {
+                    egui::CollapsingHeader::new(i18n::t("Namespace"))
+                        .default_open(true)
+                        .show(ui, |ui| {
+                            egui::ScrollArea::vertical()
+                                .id_salt("aml_tree")
+                                .max_height(300.0)
+                                .show(ui, |ui| show_aml_tree(ui, tree, 0));
+                        });
+                    ui.separator();
+                }
+                if !self.acpi_address_refs.is_empty() && self.active_tab == Tab::Parsed {
+                    let mut follow = None;
+                    egui::CollapsingHeader::new(i18n::t("Pointers"))
+                        .default_open(true)
+                        .show(ui, |ui| {
+                            follow = show_acpi_address_refs(ui, &self.acpi_address_refs);
+                        });
+                    ui.separator();
+                    if let Some(info) = follow {
+                        self.select_acpi(info);
+                        return;
+                    }
+                }
+                let acpi_selected = match &self.selected_item {
+                    Selection::Acpi(_) => true,
+                    Selection::File(i) => self.loaded_files.get(*i).is_some_and(|f| {
+                        matches!(f.kind, parsers::FirmwareFileKind::AcpiTable)
+                    }),
+                    _ => false,
+                };
+                if acpi_selected && self.active_tab == Tab::Parsed {
+                    if let Some((reported, read)) = parsers::acpi_truncation(&self.cached_bytes) {
+                        ui.colored_label(
+                            Color32::from_rgb(200, 50, 50),
+                            i18n::tf(
+                                "⚠ Table reports {} bytes but only {} were read (truncated)",
+                                &[&reported, &read],
+                            ),
+                        );
+                        ui.separator();
+                    }
+                    if let Some(layout) = report::acpi_header_layout(&self.cached_bytes) {
+                        ui.checkbox(&mut self.show_header_layout, i18n::t("Header layout"))
+                            .on_hover_text(i18n::t(
+                                "Show each header field's offset, size, and raw bytes",
+                            ));
+                        if self.show_header_layout {
+                            show_header_layout(ui, &layout);
+                        }
+                        ui.separator();
+                    }
+                }
+                if self.active_tab == Tab::Hex {
+                    self.show_hex_rows(ui);
+                    return;
+                }
                 egui::ScrollArea::vertical().show(ui, |ui| {
-                    let text = match self.active_tab {
-                        Tab::Hex => &mut self.cached_hex,
-                        Tab::Parsed => &mut self.cached_parsed,
-                    };
+                    let text = &mut self.cached_parsed;
 
                     // Highlight search matches in the active text
                     let matches = self.search_matches.clone();
                     let query = self.search_query.clone();
                     let active_idx = self.search_current;
+                    let font_size = self.data_font_size;
                     let mut layouter =
                         move |ui: &egui::Ui, text: &str, wrap_width: f32| -> Arc<Galley> {
                             let mut job = egui::text::LayoutJob::default();
                             let default_fmt = TextFormat {
-                                font_id: FontId::monospace(14.0),
+                                font_id: FontId::monospace(font_size),
                                 color: ui.visuals().text_color(),
                                 ..Default::default()
                             };
@@ -1138,7 +6464,7 @@ impl eframe::App for DumpApp {
                     ui.add_sized(
                         ui.available_size(),
                         egui::TextEdit::multiline(text)
-                            .font(egui::TextStyle::Monospace)
+                            .font(FontId::monospace(font_size))
                             .lock_focus(true)
                             .layouter(&mut layouter),
                     );
@@ -1146,24 +6472,201 @@ impl eframe::App for DumpApp {
             });
         });
 
+        if restart_clicked {
+            match api::relaunch_as_admin() {
+                // Close through eframe so `save` and `on_exit` still run for this session
+                Ok(()) => ctx.send_viewport_cmd(egui::ViewportCommand::Close),
+                Err(e) => self.status = e.to_string(),
+            }
+        }
+        if reload_clicked {
+            self.reload(ctx);
+        }
+        if source_changed {
+            // A different source is not a reload, so don't mark its tables as changed
+            self.table_cache.lock().unwrap().clear();
+            self.load_acpi();
+        }
+        if export_all_clicked {
+            self.export_all_acpi(ctx);
+        }
+
         egui::TopBottomPanel::bottom("status_bar").show(ctx, |ui| {
             ui.horizontal(|ui| {
-                ui.label(format!("Status: {}", self.status));
+                ui.label(i18n::tf("Status: {}", &[&self.status]));
+                if let Some(job) = &self.export_job {
+                    ui.separator();
+                    let fraction = if job.total == 0 {
+                        0.0
+                    } else {
+                        job.index as f32 / job.total as f32
+                    };
+                    ui.add(
+                        egui::ProgressBar::new(fraction)
+                            .desired_width(220.0)
+                            .text(i18n::tf(
+                                "Exporting {}/{} {}",
+                                &[&job.index, &job.total, &job.signature],
+                            )),
+                    );
+                    let cancelling = job.cancel.load(Ordering::Relaxed);
+                    if ui
+                        .add_enabled(!cancelling, egui::Button::new(i18n::t("Cancel")))
+                        .on_hover_text(i18n::t(
+                            "Stop after the tables already being read; files written so far are kept",
+                        ))
+                        .clicked()
+                    {
+                        job.cancel.store(true, Ordering::Relaxed);
+                    }
+                }
+                if let Some((start, end)) =
+                    self.hex_selection.filter(|_| self.active_tab == Tab::Hex)
+                {
+                    let base_offset = self.hex_base_offset();
+                    ui.separator();
+                    ui.label(i18n::tf(
+                        "Selected {}..{}, {} bytes",
+                        &[
+                            &format!("0x{:X}", base_offset + start),
+                            &format!("0x{:X}", base_offset + end),
+                            &(end - start + 1),
+                        ],
+                    ));
+                }
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.label(self.status_segments());
+                });
             });
         });
     }
 
+    fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        for path in &self.hex_editor_temp_files {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+
     fn save(&mut self, storage: &mut dyn Storage) {
+        storage.set_string("ui.theme", self.theme.key().to_string());
+        storage.set_string("ui.sidebar_filter", self.sidebar_filter.clone());
+        storage.set_string("ui.search_query", self.search_query.clone());
         storage.set_string(
-            "ui.dark_mode",
-            if self.dark_mode {
+            "ui.acpi_tree_view",
+            if self.acpi_tree_view {
                 "1".to_string()
             } else {
                 "0".to_string()
             },
         );
-        storage.set_string("ui.sidebar_filter", self.sidebar_filter.clone());
-        storage.set_string("ui.search_query", self.search_query.clone());
+        storage.set_string("ui.acpi_sort", self.acpi_sort.key().to_string());
+        storage.set_string(
+            "ui.acpi_group",
+            if self.acpi_group_duplicates {
+                "1".to_string()
+            } else {
+                "0".to_string()
+            },
+        );
+        storage.set_string("ui.string_encoding", self.string_encoding.key().to_string());
+        storage.set_string("ui.hex_editor_path", self.hex_editor_path.clone());
+        storage.set_string("ui.export_name_template", self.export_name_template.clone());
+        storage.set_string("ui.export_dir", self.export_dir.clone());
+        storage.set_string("ui.language", self.language.key().to_string());
+        storage.set_string("ui.acpi_source", self.acpi_source.key().to_string());
+        storage.set_string(
+            "ui.smbios_strip_wrapper",
+            if self.smbios_strip_wrapper {
+                "1".to_string()
+            } else {
+                "0".to_string()
+            },
+        );
+        storage.set_string(
+            "ui.populated_only",
+            if self.populated_only {
+                "1".to_string()
+            } else {
+                "0".to_string()
+            },
+        );
+        storage.set_string(
+            "ui.load_on_startup",
+            if self.load_on_startup {
+                "1".to_string()
+            } else {
+                "0".to_string()
+            },
+        );
+        storage.set_string(
+            "ui.hide_end_of_table",
+            if self.hide_end_of_table {
+                "1".to_string()
+            } else {
+                "0".to_string()
+            },
+        );
+        storage.set_string(
+            "ui.hex_absolute_offsets",
+            if self.hex_absolute_offsets {
+                "1".to_string()
+            } else {
+                "0".to_string()
+            },
+        );
+        storage.set_string(
+            "ui.hex_byte_names",
+            if self.hex_byte_names {
+                "1".to_string()
+            } else {
+                "0".to_string()
+            },
+        );
+        storage.set_string(
+            "ui.hex_collapse_runs",
+            if self.hex_collapse_runs {
+                "1".to_string()
+            } else {
+                "0".to_string()
+            },
+        );
+        storage.set_string(
+            "ui.search_unique_lines",
+            if self.search_unique_lines {
+                "1".to_string()
+            } else {
+                "0".to_string()
+            },
+        );
+        storage.set_string(
+            "ui.search_panel_open",
+            if self.search_panel_open {
+                "1".to_string()
+            } else {
+                "0".to_string()
+            },
+        );
+        storage.set_string("ui.data_font_size", self.data_font_size.to_string());
+        storage.set_string(
+            "ui.byte_stats_open",
+            if self.byte_stats_open {
+                "1".to_string()
+            } else {
+                "0".to_string()
+            },
+        );
+        let recent: Vec<String> = self
+            .recent_files
+            .iter()
+            .map(|p| p.to_string_lossy().to_string())
+            .collect();
+        storage.set_string("ui.recent_files", recent.join("\n"));
+        let pins: Vec<String> = self.pins.iter().map(Pin::key).collect();
+        storage.set_string("ui.pins", pins.join("\n"));
+        // Notes span several lines, so they are stored as a JSON object
+        if let Ok(notes) = serde_json::to_string(&self.notes) {
+            storage.set_string("ui.notes", notes);
+        }
         storage.set_string(
             "ui.active_tab",
             match self.active_tab {
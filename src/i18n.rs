@@ -0,0 +1,782 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// A language the graphical interface can be shown in.
+///
+/// Only the interface chrome is translated; parsed field names and values stay in English
+/// because they are the terms used by the ACPI and SMBIOS specifications.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Language {
+    /// English, the language the strings are written in.
+    #[default]
+    English,
+    /// Traditional Chinese (Taiwan).
+    TraditionalChinese,
+}
+
+impl Language {
+    /// All languages, in the order they are offered in the UI.
+    pub const ALL: [Language; 2] = [Language::English, Language::TraditionalChinese];
+
+    /// Returns the name of the language in that language.
+    pub fn label(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::TraditionalChinese => "繁體中文",
+        }
+    }
+
+    /// Returns the stable key used to persist the language.
+    pub fn key(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::TraditionalChinese => "zh-TW",
+        }
+    }
+
+    /// Parses a key produced by [`Language::key`].
+    pub fn from_key(key: &str) -> Option<Language> {
+        Language::ALL.into_iter().find(|l| l.key() == key)
+    }
+}
+
+/// The language [`t`] translates into, stored as its index in [`Language::ALL`].
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+/// Sets the language used by [`t`] and [`tf`].
+pub fn set_language(language: Language) {
+    let index = Language::ALL
+        .iter()
+        .position(|&l| l == language)
+        .unwrap_or(0);
+    CURRENT.store(index as u8, Ordering::Relaxed);
+}
+
+/// Returns the language currently used by [`t`] and [`tf`].
+pub fn language() -> Language {
+    Language::ALL
+        .get(CURRENT.load(Ordering::Relaxed) as usize)
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Translations of the interface strings, keyed by the English text.
+///
+/// `{}` placeholders are filled in order by [`tf`] and must appear the same number of
+/// times in every translation.
+const TRANSLATIONS: &[(&str, &str)] = &[
+    // Status and status bar
+    ("Ready", "就緒"),
+    ("Status: {}", "狀態：{}"),
+    ("SMBIOS {}.{} | {} structures", "SMBIOS {}.{} | {} 個結構"),
+    ("SMBIOS: not loaded", "SMBIOS：未載入"),
+    ("ACPI: {} tables", "ACPI：{} 個表格"),
+    ("ACPI: not loaded", "ACPI：未載入"),
+    ("{} | {} | Admin: {}", "{} | {} | 系統管理員：{}"),
+    ("yes", "是"),
+    ("no", "否"),
+    ("Exporting {}/{} {}", "正在匯出 {}/{} {}"),
+    ("Selected {}..{}, {} bytes", "已選取 {}..{}，{} 個位元組"),
+    (
+        "Copied SMBIOS structures as XML",
+        "已將 SMBIOS 結構複製為 XML",
+    ),
+    ("Load SMBIOS data first", "請先載入 SMBIOS 資料"),
+    ("Load ACPI tables first", "請先載入 ACPI 表格"),
+    (
+        "Load ACPI tables or SMBIOS data first",
+        "請先載入 ACPI 表格或 SMBIOS 資料",
+    ),
+    ("Select an item first", "請先選取項目"),
+    ("Select an ACPI table first", "請先選取 ACPI 表格"),
+    ("Select an SMBIOS item", "請選取 SMBIOS 項目"),
+    ("Select an item to export", "請選取要匯出的項目"),
+    ("Select an item to copy", "請選取要複製的項目"),
+    (
+        "Select bytes in the hex view first",
+        "請先在十六進位檢視中選取位元組",
+    ),
+    ("Copied {} bytes as Base64", "已將 {} 個位元組複製為 Base64"),
+    ("No ACPI tables found", "找不到 ACPI 表格"),
+    (
+        "ACPI tables could not be read: access denied. Restart as Administrator.",
+        "無法讀取 ACPI 表格：存取遭拒。請以系統管理員身分重新啟動。",
+    ),
+    (
+        "No ACPI tables found: the firmware reports none",
+        "找不到 ACPI 表格：韌體未回報任何表格",
+    ),
+    ("ACPI enumeration failed: {}", "ACPI 列舉失敗：{}"),
+    ("Loaded {} ACPI tables", "已載入 {} 個 ACPI 表格"),
+    ("Loaded SMBIOS data via {}", "已透過 {} 載入 SMBIOS 資料"),
+    ("SMBIOS load failed: {}", "SMBIOS 載入失敗：{}"),
+    ("No ACPI tables changed", "沒有 ACPI 表格變更"),
+    ("Changed ACPI tables: {}", "已變更的 ACPI 表格：{}"),
+    ("{} SMBIOS structures changed", "{} 個 SMBIOS 結構已變更"),
+    ("Loaded ACPI {}", "已載入 ACPI {}"),
+    ("Error: {}", "錯誤：{}"),
+    ("ACPI load failed: {}", "ACPI 載入失敗：{}"),
+    ("Loaded SMBIOS type {}", "已載入 SMBIOS 類型 {}"),
+    ("SMBIOS parse failed", "SMBIOS 解析失敗"),
+    ("Loaded from file: {}", "已從檔案載入：{}"),
+    ("Loaded from Base64: {}", "已從 Base64 載入：{}"),
+    ("Failed to read {}: {}", "無法讀取 {}：{}"),
+    ("Failed to write {}: {}", "無法寫入 {}：{}"),
+    ("Checksum fixed", "已修正校驗和"),
+    ("Likely virtual: {} ({})", "可能為虛擬機器：{}（{}）"),
+    ("Likely virtual: {}", "可能為虛擬機器：{}"),
+    ("No virtual machine markers found", "未發現虛擬機器特徵"),
+    (
+        "Opened {} in external hex editor",
+        "已在外部十六進位編輯器中開啟 {}",
+    ),
+    (
+        "Hex editor reset to the .bin file handler",
+        "十六進位編輯器已重設為 .bin 檔案的預設程式",
+    ),
+    (
+        "Exported {} tables, {} failed",
+        "已匯出 {} 個表格，{} 個失敗",
+    ),
+    (
+        "Full report: {} files written, {} failed",
+        "完整報告：已寫入 {} 個檔案，{} 個失敗",
+    ),
+    (
+        "Export cancelled: {} tables exported, {} failed",
+        "匯出已取消：已匯出 {} 個表格，{} 個失敗",
+    ),
+    (
+        "Full report cancelled: {} files written, {} failed",
+        "完整報告已取消：已寫入 {} 個檔案，{} 個失敗",
+    ),
+    (
+        "Stop after the tables already being read; files written so far are kept",
+        "在目前正在讀取的表格完成後停止；已寫入的檔案會保留",
+    ),
+    ("No parsed fields to copy", "沒有可複製的解析欄位"),
+    // Dialogs
+    ("Export Error", "匯出錯誤"),
+    ("Failed to write file: {}", "無法寫入檔案：{}"),
+    ("Failed to create file: {}", "無法建立檔案：{}"),
+    ("Failed to read table data: {}", "無法讀取表格資料：{}"),
+    (
+        "Failed to parse SMBIOS structure.",
+        "無法解析 SMBIOS 結構。",
+    ),
+    (
+        "No parsed fields available for this SMBIOS structure.",
+        "此 SMBIOS 結構沒有可用的解析欄位。",
+    ),
+    ("Failed to serialize JSON: {}", "無法序列化 JSON：{}"),
+    ("Hex Editor Error", "十六進位編輯器錯誤"),
+    (
+        "{}\n\nChoose a hex editor from the table's context menu.",
+        "{}\n\n請從表格的右鍵選單選擇十六進位編輯器。",
+    ),
+    ("HTML Files", "HTML 檔案"),
+    ("Text Files", "文字檔案"),
+    ("Programs", "程式"),
+    (
+        "Select Folder to Export All ACPI Tables",
+        "選擇要匯出所有 ACPI 表格的資料夾",
+    ),
+    ("Successfully exported {} tables.", "已成功匯出 {} 個表格。"),
+    (
+        "Exported {} tables, {} failed.\n\nErrors:\n{}",
+        "已匯出 {} 個表格，{} 個失敗。\n\n錯誤：\n{}",
+    ),
+    ("Export Complete", "匯出完成"),
+    (
+        "Select Folder for Full System Report",
+        "選擇完整系統報告的資料夾",
+    ),
+    ("Wrote {} files to {}.", "已將 {} 個檔案寫入 {}。"),
+    (
+        "Wrote {} files to {}, {} failed.\n\nErrors:\n{}",
+        "已將 {} 個檔案寫入 {}，{} 個失敗。\n\n錯誤：\n{}",
+    ),
+    ("Report Complete", "報告完成"),
+    ("Report Cancelled", "報告已取消"),
+    (
+        "The report was cancelled; manifest.txt lists what was skipped.",
+        "報告已取消；manifest.txt 列出了略過的項目。",
+    ),
+    ("Save Full SMBIOS Data", "儲存完整 SMBIOS 資料"),
+    // File menu
+    ("File", "檔案"),
+    ("Open Dump…", "開啟傾印…"),
+    ("Recent", "最近使用"),
+    ("No recent files", "沒有最近使用的檔案"),
+    ("⚠ {} (missing)", "⚠ {}（遺失）"),
+    ("Clear Recent", "清除最近使用"),
+    ("Open Dump Files", "開啟傾印檔案"),
+    ("Firmware Dumps", "韌體傾印"),
+    ("All Files", "所有檔案"),
+    // Base64
+    ("Load from Base64", "從 Base64 載入"),
+    (
+        "Paste the Base64 text of an ACPI table or SMBIOS dump:",
+        "貼上 ACPI 表格或 SMBIOS 傾印的 Base64 文字：",
+    ),
+    (
+        "Paste the Base64 text of an ACPI table or SMBIOS dump first.",
+        "請先貼上 ACPI 表格或 SMBIOS 傾印的 Base64 文字。",
+    ),
+    ("Not valid Base64: {}", "不是有效的 Base64：{}"),
+    (
+        "The decoded {} bytes are neither an ACPI table nor an SMBIOS dump.",
+        "解碼後的 {} 個位元組既不是 ACPI 表格，也不是 SMBIOS 傾印。",
+    ),
+    ("Load", "載入"),
+    ("Cancel", "取消"),
+    // Hex editing
+    ("Invalid character '{}'", "無效的字元 '{}'"),
+    (
+        "Expected {} hex digits ({} bytes), found {}",
+        "預期 {} 個十六進位數字（{} 個位元組），實際為 {}",
+    ),
+    // Command palette
+    ("Command Palette", "命令選擇區"),
+    ("Type a command...", "輸入命令..."),
+    ("No matching commands", "沒有符合的命令"),
+    ("Load ACPI Tables", "載入 ACPI 表格"),
+    ("Load SMBIOS Data", "載入 SMBIOS 資料"),
+    ("Reload ACPI and SMBIOS", "重新載入 ACPI 與 SMBIOS"),
+    ("Export Raw Binary", "匯出原始二進位檔"),
+    ("Export Parsed", "匯出解析結果"),
+    ("Export Selected Bytes", "匯出選取的位元組"),
+    ("Open in Hex Editor", "以十六進位編輯器開啟"),
+    (
+        "Export Selected SMBIOS as JSON",
+        "將選取的 SMBIOS 匯出為 JSON",
+    ),
+    (
+        "Export Selected SMBIOS as CSV",
+        "將選取的 SMBIOS 匯出為 CSV",
+    ),
+    ("Export Full SMBIOS Blob", "匯出完整 SMBIOS 資料"),
+    ("Copy SMBIOS as XML", "將 SMBIOS 複製為 XML"),
+    ("Copy as Base64", "複製為 Base64"),
+    (
+        "Export All ACPI Tables to AML",
+        "將所有 ACPI 表格匯出為 AML",
+    ),
+    ("Export All Parsed (SMBIOS)", "匯出所有解析結果 (SMBIOS)"),
+    ("Export All Parsed (ACPI)", "匯出所有解析結果 (ACPI)"),
+    ("Export HTML Report", "匯出 HTML 報告"),
+    ("Full System Report", "完整系統報告"),
+    ("Toggle Theme", "切換主題"),
+    ("Detect Virtual Machine", "偵測虛擬機器"),
+    ("Toggle Search", "切換搜尋"),
+    ("Toggle Byte Stats", "切換位元組統計"),
+    ("Switch to Hex View", "切換到十六進位檢視"),
+    ("Switch to Parsed View", "切換到解析檢視"),
+    ("Filter Sidebar", "篩選側邊欄"),
+    // Sidebar
+    (
+        "Running without Administrator privileges. Some firmware reads may fail.",
+        "未以系統管理員權限執行，部分韌體讀取可能會失敗。",
+    ),
+    ("🛡 Restart as Administrator", "🛡 以系統管理員身分重新啟動"),
+    (
+        "⚠ {} placeholder values detected",
+        "⚠ 偵測到 {} 個預留位置值",
+    ),
+    ("Firmware Tables", "韌體表格"),
+    ("Language", "語言"),
+    ("System", "系統"),
+    ("Light", "淺色"),
+    ("Dark", "深色"),
+    ("Color theme; System follows the Windows app mode", "色彩主題；「系統」會依照 Windows 應用程式模式"),
+    ("Filter tables...", "篩選表格..."),
+    (
+        "Filter by label text, or narrow with tokens:\n\
+         type:17 or #17 - SMBIOS structures of type 17\n\
+         sig:SSDT - ACPI tables with signature SSDT\n\
+         Tokens and text can be combined, e.g. \"#17 DIMM\".",
+        "依標籤文字篩選，或以關鍵字縮小範圍：\n\
+         type:17 或 #17 - 類型 17 的 SMBIOS 結構\n\
+         sig:SSDT - 簽章為 SSDT 的 ACPI 表格\n\
+         關鍵字與文字可以組合使用，例如 \"#17 DIMM\"。",
+    ),
+    ("ACPI Tables", "ACPI 表格"),
+    ("💾 Export All to AML", "💾 全部匯出為 AML"),
+    ("🔄 Reload", "🔄 重新載入"),
+    (
+        "Re-read ACPI and SMBIOS and mark what changed",
+        "重新讀取 ACPI 與 SMBIOS 並標示變更之處",
+    ),
+    ("Tree view", "樹狀檢視"),
+    ("Source", "來源"),
+    ("Source: {}", "來源：{}"),
+    ("Registry", "登錄檔"),
+    ("Firmware API", "韌體 API"),
+    ("Registry + API", "登錄檔 + API"),
+    ("Where ACPI tables are discovered", "ACPI 表格的探索來源"),
+    (
+        "Show RSDP -> XSDT -> tables -> FADT -> DSDT/FACS",
+        "顯示 RSDP -> XSDT -> 表格 -> FADT -> DSDT/FACS",
+    ),
+    ("Sort", "排序"),
+    ("Signature", "簽章"),
+    ("Table ID", "表格 ID"),
+    ("Size", "大小"),
+    ("Group duplicates", "合併重複項目"),
+    (
+        "Collapse tables sharing a signature (e.g. SSDT)",
+        "摺疊簽章相同的表格（例如 SSDT）",
+    ),
+    ("Signature Legend", "簽章說明"),
+    ("{} ({} tables)", "{}（{} 個表格）"),
+    ("Licensing ({} tables)", "授權（{} 個表格）"),
+    (
+        "OEM activation tables (SLIC, MSDM)",
+        "OEM 啟用表格（SLIC、MSDM）",
+    ),
+    (
+        "OEM: {}\nTable ID: {}\nRevision: {}\nSize: {} bytes\nRegistry: {}",
+        "OEM：{}\n表格 ID：{}\n修訂：{}\n大小：{} 個位元組\n登錄檔：{}",
+    ),
+    (
+        "Signature bytes are not printable ASCII; they are shown as '.'",
+        "簽章位元組不是可列印的 ASCII，以 '.' 顯示",
+    ),
+    ("🔧 Open in Hex Editor", "🔧 以十六進位編輯器開啟"),
+    ("Choose Hex Editor...", "選擇十六進位編輯器..."),
+    ("Use Default .bin Handler", "使用 .bin 預設程式"),
+    ("SMBIOS Data", "SMBIOS 資料"),
+    ("Version {}.{} | DMI rev {}", "版本 {}.{} | DMI 修訂 {}"),
+    ("💾 Export Full Blob", "💾 匯出完整資料"),
+    ("📋 Copy as XML", "📋 複製為 XML"),
+    (
+        "Copy every parsed structure as XML",
+        "將所有解析的結構複製為 XML",
+    ),
+    ("Strings", "字串"),
+    (
+        "Character encoding for SMBIOS strings",
+        "SMBIOS 字串的字元編碼",
+    ),
+    ("Loaded from File", "從檔案載入"),
+    ("{} bytes", "{} 個位元組"),
+    ("Sensors ({})", "感測器（{}）"),
+    ("Sockets ({})", "插槽（{}）"),
+    ("Populated", "已安裝"),
+    ("Empty", "空"),
+    // Toolbar
+    ("Hex View", "十六進位檢視"),
+    ("Parsed View", "解析檢視"),
+    ("🔍 Search (Ctrl+F)", "🔍 搜尋 (Ctrl+F)"),
+    ("📊 Byte Stats", "📊 位元組統計"),
+    (
+        "Show the byte distribution of the selected item",
+        "顯示所選項目的位元組分佈",
+    ),
+    ("📋 Copy", "📋 複製"),
+    ("Copy current view to clipboard", "將目前檢視複製到剪貼簿"),
+    ("📝 Copy as Markdown", "📝 複製為 Markdown"),
+    (
+        "Copy the parsed fields as a Markdown table",
+        "將解析欄位複製為 Markdown 表格",
+    ),
+    ("📋 Copy Selected Bytes", "📋 複製選取的位元組"),
+    (
+        "Copy the bytes selected in the hex view as hex",
+        "以十六進位文字複製十六進位檢視中選取的位元組",
+    ),
+    ("💾 Export Selection", "💾 匯出選取範圍"),
+    (
+        "Save the bytes selected in the hex view to a .bin file",
+        "將十六進位檢視中選取的位元組儲存為 .bin 檔案",
+    ),
+    ("📋 Copy as Base64", "📋 複製為 Base64"),
+    (
+        "Copy the selected item's bytes as Base64 for pasting",
+        "將所選項目的位元組複製為 Base64 以便貼上",
+    ),
+    ("📥 Load from Base64…", "📥 從 Base64 載入…"),
+    (
+        "Decode a pasted ACPI table or SMBIOS dump",
+        "解碼貼上的 ACPI 表格或 SMBIOS 傾印",
+    ),
+    ("📦 Export Raw Binary", "📦 匯出原始二進位檔"),
+    ("📥 Export Parsed", "📥 匯出解析結果"),
+    (
+        "📥 Export All Parsed (SMBIOS)",
+        "📥 匯出所有解析結果 (SMBIOS)",
+    ),
+    (
+        "Write every SMBIOS structure's parsed view to one file",
+        "將每個 SMBIOS 結構的解析結果寫入單一檔案",
+    ),
+    ("📥 Export All Parsed (ACPI)", "📥 匯出所有解析結果 (ACPI)"),
+    (
+        "Write every ACPI table's parsed view to one file",
+        "將每個 ACPI 表格的解析結果寫入單一檔案",
+    ),
+    ("🌐 Export HTML Report", "🌐 匯出 HTML 報告"),
+    (
+        "Write the summary and every parsed table to one HTML page",
+        "將摘要與所有解析的表格寫入單一 HTML 頁面",
+    ),
+    ("📑 Full System Report", "📑 完整系統報告"),
+    (
+        "Write all tables, the SMBIOS blob, and summaries to a folder",
+        "將所有表格、SMBIOS 資料與摘要寫入資料夾",
+    ),
+    ("✏ Edit Mode", "✏ 編輯模式"),
+    (
+        "Edit the hex digits; Export Raw Binary saves the edits",
+        "編輯十六進位數字；「匯出原始二進位檔」會儲存編輯內容",
+    ),
+    ("Modified", "已修改"),
+    ("Checksum: OK", "校驗和：正確"),
+    ("Checksum: invalid (sum {})", "校驗和：無效（總和 {}）"),
+    ("Fix Checksum", "修正校驗和"),
+    // Search
+    ("Find:", "尋找："),
+    ("Enter text...", "輸入文字..."),
+    ("{} matches{}", "{} 個符合項目{}"),
+    ("Prev", "上一個"),
+    ("Next", "下一個"),
+    ("📋 Copy matching lines", "📋 複製符合的行"),
+    (
+        "Copy every line that contains a match to the clipboard",
+        "將每一個包含符合項目的行複製到剪貼簿",
+    ),
+    ("Copied {} matching lines", "已複製 {} 個符合的行"),
+    ("Unique lines", "不重複的行"),
+    ("Copy identical lines only once", "相同的行只複製一次"),
+    ("Close", "關閉"),
+    // Data views
+    (
+        "Select an item to see its byte statistics.",
+        "選取項目以查看其位元組統計。",
+    ),
+    ("Length: {} bytes", "長度：{} 個位元組"),
+    ("{}-{}: {} bytes", "{}-{}：{} 個位元組"),
+    ("Zero: {} ({}%)", "零：{}（{}%）"),
+    ("Printable ASCII: {} ({}%)", "可列印 ASCII：{}（{}%）"),
+    ("Most common: {} x{}", "最常見：{} x{}"),
+    ("Locality Distances", "區域距離"),
+    ("Header layout", "標頭配置"),
+    ("Only show populated", "僅顯示已安裝"),
+    (
+        "Hide empty memory slots and unused expansion slots",
+        "隱藏空的記憶體插槽與未使用的擴充插槽",
+    ),
+    ("Strip header", "去除標頭"),
+    ("Blob offsets", "整體位移"),
+    (
+        "Number offsets from the start of the SMBIOS blob instead of the structure",
+        "位移從 SMBIOS 資料起點計算，而非從結構起點",
+    ),
+    (
+        "Export the bare structure table that dmidecode and other SMBIOS tools expect, without the Windows 8-byte RawSMBIOSData header",
+        "匯出 dmidecode 等 SMBIOS 工具所需的純結構表，不含 Windows 8 位元組 RawSMBIOSData 標頭",
+    ),
+    ("SMBIOS structure table (dmidecode)", "SMBIOS 結構表 (dmidecode)"),
+    (
+        "Windows RawSMBIOSData (8-byte header + table)",
+        "Windows RawSMBIOSData (8 位元組標頭 + 結構表)",
+    ),
+    (
+        "Show each header field's offset, size, and raw bytes",
+        "顯示每個標頭欄位的位移、大小與原始位元組",
+    ),
+    ("Field", "欄位"),
+    ("Offset", "位移"),
+    ("Raw Bytes", "原始位元組"),
+    ("Value", "值"),
+    ("{} B", "{} B"),
+    ("Go to this structure", "前往此結構"),
+    ("📋 Copy Handle", "📋 複製控制代碼"),
+    ("Copy this structure's handle", "複製此結構的控制代碼"),
+    ("Namespace", "命名空間"),
+    ("Offset {}", "位移 {}"),
+    ("📋 Copy Path", "📋 複製路徑"),
+    ("Interpret as:", "解讀為："),
+    ("auto", "自動"),
+    (
+        "Decode these bytes with another type's parser",
+        "以其他類型的解析器解碼這些位元組",
+    ),
+    (
+        "⚠ Forced interpretation: fields may not mean what their names say",
+        "⚠ 強制解讀：欄位內容可能與名稱不符",
+    ),
+    ("Tables by Size", "依大小排列的表格"),
+    ("📏 Tables by Size", "📏 依大小排列的表格"),
+    (
+        "List every ACPI table by size with the total firmware footprint",
+        "依大小列出所有 ACPI 表格及韌體總佔用空間",
+    ),
+    (
+        "Load the ACPI tables or SMBIOS data first.",
+        "請先載入 ACPI 表格或 SMBIOS 資料。",
+    ),
+    ("Table", "表格"),
+    ("Table ⏶", "表格 ⏶"),
+    ("Size ⏷", "大小 ⏷"),
+    ("ACPI ({} tables)", "ACPI（{} 個表格）"),
+    ("SMBIOS", "SMBIOS"),
+    ("Not loaded", "未載入"),
+    ("Total", "總計"),
+    ("Largest", "最大"),
+    ("{}, {} bytes", "{}，{} 個位元組"),
+    ("🔒 Export (redacted)", "🔒 匯出（遮蔽）"),
+    (
+        "Serial numbers, UUIDs, asset tags, and the product key read REDACTED",
+        "序號、UUID、資產標籤與產品金鑰會顯示為 REDACTED",
+    ),
+    ("Selected SMBIOS as JSON", "選取的 SMBIOS（JSON）"),
+    ("All Parsed (SMBIOS, dmidecode)", "全部解析結果（SMBIOS，dmidecode）"),
+    ("All Parsed (ACPI)", "全部解析結果（ACPI）"),
+    ("HTML Report", "HTML 報告"),
+    (
+        "Leaves out the raw SMBIOS blob and MSDM table, which hold these values",
+        "不含保存這些值的原始 SMBIOS 資料與 MSDM 表格",
+    ),
+    ("Export HTML Report (Redacted)", "匯出 HTML 報告（遮蔽）"),
+    ("Full System Report (Redacted)", "完整系統報告（遮蔽）"),
+    ("Data font size: {} pt", "資料字型大小：{} pt"),
+    ("★ Pinned ({})", "★ 已釘選 ({})"),
+    ("Pin to the top of the sidebar", "釘選到側邊欄頂端"),
+    ("Unpin", "取消釘選"),
+    ("Type {}, Handle 0x{}", "類型 {}，Handle 0x{}"),
+    ("{} (not present)", "{} (不存在)"),
+    ("{} (not loaded)", "{} (未載入)"),
+    (
+        "The pinned entry is not in the currently loaded tables",
+        "目前載入的表格中沒有此釘選項目",
+    ),
+    ("Load the tables to resolve this pin", "載入表格以解析此釘選項目"),
+    ("Hide End-of-Table marker", "隱藏表格結尾標記"),
+    (
+        "Hide the End-of-Table (Type 127) and Inactive (Type 126) structures from this list; filter with #127 to show them",
+        "在此清單中隱藏表格結尾 (Type 127) 與非作用中 (Type 126) 結構；以 #127 篩選可顯示它們",
+    ),
+    ("Loading firmware tables…", "正在載入韌體表格…"),
+    ("Load tables on startup", "啟動時載入表格"),
+    (
+        "Read ACPI and SMBIOS data in the background when the window opens (administrator only)",
+        "在視窗開啟時於背景讀取 ACPI 與 SMBIOS 資料 (僅限系統管理員)",
+    ),
+    ("NUMA ({} nodes)", "NUMA ({} 個節點)"),
+    ("No SLIT: node distances are unknown", "沒有 SLIT：節點距離未知"),
+    ("Checksum: not checked (table truncated)", "總和檢查碼：未檢查（表格已截斷）"),
+    (
+        "⚠ Table reports {} bytes but only {} were read (truncated)",
+        "⚠ 表格標示為 {} 位元組，但只讀取到 {} 位元組（已截斷）",
+    ),
+    ("💾 Export Raw", "💾 匯出原始資料"),
+    ("💾 Export Parsed", "💾 匯出解析文字"),
+    ("📋 Copy Parsed", "📋 複製解析文字"),
+    ("📋 Copy Signature", "📋 複製簽章"),
+    ("📋 Copy Physical Address", "📋 複製實體位址"),
+    ("Copied parsed text", "已複製解析文字"),
+    ("Byte names", "位元組名稱"),
+    (
+        "Hover the ASCII column to see each byte's value and control character name",
+        "將游標停在 ASCII 欄上，可查看每個位元組的值與控制字元名稱",
+    ),
+    ("SMBIOS {} (table)", "SMBIOS {}（表格）"),
+    ("SMBIOS {} (override)", "SMBIOS {}（覆寫）"),
+    ("Table version", "表格版本"),
+    ("Parse as", "解析規則"),
+    (
+        "Decode the UUID byte order, BIOS ROM Size, and memory device Size by another SMBIOS version's rules",
+        "依其他 SMBIOS 版本的規則解碼 UUID 位元組順序、BIOS ROM 大小與記憶體裝置大小",
+    ),
+    (
+        "⚠ Parsed with SMBIOS {} rules, not the table's own version",
+        "⚠ 以 SMBIOS {} 規則解析，而非表格本身的版本",
+    ),
+    ("Export ACPI Manifest", "匯出 ACPI 清單"),
+    ("📄 Export ACPI Manifest", "📄 匯出 ACPI 清單"),
+    (
+        "Save each table's signature, OEM and table IDs, revision, length, address, and source as CSV",
+        "將每個表格的簽章、OEM 與表格 ID、修訂版、長度、位址與來源儲存為 CSV",
+    ),
+    ("🔀 SMBIOS Diff", "🔀 SMBIOS 差異"),
+    (
+        "Compare two SMBIOS dumps, or this system and a dump, field by field",
+        "逐欄位比較兩份 SMBIOS 傾印，或本系統與一份傾印",
+    ),
+    ("SMBIOS Diff", "SMBIOS 差異"),
+    ("This system", "本系統"),
+    (
+        "Load this system's SMBIOS data or open SMBIOS dumps so there are two to compare.",
+        "請載入本系統的 SMBIOS 資料或開啟 SMBIOS 傾印，以取得兩份可比較的資料。",
+    ),
+    ("Compare", "比較"),
+    ("A: {}, B: {}", "A：{}，B：{}"),
+    ("No structure differs.", "沒有結構不同。"),
+    ("SMBIOS diff: {}", "SMBIOS 差異：{}"),
+    ("Copied SMBIOS diff", "已複製 SMBIOS 差異"),
+    ("- {} only in A", "- {} 僅存在於 A"),
+    ("+ {} only in B", "+ {} 僅存在於 B"),
+    ("(absent)", "（不存在）"),
+    (
+        "SMBIOS data could not be read: access denied. Restart as Administrator.",
+        "無法讀取 SMBIOS 資料：存取遭拒。請以系統管理員身分重新啟動。",
+    ),
+    ("SMBIOS: access denied", "SMBIOS：存取遭拒"),
+    ("ACPI: access denied", "ACPI：存取遭拒"),
+    (
+        "Access denied: reading firmware tables requires Administrator privileges.",
+        "存取遭拒：讀取韌體表格需要系統管理員權限。",
+    ),
+    (
+        "Access denied even though the tool is running as Administrator.",
+        "即使以系統管理員身分執行，存取仍遭拒。",
+    ),
+    ("Load failed: {}", "載入失敗：{}"),
+    ("Collapse runs", "摺疊重複位元組"),
+    (
+        "Show lines of one repeated byte, such as zero padding, as a single line ({} collapsible here)",
+        "將僅含同一位元組的列（例如補零）顯示為單一列（此處可摺疊 {} 處）",
+    ),
+    ("Copy this value", "複製此值"),
+    ("Copied {}", "已複製 {}"),
+    ("Pointers", "指標"),
+    ("Go to this table", "前往此表格"),
+    (
+        "Not in the table list; read from the firmware by its signature",
+        "不在表格清單中；依簽章從韌體讀取",
+    ),
+    ("not directly retrievable", "無法直接取得"),
+    (
+        "The firmware API reads tables by signature, not by address",
+        "韌體 API 依簽章讀取表格，而非依位址",
+    ),
+    ("Export Naming", "匯出命名"),
+    ("Filename template", "檔名範本"),
+    (
+        "Used by Export Raw, Export Parsed, and Export All; leave empty for the built-in names",
+        "用於匯出原始資料、匯出解析結果及全部匯出；留空則使用內建名稱",
+    ),
+    ("Output folder", "輸出資料夾"),
+    ("Not set", "未設定"),
+    ("Choose…", "選擇…"),
+    ("Clear", "清除"),
+    ("Select Default Export Folder", "選擇預設匯出資料夾"),
+    ("Table Advisory", "表格建議"),
+    ("ℹ Table Advisory", "ℹ 表格建議"),
+    (
+        "Compare the ACPI tables found with the ones most PCs provide",
+        "將找到的 ACPI 表格與大多數電腦提供的表格比較",
+    ),
+    ("Load the ACPI tables first.", "請先載入 ACPI 表格。"),
+    ("Expected tables", "預期的表格"),
+    ("All commonly expected tables are present.", "所有常見的預期表格皆存在。"),
+    ("⚠ {} missing: {}", "⚠ 缺少 {}：{}"),
+    ("Unusual tables", "不尋常的表格"),
+    ("None", "無"),
+    (
+        "Not a standard ACPI signature; usually an OEM-specific table",
+        "不是標準的 ACPI 簽章；通常是 OEM 專用表格",
+    ),
+    ("root list of every other table", "列出所有其他表格的根表格"),
+    ("fixed hardware registers and power management", "固定硬體暫存器與電源管理"),
+    ("AML definition of the platform's devices", "平台裝置的 AML 定義"),
+    ("waking vector and Global Lock", "喚醒向量與全域鎖定"),
+    ("interrupt controllers and processors", "中斷控制器與處理器"),
+    ("high precision event timer", "高精度事件計時器"),
+    ("PCI Express configuration space (ECAM) windows", "PCI Express 組態空間 (ECAM) 視窗"),
+    (
+        "SMBIOS version that introduced this structure type",
+        "引入此結構類型的 SMBIOS 版本",
+    ),
+    ("Export Hex Dump", "匯出十六進位傾印"),
+    ("💾 Export Hex Dump", "💾 匯出十六進位傾印"),
+    ("📥 Export Hex Dump", "📥 匯出十六進位傾印"),
+    (
+        "Save the hex view as text, with its offsets and collapsed runs",
+        "將十六進位檢視存成文字，包含位移與摺疊的重複區段",
+    ),
+    ("{} ({} unique, {} duplicate)", "{}（{} 個不重複，{} 個重複）"),
+    ("Identical copies ({})", "相同副本（{}）"),
+    ("Tables whose bytes match an entry above", "內容與上方項目完全相同的表格"),
+    ("OEM {} | Table ID {} | {} bytes", "OEM {} | 表格 ID {} | {} 個位元組"),
+    ("Handle 0x{} | {} bytes", "Handle 0x{} | {} 個位元組"),
+    ("Export Trimmed Copy", "匯出截短副本"),
+    ("Keep the first", "保留前"),
+    (" bytes", " 個位元組"),
+    ("Up to the end of the hex selection", "截至十六進位選取範圍結尾"),
+    ("Rewrite the length field and checksum", "改寫長度欄位與校驗和"),
+    (
+        "Set the header length to the new size and recompute the checksum so the copy still parses",
+        "將標頭長度設為新大小並重新計算校驗和，使副本仍可解析",
+    ),
+    ("Only ACPI tables have a length field to rewrite", "只有 ACPI 表格有可改寫的長度欄位"),
+    ("Unsaved hex edits are included", "包含尚未儲存的十六進位編輯"),
+    ("Export…", "匯出…"),
+    ("Type", "類型"),
+    ("Handle", "Handle"),
+    ("All SMBIOS Fields", "所有 SMBIOS 欄位"),
+    ("▦ All SMBIOS Fields", "▦ 所有 SMBIOS 欄位"),
+    (
+        "Search and sort every parsed SMBIOS field in one table",
+        "在單一表格中搜尋並排序所有已解析的 SMBIOS 欄位",
+    ),
+    ("Load the SMBIOS data first.", "請先載入 SMBIOS 資料。"),
+    ("Filter:", "篩選："),
+    ("Type, handle, field, or value", "類型、Handle、欄位或值"),
+    ("{} of {} fields", "{} / {} 個欄位"),
+    ("Export CSV", "匯出 CSV"),
+    ("Save the rows shown, in this order", "依目前順序儲存顯示的列"),
+    ("⚠ 1 warning", "⚠ 1 個警告"),
+    ("⚠ {} warnings", "⚠ {} 個警告"),
+    ("📝 Notes", "📝 備註"),
+    (
+        "Notes are saved with the settings and included in JSON and HTML exports",
+        "備註會隨設定一併儲存，並包含在 JSON 與 HTML 匯出中",
+    ),
+    ("Reveal product key", "顯示產品金鑰"),
+    (
+        "Reports and CLI output always mask the key",
+        "報告與命令列輸出一律會遮蔽金鑰",
+    ),
+    (
+        "Unrecognized file: not an ACPI table or SMBIOS dump.",
+        "無法辨識的檔案：不是 ACPI 表格或 SMBIOS 傾印。",
+    ),
+];
+
+/// Translates an interface string into the current language.
+///
+/// # Arguments
+/// * `key` - The English text of the string.
+///
+/// # Returns
+/// The translation, or `key` itself in English or when no translation exists.
+pub fn t(key: &str) -> &str {
+    if language() == Language::English {
+        return key;
+    }
+    TRANSLATIONS
+        .iter()
+        .find(|(english, _)| *english == key)
+        .map_or(key, |(_, translated)| translated)
+}
+
+/// Translates a string template and fills its `{}` placeholders in order.
+///
+/// # Arguments
+/// * `key` - The English template, e.g. `"Loaded {} ACPI tables"`.
+/// * `args` - One value per placeholder.
+pub fn tf(key: &str, args: &[&dyn std::fmt::Display]) -> String {
+    let mut out = String::new();
+    let mut args = args.iter();
+    let mut rest = t(key);
+    while let Some(pos) = rest.find("{}") {
+        out.push_str(&rest[..pos]);
+        if let Some(arg) = args.next() {
+            out.push_str(&arg.to_string());
+        }
+        rest = &rest[pos + 2..];
+    }
+    out.push_str(rest);
+    out
+}
@@ -0,0 +1,112 @@
+use log::{LevelFilter, Log, Metadata, Record};
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Name of the log file the GUI writes next to the executable.
+pub const GUI_LOG_FILE: &str = "acpi-smbios-dumper.log";
+
+/// Level of the GUI log file.
+const GUI_LOG_LEVEL: LevelFilter = LevelFilter::Info;
+
+/// Writes log records to stderr and/or a file, stamped with the time since startup.
+struct Logger {
+    /// Most verbose level that is written.
+    level: LevelFilter,
+    /// Whether records are echoed to stderr.
+    stderr: bool,
+    /// The log file, if one was opened.
+    file: Option<Mutex<File>>,
+    /// When the logger was installed.
+    start: Instant,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let line = format!(
+            "[{:>9.3}s {:<5} {}] {}\n",
+            self.start.elapsed().as_secs_f64(),
+            record.level(),
+            record.target(),
+            record.args()
+        );
+        if self.stderr {
+            eprint!("{}", line);
+        }
+        if let Some(file) = &self.file {
+            let _ = file.lock().unwrap().write_all(line.as_bytes());
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(file) = &self.file {
+            let _ = file.lock().unwrap().flush();
+        }
+    }
+}
+
+/// Parses a `--log-level` value: off, error, warn, info, debug, or trace.
+pub fn parse_level(value: &str) -> Option<LevelFilter> {
+    value.parse().ok()
+}
+
+/// Installs the process-wide logger.
+///
+/// Only the first call takes effect; later calls leave the installed logger in place.
+///
+/// # Arguments
+/// * `level` - Most verbose level that is written.
+/// * `stderr` - Whether records are echoed to stderr.
+/// * `file` - File to write records to; it is truncated first.
+///
+/// # Returns
+/// An error if the log file could not be created.
+pub fn init(level: LevelFilter, stderr: bool, file: Option<&Path>) -> std::io::Result<()> {
+    let file = match file {
+        Some(path) => {
+            let mut file = File::create(path)?;
+            writeln!(
+                file,
+                "{} {} log",
+                env!("CARGO_PKG_NAME"),
+                env!("CARGO_PKG_VERSION")
+            )?;
+            Some(Mutex::new(file))
+        }
+        None => None,
+    };
+    let logger = Box::leak(Box::new(Logger {
+        level,
+        stderr,
+        file,
+        start: Instant::now(),
+    }));
+    if log::set_logger(logger).is_ok() {
+        log::set_max_level(level);
+    }
+    Ok(())
+}
+
+/// Returns the path of the GUI log file, next to the executable.
+pub fn gui_log_path() -> Option<PathBuf> {
+    let exe = std::env::current_exe().ok()?;
+    Some(exe.parent()?.join(GUI_LOG_FILE))
+}
+
+/// Starts logging for the GUI to [`gui_log_path`].
+///
+/// The GUI keeps running without a log when the executable's folder is not writable.
+pub fn init_gui() {
+    if let Some(path) = gui_log_path() {
+        let _ = init(GUI_LOG_LEVEL, false, Some(&path));
+    }
+}
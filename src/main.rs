@@ -2,26 +2,56 @@
 
 /// The `api` module handles low-level firmware table retrieval from the OS.
 mod api;
+/// The `cli` module implements the headless command-line interface.
+mod cli;
+/// The `error` module defines the typed errors returned by `api` and `parsers`.
+mod error;
 /// The `gui` module manages the application's graphical user interface.
 mod gui;
+/// The `i18n` module translates the graphical interface's strings.
+mod i18n;
+/// The `logging` module writes diagnostic logs to stderr and log files.
+mod logging;
 /// The `parsers` module containing logic to interpret raw bytes for ACPI and SMBIOS.
 mod parsers;
+/// The `report` module renders parsed data as text/JSON shared by the GUI and CLI.
+mod report;
 
+use windows::Win32::System::Console::{AttachConsole, ATTACH_PARENT_PROCESS};
 use windows::Win32::UI::Shell::IsUserAnAdmin;
 
 /// The entry point of the application.
 ///
 /// It performs a preliminary check for administrator privileges, which are required
-/// to access firmware tables on Windows, and then launches the GUI.
+/// to access firmware tables on Windows, and then runs the CLI when arguments are
+/// given or launches the GUI otherwise.
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() {
+        // Release builds use the GUI subsystem, so reattach to the caller's console for output
+        unsafe {
+            let _ = AttachConsole(ATTACH_PARENT_PROCESS);
+        }
+    }
+
     // Check if the application is running with Administrator privileges
     let is_admin = unsafe { IsUserAnAdmin().as_bool() };
     if !is_admin {
         eprintln!("WARNING: Not running as Administrator. Firmware APIs will likely fail.");
+        eprintln!(
+            "HINT: Re-run from an elevated prompt, or use \"Restart as Administrator\" in the GUI."
+        );
+    }
+
+    if !args.is_empty() {
+        std::process::exit(cli::run(&args));
     }
 
     // Launch the Graphical User Interface
+    logging::init_gui();
+    log::info!("Starting the GUI (administrator: {})", is_admin);
     if let Err(e) = gui::run() {
+        log::error!("GUI error: {}", e);
         eprintln!("GUI Error: {}", e);
     }
 }
@@ -1,4 +1,6 @@
+use crate::error::DumperError;
 use byteorder::{ByteOrder, LittleEndian};
+use std::ops::Range;
 
 /// Standard ACPI table header structure (36 bytes).
 ///
@@ -32,10 +34,14 @@ pub struct AcpiTableHeader {
 /// * `data` - The raw byte slice containing the ACPI table.
 ///
 /// # Returns
-/// A `Result` containing the parsed `AcpiTableHeader` or an error string if the data is too short.
-pub fn parse_acpi_header(data: &[u8]) -> Result<AcpiTableHeader, String> {
+/// A `Result` containing the parsed `AcpiTableHeader`, or [`DumperError::TooShort`] if the
+/// data is shorter than the header.
+pub fn parse_acpi_header(data: &[u8]) -> Result<AcpiTableHeader, DumperError> {
     if data.len() < 36 {
-        return Err("Data too short for ACPI header".into());
+        return Err(DumperError::TooShort {
+            need: 36,
+            got: data.len(),
+        });
     }
 
     let signature = clean_str(&data[0..4]);
@@ -61,6 +67,167 @@ pub fn parse_acpi_header(data: &[u8]) -> Result<AcpiTableHeader, String> {
     })
 }
 
+/// Formats an ACPI header OEM revision, adding the date when it looks like BCD `0xYYYYMMDD`.
+///
+/// # Arguments
+/// * `value` - The raw OEM revision.
+///
+/// # Returns
+/// The hex value, e.g. "0x20231115 (2023-11-15)" or just "0x00000001".
+pub fn oem_revision_str(value: u32) -> String {
+    let raw = format!("0x{:08X}", value);
+    let is_bcd = (0..8).all(|i| (value >> (i * 4)) & 0xF <= 9);
+    let year = value >> 16;
+    let month = (value >> 8) & 0xFF;
+    let day = value & 0xFF;
+    if is_bcd
+        && (0x2000..=0x2099).contains(&year)
+        && (0x01..=0x12).contains(&month)
+        && (0x01..=0x31).contains(&day)
+    {
+        format!("{} ({:04X}-{:02X}-{:02X})", raw, year, month, day)
+    } else {
+        raw
+    }
+}
+
+/// Sums every byte of an ACPI table modulo 256; a table with a valid checksum sums to zero.
+pub fn acpi_checksum_sum(data: &[u8]) -> u8 {
+    data.iter().fold(0u8, |sum, &b| sum.wrapping_add(b))
+}
+
+/// Checks whether an ACPI table's header claims more bytes than were read.
+///
+/// The checksum covers the header's length, so it cannot be checked or fixed over a
+/// truncated read.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the table.
+///
+/// # Returns
+/// `Some((reported, read))` when the length field exceeds `data.len()`. `None` for a
+/// complete table, data shorter than a header, and the RSDP, whose bytes 4..8 are part of
+/// its signature.
+pub fn acpi_truncation(data: &[u8]) -> Option<(u32, usize)> {
+    if data.len() < 36 || data.starts_with(b"RSD PTR ") {
+        return None;
+    }
+    let reported = LittleEndian::read_u32(&data[4..8]);
+    (reported as usize > data.len()).then_some((reported, data.len()))
+}
+
+/// Rewrites the checksum byte (offset 9) so the table sums to zero.
+///
+/// # Returns
+/// `false` if the data is too short to hold an ACPI header or the table is truncated.
+pub fn fix_acpi_checksum(data: &mut [u8]) -> bool {
+    if data.len() < 36 || acpi_truncation(data).is_some() {
+        return false;
+    }
+    data[9] = 0;
+    data[9] = 0u8.wrapping_sub(acpi_checksum_sum(data));
+    true
+}
+
+/// Cuts a table down to its first `length` bytes, e.g. for a minimal sample in a bug report.
+///
+/// With `rewrite_header` the copy keeps a consistent ACPI header: the length field is set
+/// to the new size and the checksum recomputed, so it still parses and validates. The RSDP,
+/// the FACS, and copies shorter than a header are cut without rewriting.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the table.
+/// * `length` - How many bytes to keep; a length past the end keeps everything.
+/// * `rewrite_header` - Rewrite the length field and checksum to match the cut.
+pub fn trim_acpi_table(data: &[u8], length: usize, rewrite_header: bool) -> Vec<u8> {
+    let mut out = data[..length.min(data.len())].to_vec();
+    if rewrite_header
+        && out.len() >= 36
+        && !out.starts_with(b"RSD PTR ")
+        && !out.starts_with(b"FACS")
+    {
+        let length = out.len() as u32;
+        LittleEndian::write_u32(&mut out[4..8], length);
+        fix_acpi_checksum(&mut out);
+    }
+    out
+}
+
+/// Returns a one-line description for a well-known ACPI table signature.
+///
+/// # Arguments
+/// * `sig` - The 4-character table signature (e.g., "FACP").
+///
+/// # Returns
+/// `Some` description for recognized signatures, `None` for unknown or OEM-specific ones.
+pub fn acpi_signature_description(sig: &str) -> Option<&'static str> {
+    let desc = match sig {
+        "APIC" => "Multiple APIC Description Table (MADT)",
+        "ASF!" => "Alert Standard Format Table",
+        "BERT" => "Boot Error Record Table",
+        "BGRT" => "Boot Graphics Resource Table",
+        "BOOT" => "Simple Boot Flag Table",
+        "CEDT" => "CXL Early Discovery Table",
+        "CPEP" => "Corrected Platform Error Polling Table",
+        "CSRT" => "Core System Resource Table",
+        "DBG2" => "Debug Port Table 2",
+        "DBGP" => "Debug Port Table",
+        "DMAR" => "DMA Remapping Table (Intel VT-d)",
+        "DRTM" => "Dynamic Root of Trust for Measurement Table",
+        "DSDT" => "Differentiated System Description Table",
+        "ECDT" => "Embedded Controller Boot Resources Table",
+        "EINJ" => "Error Injection Table",
+        "ERST" => "Error Record Serialization Table",
+        "FACP" | "FADT" => "Fixed ACPI Description Table",
+        "FACS" => "Firmware ACPI Control Structure",
+        "FPDT" => "Firmware Performance Data Table",
+        "GTDT" => "Generic Timer Description Table",
+        "HEST" => "Hardware Error Source Table",
+        "HMAT" => "Heterogeneous Memory Attribute Table",
+        "HPET" => "High Precision Event Timer Table",
+        "IBFT" => "iSCSI Boot Firmware Table",
+        "IORT" => "I/O Remapping Table",
+        "IVRS" => "I/O Virtualization Reporting Structure (AMD-Vi)",
+        "LPIT" => "Low Power Idle Table",
+        "MCFG" => "PCI Express Memory-mapped Configuration Table",
+        "MCHI" => "Management Controller Host Interface Table",
+        "MPST" => "Memory Power State Table",
+        "MSCT" => "Maximum System Characteristics Table",
+        "MSDM" => "Microsoft Data Management Table",
+        "NFIT" => "NVDIMM Firmware Interface Table",
+        "NHLT" => "Non-HD Audio Link Table",
+        "PCCT" => "Platform Communications Channel Table",
+        "PHAT" => "Platform Health Assessment Table",
+        "PMTT" => "Platform Memory Topology Table",
+        "PPTT" => "Processor Properties Topology Table",
+        "RASF" => "ACPI RAS Feature Table",
+        "RSDP" => "Root System Description Pointer",
+        "RSDT" => "Root System Description Table",
+        "SBST" => "Smart Battery Specification Table",
+        "SDEI" => "Software Delegated Exception Interface Table",
+        "SDEV" => "Secure Devices Table",
+        "SLIC" => "Software Licensing Description Table",
+        "SLIT" => "System Locality Distance Information Table",
+        "SPCR" => "Serial Port Console Redirection Table",
+        "SPMI" => "Server Platform Management Interface Table",
+        "SRAT" => "System Resource Affinity Table",
+        "SSDT" => "Secondary System Description Table",
+        "STAO" => "Status Override Table",
+        "TCPA" => "Trusted Computing Platform Alliance Capabilities Table",
+        "TPM2" => "Trusted Platform Module 2 Table",
+        "UEFI" => "UEFI ACPI Data Table",
+        "WAET" => "Windows ACPI Emulated Devices Table",
+        "WDAT" => "Watchdog Action Table",
+        "WDRT" => "Watchdog Resource Table",
+        "WPBT" => "Windows Platform Binary Table",
+        "WSMT" => "Windows SMM Security Mitigations Table",
+        "XENV" => "Xen Project Table",
+        "XSDT" => "Extended System Description Table",
+        _ => return None,
+    };
+    Some(desc)
+}
+
 /// Extracts DSDT and FACS physical addresses from a Fixed ACPI Description Table (FADT/FACP).
 ///
 /// # Arguments
@@ -111,928 +278,6669 @@ pub fn parse_fadt_references(data: &[u8]) -> Vec<(u64, String)> {
     refs
 }
 
-/// Cleans a byte slice by converting it to a lossy UTF-8 string and trimming null terminators.
-fn clean_str(bytes: &[u8]) -> String {
-    String::from_utf8_lossy(bytes)
-        .trim_matches(char::from(0))
-        .to_string()
-}
-
-/// Parses an eXtended System Description Table (XSDT) to extract 64-bit physical address entries.
+/// Parses the Windows SMM Security Mitigations Table (WSMT).
 ///
 /// # Arguments
-/// * `data` - The raw binary data of the XSDT table.
-/// * `addr_map` - A map of addresses to known signatures used for labeling entries.
+/// * `data` - The raw binary data of the WSMT table.
 ///
 /// # Returns
-/// `Option<Vec<(usize, u64, String)>>` containing the index, address, and label for each entry.
-pub fn parse_xsdt_entries(
-    data: &[u8],
-    addr_map: &std::collections::HashMap<u64, String>,
-) -> Option<Vec<(usize, u64, String)>> {
-    if data.len() < 36 {
-        return None;
+/// A vector of (field name, value) pairs, empty if the table is not a valid WSMT.
+pub fn parse_wsmt(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 40 || clean_str(&data[0..4]) != "WSMT" {
+        return info;
     }
 
-    let sig = clean_str(&data[0..4]);
-    if sig != "XSDT" {
-        return None;
-    }
+    let flags = LittleEndian::read_u32(&data[36..40]);
+    info.push((
+        "Protection Flags".to_string(),
+        flags_with_names(
+            format!("0x{:08X}", flags),
+            &decode_flags(
+                flags as u64,
+                &[
+                    (0, "FIXED_COMM_BUFFERS"),
+                    (1, "COMM_BUFFER_NESTED_PTR_PROTECTION"),
+                    (2, "SYSTEM_RESOURCE_PROTECTION"),
+                ],
+            ),
+        ),
+    ));
+    info
+}
 
-    let table_len = LittleEndian::read_u32(&data[4..8]) as usize;
-    if table_len > data.len() || table_len < 36 {
-        return None;
+/// Parses the Trusted Platform Module 2.0 table (TPM2).
+///
+/// # Arguments
+/// * `data` - The raw binary data of the TPM2 table.
+///
+/// # Returns
+/// A vector of (field name, value) pairs, empty if the table is not a valid TPM2 table.
+pub fn parse_tpm2(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 52 || clean_str(&data[0..4]) != "TPM2" {
+        return info;
     }
 
-    // XSDT entries start at offset 36 (after standard header)
-    // Each entry is 8 bytes (64-bit pointer)
-    let entries_data = &data[36..table_len];
-    let entry_count = entries_data.len() / 8;
-
-    let mut entries = Vec::new();
-    for i in 0..entry_count {
-        let offset = i * 8;
-        if offset + 8 > entries_data.len() {
-            break;
-        }
-        let addr = LittleEndian::read_u64(&entries_data[offset..offset + 8]);
+    let platform_class = LittleEndian::read_u16(&data[36..38]);
+    let control_area = LittleEndian::read_u64(&data[40..48]);
+    let start_method = LittleEndian::read_u32(&data[48..52]);
+    info.push((
+        "Platform Class".to_string(),
+        match platform_class {
+            0 => "Client".to_string(),
+            1 => "Server".to_string(),
+            _ => format!("Unknown (0x{:04X})", platform_class),
+        },
+    ));
+    info.push((
+        "Control Area Address".to_string(),
+        format!("0x{:016X}", control_area),
+    ));
+    info.push((
+        "Start Method".to_string(),
+        tpm2_start_method_str(start_method),
+    ));
+    info
+}
 
-        let label = addr_map
-            .get(&addr)
-            .cloned()
-            .unwrap_or_else(|| format!("Entry{}", i));
-        entries.push((i, addr, label));
+/// Returns human-readable TPM2 start method string.
+fn tpm2_start_method_str(code: u32) -> String {
+    match code {
+        0 => "Not Allowed".to_string(),
+        1 => "Legacy".to_string(),
+        2 => "ACPI".to_string(),
+        6 => "TIS (Memory-mapped FIFO)".to_string(),
+        7 => "CRB".to_string(),
+        8 => "CRB with ACPI Start".to_string(),
+        11 => "CRB with Arm SMC".to_string(),
+        _ => format!("Unknown (0x{:08X})", code),
     }
-
-    Some(entries)
 }
 
-/// Metadata for the raw SMBIOS data structure as retrieved from Windows.
-#[derive(Debug)]
-pub struct RawSMBIOSData {
-    pub _major_version: u8,
-    pub _minor_version: u8,
-    pub _dmi_revision: u8,
-    pub _length: u32,
+/// Decodes a 12-byte ACPI Generic Address Structure (GAS).
+///
+/// # Arguments
+/// * `gas` - The raw bytes of the structure; shorter slices are reported as truncated.
+///
+/// # Returns
+/// A one-line description such as `SystemMemory 0x00000000FED40000 (32-bit, offset 0, DWord)`.
+pub fn parse_gas(gas: &[u8]) -> String {
+    if gas.len() < 12 {
+        return "<truncated GAS>".to_string();
+    }
+    let space = match gas[0] {
+        0x00 => "SystemMemory".to_string(),
+        0x01 => "SystemIO".to_string(),
+        0x02 => "PCI Config".to_string(),
+        0x03 => "EmbeddedControl".to_string(),
+        0x04 => "SMBus".to_string(),
+        0x05 => "SystemCMOS".to_string(),
+        0x06 => "PciBarTarget".to_string(),
+        0x07 => "IPMI".to_string(),
+        0x08 => "GPIO".to_string(),
+        0x09 => "GenericSerialBus".to_string(),
+        0x0A => "PCC".to_string(),
+        0x0B => "PRM".to_string(),
+        0x7F => "FunctionalFixedHW".to_string(),
+        other => format!("Unknown (0x{:02X})", other),
+    };
+    let access = match gas[3] {
+        0 => "Undefined",
+        1 => "Byte",
+        2 => "Word",
+        3 => "DWord",
+        4 => "QWord",
+        _ => "Unknown",
+    };
+    format!(
+        "{} 0x{:016X} ({}-bit, offset {}, {})",
+        space,
+        LittleEndian::read_u64(&gas[4..12]),
+        gas[1],
+        gas[2],
+        access
+    )
 }
 
-/// Parses the header of the raw SMBIOS data blob returned by Windows APIs.
+/// Parses the Boot Error Record Table (BERT).
 ///
 /// # Arguments
-/// * `data` - The raw SMBIOS data buffer.
+/// * `data` - The raw binary data of the BERT table.
 ///
 /// # Returns
-/// `Option<(RawSMBIOSData, usize)>` containing the parsed header and the size of the header in bytes.
-pub fn parse_raw_smbios_data_header(data: &[u8]) -> Option<(RawSMBIOSData, usize)> {
-    if data.len() < 8 {
-        return None;
+/// A vector of (field name, value) pairs, empty if the table is not a valid BERT.
+pub fn parse_bert(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 48 || clean_str(&data[0..4]) != "BERT" {
+        return info;
     }
-    // struct RawSMBIOSData {
-    //   BYTE  Used20CallingMethod;
-    //   BYTE  SMBIOSMajorVersion;
-    //   BYTE  SMBIOSMinorVersion;
-    //   BYTE  DmiRevision;
-    //   DWORD Length;
-    //   BYTE  SMBIOSTableData[];
-    // };
-    let _u20 = data[0];
-    let major = data[1];
-    let minor = data[2];
-    let dmi = data[3];
-    let length = LittleEndian::read_u32(&data[4..8]);
-
-    Some((
-        RawSMBIOSData {
-            _major_version: major,
-            _minor_version: minor,
-            _dmi_revision: dmi,
-            _length: length,
-        },
-        8,
-    ))
+    info.push((
+        "Boot Error Region Length".to_string(),
+        format!("{} bytes", LittleEndian::read_u32(&data[36..40])),
+    ));
+    info.push((
+        "Boot Error Region".to_string(),
+        format!("0x{:016X}", LittleEndian::read_u64(&data[40..48])),
+    ));
+    info
 }
 
-/// Header for an individual SMBIOS structure.
-#[derive(Debug, Clone)]
-pub struct SmbiosStructureHeader {
-    /// The SMBIOS type ID (e.g., 0 for BIOS, 1 for System).
-    pub type_id: u8,
-    /// The length of the formatted portion of the structure.
-    pub length: u8,
-    /// The unique handle for this structure instance.
-    pub handle: u16,
+/// Parses the Error Injection table (EINJ) and its injection instruction entries.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the EINJ table.
+///
+/// # Returns
+/// A vector of (field name, value) pairs, empty if the table is not a valid EINJ.
+pub fn parse_einj(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 48 || clean_str(&data[0..4]) != "EINJ" {
+        return info;
+    }
+    info.push((
+        "Injection Header Size".to_string(),
+        format!("{}", LittleEndian::read_u32(&data[36..40])),
+    ));
+    info.push(("Injection Flags".to_string(), format!("0x{:02X}", data[40])));
+    let count = LittleEndian::read_u32(&data[44..48]);
+    info.push(("Injection Entry Count".to_string(), format!("{}", count)));
+    info.extend(parse_apei_instructions(data, count, einj_action_str));
+    info
 }
 
-/// Parses a single SMBIOS structure header and calculated its total size (including strings).
+/// Parses the Error Record Serialization Table (ERST) and its serialization instruction entries.
 ///
 /// # Arguments
-/// * `data` - The raw SMBIOS data buffer.
-/// * `offset` - The current offset into the buffer.
+/// * `data` - The raw binary data of the ERST table.
 ///
 /// # Returns
-/// `Result` containing the header and the next offset (end of the structure).
-pub fn parse_smbios_structure(
-    data: &[u8],
-    offset: usize,
-) -> Result<(SmbiosStructureHeader, usize), ()> {
-    if offset + 4 > data.len() {
-        return Err(());
+/// A vector of (field name, value) pairs, empty if the table is not a valid ERST.
+pub fn parse_erst(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 48 || clean_str(&data[0..4]) != "ERST" {
+        return info;
     }
+    info.push((
+        "Serialization Header Size".to_string(),
+        format!("{}", LittleEndian::read_u32(&data[36..40])),
+    ));
+    let count = LittleEndian::read_u32(&data[44..48]);
+    info.push(("Instruction Entry Count".to_string(), format!("{}", count)));
+    info.extend(parse_apei_instructions(data, count, erst_action_str));
+    info
+}
 
-    let type_id = data[offset];
-    let length = data[offset + 1];
-    let handle = LittleEndian::read_u16(&data[offset + 2..offset + 4]);
-
-    if length < 4 {
-        return Err(());
+/// Decodes the 32-byte instruction entries shared by EINJ and ERST, starting at offset 48.
+fn parse_apei_instructions(
+    data: &[u8],
+    count: u32,
+    action_str: fn(u8) -> String,
+) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    for i in 0..count as usize {
+        let pos = 48 + i * 32;
+        if pos + 32 > data.len() {
+            info.push((
+                format!("Entry {}", i),
+                format!("<truncated at offset 0x{:X}>", pos),
+            ));
+            break;
+        }
+        let flags = data[pos + 2];
+        info.push((
+            format!("Entry {}", i),
+            format!(
+                "{} / {}, flags {}, {}, value 0x{:X}, mask 0x{:X}",
+                action_str(data[pos]),
+                apei_instruction_str(data[pos + 1]),
+                flags_with_names(
+                    format!("0x{:02X}", flags),
+                    &decode_flags(flags as u64, &[(0, "PRESERVE_REGISTER")]),
+                ),
+                parse_gas(&data[pos + 4..pos + 16]),
+                LittleEndian::read_u64(&data[pos + 16..pos + 24]),
+                LittleEndian::read_u64(&data[pos + 24..pos + 32]),
+            ),
+        ));
     }
+    info
+}
 
-    let header = SmbiosStructureHeader {
-        type_id,
-        length,
-        handle,
-    };
-
-    // Find end of structure (terminated by a double null: 00 00)
-    let formatted_end = offset + length as usize;
-    let mut current = formatted_end;
+/// Returns human-readable EINJ injection action string.
+fn einj_action_str(code: u8) -> String {
+    match code {
+        0x00 => "BEGIN_INJECTION_OPERATION".to_string(),
+        0x01 => "GET_TRIGGER_ERROR_ACTION_TABLE".to_string(),
+        0x02 => "SET_ERROR_TYPE".to_string(),
+        0x03 => "GET_ERROR_TYPE".to_string(),
+        0x04 => "END_OPERATION".to_string(),
+        0x05 => "EXECUTE_OPERATION".to_string(),
+        0x06 => "CHECK_BUSY_STATUS".to_string(),
+        0x07 => "GET_COMMAND_STATUS".to_string(),
+        0x08 => "SET_ERROR_TYPE_WITH_ADDRESS".to_string(),
+        0x09 => "GET_EXECUTE_OPERATION_TIMINGS".to_string(),
+        0xFF => "TRIGGER_ERROR".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
 
-    while current + 1 < data.len() {
-        if data[current] == 0 && data[current + 1] == 0 {
-            return Ok((header, current + 2));
-        }
-        current += 1;
+/// Returns human-readable ERST serialization action string.
+fn erst_action_str(code: u8) -> String {
+    match code {
+        0x00 => "BEGIN_WRITE_OPERATION".to_string(),
+        0x01 => "BEGIN_READ_OPERATION".to_string(),
+        0x02 => "BEGIN_CLEAR_OPERATION".to_string(),
+        0x03 => "END_OPERATION".to_string(),
+        0x04 => "SET_RECORD_OFFSET".to_string(),
+        0x05 => "EXECUTE_OPERATION".to_string(),
+        0x06 => "CHECK_BUSY_STATUS".to_string(),
+        0x07 => "GET_COMMAND_STATUS".to_string(),
+        0x08 => "GET_RECORD_IDENTIFIER".to_string(),
+        0x09 => "SET_RECORD_IDENTIFIER".to_string(),
+        0x0A => "GET_RECORD_COUNT".to_string(),
+        0x0B => "BEGIN_DUMMY_WRITE_OPERATION".to_string(),
+        0x0D => "GET_ERROR_LOG_ADDRESS_RANGE".to_string(),
+        0x0E => "GET_ERROR_LOG_ADDRESS_RANGE_LENGTH".to_string(),
+        0x0F => "GET_ERROR_LOG_ADDRESS_RANGE_ATTRIBUTES".to_string(),
+        0x10 => "GET_EXECUTE_OPERATION_TIMINGS".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
     }
+}
 
-    Ok((header, data.len()))
+/// Returns human-readable APEI instruction string (shared by EINJ and ERST).
+fn apei_instruction_str(code: u8) -> String {
+    match code {
+        0x00 => "READ_REGISTER".to_string(),
+        0x01 => "READ_REGISTER_VALUE".to_string(),
+        0x02 => "WRITE_REGISTER".to_string(),
+        0x03 => "WRITE_REGISTER_VALUE".to_string(),
+        0x04 => "NOOP".to_string(),
+        0x05 => "LOAD_VAR1".to_string(),
+        0x06 => "LOAD_VAR2".to_string(),
+        0x07 => "STORE_VAR1".to_string(),
+        0x08 => "ADD".to_string(),
+        0x09 => "SUBTRACT".to_string(),
+        0x0A => "ADD_VALUE".to_string(),
+        0x0B => "SUBTRACT_VALUE".to_string(),
+        0x0C => "STALL".to_string(),
+        0x0D => "STALL_WHILE_TRUE".to_string(),
+        0x0E => "SKIP_NEXT_INSTRUCTION_IF_TRUE".to_string(),
+        0x0F => "GOTO".to_string(),
+        0x10 => "SET_SRC_ADDRESS_BASE".to_string(),
+        0x11 => "SET_DST_ADDRESS_BASE".to_string(),
+        0x12 => "MOVE_DATA".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
 }
 
-/// Extracts the string pool following the formatted portion of an SMBIOS structure.
+/// Parses the Hardware Error Source Table (HEST) and its error source structures.
+///
+/// Sources of an unknown type have no known size, so decoding stops there and the
+/// remaining bytes are reported by offset.
 ///
 /// # Arguments
-/// * `data` - The raw SMBIOS data buffer.
-/// * `offset` - The starting offset of the structure's formatted portion.
-/// * `length` - The length of the formatted portion.
+/// * `data` - The raw binary data of the HEST table.
 ///
 /// # Returns
-/// A vector of strings extracted from the string pool.
-pub fn get_smbios_strings(data: &[u8], offset: usize, length: u8) -> Vec<String> {
-    let mut strings = Vec::new();
-    let str_start = offset + length as usize;
+/// A vector of (field name, value) pairs, empty if the table is not a valid HEST.
+pub fn parse_hest(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 40 || clean_str(&data[0..4]) != "HEST" {
+        return info;
+    }
+    let count = LittleEndian::read_u32(&data[36..40]);
+    info.push(("Error Source Count".to_string(), format!("{}", count)));
 
-    if str_start >= data.len() {
-        return strings;
+    let end = (LittleEndian::read_u32(&data[4..8]) as usize).min(data.len());
+    let mut pos = 40;
+    for i in 0..count as usize {
+        if pos + 4 > end {
+            break;
+        }
+        let source_type = LittleEndian::read_u16(&data[pos..pos + 2]);
+        let source_id = LittleEndian::read_u16(&data[pos + 2..pos + 4]);
+        // Machine-check sources carry a trailing array of 28-byte bank structures
+        let bank_count = |at: usize| data.get(pos + at).map_or(0, |&n| n as usize);
+        let size = match source_type {
+            0 => 40 + 28 * bank_count(32),
+            1 | 11 => 48 + 28 * bank_count(44),
+            2 => 20,
+            6 => 48,
+            7 => 44,
+            8 => 56,
+            9 => 64,
+            10 => 92,
+            _ => 0,
+        };
+        if size == 0 || pos + size > end {
+            info.push((
+                format!("Source {}", i),
+                format!(
+                    "Type {} ({}) at offset 0x{:X}, not decoded",
+                    source_type,
+                    hest_source_type_str(source_type),
+                    pos
+                ),
+            ));
+            break;
+        }
+
+        let mut desc = format!("{}, ID {}", hest_source_type_str(source_type), source_id);
+        if source_type == 9 || source_type == 10 {
+            desc.push_str(&format!(
+                ", status at {}",
+                parse_gas(&data[pos + 20..pos + 32])
+            ));
+        }
+        info.push((format!("Source {}", i), desc));
+        pos += size;
     }
+    info
+}
 
-    let mut current_idx = str_start;
-    while current_idx < data.len() {
-        match data[current_idx..].iter().position(|&b| b == 0) {
-            Some(pos) => {
-                let null_idx = current_idx + pos;
-                if null_idx == current_idx {
-                    break;
-                }
+/// Returns human-readable HEST error source type string.
+fn hest_source_type_str(code: u16) -> String {
+    match code {
+        0 => "IA-32 Machine Check Exception".to_string(),
+        1 => "IA-32 Corrected Machine Check".to_string(),
+        2 => "IA-32 NMI".to_string(),
+        6 => "PCIe Root Port AER".to_string(),
+        7 => "PCIe Device AER".to_string(),
+        8 => "PCIe Bridge AER".to_string(),
+        9 => "Generic Hardware Error Source".to_string(),
+        10 => "Generic Hardware Error Source v2".to_string(),
+        11 => "IA-32 Deferred Machine Check".to_string(),
+        _ => format!("Unknown (0x{:04X})", code),
+    }
+}
 
-                let s_bytes = &data[current_idx..null_idx];
-                strings.push(String::from_utf8_lossy(s_bytes).to_string());
+/// Formats a 16-byte little-endian GUID, falling back to plain hex.
+fn guid_str(bytes: &[u8]) -> String {
+    match uuid::Uuid::from_slice_le(bytes) {
+        Ok(u) => u.to_string().to_uppercase(),
+        Err(_) => hex::encode(bytes).to_uppercase(),
+    }
+}
 
-                current_idx = null_idx + 1;
+/// Returns the name of a well-known NFIT SPA range type GUID.
+fn nfit_spa_type_str(guid: &str) -> &'static str {
+    match guid {
+        "7305944F-FDDA-44E3-B16C-3F22D252E5D0" => "Volatile Memory",
+        "66F0D379-B4F3-4074-AC43-0D3318B78CDB" => "Byte-Addressable Persistent Memory",
+        "92F701F6-13B4-405D-910B-299367E8234C" => "NVDIMM Control Region",
+        "91AF0530-5D86-470E-A6B0-0A2DB9408249" => "NVDIMM Block Data Window Region",
+        "77AB535A-45FC-624B-5560-F7B281D1F96E" => "RAM Disk (Virtual Disk)",
+        "3D5ABD30-4175-87CE-6D64-D2ADE523C4BB" => "RAM Disk (Virtual CD)",
+        "5CEA02C9-4D07-69D3-269F-4496FBE096F9" => "Persistent RAM Disk (Virtual Disk)",
+        "08018188-42CD-BB48-100F-5387D53DED3D" => "Persistent RAM Disk (Virtual CD)",
+        _ => "",
+    }
+}
 
-                if current_idx < data.len() && data[current_idx] == 0 {
-                    break;
-                }
-            }
-            None => break,
-        }
+/// Returns the name of an NFIT structure type.
+fn nfit_structure_type_str(code: u16) -> String {
+    match code {
+        0 => "SPA Range".to_string(),
+        1 => "NVDIMM Region Mapping".to_string(),
+        2 => "Interleave".to_string(),
+        3 => "SMBIOS Management Information".to_string(),
+        4 => "NVDIMM Control Region".to_string(),
+        5 => "NVDIMM Block Data Window Region".to_string(),
+        6 => "Flush Hint Address".to_string(),
+        7 => "Platform Capabilities".to_string(),
+        _ => format!("Unknown (0x{:04X})", code),
     }
-    strings
 }
 
-/// Retrieves a string from the SMBIOS string pool by its 1-based index.
+/// Parses the NVDIMM Firmware Interface Table (NFIT) describing persistent-memory topology.
+///
+/// SPA Range, NVDIMM Region Mapping, and Interleave structures are decoded; other
+/// structure types are listed by type and length.
 ///
 /// # Arguments
-/// * `strings` - The list of strings extracted from the structure.
+/// * `data` - The raw binary data of the NFIT table.
+///
+/// # Returns
+/// A vector of (field name, value) pairs, empty if the table is not a valid NFIT.
+pub fn parse_nfit(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 40 || clean_str(&data[0..4]) != "NFIT" {
+        return info;
+    }
+    let read_u16 = |at: usize| LittleEndian::read_u16(&data[at..at + 2]);
+    let read_u32 = |at: usize| LittleEndian::read_u32(&data[at..at + 4]);
+    let read_u64 = |at: usize| LittleEndian::read_u64(&data[at..at + 8]);
+
+    let end = (read_u32(4) as usize).min(data.len());
+    // 4 reserved bytes follow the standard header
+    let mut pos = 40;
+    let mut index = 0;
+    while pos + 4 <= end {
+        let kind = read_u16(pos);
+        let length = read_u16(pos + 2) as usize;
+        if length < 4 || pos + length > end {
+            info.push((
+                format!("Structure {}", index),
+                format!("Invalid length {} at offset 0x{:X}", length, pos),
+            ));
+            break;
+        }
+        let prefix = format!("Structure {}", index);
+        info.push((format!("{} Type", prefix), nfit_structure_type_str(kind)));
+        match kind {
+            0 if length >= 56 => {
+                let guid = guid_str(&data[pos + 16..pos + 32]);
+                let base = read_u64(pos + 32);
+                let size = read_u64(pos + 40);
+                info.push((
+                    format!("{} SPA Range Index", prefix),
+                    format!("{}", read_u16(pos + 4)),
+                ));
+                info.push((
+                    format!("{} Proximity Domain", prefix),
+                    format!("{}", read_u32(pos + 12)),
+                ));
+                let name = nfit_spa_type_str(&guid);
+                info.push((
+                    format!("{} Range Type GUID", prefix),
+                    if name.is_empty() {
+                        guid
+                    } else {
+                        format!("{} ({})", guid, name)
+                    },
+                ));
+                info.push((
+                    format!("{} Address Range", prefix),
+                    format!(
+                        "0x{:016X}-0x{:016X} ({} MB)",
+                        base,
+                        base.wrapping_add(size).wrapping_sub(1),
+                        size / (1024 * 1024)
+                    ),
+                ));
+                info.push((
+                    format!("{} Memory Mapping Attributes", prefix),
+                    format!("0x{:016X}", read_u64(pos + 48)),
+                ));
+            }
+            1 if length >= 48 => {
+                info.push((
+                    format!("{} Device Handle", prefix),
+                    format!("0x{:08X}", read_u32(pos + 4)),
+                ));
+                info.push((
+                    format!("{} Physical ID", prefix),
+                    format!("0x{:04X}", read_u16(pos + 8)),
+                ));
+                info.push((
+                    format!("{} Region ID", prefix),
+                    format!("{}", read_u16(pos + 10)),
+                ));
+                info.push((
+                    format!("{} SPA Range Index", prefix),
+                    format!("{}", read_u16(pos + 12)),
+                ));
+                info.push((
+                    format!("{} Control Region Index", prefix),
+                    format!("{}", read_u16(pos + 14)),
+                ));
+                info.push((
+                    format!("{} Region Size", prefix),
+                    format!("0x{:X}", read_u64(pos + 16)),
+                ));
+                info.push((
+                    format!("{} Region Offset", prefix),
+                    format!("0x{:X}", read_u64(pos + 24)),
+                ));
+                info.push((
+                    format!("{} DPA Base", prefix),
+                    format!("0x{:016X}", read_u64(pos + 32)),
+                ));
+                info.push((
+                    format!("{} Interleave Index", prefix),
+                    format!("{}", read_u16(pos + 40)),
+                ));
+                info.push((
+                    format!("{} Interleave Ways", prefix),
+                    format!("{}", read_u16(pos + 42)),
+                ));
+                info.push((
+                    format!("{} State Flags", prefix),
+                    format!("0x{:04X}", read_u16(pos + 44)),
+                ));
+            }
+            2 if length >= 16 => {
+                let lines = read_u32(pos + 8);
+                info.push((
+                    format!("{} Interleave Index", prefix),
+                    format!("{}", read_u16(pos + 4)),
+                ));
+                info.push((format!("{} Lines Described", prefix), format!("{}", lines)));
+                info.push((
+                    format!("{} Line Size", prefix),
+                    format!("{} bytes", read_u32(pos + 12)),
+                ));
+                let offsets: Vec<String> = (0..lines as usize)
+                    .map(|i| pos + 16 + i * 4)
+                    .take_while(|&at| at + 4 <= pos + length)
+                    .map(|at| format!("{}", read_u32(at)))
+                    .collect();
+                if !offsets.is_empty() {
+                    info.push((format!("{} Line Offsets", prefix), offsets.join(", ")));
+                }
+            }
+            _ => info.push((format!("{} Length", prefix), format!("{}", length))),
+        }
+        pos += length;
+        index += 1;
+    }
+    info
+}
+
+/// Returns the name of an IORT node type.
+fn iort_node_type_str(code: u8) -> String {
+    match code {
+        0 => "ITS Group".to_string(),
+        1 => "Named Component".to_string(),
+        2 => "Root Complex".to_string(),
+        3 => "SMMUv1/v2".to_string(),
+        4 => "SMMUv3".to_string(),
+        5 => "PMCG".to_string(),
+        6 => "Reserved Memory Range".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Parses the I/O Remapping Table (IORT) describing Arm SMMU and ITS topology.
+///
+/// Each node is listed with its type-specific fields and ID mappings; mapping output
+/// references are resolved to the type of the node they point at.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the IORT table.
+///
+/// # Returns
+/// A vector of (field name, value) pairs, empty if the table is not a valid IORT.
+pub fn parse_iort(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 48 || clean_str(&data[0..4]) != "IORT" {
+        return info;
+    }
+    let read_u32 = |at: usize| LittleEndian::read_u32(&data[at..at + 4]);
+    let read_u64 = |at: usize| LittleEndian::read_u64(&data[at..at + 8]);
+
+    let end = (read_u32(4) as usize).min(data.len());
+    let count = read_u32(36);
+    let array_offset = read_u32(40) as usize;
+    info.push(("Node Count".to_string(), format!("{}", count)));
+    info.push((
+        "Node Array Offset".to_string(),
+        format!("0x{:X}", array_offset),
+    ));
+
+    // Walk once to learn where each node lives, so mapping references can be named
+    let mut nodes: Vec<(usize, u8, usize)> = Vec::new();
+    let mut pos = array_offset;
+    for _ in 0..count {
+        if pos + 16 > end {
+            break;
+        }
+        let length = LittleEndian::read_u16(&data[pos + 1..pos + 3]) as usize;
+        if length < 16 || pos + length > end {
+            info.push((
+                "Error".to_string(),
+                format!("Invalid node length {} at offset 0x{:X}", length, pos),
+            ));
+            break;
+        }
+        nodes.push((pos, data[pos], length));
+        pos += length;
+    }
+    let node_name = |offset: usize| match nodes.iter().position(|(o, _, _)| *o == offset) {
+        Some(i) => format!("Node {} ({})", i, iort_node_type_str(nodes[i].1)),
+        None => format!("<no node at 0x{:X}>", offset),
+    };
+
+    for (i, &(pos, kind, length)) in nodes.iter().enumerate() {
+        let prefix = format!("Node {}", i);
+        info.push((
+            format!("{} Type", prefix),
+            format!(
+                "{} @0x{:X}, {} bytes, rev {}",
+                iort_node_type_str(kind),
+                pos,
+                length,
+                data[pos + 3]
+            ),
+        ));
+        match kind {
+            0 if length >= 20 => {
+                let its_count = read_u32(pos + 16) as usize;
+                let ids: Vec<String> = (0..its_count)
+                    .map(|j| pos + 20 + j * 4)
+                    .take_while(|&at| at + 4 <= pos + length)
+                    .map(|at| format!("{}", read_u32(at)))
+                    .collect();
+                info.push((format!("{} ITS IDs", prefix), ids.join(", ")));
+            }
+            1 if length > 29 => {
+                let name_end = data[pos + 29..pos + length]
+                    .iter()
+                    .position(|&b| b == 0)
+                    .map_or(pos + length, |n| pos + 29 + n);
+                info.push((
+                    format!("{} Device Name", prefix),
+                    String::from_utf8_lossy(&data[pos + 29..name_end]).into_owned(),
+                ));
+                info.push((
+                    format!("{} Address Size Limit", prefix),
+                    format!("{} bits", data[pos + 28]),
+                ));
+            }
+            2 if length >= 33 => {
+                info.push((
+                    format!("{} PCI Segment", prefix),
+                    format!("{}", read_u32(pos + 28)),
+                ));
+                info.push((
+                    format!("{} ATS", prefix),
+                    if read_u32(pos + 24) & 0x1 != 0 {
+                        "Supported".to_string()
+                    } else {
+                        "Not Supported".to_string()
+                    },
+                ));
+                info.push((
+                    format!("{} Address Size Limit", prefix),
+                    format!("{} bits", data[pos + 32]),
+                ));
+            }
+            3 if length >= 40 => {
+                info.push((
+                    format!("{} Base Address", prefix),
+                    format!("0x{:016X}", read_u64(pos + 16)),
+                ));
+                info.push((
+                    format!("{} Span", prefix),
+                    format!("0x{:X}", read_u64(pos + 24)),
+                ));
+                info.push((
+                    format!("{} Model", prefix),
+                    format!("{}", read_u32(pos + 32)),
+                ));
+            }
+            4 if length >= 44 => {
+                info.push((
+                    format!("{} Base Address", prefix),
+                    format!("0x{:016X}", read_u64(pos + 16)),
+                ));
+                info.push((
+                    format!("{} Flags", prefix),
+                    format!("0x{:08X}", read_u32(pos + 24)),
+                ));
+                info.push((
+                    format!("{} Model", prefix),
+                    format!("{}", read_u32(pos + 40)),
+                ));
+            }
+            _ => {}
+        }
+
+        let mapping_count = read_u32(pos + 8) as usize;
+        let mapping_offset = read_u32(pos + 12) as usize;
+        for j in 0..mapping_count {
+            let at = pos + mapping_offset + j * 20;
+            if at + 20 > pos + length {
+                break;
+            }
+            let input_base = read_u32(at);
+            let id_count = read_u32(at + 4);
+            let output_base = read_u32(at + 8);
+            let single = read_u32(at + 16) & 0x1 != 0;
+            // The ID count field holds the number of IDs in the range minus one
+            let mapped = if single {
+                format!("Single ID 0x{:X}", output_base)
+            } else {
+                format!(
+                    "0x{:X}-0x{:X} -> 0x{:X}-0x{:X}",
+                    input_base,
+                    input_base.wrapping_add(id_count),
+                    output_base,
+                    output_base.wrapping_add(id_count)
+                )
+            };
+            info.push((
+                format!("{} Mapping {}", prefix, j),
+                format!("{} to {}", mapped, node_name(read_u32(at + 12) as usize)),
+            ));
+        }
+    }
+    info
+}
+
+/// Reads the distance matrix of a System Locality Distance Information Table (SLIT).
+///
+/// # Arguments
+/// * `data` - The raw binary data of the SLIT table.
+///
+/// # Returns
+/// One row of distances per locality, or `None` if the table is invalid or the locality
+/// count does not fit in the table length.
+pub fn slit_matrix(data: &[u8]) -> Option<Vec<Vec<u8>>> {
+    if data.len() < 44 || clean_str(&data[0..4]) != "SLIT" {
+        return None;
+    }
+    let length = (LittleEndian::read_u32(&data[4..8]) as usize).min(data.len());
+    let count = LittleEndian::read_u64(&data[36..44]);
+    // A bogus count must not overflow the size computation or allocate a huge matrix
+    let count = usize::try_from(count).ok()?;
+    let cells = count.checked_mul(count)?;
+    if 44usize.checked_add(cells)? > length {
+        return None;
+    }
+    Some(
+        (0..count)
+            .map(|row| data[44 + row * count..44 + (row + 1) * count].to_vec())
+            .collect(),
+    )
+}
+
+/// Parses the System Locality Distance Information Table (SLIT).
+///
+/// # Arguments
+/// * `data` - The raw binary data of the SLIT table.
+///
+/// # Returns
+/// A vector of (field name, value) pairs with one row of distances per locality, empty if
+/// the table is not a valid SLIT.
+pub fn parse_slit(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 44 || clean_str(&data[0..4]) != "SLIT" {
+        return info;
+    }
+    let count = LittleEndian::read_u64(&data[36..44]);
+    info.push(("Locality Count".to_string(), format!("{}", count)));
+    match slit_matrix(data) {
+        Some(matrix) => {
+            for (i, row) in matrix.iter().enumerate() {
+                let distances: Vec<String> = row.iter().map(|d| format!("{:>3}", d)).collect();
+                info.push((format!("Locality {}", i), distances.join(" ")));
+            }
+        }
+        None => info.push((
+            "Error".to_string(),
+            format!("{} localities do not fit in the table length", count),
+        )),
+    }
+    info
+}
+
+/// An enabled affinity structure of a System Resource Affinity Table (SRAT).
+#[derive(Debug, Clone, PartialEq)]
+pub enum SratAffinity {
+    /// A processor (Local APIC/SAPIC, x2APIC, or GICC affinity) in a proximity domain.
+    Processor {
+        /// The proximity domain the processor belongs to.
+        domain: u32,
+        /// The APIC ID, x2APIC ID, or ACPI Processor UID, depending on the structure.
+        id: u32,
+    },
+    /// A memory range in a proximity domain.
+    Memory {
+        /// The proximity domain the range belongs to.
+        domain: u32,
+        /// Base physical address of the range.
+        base: u64,
+        /// Length of the range in bytes.
+        length: u64,
+        /// Whether the range is hot-pluggable.
+        hot_pluggable: bool,
+        /// Whether the range is non-volatile.
+        non_volatile: bool,
+    },
+}
+
+/// Reads the enabled processor and memory affinity structures of an SRAT.
+///
+/// Structures whose Enabled flag is clear describe absent processors or memory and are
+/// skipped; ITS and Generic Initiator affinity structures are not included.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the SRAT table.
+///
+/// # Returns
+/// The affinity structures in table order, or `None` if the table is not a valid SRAT.
+pub fn srat_affinities(data: &[u8]) -> Option<Vec<SratAffinity>> {
+    if data.len() < 48 || clean_str(&data[0..4]) != "SRAT" {
+        return None;
+    }
+    let read_u32 = |at: usize| LittleEndian::read_u32(&data[at..at + 4]);
+    let read_u64 = |at: usize| LittleEndian::read_u64(&data[at..at + 8]);
+    let end = (read_u32(4) as usize).min(data.len());
+
+    let mut affinities = Vec::new();
+    let mut pos = 48;
+    while pos + 2 <= end {
+        let kind = data[pos];
+        let length = data[pos + 1] as usize;
+        if length < 2 || pos + length > end {
+            break;
+        }
+        let affinity = match kind {
+            // Processor Local APIC/SAPIC: the domain is split across bytes 2 and 9..12
+            0 if length >= 16 && read_u32(pos + 4) & 0x1 != 0 => Some(SratAffinity::Processor {
+                domain: data[pos + 2] as u32
+                    | (data[pos + 9] as u32) << 8
+                    | (data[pos + 10] as u32) << 16
+                    | (data[pos + 11] as u32) << 24,
+                id: data[pos + 3] as u32,
+            }),
+            1 if length >= 40 && read_u32(pos + 28) & 0x1 != 0 => {
+                let flags = read_u32(pos + 28);
+                Some(SratAffinity::Memory {
+                    domain: read_u32(pos + 2),
+                    base: read_u64(pos + 8),
+                    length: read_u64(pos + 16),
+                    hot_pluggable: flags & 0x2 != 0,
+                    non_volatile: flags & 0x4 != 0,
+                })
+            }
+            2 if length >= 24 && read_u32(pos + 12) & 0x1 != 0 => Some(SratAffinity::Processor {
+                domain: read_u32(pos + 4),
+                id: read_u32(pos + 8),
+            }),
+            3 if length >= 18 && read_u32(pos + 10) & 0x1 != 0 => Some(SratAffinity::Processor {
+                domain: read_u32(pos + 2),
+                id: read_u32(pos + 6),
+            }),
+            _ => None,
+        };
+        affinities.extend(affinity);
+        pos += length;
+    }
+    Some(affinities)
+}
+
+/// Converts an SRAT affinity structure type to a string.
+fn srat_structure_type_str(code: u8) -> String {
+    match code {
+        0 => "Processor Local APIC/SAPIC Affinity".to_string(),
+        1 => "Memory Affinity".to_string(),
+        2 => "Processor Local x2APIC Affinity".to_string(),
+        3 => "GICC Affinity".to_string(),
+        4 => "GIC ITS Affinity".to_string(),
+        5 => "Generic Initiator Affinity".to_string(),
+        6 => "Generic Port Affinity".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Parses the System Resource Affinity Table (SRAT).
+///
+/// # Arguments
+/// * `data` - The raw binary data of the SRAT table.
+///
+/// # Returns
+/// A vector of (field name, value) pairs with one row per affinity structure, empty if the
+/// table is not a valid SRAT.
+pub fn parse_srat(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 48 || clean_str(&data[0..4]) != "SRAT" {
+        return info;
+    }
+    let read_u32 = |at: usize| LittleEndian::read_u32(&data[at..at + 4]);
+    let read_u64 = |at: usize| LittleEndian::read_u64(&data[at..at + 8]);
+    let end = (read_u32(4) as usize).min(data.len());
+    let enabled = |flags: u32| if flags & 0x1 != 0 { "" } else { ", disabled" };
+
+    let mut pos = 48;
+    let mut index = 0;
+    while pos + 2 <= end {
+        let kind = data[pos];
+        let length = data[pos + 1] as usize;
+        if length < 2 || pos + length > end {
+            info.push((
+                "Error".to_string(),
+                format!("Invalid structure length {} at offset 0x{:X}", length, pos),
+            ));
+            break;
+        }
+        let value = match kind {
+            0 if length >= 16 => {
+                let domain = data[pos + 2] as u32
+                    | (data[pos + 9] as u32) << 8
+                    | (data[pos + 10] as u32) << 16
+                    | (data[pos + 11] as u32) << 24;
+                format!(
+                    "APIC ID 0x{:02X}, domain {}{}",
+                    data[pos + 3],
+                    domain,
+                    enabled(read_u32(pos + 4))
+                )
+            }
+            1 if length >= 40 => {
+                let flags = read_u32(pos + 28);
+                let base = read_u64(pos + 8);
+                let size = read_u64(pos + 16);
+                let mut value = format!(
+                    "0x{:016X} - 0x{:016X} ({}), domain {}{}",
+                    base,
+                    base.wrapping_add(size).wrapping_sub(1),
+                    memory_size_str(size),
+                    read_u32(pos + 2),
+                    enabled(flags)
+                );
+                if flags & 0x2 != 0 {
+                    value.push_str(", hot-pluggable");
+                }
+                if flags & 0x4 != 0 {
+                    value.push_str(", non-volatile");
+                }
+                value
+            }
+            2 if length >= 24 => format!(
+                "x2APIC ID 0x{:08X}, domain {}{}",
+                read_u32(pos + 8),
+                read_u32(pos + 4),
+                enabled(read_u32(pos + 12))
+            ),
+            3 if length >= 18 => format!(
+                "Processor UID {}, domain {}{}",
+                read_u32(pos + 6),
+                read_u32(pos + 2),
+                enabled(read_u32(pos + 10))
+            ),
+            4 if length >= 12 => {
+                format!("ITS ID {}, domain {}", read_u32(pos + 8), read_u32(pos + 2))
+            }
+            5 | 6 if length >= 32 => format!(
+                "domain {}{}",
+                read_u32(pos + 4),
+                enabled(read_u32(pos + 24))
+            ),
+            _ => format!("{} bytes", length),
+        };
+        info.push((
+            format!("{} {}", srat_structure_type_str(kind), index),
+            value,
+        ));
+        pos += length;
+        index += 1;
+    }
+    info
+}
+
+/// Names of the DMAR table flag bits.
+const DMAR_FLAG_DEFS: &[(u32, &str)] = &[
+    (0, "INTR_REMAP"),
+    (1, "X2APIC_OPT_OUT"),
+    (2, "DMA_CTRL_PLATFORM_OPT_IN"),
+];
+
+/// Converts a DMAR remapping structure type to a string.
+fn dmar_structure_type_str(code: u16) -> String {
+    match code {
+        0 => "DRHD".to_string(),
+        1 => "RMRR".to_string(),
+        2 => "ATSR".to_string(),
+        3 => "RHSA".to_string(),
+        4 => "ANDD".to_string(),
+        5 => "SATC".to_string(),
+        6 => "SIDP".to_string(),
+        _ => format!("Unknown (0x{:04X})", code),
+    }
+}
+
+/// Converts a DMAR device scope type to a string.
+fn dmar_scope_type_str(code: u8) -> String {
+    match code {
+        1 => "PCI Endpoint".to_string(),
+        2 => "PCI Sub-hierarchy".to_string(),
+        3 => "IOAPIC".to_string(),
+        4 => "HPET".to_string(),
+        5 => "ACPI Namespace Device".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Decodes the device scope entries in `data[start..end]` as PCI paths.
+///
+/// # Returns
+/// One string per scope, e.g. "PCI Endpoint 0000:00:02.0" or "IOAPIC 8 at 0000:00:1E.7/00.0".
+fn dmar_device_scopes(data: &[u8], start: usize, end: usize, segment: u16) -> Vec<String> {
+    let mut scopes = Vec::new();
+    let mut pos = start;
+    while pos + 6 <= end {
+        let length = data[pos + 1] as usize;
+        if length < 6 || pos + length > end {
+            break;
+        }
+        let kind = data[pos];
+        let enumeration_id = data[pos + 4];
+        let mut path = format!("{:04X}:{:02X}", segment, data[pos + 5]);
+        for (i, hop) in data[pos + 6..pos + length].chunks_exact(2).enumerate() {
+            if i == 0 {
+                path.push_str(&format!(":{:02X}.{}", hop[0], hop[1]));
+            } else {
+                path.push_str(&format!("/{:02X}.{}", hop[0], hop[1]));
+            }
+        }
+        scopes.push(match kind {
+            3..=5 => format!(
+                "{} {} at {}",
+                dmar_scope_type_str(kind),
+                enumeration_id,
+                path
+            ),
+            _ => format!("{} {}", dmar_scope_type_str(kind), path),
+        });
+        pos += length;
+    }
+    scopes
+}
+
+/// Parses the DMA Remapping table (DMAR) describing Intel VT-d remapping hardware.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the DMAR table.
+///
+/// # Returns
+/// A vector of (field name, value) pairs, empty if the table is not a valid DMAR.
+pub fn parse_dmar(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 48 || clean_str(&data[0..4]) != "DMAR" {
+        return info;
+    }
+    let read_u16 = |at: usize| LittleEndian::read_u16(&data[at..at + 2]);
+    let read_u64 = |at: usize| LittleEndian::read_u64(&data[at..at + 8]);
+
+    let end = (LittleEndian::read_u32(&data[4..8]) as usize).min(data.len());
+    info.push((
+        "Host Address Width".to_string(),
+        format!("{} bits", data[36] as u32 + 1),
+    ));
+    let flags = data[37];
+    info.push((
+        "Flags".to_string(),
+        flags_with_names(
+            format!("0x{:02X}", flags),
+            &decode_flags(flags as u64, DMAR_FLAG_DEFS),
+        ),
+    ));
+
+    let mut pos = 48;
+    let mut index = 0;
+    while pos + 4 <= end {
+        let kind = read_u16(pos);
+        let length = read_u16(pos + 2) as usize;
+        if length < 4 || pos + length > end {
+            info.push((
+                "Error".to_string(),
+                format!("Invalid structure length {} at offset 0x{:X}", length, pos),
+            ));
+            break;
+        }
+        let prefix = format!("{} {}", dmar_structure_type_str(kind), index);
+        let mut scopes_at = None;
+        match kind {
+            0 if length >= 16 => {
+                let segment = read_u16(pos + 6);
+                info.push((
+                    format!("{} Register Base", prefix),
+                    format!("0x{:016X}", read_u64(pos + 8)),
+                ));
+                info.push((format!("{} Segment", prefix), format!("{}", segment)));
+                info.push((
+                    format!("{} Flags", prefix),
+                    if data[pos + 4] & 0x1 != 0 {
+                        "INCLUDE_PCI_ALL".to_string()
+                    } else {
+                        "0x00".to_string()
+                    },
+                ));
+                scopes_at = Some((pos + 16, segment));
+            }
+            1 if length >= 24 => {
+                let segment = read_u16(pos + 6);
+                info.push((format!("{} Segment", prefix), format!("{}", segment)));
+                info.push((
+                    format!("{} Range", prefix),
+                    format!(
+                        "0x{:016X} - 0x{:016X}",
+                        read_u64(pos + 8),
+                        read_u64(pos + 16)
+                    ),
+                ));
+                scopes_at = Some((pos + 24, segment));
+            }
+            2 | 5 if length >= 8 => {
+                let segment = read_u16(pos + 6);
+                info.push((format!("{} Segment", prefix), format!("{}", segment)));
+                if kind == 2 {
+                    info.push((
+                        format!("{} Flags", prefix),
+                        if data[pos + 4] & 0x1 != 0 {
+                            "ALL_PORTS".to_string()
+                        } else {
+                            "0x00".to_string()
+                        },
+                    ));
+                }
+                scopes_at = Some((pos + 8, segment));
+            }
+            3 if length >= 20 => {
+                info.push((
+                    format!("{} Register Base", prefix),
+                    format!("0x{:016X}", read_u64(pos + 8)),
+                ));
+                info.push((
+                    format!("{} Proximity Domain", prefix),
+                    format!("{}", LittleEndian::read_u32(&data[pos + 16..pos + 20])),
+                ));
+            }
+            4 if length > 8 => {
+                info.push((
+                    format!("{} Device Number", prefix),
+                    format!("{}", data[pos + 7]),
+                ));
+                info.push((
+                    format!("{} Object Name", prefix),
+                    clean_str(&data[pos + 8..pos + length]),
+                ));
+            }
+            _ => info.push((format!("{} Length", prefix), format!("{} bytes", length))),
+        }
+        if let Some((start, segment)) = scopes_at {
+            for (j, scope) in dmar_device_scopes(data, start, pos + length, segment)
+                .into_iter()
+                .enumerate()
+            {
+                info.push((format!("{} Scope {}", prefix, j), scope));
+            }
+        }
+        pos += length;
+        index += 1;
+    }
+    info
+}
+
+/// Bit definitions for the PPTT Processor Hierarchy Node flags.
+const PPTT_PROCESSOR_FLAG_DEFS: &[(u32, &str)] = &[
+    (0, "Physical Package"),
+    (1, "ACPI Processor ID Valid"),
+    (2, "Thread"),
+    (3, "Leaf"),
+    (4, "Identical Implementation"),
+];
+
+/// Describes a PPTT cache type structure at `offset`: size, ways, line size, type, and policy.
+fn pptt_cache_summary(data: &[u8], offset: usize) -> String {
+    if offset + 24 > data.len() {
+        return format!("<truncated cache at 0x{:X}>", offset);
+    }
+    let size = LittleEndian::read_u32(&data[offset + 12..offset + 16]);
+    let ways = data[offset + 20];
+    let attributes = data[offset + 21];
+    let line_size = LittleEndian::read_u16(&data[offset + 22..offset + 24]);
+    let cache_type = match (attributes >> 2) & 0x3 {
+        0 => "Data",
+        1 => "Instruction",
+        _ => "Unified",
+    };
+    let policy = if attributes & 0x10 != 0 {
+        "Write-Through"
+    } else {
+        "Write-Back"
+    };
+    let size_str = if size >= 1024 && size % 1024 == 0 {
+        format!("{} KB", size / 1024)
+    } else {
+        format!("{} bytes", size)
+    };
+    format!(
+        "{} {}, {}-way, {} B lines, {}",
+        cache_type, size_str, ways, line_size, policy
+    )
+}
+
+/// Parses the Processor Properties Topology Table (PPTT).
+///
+/// Processor nodes are listed as a tree by resolving their parent offsets; each node's
+/// private caches are shown beneath it, followed along their next-level-of-cache chain.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the PPTT table.
+///
+/// # Returns
+/// A vector of (field name, value) pairs, empty if the table is not a valid PPTT.
+pub fn parse_pptt(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 36 || clean_str(&data[0..4]) != "PPTT" {
+        return info;
+    }
+    let read_u32 = |at: usize| LittleEndian::read_u32(&data[at..at + 4]) as usize;
+
+    // (offset, parent offset) of each processor node, and the cache node offsets
+    let end = read_u32(4).min(data.len());
+    let mut processors: Vec<(usize, usize)> = Vec::new();
+    let mut cache_count = 0;
+    let mut pos = 36;
+    while pos + 4 <= end {
+        let length = data[pos + 1] as usize;
+        if length < 4 || pos + length > end {
+            info.push((
+                "Error".to_string(),
+                format!("Invalid structure length {} at offset 0x{:X}", length, pos),
+            ));
+            break;
+        }
+        match data[pos] {
+            0 if length >= 20 => processors.push((pos, read_u32(pos + 8))),
+            1 if length >= 24 => cache_count += 1,
+            _ => {}
+        }
+        pos += length;
+    }
+    info.push((
+        "Processor Nodes".to_string(),
+        format!("{}", processors.len()),
+    ));
+    info.push(("Cache Nodes".to_string(), format!("{}", cache_count)));
+
+    // Depth-first from the roots; a depth limit guards against parent cycles
+    let mut stack: Vec<(usize, usize)> = processors
+        .iter()
+        .rev()
+        .filter(|(_, parent)| *parent == 0 || !processors.iter().any(|(o, _)| o == parent))
+        .map(|&(offset, _)| (offset, 0))
+        .collect();
+    while let Some((offset, depth)) = stack.pop() {
+        if depth > 16 {
+            continue;
+        }
+        let indent = "  ".repeat(depth);
+        let flags = read_u32(offset + 4) as u32;
+        let acpi_id = read_u32(offset + 12);
+        let names = decode_flags(flags as u64, PPTT_PROCESSOR_FLAG_DEFS);
+        let id = if flags & 0x2 != 0 {
+            format!("ACPI ID {}", acpi_id)
+        } else {
+            "No ACPI ID".to_string()
+        };
+        info.push((
+            format!("{}Processor @0x{:X}", indent, offset),
+            if names.is_empty() {
+                id
+            } else {
+                format!("{} ({})", id, names.join(", "))
+            },
+        ));
+
+        let length = data[offset + 1] as usize;
+        let resources = read_u32(offset + 16);
+        for i in 0..resources {
+            let at = offset + 20 + i * 4;
+            if at + 4 > offset + length {
+                break;
+            }
+            // Follow the cache chain (L1 -> L2 -> ...) with a hop limit
+            let mut cache = read_u32(at);
+            let mut level = 0;
+            while cache != 0 && level < 8 {
+                if cache + 24 > end || data[cache] != 1 {
+                    info.push((
+                        format!("{}  Resource @0x{:X}", indent, cache),
+                        "Not a cache structure".to_string(),
+                    ));
+                    break;
+                }
+                info.push((
+                    format!("{}  {}Cache @0x{:X}", indent, "-> ".repeat(level), cache),
+                    pptt_cache_summary(data, cache),
+                ));
+                cache = read_u32(cache + 8);
+                level += 1;
+            }
+        }
+
+        for &(child, _) in processors
+            .iter()
+            .rev()
+            .filter(|(_, parent)| *parent == offset)
+        {
+            stack.push((child, depth + 1));
+        }
+    }
+    info
+}
+
+/// Bit definitions for the SPCR Interrupt Type field.
+const SPCR_INTERRUPT_TYPE_DEFS: &[(u32, &str)] = &[
+    (0, "PC-AT 8259 IRQ"),
+    (1, "I/O APIC"),
+    (2, "I/O SAPIC"),
+    (3, "ARMH GIC"),
+    (4, "RISC-V PLIC/APLIC"),
+];
+
+/// Bit definitions for the SPCR Flow Control field.
+const SPCR_FLOW_CONTROL_DEFS: &[(u32, &str)] = &[(0, "DCD"), (1, "RTS/CTS"), (2, "XON/XOFF")];
+
+/// Decodes the serial interface subtype used by SPCR and DBG2.
+fn serial_interface_type_str(code: u8) -> String {
+    match code {
+        0x00 => "16550 compatible".to_string(),
+        0x01 => "16450 compatible".to_string(),
+        0x02 => "MAX311xE SPI UART".to_string(),
+        0x03 => "Arm PL011 UART".to_string(),
+        0x04 => "Qualcomm MSM8x60".to_string(),
+        0x05 => "NVIDIA 16550".to_string(),
+        0x06 => "TI OMAP".to_string(),
+        0x08 => "APM88xxxx".to_string(),
+        0x09 => "Qualcomm MSM8974".to_string(),
+        0x0A => "Samsung SAM5250".to_string(),
+        0x0B => "Intel USIF".to_string(),
+        0x0C => "i.MX 6".to_string(),
+        0x0D => "Arm SBSA (32-bit only)".to_string(),
+        0x0E => "Arm SBSA Generic UART".to_string(),
+        0x0F => "Arm DCC".to_string(),
+        0x10 => "BCM2835".to_string(),
+        0x11 => "SDM845 (1.8432 MHz)".to_string(),
+        0x12 => "16550 with GAS".to_string(),
+        0x13 => "SDM845 (7.372 MHz)".to_string(),
+        0x14 => "Intel LPSS".to_string(),
+        0x15 => "RISC-V SBI console".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Returns true for the OEM activation tables grouped under "Licensing" (SLIC and MSDM).
+pub fn is_licensing_table(signature: &str) -> bool {
+    matches!(signature, "SLIC" | "MSDM")
+}
+
+/// Extracts the product key stored in an MSDM table.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the MSDM table.
+///
+/// # Returns
+/// The key as stored, or `None` if the table is not a valid MSDM.
+pub fn msdm_product_key(data: &[u8]) -> Option<String> {
+    if data.len() < 56 || clean_str(&data[0..4]) != "MSDM" {
+        return None;
+    }
+    let length = LittleEndian::read_u32(&data[52..56]) as usize;
+    let key = data.get(56..56usize.checked_add(length)?)?;
+    Some(clean_str(key).trim().to_string())
+}
+
+/// Masks a product key, keeping only its last group visible like Windows does.
+pub fn mask_product_key(key: &str) -> String {
+    let visible = key.rsplit('-').next().unwrap_or("").len();
+    let hidden = key.len() - visible;
+    key.char_indices()
+        .map(|(i, c)| if i < hidden && c != '-' { 'X' } else { c })
+        .collect()
+}
+
+/// Parses the Microsoft Data Management table (MSDM).
+///
+/// The product key is masked; callers that let the user reveal it use [`msdm_product_key`].
+///
+/// # Arguments
+/// * `data` - The raw binary data of the MSDM table.
+///
+/// # Returns
+/// A vector of (field name, value) pairs, empty if the table is not a valid MSDM.
+pub fn parse_msdm(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    let Some(key) = msdm_product_key(data) else {
+        return info;
+    };
+    let read_u32 = |at: usize| LittleEndian::read_u32(&data[at..at + 4]);
+
+    info.push(("Version".to_string(), format!("{}", read_u32(36))));
+    info.push((
+        "Data Type".to_string(),
+        match read_u32(44) {
+            1 => "Product Key".to_string(),
+            other => format!("Unknown ({})", other),
+        },
+    ));
+    info.push(("Data Length".to_string(), format!("{}", read_u32(52))));
+    info.push(("Product Key".to_string(), mask_product_key(&key)));
+    info
+}
+
+/// Parses the Software Licensing Description Table (SLIC).
+///
+/// Only the header fields of the OEM public key and Windows marker are decoded; the key
+/// modulus and marker signature are reported by size rather than dumped.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the SLIC table.
+///
+/// # Returns
+/// A vector of (field name, value) pairs, empty if the table is not a valid SLIC.
+pub fn parse_slic(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 36 || clean_str(&data[0..4]) != "SLIC" {
+        return info;
+    }
+    let read_u32 = |at: usize| LittleEndian::read_u32(&data[at..at + 4]);
+
+    let end = (read_u32(4) as usize).min(data.len());
+    let mut pos = 36;
+    while pos + 8 <= end {
+        let kind = read_u32(pos);
+        let length = read_u32(pos + 4) as usize;
+        if length < 8 || pos + length > end {
+            info.push((
+                "Error".to_string(),
+                format!("Invalid structure length {} at offset 0x{:X}", length, pos),
+            ));
+            break;
+        }
+        match kind {
+            // OEM Public Key: a Windows PUBLICKEYBLOB holding an RSA key
+            0 if length >= 0x1C => {
+                info.push((
+                    "Public Key Type".to_string(),
+                    match data[pos + 8] {
+                        0x06 => "PUBLICKEYBLOB".to_string(),
+                        other => format!("Unknown (0x{:02X})", other),
+                    },
+                ));
+                info.push((
+                    "Public Key Version".to_string(),
+                    format!("{}", data[pos + 9]),
+                ));
+                info.push((
+                    "Public Key Algorithm".to_string(),
+                    match read_u32(pos + 12) {
+                        0x2400 => "RSA Signature (0x2400)".to_string(),
+                        other => format!("Unknown (0x{:08X})", other),
+                    },
+                ));
+                info.push((
+                    "Public Key Magic".to_string(),
+                    clean_str(&data[pos + 16..pos + 20]),
+                ));
+                info.push((
+                    "Public Key Bit Length".to_string(),
+                    format!("{}", read_u32(pos + 20)),
+                ));
+                info.push((
+                    "Public Key Exponent".to_string(),
+                    format!("{}", read_u32(pos + 24)),
+                ));
+                info.push((
+                    "Public Key Modulus".to_string(),
+                    format!("{} bytes (not shown)", length - 0x1C),
+                ));
+            }
+            // Windows Marker
+            1 if length >= 0x36 => {
+                let version = read_u32(pos + 8);
+                info.push((
+                    "Marker Version".to_string(),
+                    format!("{}.{} (0x{:08X})", version >> 16, version & 0xFFFF, version),
+                ));
+                info.push((
+                    "Marker OEM ID".to_string(),
+                    clean_str(&data[pos + 12..pos + 18]),
+                ));
+                info.push((
+                    "Marker OEM Table ID".to_string(),
+                    clean_str(&data[pos + 18..pos + 26]),
+                ));
+                info.push((
+                    "Windows Flag".to_string(),
+                    clean_str(&data[pos + 26..pos + 34]).trim_end().to_string(),
+                ));
+                let slic_version = read_u32(pos + 34);
+                info.push((
+                    "SLIC Version".to_string(),
+                    format!(
+                        "{}.{} (0x{:08X})",
+                        slic_version >> 16,
+                        slic_version & 0xFFFF,
+                        slic_version
+                    ),
+                ));
+                info.push((
+                    "Marker Signature".to_string(),
+                    format!("{} bytes (not shown)", length - 0x36),
+                ));
+            }
+            _ => info.push((
+                format!("Structure {}", kind),
+                format!("{} bytes at offset 0x{:X}", length, pos),
+            )),
+        }
+        pos += length;
+    }
+    info
+}
+
+/// Bit definitions for the WAET Emulated Device Flags field.
+const WAET_FLAG_DEFS: &[(u32, &str)] = &[(0, "RTC Good"), (1, "PM Timer Good")];
+
+/// Parses the Windows ACPI Emulated Devices Table (WAET).
+///
+/// # Arguments
+/// * `data` - The raw binary data of the WAET table.
+///
+/// # Returns
+/// A vector of (field name, value) pairs, empty if the table is not a valid WAET.
+pub fn parse_waet(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 40 || clean_str(&data[0..4]) != "WAET" {
+        return info;
+    }
+
+    let flags = LittleEndian::read_u32(&data[36..40]);
+    info.push((
+        "Emulated Device Flags".to_string(),
+        flags_with_names(
+            format!("0x{:08X}", flags),
+            &decode_flags(flags as u64, WAET_FLAG_DEFS),
+        ),
+    ));
+    info
+}
+
+/// Bit definitions for the FACS Global Lock field.
+const FACS_GLOBAL_LOCK_DEFS: &[(u32, &str)] = &[(0, "Pending"), (1, "Owned")];
+
+/// Bit definitions for the FACS Flags field.
+const FACS_FLAG_DEFS: &[(u32, &str)] = &[(0, "S4BIOS_F"), (1, "64BIT_WAKE_SUPPORTED_F")];
+
+/// Bit definitions for the FACS OSPM Flags field.
+const FACS_OSPM_FLAG_DEFS: &[(u32, &str)] = &[(0, "64BIT_WAKE_F")];
+
+/// Parses the Firmware ACPI Control Structure (FACS).
+///
+/// The FACS has no standard 36-byte header: only the signature and length are shared with
+/// other tables, so it is decoded here on its own instead of through [`parse_acpi_details`].
+/// The X Firmware Waking Vector (ACPI 2.0) and the Version and OSPM Flags (ACPI 4.0) are
+/// reported when the structure is long enough to hold them.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the FACS.
+///
+/// # Returns
+/// A vector of (field name, value) pairs, empty if the data is not a valid FACS.
+pub fn parse_facs(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 0x18 || !data.starts_with(b"FACS") {
+        return info;
+    }
+
+    info.push((
+        "Length".to_string(),
+        format!("{}", LittleEndian::read_u32(&data[0x04..0x08])),
+    ));
+    info.push((
+        "Hardware Signature".to_string(),
+        format!("0x{:08X}", LittleEndian::read_u32(&data[0x08..0x0C])),
+    ));
+    info.push((
+        "Firmware Waking Vector".to_string(),
+        format!("0x{:08X}", LittleEndian::read_u32(&data[0x0C..0x10])),
+    ));
+    let lock = LittleEndian::read_u32(&data[0x10..0x14]);
+    info.push((
+        "Global Lock".to_string(),
+        flags_with_names(
+            format!("0x{:08X}", lock),
+            &decode_flags(lock as u64, FACS_GLOBAL_LOCK_DEFS),
+        ),
+    ));
+    let flags = LittleEndian::read_u32(&data[0x14..0x18]);
+    info.push((
+        "Flags".to_string(),
+        flags_with_names(
+            format!("0x{:08X}", flags),
+            &decode_flags(flags as u64, FACS_FLAG_DEFS),
+        ),
+    ));
+    if data.len() >= 0x20 {
+        info.push((
+            "X Firmware Waking Vector".to_string(),
+            format!("0x{:016X}", LittleEndian::read_u64(&data[0x18..0x20])),
+        ));
+    }
+    if data.len() >= 0x28 {
+        info.push(("Version".to_string(), format!("{}", data[0x20])));
+        let ospm = LittleEndian::read_u32(&data[0x24..0x28]);
+        info.push((
+            "OSPM Flags".to_string(),
+            flags_with_names(
+                format!("0x{:08X}", ospm),
+                &decode_flags(ospm as u64, FACS_OSPM_FLAG_DEFS),
+            ),
+        ));
+    }
+    info
+}
+
+/// One ECAM window described by an MCFG configuration space base address allocation.
+pub struct EcamRegion {
+    /// Physical address that bus 0 of the segment would map to.
+    pub base_address: u64,
+    /// PCI segment group number.
+    pub segment: u16,
+    /// First bus decoded by the window.
+    pub start_bus: u8,
+    /// Last bus decoded by the window.
+    pub end_bus: u8,
+}
+
+impl EcamRegion {
+    /// Returns the physical address of the configuration space of a bus/device/function.
+    ///
+    /// # Returns
+    /// The address, or `None` if the bus is outside the window or the device or function
+    /// number is out of range.
+    pub fn config_address(&self, bus: u8, device: u8, function: u8) -> Option<u64> {
+        if bus < self.start_bus || bus > self.end_bus || device > 31 || function > 7 {
+            return None;
+        }
+        let offset = ((bus as u64) << 20) | ((device as u64) << 15) | ((function as u64) << 12);
+        self.base_address.checked_add(offset)
+    }
+}
+
+/// Parses the configuration space base address allocations of an MCFG table.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the MCFG table.
+///
+/// # Returns
+/// One region per allocation, empty if the table is not a valid MCFG.
+pub fn parse_mcfg_regions(data: &[u8]) -> Vec<EcamRegion> {
+    let mut regions = Vec::new();
+    if data.len() < 44 || clean_str(&data[0..4]) != "MCFG" {
+        return regions;
+    }
+    let length = (LittleEndian::read_u32(&data[4..8]) as usize).min(data.len());
+    let mut pos = 44;
+    while pos + 16 <= length {
+        regions.push(EcamRegion {
+            base_address: LittleEndian::read_u64(&data[pos..pos + 8]),
+            segment: LittleEndian::read_u16(&data[pos + 8..pos + 10]),
+            start_bus: data[pos + 10],
+            end_bus: data[pos + 11],
+        });
+        pos += 16;
+    }
+    regions
+}
+
+/// Parses the PCI Express Memory-mapped Configuration Table (MCFG).
+///
+/// # Arguments
+/// * `data` - The raw binary data of the MCFG table.
+///
+/// # Returns
+/// A vector of (field name, value) pairs, one per ECAM window, empty if the table is not a
+/// valid MCFG.
+pub fn parse_mcfg(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 44 || clean_str(&data[0..4]) != "MCFG" {
+        return info;
+    }
+
+    let regions = parse_mcfg_regions(data);
+    if regions.is_empty() {
+        info.push(("Allocations".to_string(), "None".to_string()));
+    }
+    for (i, region) in regions.iter().enumerate() {
+        let buses = (region.end_bus as u64 + 1).saturating_sub(region.start_bus as u64);
+        let window = region.base_address + ((region.start_bus as u64) << 20);
+        info.push((
+            format!("Allocation {}", i),
+            format!(
+                "Segment {:04X}, Buses {:02X}-{:02X}, ECAM 0x{:016X} ({} MB)",
+                region.segment, region.start_bus, region.end_bus, window, buses
+            ),
+        ));
+    }
+    info
+}
+
+/// Decodes a DBG2 debug device's port type and subtype.
+fn dbg2_port_str(port_type: u16, subtype: u16) -> String {
+    match port_type {
+        0x8000 => match u8::try_from(subtype) {
+            Ok(code) => format!("Serial, {}", serial_interface_type_str(code)),
+            Err(_) => format!("Serial, Unknown (0x{:04X})", subtype),
+        },
+        0x8001 => match subtype {
+            0 => "IEEE 1394, Standard OHCI".to_string(),
+            _ => format!("IEEE 1394, Unknown (0x{:04X})", subtype),
+        },
+        0x8002 => match subtype {
+            0 => "USB, XHCI Debug".to_string(),
+            1 => "USB, EHCI Debug".to_string(),
+            _ => format!("USB, Unknown (0x{:04X})", subtype),
+        },
+        // Network subtypes are the controller's PCI vendor ID
+        0x8003 => format!("Network, Vendor 0x{:04X}", subtype),
+        _ => format!("Unknown (0x{:04X}), Subtype 0x{:04X}", port_type, subtype),
+    }
+}
+
+/// Parses the Debug Port Table 2 (DBG2), listing each debug device and its registers.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the DBG2 table.
+///
+/// # Returns
+/// A vector of (field name, value) pairs, empty if the table is not a valid DBG2.
+pub fn parse_dbg2(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 44 || clean_str(&data[0..4]) != "DBG2" {
+        return info;
+    }
+    let read_u16 = |at: usize| LittleEndian::read_u16(&data[at..at + 2]) as usize;
+
+    let end = (LittleEndian::read_u32(&data[4..8]) as usize).min(data.len());
+    let first = LittleEndian::read_u32(&data[36..40]) as usize;
+    let count = LittleEndian::read_u32(&data[40..44]);
+    info.push(("Device Count".to_string(), format!("{}", count)));
+
+    let mut pos = first;
+    for index in 0..count {
+        if pos + 22 > end {
+            info.push((
+                "Error".to_string(),
+                format!("Device {} at offset 0x{:X} is truncated", index, pos),
+            ));
+            break;
+        }
+        let length = read_u16(pos + 1);
+        if length < 22 || pos + length > end {
+            info.push((
+                "Error".to_string(),
+                format!("Invalid device length {} at offset 0x{:X}", length, pos),
+            ));
+            break;
+        }
+        let device = &data[pos..pos + length];
+        let prefix = format!("Device {}", index);
+        info.push((
+            format!("{} Port", prefix),
+            dbg2_port_str(
+                LittleEndian::read_u16(&device[12..14]),
+                LittleEndian::read_u16(&device[14..16]),
+            ),
+        ));
+
+        let namespace_len = LittleEndian::read_u16(&device[4..6]) as usize;
+        let namespace_at = LittleEndian::read_u16(&device[6..8]) as usize;
+        if let Some(name) = namespace_at
+            .checked_add(namespace_len)
+            .and_then(|stop| device.get(namespace_at..stop))
+        {
+            info.push((format!("{} Namespace", prefix), clean_str(name)));
+        }
+
+        let registers = device[3] as usize;
+        let gas_at = LittleEndian::read_u16(&device[18..20]) as usize;
+        let sizes_at = LittleEndian::read_u16(&device[20..22]) as usize;
+        info.push((
+            format!("{} Base Address Registers", prefix),
+            format!("{}", registers),
+        ));
+        for reg in 0..registers {
+            let Some(gas) = device.get(gas_at + reg * 12..gas_at + reg * 12 + 12) else {
+                break;
+            };
+            let mut value = parse_gas(gas);
+            if let Some(size) = device.get(sizes_at + reg * 4..sizes_at + reg * 4 + 4) {
+                value.push_str(&format!(", Size 0x{:X}", LittleEndian::read_u32(size)));
+            }
+            info.push((format!("{} Register {}", prefix, reg), value));
+        }
+        pos += length;
+    }
+    info
+}
+
+/// Decodes the SPCR Configured Baud Rate field.
+fn spcr_baud_rate_str(code: u8) -> String {
+    match code {
+        0 => "As is (firmware setting)".to_string(),
+        3 => "9600".to_string(),
+        4 => "19200".to_string(),
+        6 => "57600".to_string(),
+        7 => "115200".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Decodes the SPCR Terminal Type field.
+fn spcr_terminal_type_str(code: u8) -> String {
+    match code {
+        0 => "VT100".to_string(),
+        1 => "Extended VT100 (VT100+)".to_string(),
+        2 => "VT-UTF8".to_string(),
+        3 => "ANSI".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Parses the Serial Port Console Redirection table (SPCR).
+///
+/// # Arguments
+/// * `data` - The raw binary data of the SPCR table.
+///
+/// # Returns
+/// A vector of (field name, value) pairs, empty if the table is not a valid SPCR.
+pub fn parse_spcr(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 80 || clean_str(&data[0..4]) != "SPCR" {
+        return info;
+    }
+
+    info.push((
+        "Interface Type".to_string(),
+        serial_interface_type_str(data[36]),
+    ));
+    info.push(("Base Address".to_string(), parse_gas(&data[40..52])));
+    let interrupt_type = data[52];
+    info.push((
+        "Interrupt Type".to_string(),
+        flags_with_names(
+            format!("0x{:02X}", interrupt_type),
+            &decode_flags(interrupt_type as u64, SPCR_INTERRUPT_TYPE_DEFS),
+        ),
+    ));
+    info.push(("IRQ".to_string(), format!("{}", data[53])));
+    info.push((
+        "Global System Interrupt".to_string(),
+        format!("{}", LittleEndian::read_u32(&data[54..58])),
+    ));
+    info.push(("Baud Rate".to_string(), spcr_baud_rate_str(data[58])));
+    info.push((
+        "Parity".to_string(),
+        match data[59] {
+            0 => "None".to_string(),
+            other => format!("Unknown (0x{:02X})", other),
+        },
+    ));
+    info.push((
+        "Stop Bits".to_string(),
+        match data[60] {
+            1 => "1".to_string(),
+            other => format!("Unknown (0x{:02X})", other),
+        },
+    ));
+    let flow_control = data[61];
+    info.push((
+        "Flow Control".to_string(),
+        flags_with_names(
+            format!("0x{:02X}", flow_control),
+            &decode_flags(flow_control as u64, SPCR_FLOW_CONTROL_DEFS),
+        ),
+    ));
+    info.push((
+        "Terminal Type".to_string(),
+        spcr_terminal_type_str(data[62]),
+    ));
+
+    let device_id = LittleEndian::read_u16(&data[64..66]);
+    let vendor_id = LittleEndian::read_u16(&data[66..68]);
+    if vendor_id == 0xFFFF {
+        info.push((
+            "PCI Device".to_string(),
+            "None (not a PCI device)".to_string(),
+        ));
+    } else {
+        info.push(("PCI Device ID".to_string(), format!("0x{:04X}", device_id)));
+        info.push(("PCI Vendor ID".to_string(), format!("0x{:04X}", vendor_id)));
+        info.push((
+            "PCI Address".to_string(),
+            format!(
+                "{:04X}:{:02X}:{:02X}.{:X}",
+                data[75], data[68], data[69], data[70]
+            ),
+        ));
+        info.push((
+            "PCI Flags".to_string(),
+            format!("0x{:08X}", LittleEndian::read_u32(&data[71..75])),
+        ));
+    }
+
+    // UART Clock Frequency was added in revision 3
+    if data[8] >= 3 {
+        match LittleEndian::read_u32(&data[76..80]) {
+            0 => {}
+            hz => info.push(("UART Clock Frequency".to_string(), format!("{} Hz", hz))),
+        }
+    }
+    info
+}
+
+/// Bit definitions for GTDT timer interrupt flags.
+const GTDT_TIMER_FLAG_DEFS: &[(u32, &str)] =
+    &[(0, "Edge Triggered"), (1, "Active Low"), (2, "Always-on")];
+
+/// Parses the Generic Timer Description Table (GTDT) used on Arm platforms.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the GTDT table.
+///
+/// # Returns
+/// A vector of (field name, value) pairs, empty if the table is not a valid GTDT.
+pub fn parse_gtdt(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 96 || clean_str(&data[0..4]) != "GTDT" {
+        return info;
+    }
+    let read_u32 = |at: usize| LittleEndian::read_u32(&data[at..at + 4]);
+    let read_u64 = |at: usize| LittleEndian::read_u64(&data[at..at + 8]);
+
+    info.push((
+        "CntControlBase".to_string(),
+        format!("0x{:016X}", read_u64(36)),
+    ));
+    info.push((
+        "CntReadBase".to_string(),
+        format!("0x{:016X}", read_u64(80)),
+    ));
+
+    let mut timers = vec![
+        ("Secure EL1 Timer", 48),
+        ("Non-Secure EL1 Timer", 56),
+        ("Virtual EL1 Timer", 64),
+        ("EL2 Timer", 72),
+    ];
+    // Virtual EL2 timer fields were added in revision 3
+    if data[8] >= 3 && data.len() >= 104 {
+        timers.push(("Virtual EL2 Timer", 96));
+    }
+    for (name, at) in timers {
+        let flags = read_u32(at + 4);
+        info.push((format!("{} GSIV", name), format!("{}", read_u32(at))));
+        info.push((
+            format!("{} Flags", name),
+            flags_with_names(
+                format!("0x{:08X}", flags),
+                &decode_flags(flags as u64, GTDT_TIMER_FLAG_DEFS),
+            ),
+        ));
+    }
+
+    let count = read_u32(88);
+    let offset = read_u32(92) as usize;
+    info.push(("Platform Timer Count".to_string(), format!("{}", count)));
+    info.push(("Platform Timer Offset".to_string(), format!("{}", offset)));
+
+    let end = (read_u32(4) as usize).min(data.len());
+    let mut pos = offset;
+    for i in 0..count as usize {
+        if pos + 4 > end {
+            break;
+        }
+        let timer_type = data[pos];
+        let length = LittleEndian::read_u16(&data[pos + 1..pos + 3]) as usize;
+        if length < 4 || pos + length > end {
+            break;
+        }
+        let prefix = format!("Platform Timer {}", i);
+        match timer_type {
+            0 if length >= 20 => {
+                info.push((format!("{} Type", prefix), "GT Block".to_string()));
+                info.push((
+                    format!("{} CntCtlBase", prefix),
+                    format!("0x{:016X}", read_u64(pos + 4)),
+                ));
+                info.push((
+                    format!("{} Block Timer Count", prefix),
+                    format!("{}", read_u32(pos + 12)),
+                ));
+            }
+            1 if length >= 28 => {
+                let flags = read_u32(pos + 24);
+                info.push((
+                    format!("{} Type", prefix),
+                    "Arm Generic Watchdog".to_string(),
+                ));
+                info.push((
+                    format!("{} Refresh Frame", prefix),
+                    format!("0x{:016X}", read_u64(pos + 4)),
+                ));
+                info.push((
+                    format!("{} Control Frame", prefix),
+                    format!("0x{:016X}", read_u64(pos + 12)),
+                ));
+                info.push((
+                    format!("{} GSIV", prefix),
+                    format!("{}", read_u32(pos + 20)),
+                ));
+                info.push((
+                    format!("{} Flags", prefix),
+                    flags_with_names(
+                        format!("0x{:08X}", flags),
+                        &decode_flags(
+                            flags as u64,
+                            &[(0, "Edge Triggered"), (1, "Active Low"), (2, "Secure")],
+                        ),
+                    ),
+                ));
+            }
+            _ => info.push((
+                format!("{} Type", prefix),
+                format!("Unknown (0x{:02X}) at offset 0x{:X}", timer_type, pos),
+            )),
+        }
+        pos += length;
+    }
+    info
+}
+
+/// Bit definitions for the FADT Flags field (offset 112).
+const FADT_FLAG_DEFS: &[(u32, &str)] = &[
+    (0, "WBINVD"),
+    (1, "WBINVD_FLUSH"),
+    (2, "PROC_C1"),
+    (3, "P_LVL2_UP"),
+    (4, "PWR_BUTTON"),
+    (5, "SLP_BUTTON"),
+    (6, "FIX_RTC"),
+    (7, "RTC_S4"),
+    (8, "TMR_VAL_EXT"),
+    (9, "DCK_CAP"),
+    (10, "RESET_REG_SUP"),
+    (11, "SEALED_CASE"),
+    (12, "HEADLESS"),
+    (13, "CPU_SW_SLP"),
+    (14, "PCI_EXP_WAK"),
+    (15, "USE_PLATFORM_CLOCK"),
+    (16, "S4_RTC_STS_VALID"),
+    (17, "REMOTE_POWER_ON_CAPABLE"),
+    (18, "FORCE_APIC_CLUSTER_MODEL"),
+    (19, "FORCE_APIC_PHYSICAL_DESTINATION_MODE"),
+    (20, "HW_REDUCED_ACPI"),
+    (21, "LOW_POWER_S0_IDLE_CAPABLE"),
+];
+
+/// Bit definitions for the FADT IA-PC Boot Architecture Flags field (offset 109).
+const FADT_IAPC_BOOT_ARCH_DEFS: &[(u32, &str)] = &[
+    (0, "LEGACY_DEVICES"),
+    (1, "8042"),
+    (2, "VGA Not Present"),
+    (3, "MSI Not Supported"),
+    (4, "PCIe ASPM Controls"),
+    (5, "CMOS RTC Not Present"),
+];
+
+/// Returns the names of all bits set in `value`.
+///
+/// # Arguments
+/// * `value` - The raw bitmask.
+/// * `defs` - Pairs of bit position and flag name.
+///
+/// # Returns
+/// The names of the defined bits that are set, in the order they appear in `defs`.
+pub fn decode_flags(value: u64, defs: &[(u32, &str)]) -> Vec<String> {
+    defs.iter()
+        .filter(|(bit, _)| *bit < 64 && value & (1u64 << bit) != 0)
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
+
+/// Formats a raw hex value followed by its decoded flag names in parentheses.
+fn flags_with_names(raw: String, names: &[String]) -> String {
+    if names.is_empty() {
+        raw
+    } else {
+        format!("{} ({})", raw, names.join(", "))
+    }
+}
+
+/// Offsets of the Generic Address Structure fields in the FADT (ACPI 2.0 and later).
+const FADT_GAS_FIELDS: &[(usize, &str)] = &[
+    (116, "RESET_REG"),
+    (148, "X_PM1a_EVT_BLK"),
+    (160, "X_PM1b_EVT_BLK"),
+    (172, "X_PM1a_CNT_BLK"),
+    (184, "X_PM1b_CNT_BLK"),
+    (196, "X_PM2_CNT_BLK"),
+    (208, "X_PM_TMR_BLK"),
+    (220, "X_GPE0_BLK"),
+    (232, "X_GPE1_BLK"),
+    (244, "SLEEP_CONTROL_REG"),
+    (256, "SLEEP_STATUS_REG"),
+];
+
+/// Decodes the flag and register fields of a Fixed ACPI Description Table (FADT/FACP).
+///
+/// # Arguments
+/// * `data` - The raw binary data of the FADT table.
+///
+/// # Returns
+/// A vector of (field name, value) pairs: the flag fields as raw hex followed by the set
+/// flag names, then every Generic Address Structure the table is long enough to hold.
+pub fn parse_fadt(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 4 || clean_str(&data[0..4]) != "FACP" {
+        return info;
+    }
+
+    if data.len() >= 111 {
+        let boot_arch = LittleEndian::read_u16(&data[109..111]);
+        info.push((
+            "IA-PC Boot Arch".to_string(),
+            flags_with_names(
+                format!("0x{:04X}", boot_arch),
+                &decode_flags(boot_arch as u64, FADT_IAPC_BOOT_ARCH_DEFS),
+            ),
+        ));
+    }
+    if data.len() >= 116 {
+        let flags = LittleEndian::read_u32(&data[112..116]);
+        info.push((
+            "Flags".to_string(),
+            flags_with_names(
+                format!("0x{:08X}", flags),
+                &decode_flags(flags as u64, FADT_FLAG_DEFS),
+            ),
+        ));
+    }
+    for &(offset, name) in FADT_GAS_FIELDS {
+        if let Some(gas) = data.get(offset..offset + 12) {
+            info.push((name.to_string(), parse_gas(gas)));
+        }
+    }
+    info
+}
+
+/// Parses the High Precision Event Timer table (HPET).
+///
+/// # Arguments
+/// * `data` - The raw binary data of the HPET table.
+///
+/// # Returns
+/// A vector of (field name, value) pairs, empty if the table is not a valid HPET.
+pub fn parse_hpet(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 56 || clean_str(&data[0..4]) != "HPET" {
+        return info;
+    }
+
+    let block_id = LittleEndian::read_u32(&data[36..40]);
+    info.push((
+        "Event Timer Block ID".to_string(),
+        format!("0x{:08X}", block_id),
+    ));
+    info.push((
+        "Hardware Revision".to_string(),
+        format!("{}", block_id & 0xFF),
+    ));
+    info.push((
+        "Comparators".to_string(),
+        format!("{}", ((block_id >> 8) & 0x1F) + 1),
+    ));
+    info.push((
+        "Counter Size".to_string(),
+        if block_id & (1 << 13) != 0 {
+            "64-bit".to_string()
+        } else {
+            "32-bit".to_string()
+        },
+    ));
+    info.push((
+        "Legacy Replacement IRQ Routing".to_string(),
+        if block_id & (1 << 15) != 0 {
+            "Capable".to_string()
+        } else {
+            "Not capable".to_string()
+        },
+    ));
+    info.push((
+        "PCI Vendor ID".to_string(),
+        format!("0x{:04X}", block_id >> 16),
+    ));
+    info.push(("Base Address".to_string(), parse_gas(&data[40..52])));
+    info.push(("HPET Number".to_string(), format!("{}", data[52])));
+    info.push((
+        "Minimum Clock Tick".to_string(),
+        format!("{}", LittleEndian::read_u16(&data[53..55])),
+    ));
+    info.push((
+        "Page Protection".to_string(),
+        match data[55] & 0x0F {
+            0 => "None".to_string(),
+            1 => "4 KB".to_string(),
+            2 => "64 KB".to_string(),
+            other => format!("Unknown (0x{:02X})", other),
+        },
+    ));
+    info
+}
+
+/// Decodes an AML PkgLength at `at`.
+///
+/// # Returns
+/// The package length (which counts the PkgLength bytes themselves) and the number of
+/// bytes the encoding uses, or `None` if it is malformed or runs past `limit`.
+fn aml_pkg_length(data: &[u8], at: usize, limit: usize) -> Option<(usize, usize)> {
+    let lead = *data.get(at)?;
+    let follow = (lead >> 6) as usize;
+    if at + 1 + follow > limit {
+        return None;
+    }
+    let length = if follow == 0 {
+        (lead & 0x3F) as usize
+    } else {
+        (1..=follow).fold((lead & 0x0F) as usize, |acc, i| {
+            acc | (data[at + i] as usize) << (4 + 8 * (i - 1))
+        })
+    };
+    (length > follow && at + length <= limit).then_some((length, follow + 1))
+}
+
+/// Decodes an AML NameString at `at` and resolves it against the scope path `scope`.
+///
+/// # Returns
+/// The absolute path (e.g. `\_SB.PCI0`) and the number of bytes consumed, or `None` if
+/// the bytes are not a valid NameString.
+fn aml_name_string(data: &[u8], at: usize, limit: usize, scope: &str) -> Option<(String, usize)> {
+    // Every byte read, prefixes included, must lie inside the enclosing package
+    let byte = |pos: usize| data.get(pos).copied().filter(|_| pos < limit);
+    let mut pos = at;
+    let mut path: Vec<String> = if byte(pos) == Some(b'\\') {
+        pos += 1;
+        Vec::new()
+    } else {
+        let mut parts: Vec<String> = scope
+            .trim_start_matches('\\')
+            .split('.')
+            .filter(|p| !p.is_empty())
+            .map(String::from)
+            .collect();
+        while byte(pos) == Some(b'^') {
+            parts.pop();
+            pos += 1;
+        }
+        parts
+    };
+    let count = match byte(pos)? {
+        0x00 => {
+            pos += 1;
+            0
+        }
+        0x2E => {
+            pos += 1;
+            2
+        }
+        0x2F => {
+            pos += 2;
+            byte(pos - 1)? as usize
+        }
+        _ => 1,
+    };
+    for _ in 0..count {
+        let seg = data.get(pos..pos + 4).filter(|_| pos + 4 <= limit)?;
+        let valid = seg
+            .iter()
+            .enumerate()
+            .all(|(i, &c)| c.is_ascii_uppercase() || c == b'_' || (i > 0 && c.is_ascii_digit()));
+        if !valid {
+            return None;
+        }
+        // Trailing underscores are padding, as in `_SB_`
+        let name = String::from_utf8_lossy(seg);
+        let trimmed = name.trim_end_matches('_');
+        path.push(if trimmed.is_empty() { "_" } else { trimmed }.to_string());
+        pos += 4;
+    }
+    Some((format!("\\{}", path.join(".")), pos - at))
+}
+
+/// One declaration found by [`walk_aml`].
+struct AmlDeclaration {
+    /// `Scope`, `Device`, `Processor`, `PowerResource`, `ThermalZone`, `Method`, or `Name`.
+    kind: &'static str,
+    /// Absolute namespace path, e.g. `\_SB.PCI0`.
+    path: String,
+    /// Offset of the declaring opcode within the table.
+    offset: usize,
+    /// Argument count of a method.
+    args: Option<u8>,
+}
+
+/// Walks the AML of a DSDT or SSDT and lists its namespace declarations in table order.
+///
+/// This is a lightweight opcode-aware walk rather than a full disassembly: Scope, Device,
+/// Processor, PowerResource, and ThermalZone bodies are entered, method bodies and
+/// control-flow or field packages are skipped by their PkgLength, and declared names are
+/// resolved to absolute paths.
+fn walk_aml(data: &[u8]) -> Vec<AmlDeclaration> {
+    let mut found = Vec::new();
+    if data.len() < 36 {
+        return found;
+    }
+    let end = (LittleEndian::read_u32(&data[4..8]) as usize).min(data.len());
+    let mut scopes: Vec<(String, usize)> = vec![("\\".to_string(), end)];
+    let mut pos = 36;
+
+    while pos < end {
+        while scopes.len() > 1 && pos >= scopes[scopes.len() - 1].1 {
+            scopes.pop();
+        }
+        let (scope, limit) = scopes[scopes.len() - 1].clone();
+
+        // (kind, opcode length, extra fixed bytes after the name) for body-bearing ops
+        let block = match (data[pos], data.get(pos + 1)) {
+            (0x10, _) => Some(("Scope", 1, 0)),
+            (0x5B, Some(0x82)) => Some(("Device", 2, 0)),
+            (0x5B, Some(0x83)) => Some(("Processor", 2, 6)),
+            (0x5B, Some(0x84)) => Some(("PowerResource", 2, 3)),
+            (0x5B, Some(0x85)) => Some(("ThermalZone", 2, 0)),
+            _ => None,
+        };
+        if let Some((kind, op_len, extra)) = block {
+            let pkg_at = pos + op_len;
+            if let Some((length, pkg_bytes)) = aml_pkg_length(data, pkg_at, limit) {
+                if let Some((path, name_len)) =
+                    aml_name_string(data, pkg_at + pkg_bytes, pkg_at + length, &scope)
+                {
+                    found.push(AmlDeclaration {
+                        kind,
+                        path: path.clone(),
+                        offset: pos,
+                        args: None,
+                    });
+                    scopes.push((path, pkg_at + length));
+                    pos = pkg_at + pkg_bytes + name_len + extra;
+                    continue;
+                }
+            }
+            pos += 1;
+            continue;
+        }
+
+        match data[pos] {
+            // MethodOp: record it and skip the body
+            0x14 => {
+                if let Some((length, pkg_bytes)) = aml_pkg_length(data, pos + 1, limit) {
+                    let name_at = pos + 1 + pkg_bytes;
+                    if let Some((path, name_len)) =
+                        aml_name_string(data, name_at, pos + 1 + length, &scope)
+                    {
+                        let args = data.get(name_at + name_len).map_or(0, |f| f & 0x07);
+                        found.push(AmlDeclaration {
+                            kind: "Method",
+                            path,
+                            offset: pos,
+                            args: Some(args),
+                        });
+                        pos += 1 + length;
+                        continue;
+                    }
+                }
+                pos += 1;
+            }
+            // NameOp: record it and skip a simple data object that follows
+            0x08 => match aml_name_string(data, pos + 1, limit, &scope) {
+                Some((path, name_len)) => {
+                    found.push(AmlDeclaration {
+                        kind: "Name",
+                        path,
+                        offset: pos,
+                        args: None,
+                    });
+                    let value_at = pos + 1 + name_len;
+                    pos = match data.get(value_at) {
+                        Some(0x0A) => value_at + 2,
+                        Some(0x0B) => value_at + 3,
+                        Some(0x0C) => value_at + 5,
+                        Some(0x0E) => value_at + 9,
+                        Some(0x0D) => data
+                            .get(value_at..limit)
+                            .and_then(|s| s.iter().position(|&b| b == 0))
+                            .map_or(limit, |n| value_at + n + 1),
+                        Some(0x11..=0x13) => aml_pkg_length(data, value_at + 1, limit)
+                            .map_or(value_at + 1, |(length, _)| value_at + 1 + length),
+                        _ => value_at,
+                    };
+                }
+                None => pos += 1,
+            },
+            // Buffer, Package, VarPackage, If, Else, While: skip the whole package
+            0x11..=0x13 | 0xA0..=0xA2 => {
+                pos += aml_pkg_length(data, pos + 1, limit).map_or(1, |(length, _)| 1 + length);
+            }
+            // Field, IndexField, BankField
+            0x5B if matches!(data.get(pos + 1), Some(0x81 | 0x86 | 0x87)) => {
+                pos += aml_pkg_length(data, pos + 2, limit).map_or(1, |(length, _)| 2 + length);
+            }
+            _ => pos += 1,
+        }
+    }
+    found
+}
+
+/// Scans the AML of a DSDT or SSDT for Device, Method, and Name declarations.
+///
+/// See [`walk_aml`] for how far the AML is decoded.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the table, including the header.
+///
+/// # Returns
+/// Device/method/name counts followed by one row per declaration.
+pub fn scan_aml_names(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 36 {
+        return info;
+    }
+    let found: Vec<(&str, String)> = walk_aml(data)
+        .into_iter()
+        .filter(|d| d.kind != "Scope")
+        .map(|d| match d.args {
+            Some(args) => (d.kind, format!("{} ({} args)", d.path, args)),
+            None => (d.kind, d.path),
+        })
+        .collect();
+
+    let count = |kind: &str| found.iter().filter(|(k, _)| *k == kind).count();
+    info.push(("Devices".to_string(), format!("{}", count("Device"))));
+    info.push(("Methods".to_string(), format!("{}", count("Method"))));
+    info.push(("Names".to_string(), format!("{}", count("Name"))));
+    for kind in ["Processor", "PowerResource", "ThermalZone"] {
+        let n = count(kind);
+        if n > 0 {
+            info.push((format!("{}s", kind), format!("{}", n)));
+        }
+    }
+    for (kind, path) in found {
+        info.push((kind.to_string(), path));
+    }
+    info
+}
+
+/// A namespace object in the scope tree of a DSDT or SSDT.
+pub struct AmlNode {
+    /// Last path segment, e.g. `GFX0`.
+    pub name: String,
+    /// Absolute namespace path, e.g. `\_SB.PCI0.GFX0`.
+    pub path: String,
+    /// `Scope`, `Device`, `Processor`, `PowerResource`, or `ThermalZone`.
+    pub kind: &'static str,
+    /// Offset of the first opcode that opened this object, or `None` for a parent that is
+    /// only implied by a child's path (e.g. a `Scope (\_SB.PCI0)` in an SSDT).
+    pub offset: Option<usize>,
+    /// Objects declared inside this one, in table order.
+    pub children: Vec<AmlNode>,
+}
+
+/// Reconstructs the namespace scope tree of a DSDT or SSDT without decoding method bodies.
+///
+/// Every Scope, Device, Processor, PowerResource, and ThermalZone found by [`walk_aml`] is
+/// placed under its parent path. Objects opened more than once, such as `\_SB` reopened by
+/// several `Scope` blocks, are merged into one node, and an object declared as a Device
+/// keeps that kind even if a later Scope reopens it.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the table, including the header.
+///
+/// # Returns
+/// The top-level objects below the root scope.
+pub fn aml_scope_tree(data: &[u8]) -> Vec<AmlNode> {
+    let mut roots: Vec<AmlNode> = Vec::new();
+    for decl in walk_aml(data) {
+        if matches!(decl.kind, "Method" | "Name") {
+            continue;
+        }
+        let segments: Vec<&str> = decl
+            .path
+            .trim_start_matches('\\')
+            .split('.')
+            .filter(|p| !p.is_empty())
+            .collect();
+        let mut level = &mut roots;
+        for (depth, segment) in segments.iter().enumerate() {
+            let index = match level.iter().position(|n| n.name == *segment) {
+                Some(index) => index,
+                None => {
+                    level.push(AmlNode {
+                        name: segment.to_string(),
+                        path: format!("\\{}", segments[..=depth].join(".")),
+                        kind: "Scope",
+                        offset: None,
+                        children: Vec::new(),
+                    });
+                    level.len() - 1
+                }
+            };
+            let node = &mut level[index];
+            if depth + 1 == segments.len() {
+                node.offset.get_or_insert(decl.offset);
+                if decl.kind != "Scope" {
+                    node.kind = decl.kind;
+                }
+            }
+            level = &mut node.children;
+        }
+    }
+    roots
+}
+
+/// Dispatches an ACPI table to a signature-specific parser for the parsed view.
+///
+/// # Arguments
+/// * `signature` - The 4-character table signature.
+/// * `data` - The raw binary data of the table, including the header.
+///
+/// # Returns
+/// `Some` with the decoded (field name, value) pairs if a parser exists for the signature.
+pub fn parse_acpi_details(signature: &str, data: &[u8]) -> Option<Vec<(String, String)>> {
+    match signature {
+        "FACP" => Some(parse_fadt(data)),
+        "HPET" => Some(parse_hpet(data)),
+        "FPDT" => Some(parse_fpdt(data)),
+        "BGRT" => Some(parse_bgrt(data)),
+        "WSMT" => Some(parse_wsmt(data)),
+        "TPM2" => Some(parse_tpm2(data)),
+        "BERT" => Some(parse_bert(data)),
+        "EINJ" => Some(parse_einj(data)),
+        "ERST" => Some(parse_erst(data)),
+        "HEST" => Some(parse_hest(data)),
+        "GTDT" => Some(parse_gtdt(data)),
+        "NFIT" => Some(parse_nfit(data)),
+        "SPCR" => Some(parse_spcr(data)),
+        "PPTT" => Some(parse_pptt(data)),
+        "IORT" => Some(parse_iort(data)),
+        "DMAR" => Some(parse_dmar(data)),
+        "SLIT" => Some(parse_slit(data)),
+        "SRAT" => Some(parse_srat(data)),
+        "WAET" => Some(parse_waet(data)),
+        "MCFG" => Some(parse_mcfg(data)),
+        "DBG2" => Some(parse_dbg2(data)),
+        "MSDM" => Some(parse_msdm(data)),
+        "SLIC" => Some(parse_slic(data)),
+        "DSDT" | "SSDT" => Some(scan_aml_names(data)),
+        _ => None,
+    }
+}
+
+/// Parses the performance record pointers of a Firmware Performance Data Table (FPDT).
+///
+/// The pointed-to FBPT/S3PT tables live in firmware-reserved memory that cannot be read
+/// from user mode, so only the pointers themselves are reported.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the FPDT table.
+///
+/// # Returns
+/// A vector of (field name, value) pairs for each performance record.
+pub fn parse_fpdt(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 36 || clean_str(&data[0..4]) != "FPDT" {
+        return info;
+    }
+
+    let end = (LittleEndian::read_u32(&data[4..8]) as usize).min(data.len());
+    let mut pos = 36;
+    let mut index = 0;
+    while pos + 4 <= end {
+        let record_type = LittleEndian::read_u16(&data[pos..pos + 2]);
+        let length = data[pos + 2] as usize;
+        let revision = data[pos + 3];
+        if length < 4 || pos + length > end {
+            break;
+        }
+
+        let prefix = format!("Record {}", index);
+        info.push((
+            format!("{} Type", prefix),
+            format!(
+                "0x{:04X} ({})",
+                record_type,
+                fpdt_record_type_str(record_type)
+            ),
+        ));
+        info.push((format!("{} Length", prefix), format!("{}", length)));
+        info.push((format!("{} Revision", prefix), format!("{}", revision)));
+        if (record_type == 0x0000 || record_type == 0x0001) && length >= 16 {
+            let pointer = LittleEndian::read_u64(&data[pos + 8..pos + 16]);
+            info.push((
+                format!("{} Pointer", prefix),
+                format!("0x{:016X} (in firmware memory, not readable)", pointer),
+            ));
+        }
+
+        pos += length;
+        index += 1;
+    }
+    info
+}
+
+/// Returns human-readable FPDT performance record type string.
+fn fpdt_record_type_str(code: u16) -> String {
+    match code {
+        0x0000 => "Firmware Basic Boot Performance Pointer".to_string(),
+        0x0001 => "S3 Performance Table Pointer".to_string(),
+        _ => "Reserved".to_string(),
+    }
+}
+
+/// Parses the boot logo metadata of a Boot Graphics Resource Table (BGRT).
+///
+/// # Arguments
+/// * `data` - The raw binary data of the BGRT table.
+///
+/// # Returns
+/// A vector of (field name, value) pairs, empty if the table is not a valid BGRT.
+pub fn parse_bgrt(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 56 || clean_str(&data[0..4]) != "BGRT" {
+        return info;
+    }
+
+    let version = LittleEndian::read_u16(&data[36..38]);
+    let status = data[38];
+    let image_type = data[39];
+    let image_address = LittleEndian::read_u64(&data[40..48]);
+    let offset_x = LittleEndian::read_u32(&data[48..52]);
+    let offset_y = LittleEndian::read_u32(&data[52..56]);
+
+    let orientation = match (status >> 1) & 0x03 {
+        0 => "0",
+        1 => "90",
+        2 => "180",
+        _ => "270",
+    };
+    info.push(("Version".to_string(), format!("{}", version)));
+    info.push((
+        "Status".to_string(),
+        format!(
+            "0x{:02X} ({}, {} degrees)",
+            status,
+            if status & 0x01 != 0 {
+                "Displayed"
+            } else {
+                "Not Displayed"
+            },
+            orientation
+        ),
+    ));
+    info.push((
+        "Image Type".to_string(),
+        match image_type {
+            0 => "Bitmap".to_string(),
+            _ => format!("Reserved (0x{:02X})", image_type),
+        },
+    ));
+    info.push((
+        "Image Address".to_string(),
+        format!("0x{:016X}", image_address),
+    ));
+    info.push(("Image Offset X".to_string(), format!("{}", offset_x)));
+    info.push(("Image Offset Y".to_string(), format!("{}", offset_y)));
+    info
+}
+
+/// Cleans a byte slice by converting it to a lossy UTF-8 string and trimming null terminators.
+fn clean_str(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes)
+        .trim_matches(char::from(0))
+        .to_string()
+}
+
+/// Parses an eXtended System Description Table (XSDT) to extract 64-bit physical address entries.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the XSDT table.
+/// * `addr_map` - A map of addresses to known signatures used for labeling entries.
+///
+/// # Returns
+/// `Option<Vec<(usize, u64, String)>>` containing the index, address, and label for each entry.
+pub fn parse_xsdt_entries(
+    data: &[u8],
+    addr_map: &std::collections::HashMap<u64, String>,
+) -> Option<Vec<(usize, u64, String)>> {
+    if data.len() < 36 {
+        return None;
+    }
+
+    let sig = clean_str(&data[0..4]);
+    if sig != "XSDT" {
+        return None;
+    }
+
+    let table_len = LittleEndian::read_u32(&data[4..8]) as usize;
+    if table_len > data.len() || table_len < 36 {
+        return None;
+    }
+
+    // XSDT entries start at offset 36 (after standard header)
+    // Each entry is 8 bytes (64-bit pointer)
+    let entries_data = &data[36..table_len];
+    let entry_count = entries_data.len() / 8;
+
+    let mut entries = Vec::new();
+    for i in 0..entry_count {
+        let offset = i * 8;
+        if offset + 8 > entries_data.len() {
+            break;
+        }
+        let addr = LittleEndian::read_u64(&entries_data[offset..offset + 8]);
+
+        let label = addr_map
+            .get(&addr)
+            .cloned()
+            .unwrap_or_else(|| format!("Entry{}", i));
+        entries.push((i, addr, label));
+    }
+
+    Some(entries)
+}
+
+/// Parses a Root System Description Table (RSDT) to extract 32-bit physical address entries.
+///
+/// The RSDT is the ACPI 1.0 root table; firmware without an XSDT lists its tables only here.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the RSDT table.
+/// * `addr_map` - A map of addresses to known signatures used for labeling entries.
+///
+/// # Returns
+/// `Option<Vec<(usize, u64, String)>>` containing the index, address, and label for each entry.
+pub fn parse_rsdt_entries(
+    data: &[u8],
+    addr_map: &std::collections::HashMap<u64, String>,
+) -> Option<Vec<(usize, u64, String)>> {
+    if data.len() < 36 {
+        return None;
+    }
+
+    let sig = clean_str(&data[0..4]);
+    if sig != "RSDT" {
+        return None;
+    }
+
+    let table_len = LittleEndian::read_u32(&data[4..8]) as usize;
+    if table_len > data.len() || table_len < 36 {
+        return None;
+    }
+
+    // RSDT entries start at offset 36 (after standard header)
+    // Each entry is 4 bytes (32-bit pointer)
+    let entries = data[36..table_len]
+        .chunks_exact(4)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let addr = LittleEndian::read_u32(chunk) as u64;
+            let label = addr_map
+                .get(&addr)
+                .cloned()
+                .unwrap_or_else(|| format!("Entry{}", i));
+            (i, addr, label)
+        })
+        .collect();
+
+    Some(entries)
+}
+
+/// An SMBIOS specification version, used to pick between decodings that changed over time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SmbiosVersion {
+    pub major: u8,
+    pub minor: u8,
+}
+
+impl SmbiosVersion {
+    /// Versions offered by the parsing override: the first version of each changed decoding
+    /// (UUID byte order in 2.6, Type 17 Extended Size in 2.7, Extended BIOS ROM Size in 3.1),
+    /// the version before each, and the latest.
+    pub const OVERRIDES: [SmbiosVersion; 6] = [
+        SmbiosVersion::new(2, 5),
+        SmbiosVersion::new(2, 6),
+        SmbiosVersion::new(2, 7),
+        SmbiosVersion::new(3, 0),
+        SmbiosVersion::new(3, 1),
+        SmbiosVersion::new(3, 8),
+    ];
+
+    pub const fn new(major: u8, minor: u8) -> SmbiosVersion {
+        SmbiosVersion { major, minor }
+    }
+}
+
+impl std::fmt::Display for SmbiosVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// Metadata for the raw SMBIOS data structure as retrieved from Windows.
+#[derive(Debug)]
+pub struct RawSMBIOSData {
+    pub _major_version: u8,
+    pub _minor_version: u8,
+    pub _dmi_revision: u8,
+    pub _length: u32,
+}
+
+impl RawSMBIOSData {
+    /// Returns the SMBIOS version the table declares.
+    pub fn version(&self) -> SmbiosVersion {
+        SmbiosVersion::new(self._major_version, self._minor_version)
+    }
+}
+
+/// Parses the header of the raw SMBIOS data blob returned by Windows APIs.
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS data buffer.
+///
+/// # Returns
+/// `Option<(RawSMBIOSData, usize)>` containing the parsed header and the size of the header in bytes.
+pub fn parse_raw_smbios_data_header(data: &[u8]) -> Option<(RawSMBIOSData, usize)> {
+    if data.len() < 8 {
+        return None;
+    }
+    // struct RawSMBIOSData {
+    //   BYTE  Used20CallingMethod;
+    //   BYTE  SMBIOSMajorVersion;
+    //   BYTE  SMBIOSMinorVersion;
+    //   BYTE  DmiRevision;
+    //   DWORD Length;
+    //   BYTE  SMBIOSTableData[];
+    // };
+    let _u20 = data[0];
+    let major = data[1];
+    let minor = data[2];
+    let dmi = data[3];
+    let length = LittleEndian::read_u32(&data[4..8]);
+
+    Some((
+        RawSMBIOSData {
+            _major_version: major,
+            _minor_version: minor,
+            _dmi_revision: dmi,
+            _length: length,
+        },
+        8,
+    ))
+}
+
+/// Returns the bare SMBIOS structure table inside a Windows `RawSMBIOSData` blob.
+///
+/// This is the layout `dmidecode --from-dump` and most SMBIOS tools expect: the 8-byte
+/// header is dropped and the table is cut to the header's `Length` when the blob is longer.
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS data buffer.
+///
+/// # Returns
+/// The structure table bytes, or an empty slice if the blob is too short for the header.
+pub fn strip_raw_smbios_header(data: &[u8]) -> &[u8] {
+    let Some((header, offset)) = parse_raw_smbios_data_header(data) else {
+        return &[];
+    };
+    let end = offset
+        .saturating_add(header._length as usize)
+        .min(data.len());
+    &data[offset..end]
+}
+
+/// What a firmware dump file loaded from disk contains.
+pub enum FirmwareFileKind {
+    /// A single ACPI table, starting with its header.
+    AcpiTable,
+    /// An SMBIOS structure table, rewrapped in the Windows `RawSMBIOSData` layout.
+    Smbios(Vec<u8>),
+    /// The configuration space of one PCI function, or a copy of an ECAM window.
+    PciConfig,
+    /// Neither an ACPI table, an SMBIOS dump, nor a PCI configuration space dump.
+    Unknown,
+}
+
+/// Guesses whether a file holds an ACPI table, an SMBIOS dump, or PCI configuration space.
+///
+/// ACPI tables are recognised by a 4-character ASCII signature and a header length that
+/// fits the file. SMBIOS dumps are either the Windows `RawSMBIOSData` blob this tool
+/// exports or a `dmidecode --dump-bin` file starting with an `_SM_`/`_SM3_` entry point.
+/// Anything else sized like configuration space (see [`pci_config_functions`]) whose first
+/// function has a valid vendor ID and header type is taken as a PCI dump.
+///
+/// # Arguments
+/// * `data` - The file contents.
+///
+/// # Returns
+/// The detected kind; SMBIOS dumps carry the table in the `RawSMBIOSData` layout.
+pub fn detect_firmware_file(data: &[u8]) -> FirmwareFileKind {
+    if data.len() >= 36
+        && data[0..4]
+            .iter()
+            .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || *b == b'_')
+    {
+        let length = LittleEndian::read_u32(&data[4..8]) as usize;
+        if (36..=data.len()).contains(&length) {
+            return FirmwareFileKind::AcpiTable;
+        }
+    }
+
+    // dmidecode dumps hold the entry point followed by the table at the address it names
+    let entry_point = if data.starts_with(b"_SM3_") && data.len() >= 0x18 {
+        Some((
+            data[0x07],
+            data[0x08],
+            LittleEndian::read_u32(&data[0x0C..0x10]) as usize,
+            LittleEndian::read_u64(&data[0x10..0x18]),
+        ))
+    } else if data.starts_with(b"_SM_") && data.len() >= 0x1F {
+        Some((
+            data[0x06],
+            data[0x07],
+            LittleEndian::read_u16(&data[0x16..0x18]) as usize,
+            LittleEndian::read_u32(&data[0x18..0x1C]) as u64,
+        ))
+    } else {
+        None
+    };
+    if let Some((major, minor, length, address)) = entry_point {
+        let table = usize::try_from(address)
+            .ok()
+            .filter(|start| *start < data.len())
+            .map(|start| &data[start..(start.saturating_add(length)).min(data.len())]);
+        if let Some(table) = table {
+            let mut blob = vec![0, major, minor, 0];
+            blob.extend_from_slice(&(table.len() as u32).to_le_bytes());
+            blob.extend_from_slice(table);
+            return FirmwareFileKind::Smbios(blob);
+        }
+    }
+
+    if let Some((header, header_len)) = parse_raw_smbios_data_header(data) {
+        if (2..=3).contains(&header._major_version)
+            && header._length as usize <= data.len() - header_len
+            && header._length > 0
+        {
+            return FirmwareFileKind::Smbios(data.to_vec());
+        }
+    }
+
+    // Configuration space has no signature, so it is only considered once nothing else fits
+    if !pci_config_functions(data, 0).is_empty() {
+        return FirmwareFileKind::PciConfig;
+    }
+    FirmwareFileKind::Unknown
+}
+
+/// Bit definitions for the PCI Command register.
+const PCI_COMMAND_DEFS: &[(u32, &str)] = &[
+    (0, "I/O Space"),
+    (1, "Memory Space"),
+    (2, "Bus Master"),
+    (3, "Special Cycles"),
+    (4, "Memory Write and Invalidate"),
+    (5, "VGA Palette Snoop"),
+    (6, "Parity Error Response"),
+    (8, "SERR#"),
+    (9, "Fast Back-to-Back"),
+    (10, "Interrupt Disable"),
+];
+
+/// Bit definitions for the PCI Status register.
+const PCI_STATUS_DEFS: &[(u32, &str)] = &[
+    (3, "Interrupt Status"),
+    (4, "Capabilities List"),
+    (5, "66 MHz Capable"),
+    (7, "Fast Back-to-Back Capable"),
+    (8, "Master Data Parity Error"),
+    (11, "Signaled Target Abort"),
+    (12, "Received Target Abort"),
+    (13, "Received Master Abort"),
+    (14, "Signaled System Error"),
+    (15, "Detected Parity Error"),
+];
+
+/// Returns the PCI class code name, e.g. "Mass Storage Controller / NVM Express".
+///
+/// # Arguments
+/// * `class` - The base class byte.
+/// * `subclass` - The subclass byte.
+/// * `prog_if` - The programming interface byte.
+pub fn pci_class_str(class: u8, subclass: u8, prog_if: u8) -> String {
+    let base = match class {
+        0x00 => "Unclassified Device",
+        0x01 => "Mass Storage Controller",
+        0x02 => "Network Controller",
+        0x03 => "Display Controller",
+        0x04 => "Multimedia Controller",
+        0x05 => "Memory Controller",
+        0x06 => "Bridge",
+        0x07 => "Communication Controller",
+        0x08 => "Generic System Peripheral",
+        0x09 => "Input Device Controller",
+        0x0A => "Docking Station",
+        0x0B => "Processor",
+        0x0C => "Serial Bus Controller",
+        0x0D => "Wireless Controller",
+        0x0E => "Intelligent Controller",
+        0x0F => "Satellite Communications Controller",
+        0x10 => "Encryption Controller",
+        0x11 => "Signal Processing Controller",
+        0x12 => "Processing Accelerator",
+        0x13 => "Non-Essential Instrumentation",
+        0x40 => "Coprocessor",
+        0xFF => "Unassigned Class",
+        _ => return format!("Unknown (0x{:02X})", class),
+    };
+    let sub = match (class, subclass, prog_if) {
+        (0x01, 0x00, _) => "SCSI",
+        (0x01, 0x01, _) => "IDE",
+        (0x01, 0x04, _) => "RAID",
+        (0x01, 0x06, 0x01) => "SATA (AHCI)",
+        (0x01, 0x06, _) => "SATA",
+        (0x01, 0x07, _) => "SAS",
+        (0x01, 0x08, 0x02) => "NVM Express",
+        (0x01, 0x08, _) => "Non-Volatile Memory",
+        (0x02, 0x00, _) => "Ethernet",
+        (0x02, 0x80, _) => "Other",
+        (0x03, 0x00, _) => "VGA Compatible",
+        (0x03, 0x02, _) => "3D",
+        (0x03, 0x80, _) => "Other",
+        (0x04, 0x00, _) => "Video",
+        (0x04, 0x01, _) => "Audio",
+        (0x04, 0x03, _) => "Audio Device",
+        (0x05, 0x00, _) => "RAM",
+        (0x05, 0x80, _) => "Other",
+        (0x06, 0x00, _) => "Host Bridge",
+        (0x06, 0x01, _) => "ISA Bridge",
+        (0x06, 0x04, _) => "PCI-to-PCI Bridge",
+        (0x06, 0x80, _) => "Other",
+        (0x07, 0x00, _) => "Serial",
+        (0x07, 0x80, _) => "Other",
+        (0x08, 0x05, _) => "SD Host Controller",
+        (0x08, 0x80, _) => "Other",
+        (0x0C, 0x03, 0x00) => "USB (UHCI)",
+        (0x0C, 0x03, 0x10) => "USB (OHCI)",
+        (0x0C, 0x03, 0x20) => "USB (EHCI)",
+        (0x0C, 0x03, 0x30) => "USB (xHCI)",
+        (0x0C, 0x03, _) => "USB",
+        (0x0C, 0x05, _) => "SMBus",
+        (0x0C, 0x80, _) => "Other",
+        (0x0D, 0x80, _) => "Other",
+        (0x11, 0x80, _) => "Other",
+        _ => return base.to_string(),
+    };
+    format!("{} / {}", base, sub)
+}
+
+/// Returns the name of a PCI capability ID.
+fn pci_capability_str(id: u8) -> String {
+    match id {
+        0x01 => "Power Management".to_string(),
+        0x02 => "AGP".to_string(),
+        0x03 => "VPD".to_string(),
+        0x04 => "Slot Identification".to_string(),
+        0x05 => "MSI".to_string(),
+        0x06 => "CompactPCI Hot Swap".to_string(),
+        0x07 => "PCI-X".to_string(),
+        0x08 => "HyperTransport".to_string(),
+        0x09 => "Vendor Specific".to_string(),
+        0x0A => "Debug Port".to_string(),
+        0x0B => "CompactPCI Resource Control".to_string(),
+        0x0C => "PCI Hot-Plug".to_string(),
+        0x0D => "Bridge Subsystem Vendor ID".to_string(),
+        0x0E => "AGP 8x".to_string(),
+        0x0F => "Secure Device".to_string(),
+        0x10 => "PCI Express".to_string(),
+        0x11 => "MSI-X".to_string(),
+        0x12 => "SATA Data/Index".to_string(),
+        0x13 => "Advanced Features".to_string(),
+        0x14 => "Enhanced Allocation".to_string(),
+        0x15 => "Flattening Portal Bridge".to_string(),
+        _ => format!("Unknown (0x{:02X})", id),
+    }
+}
+
+/// Decodes the Base Address Registers starting at `at`, pairing up 64-bit memory BARs.
+fn pci_bars(data: &[u8], at: usize, count: usize) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    let mut index = 0;
+    while index < count {
+        let pos = at + index * 4;
+        let bar = LittleEndian::read_u32(&data[pos..pos + 4]);
+        let name = format!("BAR{}", index);
+        index += 1;
+        if bar == 0 {
+            info.push((name, "Unused".to_string()));
+            continue;
+        }
+        if bar & 0x1 != 0 {
+            info.push((name, format!("I/O 0x{:04X}", bar & !0x3)));
+            continue;
+        }
+        let prefetchable = if bar & 0x8 != 0 { ", Prefetchable" } else { "" };
+        if (bar >> 1) & 0x3 == 0x2 && index < count {
+            let high = LittleEndian::read_u32(&data[pos + 4..pos + 8]);
+            let address = ((high as u64) << 32) | (bar & !0xF) as u64;
+            info.push((
+                name,
+                format!("Memory 0x{:016X} (64-bit{})", address, prefetchable),
+            ));
+            index += 1;
+        } else {
+            info.push((
+                name,
+                format!("Memory 0x{:08X} (32-bit{})", bar & !0xF, prefetchable),
+            ));
+        }
+    }
+    info
+}
+
+/// Decodes the standard 64-byte header of a PCI function's configuration space.
+///
+/// Type 0 (endpoint) and type 1 (PCI-to-PCI bridge) headers are decoded in full; other
+/// header types show only the fields common to every header. The capability list is walked
+/// when the dump is long enough to hold it.
+///
+/// # Arguments
+/// * `data` - The function's configuration space, at least 64 bytes.
+///
+/// # Returns
+/// A vector of (field name, value) pairs, empty if the data is shorter than the header.
+pub fn parse_pci_config_header(data: &[u8]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if data.len() < 64 {
+        return info;
+    }
+
+    let command = LittleEndian::read_u16(&data[0x04..0x06]);
+    let status = LittleEndian::read_u16(&data[0x06..0x08]);
+    let header_type = data[0x0E];
+    info.push((
+        "Vendor ID".to_string(),
+        format!("0x{:04X}", LittleEndian::read_u16(&data[0x00..0x02])),
+    ));
+    info.push((
+        "Device ID".to_string(),
+        format!("0x{:04X}", LittleEndian::read_u16(&data[0x02..0x04])),
+    ));
+    info.push((
+        "Command".to_string(),
+        flags_with_names(
+            format!("0x{:04X}", command),
+            &decode_flags(command as u64, PCI_COMMAND_DEFS),
+        ),
+    ));
+    info.push((
+        "Status".to_string(),
+        flags_with_names(
+            format!("0x{:04X}", status),
+            &decode_flags(status as u64, PCI_STATUS_DEFS),
+        ),
+    ));
+    info.push(("Revision ID".to_string(), format!("0x{:02X}", data[0x08])));
+    info.push((
+        "Class Code".to_string(),
+        format!(
+            "0x{:02X}{:02X}{:02X} ({})",
+            data[0x0B],
+            data[0x0A],
+            data[0x09],
+            pci_class_str(data[0x0B], data[0x0A], data[0x09])
+        ),
+    ));
+    info.push((
+        "Cache Line Size".to_string(),
+        format!("{} bytes", data[0x0C] as u32 * 4),
+    ));
+    info.push(("Latency Timer".to_string(), format!("{}", data[0x0D])));
+    let layout = match header_type & 0x7F {
+        0x00 => "Type 0 (Endpoint)".to_string(),
+        0x01 => "Type 1 (PCI-to-PCI Bridge)".to_string(),
+        0x02 => "Type 2 (CardBus Bridge)".to_string(),
+        other => format!("Unknown (0x{:02X})", other),
+    };
+    info.push((
+        "Header Type".to_string(),
+        if header_type & 0x80 != 0 {
+            format!("{}, Multi-Function", layout)
+        } else {
+            layout
+        },
+    ));
+    info.push(("BIST".to_string(), format!("0x{:02X}", data[0x0F])));
+
+    match header_type & 0x7F {
+        0x00 => {
+            info.extend(pci_bars(data, 0x10, 6));
+            info.push((
+                "Subsystem Vendor ID".to_string(),
+                format!("0x{:04X}", LittleEndian::read_u16(&data[0x2C..0x2E])),
+            ));
+            info.push((
+                "Subsystem ID".to_string(),
+                format!("0x{:04X}", LittleEndian::read_u16(&data[0x2E..0x30])),
+            ));
+            info.push((
+                "Expansion ROM".to_string(),
+                format!("0x{:08X}", LittleEndian::read_u32(&data[0x30..0x34])),
+            ));
+        }
+        0x01 => {
+            info.extend(pci_bars(data, 0x10, 2));
+            info.push(("Primary Bus".to_string(), format!("0x{:02X}", data[0x18])));
+            info.push(("Secondary Bus".to_string(), format!("0x{:02X}", data[0x19])));
+            info.push((
+                "Subordinate Bus".to_string(),
+                format!("0x{:02X}", data[0x1A]),
+            ));
+            info.push((
+                "Expansion ROM".to_string(),
+                format!("0x{:08X}", LittleEndian::read_u32(&data[0x38..0x3C])),
+            ));
+        }
+        _ => return info,
+    }
+    info.push(("Interrupt Line".to_string(), format!("{}", data[0x3C])));
+    info.push((
+        "Interrupt Pin".to_string(),
+        match data[0x3D] {
+            0 => "None".to_string(),
+            pin @ 1..=4 => format!("INT{}#", char::from(b'A' + pin - 1)),
+            other => format!("Unknown (0x{:02X})", other),
+        },
+    ));
+
+    if status & (1 << 4) != 0 {
+        let mut capabilities = Vec::new();
+        let mut ptr = (data[0x34] & 0xFC) as usize;
+        // The list lives in the 192 bytes after the header; stop on loops or short dumps
+        while ptr >= 0x40 && ptr + 1 < data.len() && capabilities.len() < 48 {
+            capabilities.push(format!("0x{:02X} {}", ptr, pci_capability_str(data[ptr])));
+            ptr = (data[ptr + 1] & 0xFC) as usize;
+        }
+        if !capabilities.is_empty() {
+            info.push(("Capabilities".to_string(), capabilities.join(", ")));
+        }
+    }
+    info
+}
+
+/// A PCI function found in a configuration space dump.
+pub struct PciConfigFunction {
+    /// Bus, device, and function, or `None` for a single-function dump of unknown location.
+    pub location: Option<(u8, u8, u8)>,
+    /// Offset of the function's configuration space in the dump.
+    pub offset: usize,
+    /// Length of the function's configuration space in the dump.
+    pub length: usize,
+}
+
+/// Lists the PCI functions present in a configuration space dump.
+///
+/// A 64, 256, or 4096-byte file is one function's configuration space, such as Linux's
+/// `/sys/bus/pci/devices/*/config`. A larger multiple of 4 KB is a copy of an ECAM window
+/// starting at `start_bus`, with each function at its ECAM offset; absent functions read as
+/// all ones and are skipped.
+///
+/// # Arguments
+/// * `data` - The dump contents.
+/// * `start_bus` - The bus number of the first 1 MB of an ECAM window dump.
+///
+/// # Returns
+/// The functions whose vendor ID is valid, in dump order.
+pub fn pci_config_functions(data: &[u8], start_bus: u8) -> Vec<PciConfigFunction> {
+    let present = |config: &[u8]| {
+        config.len() >= 64
+            && !matches!(LittleEndian::read_u16(&config[0..2]), 0x0000 | 0xFFFF)
+            && config[0x0E] & 0x7F <= 0x02
+    };
+    if matches!(data.len(), 64 | 256 | 4096) {
+        if !present(data) {
+            return Vec::new();
+        }
+        return vec![PciConfigFunction {
+            location: None,
+            offset: 0,
+            length: data.len(),
+        }];
+    }
+    if !data.len().is_multiple_of(4096) {
+        return Vec::new();
+    }
+    data.chunks(4096)
+        .enumerate()
+        .filter(|(_, config)| present(config))
+        .map_while(|(index, _)| {
+            let bus = u8::try_from(start_bus as usize + index / 256).ok()?;
+            Some(PciConfigFunction {
+                location: Some((bus, ((index >> 3) & 0x1F) as u8, (index & 0x7) as u8)),
+                offset: index * 4096,
+                length: 4096,
+            })
+        })
+        .collect()
+}
+
+/// Header for an individual SMBIOS structure.
+#[derive(Debug, Clone)]
+pub struct SmbiosStructureHeader {
+    /// The SMBIOS type ID (e.g., 0 for BIOS, 1 for System).
+    pub type_id: u8,
+    /// The length of the formatted portion of the structure.
+    pub length: u8,
+    /// The unique handle for this structure instance.
+    pub handle: u16,
+}
+
+/// Parses a single SMBIOS structure header and calculated its total size (including strings).
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS data buffer.
+/// * `offset` - The current offset into the buffer.
+///
+/// # Returns
+/// `Result` containing the header and the next offset (end of the structure), or an error
+/// if the header does not fit or the string area is not terminated within the scan limit.
+pub fn parse_smbios_structure(
+    data: &[u8],
+    offset: usize,
+) -> Result<(SmbiosStructureHeader, usize), DumperError> {
+    if offset + 4 > data.len() {
+        return Err(DumperError::TooShort {
+            need: offset + 4,
+            got: data.len(),
+        });
+    }
+
+    let type_id = data[offset];
+    let length = data[offset + 1];
+    let handle = LittleEndian::read_u16(&data[offset + 2..offset + 4]);
+
+    if length < 4 {
+        return Err(DumperError::Malformed(format!(
+            "SMBIOS structure at offset 0x{:X} has length {}",
+            offset, length
+        )));
+    }
+
+    let header = SmbiosStructureHeader {
+        type_id,
+        length,
+        handle,
+    };
+
+    if let Some((_, end)) = counted_string_set(data, offset) {
+        return Ok((header, end));
+    }
+
+    // Find end of structure (terminated by a double null: 00 00). The scan is bounded so a
+    // blob without terminators cannot make every lookup walk the rest of the buffer.
+    let formatted_end = offset + length as usize;
+    let scan_end = data.len().min(formatted_end + MAX_SMBIOS_STRING_AREA);
+    let mut current = formatted_end;
+
+    while current + 1 < scan_end {
+        if data[current] == 0 && data[current + 1] == 0 {
+            return Ok((header, current + 2));
+        }
+        current += 1;
+    }
+
+    if scan_end < data.len() {
+        return Err(DumperError::Malformed(format!(
+            "SMBIOS structure at offset 0x{:X} has unterminated strings",
+            offset
+        )));
+    }
+
+    Ok((header, data.len()))
+}
+
+/// Formatted and total size of one SMBIOS structure.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SmbiosStructureSize {
+    /// Size of the formatted area, from the structure's Length byte.
+    pub formatted: usize,
+    /// Size of the whole structure, formatted area plus string area and terminator.
+    pub total: usize,
+    /// Whether the structure was cut off at the end of the buffer because its string
+    /// area has no double-NUL terminator (or its formatted area runs past the buffer).
+    pub clamped: bool,
+}
+
+/// Measures one SMBIOS structure the way [`parse_smbios_structure`] walks it.
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS data buffer.
+/// * `offset` - The offset of the structure header.
+///
+/// # Returns
+/// The structure's sizes, or `None` if its header cannot be parsed.
+pub fn smbios_structure_size(data: &[u8], offset: usize) -> Option<SmbiosStructureSize> {
+    let (header, next_off) = parse_smbios_structure(data, offset).ok()?;
+    let formatted_end = offset + header.length as usize;
+    let terminated = next_off >= formatted_end + 2 && data[next_off - 2..next_off] == [0, 0];
+    Some(SmbiosStructureSize {
+        formatted: header.length as usize,
+        total: next_off - offset,
+        clamped: !terminated,
+    })
+}
+
+/// Walks every structure in a raw SMBIOS blob (including the Windows 8-byte header).
+///
+/// Malformed tables are truncated at the point where they stop making sense; use
+/// [`try_walk_smbios_structures`] to find out whether that happened.
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS data buffer as returned by the firmware API.
+///
+/// # Returns
+/// A vector of `(offset, header, next_offset)` for each structure, in table order.
+pub fn walk_smbios_structures(data: &[u8]) -> Vec<(usize, SmbiosStructureHeader, usize)> {
+    walk_smbios_bounded(data).0
+}
+
+/// Walks every structure in a raw SMBIOS blob, failing if the table exceeds the parse limits.
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS data buffer as returned by the firmware API.
+///
+/// # Returns
+/// `Ok` with the same list as [`walk_smbios_structures`], or `Err` describing why the
+/// structure table was rejected as too large or malformed.
+pub fn try_walk_smbios_structures(
+    data: &[u8],
+) -> Result<Vec<(usize, SmbiosStructureHeader, usize)>, DumperError> {
+    match walk_smbios_bounded(data) {
+        (structures, None) => Ok(structures),
+        (structures, Some(e)) => {
+            log::warn!("{} (walked {} structures)", e, structures.len());
+            Err(e)
+        }
+    }
+}
+
+/// Upper bound on the number of structures walked in one SMBIOS table.
+pub const MAX_SMBIOS_STRUCTURES: usize = 4096;
+
+/// Upper bound on the total number of bytes walked in one SMBIOS table.
+pub const MAX_SMBIOS_SCAN_BYTES: usize = 4 * 1024 * 1024;
+
+/// Upper bound on the size of a single structure's string area.
+pub const MAX_SMBIOS_STRING_AREA: usize = 64 * 1024;
+
+/// Shared walker behind [`walk_smbios_structures`] and [`try_walk_smbios_structures`].
+///
+/// # Returns
+/// The structures parsed before the walk stopped, and an error if it stopped on a limit.
+fn walk_smbios_bounded(
+    data: &[u8],
+) -> (
+    Vec<(usize, SmbiosStructureHeader, usize)>,
+    Option<DumperError>,
+) {
+    let mut structures = Vec::new();
+    let Some((_, off)) = parse_raw_smbios_data_header(data) else {
+        return (structures, None);
+    };
+
+    let mut current_off = off;
+    let mut scanned = 0usize;
+    while current_off < data.len() {
+        if structures.len() >= MAX_SMBIOS_STRUCTURES {
+            let e = DumperError::Malformed(format!(
+                "SMBIOS structure table too large or malformed: more than {} structures",
+                MAX_SMBIOS_STRUCTURES
+            ));
+            return (structures, Some(e));
+        }
+
+        match parse_smbios_structure(data, current_off) {
+            Ok((header, next_off)) => {
+                structures.push((current_off, header, next_off));
+                if next_off <= current_off {
+                    break;
+                }
+                scanned += next_off - current_off;
+                if scanned > MAX_SMBIOS_SCAN_BYTES {
+                    let e = DumperError::Malformed(format!(
+                        "SMBIOS structure table too large or malformed: more than {} bytes",
+                        MAX_SMBIOS_SCAN_BYTES
+                    ));
+                    return (structures, Some(e));
+                }
+                current_off = next_off;
+            }
+            Err(_) => {
+                // A plausible header whose string area never terminates is corruption, not
+                // the end-of-table padding some firmware leaves behind.
+                if current_off + 4 <= data.len() && data[current_off + 1] >= 4 {
+                    let e = DumperError::Malformed(format!(
+                        "SMBIOS structure table too large or malformed: unterminated strings at offset 0x{:X}",
+                        current_off
+                    ));
+                    return (structures, Some(e));
+                }
+                break;
+            }
+        }
+    }
+    (structures, None)
+}
+
+/// Returns the number of strings a Type 11 or Type 12 structure declares in its Count byte.
+fn declared_string_count(data: &[u8], offset: usize) -> Option<usize> {
+    match data.get(offset)? {
+        11 | 12 if require(data, offset, 0x05) => Some(data[offset + 0x04] as usize),
+        _ => None,
+    }
+}
+
+/// Reads the string set of a structure that declares its string count (Types 11 and 12).
+///
+/// An empty string in the middle of such a set looks like the double-NUL terminator, so
+/// the set is read as Count NUL-terminated strings followed by a final NUL instead. This
+/// only applies when a non-empty string follows an empty one, and strings past the first
+/// empty one must be printable, so an overstated Count cannot swallow the next structure.
+///
+/// # Returns
+/// The byte range of every string and the end of the structure, or `None` when the
+/// double-NUL rule applies.
+fn counted_string_set(data: &[u8], offset: usize) -> Option<(Vec<Range<usize>>, usize)> {
+    let count = declared_string_count(data, offset)?;
+    let start = offset + data[offset + 1] as usize;
+    let limit = data.len().min(start + MAX_SMBIOS_STRING_AREA);
+    let mut ranges = Vec::with_capacity(count);
+    let mut at = start;
+    let mut seen_empty = false;
+    let mut text_after_empty = false;
+    for _ in 0..count {
+        let len = data.get(at..limit)?.iter().position(|&b| b == 0)?;
+        let bytes = &data[at..at + len];
+        if len == 0 {
+            seen_empty = true;
+        } else if seen_empty {
+            if bytes.iter().any(|&b| b < 0x20 || b == 0x7F) {
+                return None;
+            }
+            text_after_empty = true;
+        }
+        ranges.push(at..at + len);
+        at += len + 1;
+    }
+    if !text_after_empty || data.get(at) != Some(&0) {
+        return None;
+    }
+    Some((ranges, at + 1))
+}
+
+/// Character encoding used to decode the SMBIOS string pool.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StringEncoding {
+    /// UTF-8, with invalid sequences replaced by U+FFFD.
+    #[default]
+    Utf8Lossy,
+    /// The Windows-1252 (Western European) code page.
+    Windows1252,
+    /// Space-separated hex bytes, for inspecting the exact encoding.
+    RawHex,
+}
+
+/// Unicode code points for Windows-1252 bytes 0x80-0x9F; undefined bytes map to themselves.
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}',
+    '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+];
+
+impl StringEncoding {
+    /// All encodings, in the order they are offered in the UI.
+    pub const ALL: [StringEncoding; 3] = [
+        StringEncoding::Utf8Lossy,
+        StringEncoding::Windows1252,
+        StringEncoding::RawHex,
+    ];
+
+    /// Returns the human-readable name of the encoding.
+    pub fn label(self) -> &'static str {
+        match self {
+            StringEncoding::Utf8Lossy => "UTF-8 (lossy)",
+            StringEncoding::Windows1252 => "Windows-1252",
+            StringEncoding::RawHex => "Raw hex",
+        }
+    }
+
+    /// Returns the stable identifier used to persist the setting.
+    pub fn key(self) -> &'static str {
+        match self {
+            StringEncoding::Utf8Lossy => "utf8",
+            StringEncoding::Windows1252 => "cp1252",
+            StringEncoding::RawHex => "hex",
+        }
+    }
+
+    /// Parses an identifier produced by [`StringEncoding::key`].
+    pub fn from_key(key: &str) -> Option<StringEncoding> {
+        StringEncoding::ALL.into_iter().find(|e| e.key() == key)
+    }
+
+    /// Decodes a single string-pool entry (without its null terminator).
+    pub fn decode(self, bytes: &[u8]) -> String {
+        match self {
+            StringEncoding::Utf8Lossy => String::from_utf8_lossy(bytes).to_string(),
+            StringEncoding::Windows1252 => bytes
+                .iter()
+                .map(|&b| match b {
+                    0x80..=0x9F => WINDOWS_1252_HIGH[(b - 0x80) as usize],
+                    _ => b as char,
+                })
+                .collect(),
+            StringEncoding::RawHex => bytes
+                .iter()
+                .map(|b| format!("{:02X}", b))
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+}
+
+/// Extracts the string pool following the formatted portion of an SMBIOS structure.
+///
+/// Types 11 and 12 are read by their declared Count when it reaches past an empty string,
+/// which is kept as `""`; see [`counted_string_set`].
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS data buffer.
+/// * `offset` - The starting offset of the structure's formatted portion.
+/// * `length` - The length of the formatted portion.
+/// * `encoding` - How the string bytes are decoded.
+///
+/// # Returns
+/// A vector of strings extracted from the string pool.
+pub fn get_smbios_strings(
+    data: &[u8],
+    offset: usize,
+    length: u8,
+    encoding: StringEncoding,
+) -> Vec<String> {
+    if let Some((ranges, _)) = counted_string_set(data, offset) {
+        return ranges
+            .into_iter()
+            .map(|range| encoding.decode(&data[range]))
+            .collect();
+    }
+
+    let mut strings = Vec::new();
+    let str_start = offset + length as usize;
+
+    if str_start >= data.len() {
+        return strings;
+    }
+
+    let mut current_idx = str_start;
+    while current_idx < data.len() {
+        match data[current_idx..].iter().position(|&b| b == 0) {
+            Some(pos) => {
+                let null_idx = current_idx + pos;
+                if null_idx == current_idx {
+                    break;
+                }
+
+                let s_bytes = &data[current_idx..null_idx];
+                strings.push(encoding.decode(s_bytes));
+
+                current_idx = null_idx + 1;
+
+                if current_idx < data.len() && data[current_idx] == 0 {
+                    break;
+                }
+            }
+            None => break,
+        }
+    }
+    strings
+}
+
+/// Retrieves a string from the SMBIOS string pool by its 1-based index.
+///
+/// # Arguments
+/// * `strings` - The list of strings extracted from the structure.
 /// * `index` - The 1-based index of the string (0 means "None").
 ///
 /// # Returns
-/// The requested string, or a placeholder if the index is invalid.
-pub fn get_string_by_index(strings: &[String], index: u8) -> String {
-    if index == 0 {
-        return "None".to_string();
+/// The requested string, or a placeholder if the index is invalid.
+pub fn get_string_by_index(strings: &[String], index: u8) -> String {
+    if index == 0 {
+        return "None".to_string();
+    }
+    let idx = index as usize;
+    if idx > 0 && idx <= strings.len() {
+        strings[idx - 1].clone()
+    } else {
+        format!("<Bad String Index: {}>", index)
+    }
+}
+
+/// Returns the friendly name of an SMBIOS structure type, used for sidebar labels.
+///
+/// # Returns
+/// The type name, or an empty string for types without a known label.
+pub fn smbios_type_name(type_id: u8) -> &'static str {
+    match type_id {
+        0 => "BIOS Info",
+        1 => "System Info",
+        2 => "Baseboard",
+        3 => "Chassis",
+        4 => "Processor",
+        6 => "Memory Module",
+        7 => "Cache Info",
+        8 => "Port Connector",
+        9 => "System Slots",
+        10 => "Onboard Devices",
+        11 => "OEM Strings",
+        12 => "System Configuration Options",
+        13 => "BIOS Language",
+        14 => "Group Associations",
+        16 => "Memory Array",
+        17 => "Memory Device",
+        18 => "32-Bit Memory Error",
+        19 => "Memory Mapped",
+        20 => "Memory Device Mapped",
+        21 => "Pointing Device",
+        23 => "System Reset",
+        24 => "Hardware Security",
+        25 => "System Power Controls",
+        26 => "Voltage Probe",
+        27 => "Cooling Device",
+        28 => "Temperature Probe",
+        29 => "Current Probe",
+        30 => "Out-of-Band Remote Access",
+        31 => "Boot Integrity Services",
+        32 => "Boot Info",
+        33 => "64-Bit Memory Error",
+        34 => "Management Device",
+        35 => "Management Device Component",
+        36 => "Management Device Threshold Data",
+        37 => "Memory Channel",
+        42 => "Management Controller Host Interface",
+        127 => "End-of-Table",
+        _ => "",
+    }
+}
+
+/// Returns the SMBIOS version that introduced a structure type.
+///
+/// Point releases are folded into their minor version (Type 39 arrived in 2.3.1).
+///
+/// # Returns
+/// The version, or `None` for OEM types and numbers the specification does not assign.
+pub fn smbios_type_introduced(type_id: u8) -> Option<SmbiosVersion> {
+    let (major, minor) = match type_id {
+        0..=14 => (2, 0),
+        15..=22 => (2, 1),
+        23..=30 => (2, 2),
+        31..=39 => (2, 3),
+        40 | 41 => (2, 6),
+        42 => (2, 7),
+        43 => (3, 1),
+        44 => (3, 3),
+        45 | 46 => (3, 5),
+        126 | 127 => (2, 2),
+        _ => return None,
+    };
+    Some(SmbiosVersion::new(major, minor))
+}
+
+/// Returns the SMBIOS version after which a structure type is obsolete.
+///
+/// # Returns
+/// The version, or `None` for types that are still current.
+pub fn smbios_type_obsoleted(type_id: u8) -> Option<SmbiosVersion> {
+    match type_id {
+        // Memory Controller and Memory Module, replaced by Types 16 and 17
+        5 | 6 => Some(SmbiosVersion::new(2, 1)),
+        _ => None,
+    }
+}
+
+/// Returns the specification an ACPI table is defined in, e.g. "Intel VT-d specification".
+///
+/// # Returns
+/// `Some` specification for recognized signatures, `None` for unknown or OEM-specific ones.
+pub fn acpi_signature_spec(sig: &str) -> Option<&'static str> {
+    let spec = match sig {
+        "APIC" | "BERT" | "BGRT" | "CPEP" | "DSDT" | "ECDT" | "EINJ" | "ERST" | "FACP" | "FADT"
+        | "FACS" | "FPDT" | "GTDT" | "HEST" | "HMAT" | "MPST" | "MSCT" | "NFIT" | "PCCT"
+        | "PHAT" | "PMTT" | "PPTT" | "RASF" | "RSDP" | "RSDT" | "SBST" | "SDEV" | "SLIT"
+        | "SRAT" | "SSDT" | "XSDT" => "ACPI specification",
+        "BOOT" | "CSRT" | "DBG2" | "DBGP" | "MSDM" | "SLIC" | "SPCR" | "WAET" | "WDAT" | "WDRT"
+        | "WPBT" | "WSMT" => "Microsoft specification",
+        "ASF!" => "DMTF Alert Standard Format specification",
+        "MCHI" => "DMTF MCTP host interface specification",
+        "CEDT" => "Compute Express Link (CXL) specification",
+        "DMAR" => "Intel Virtualization Technology for Directed I/O (VT-d) specification",
+        "LPIT" => "Intel Low Power Idle Table specification",
+        "NHLT" => "Intel NHLT specification",
+        "IVRS" => "AMD I/O Virtualization Technology (IOMMU) specification",
+        "IORT" => "Arm IO Remapping Table specification",
+        "SDEI" => "Arm Software Delegated Exception Interface specification",
+        "DRTM" | "TCPA" | "TPM2" => "TCG ACPI specification",
+        "HPET" => "IA-PC HPET specification",
+        "MCFG" => "PCI Firmware specification",
+        "IBFT" => "iSCSI Boot Firmware Table specification",
+        "SPMI" => "IPMI specification",
+        "UEFI" => "UEFI specification",
+        "STAO" | "XENV" => "Xen project specification",
+        _ => return None,
+    };
+    Some(spec)
+}
+
+/// Returns the length of the formatted area of the SMBIOS structure at `offset`.
+///
+/// This is the structure's Length byte, clamped to the bytes actually in `data`, so it is
+/// 0 when even the Length byte is missing.
+fn formatted_length(data: &[u8], offset: usize) -> usize {
+    data.get(offset + 0x01)
+        .map_or(0, |&l| l as usize)
+        .min(data.len().saturating_sub(offset))
+}
+
+/// Returns whether the SMBIOS structure at `offset` has every field that ends before `end`.
+///
+/// Parsers check this before reading a group of fields and return early when it fails.
+/// The bound is [`formatted_length`], not the buffer length. So a truncated buffer cannot
+/// panic, and a shorter (older) structure is never decoded from its string set.
+///
+/// # Arguments
+/// * `data` - The raw buffer.
+/// * `offset` - Starting offset of the structure.
+/// * `end` - Offset, relative to the structure, just past the last byte to be read.
+fn require(data: &[u8], offset: usize, end: usize) -> bool {
+    end <= formatted_length(data, offset)
+}
+
+/// SMBIOS structure types that [`parse_smbios_details`] has a dedicated parser for.
+pub const PARSED_SMBIOS_TYPES: &[u8] = &[
+    0, 1, 2, 3, 4, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,
+    27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 42, 127,
+];
+
+/// Dispatches raw SMBIOS structure data to specific type parsers to get human-readable key-value pairs.
+///
+/// # Arguments
+/// * `type_id` - The SMBIOS structure type (0, 1, 2, etc.).
+/// * `data` - The raw buffer.
+/// * `offset` - Starting offset of the structure.
+/// * `strings` - The extracted strings for this structure.
+/// * `version` - The SMBIOS version whose rules decode the version-sensitive fields (the
+///   Type 0 ROM Size, the Type 1 UUID byte order, and the Type 17 Size). With `None` both
+///   UUID byte orders are listed and the extended fields are read when present.
+///
+/// # Returns
+/// `Option<Vec<(String, String)>>` containing field names and values.
+pub fn parse_smbios_details(
+    type_id: u8,
+    data: &[u8],
+    offset: usize,
+    _header_len: u8,
+    strings: &[String],
+    version: Option<SmbiosVersion>,
+) -> Option<Vec<(String, String)>> {
+    match type_id {
+        0 => Some(parse_type_0(data, offset, strings, version)),
+        1 => Some(parse_type_1(data, offset, strings, version)),
+        2 => Some(parse_type_2(data, offset, strings)),
+        3 => Some(parse_type_3(data, offset, strings)),
+        4 => Some(parse_type_4(data, offset, strings)),
+        6 => Some(parse_type_6(data, offset, strings)),
+        10 => Some(parse_type_10(data, offset, strings)),
+        12 => Some(parse_type_12(data, offset, strings)),
+        15 => Some(parse_type_15(data, offset, strings)),
+        7 => Some(parse_type_7(data, offset, strings)),
+        8 => Some(parse_type_8(data, offset, strings)),
+        9 => Some(parse_type_9(data, offset, strings)),
+        11 => Some(parse_type_11(data, offset, strings)),
+        13 => Some(parse_type_13(data, offset, strings)),
+        14 => Some(parse_type_14(data, offset, strings)),
+        16 => Some(parse_type_16(data, offset, strings)),
+        17 => Some(parse_type_17(data, offset, strings, version)),
+        19 => Some(parse_type_19(data, offset, strings)),
+        21 => Some(parse_type_21(data, offset, strings)),
+        18 => Some(parse_type_18(data, offset, strings)),
+        20 => Some(parse_type_20(data, offset, strings)),
+        22 => Some(parse_type_22(data, offset, strings)),
+        23 => Some(parse_type_23(data, offset, strings)),
+        24 => Some(parse_type_24(data, offset, strings)),
+        25 => Some(parse_type_25(data, offset, strings)),
+        26 => Some(parse_type_26(data, offset, strings)),
+        27 => Some(parse_type_27(data, offset, strings)),
+        28 => Some(parse_type_28(data, offset, strings)),
+        29 => Some(parse_type_29(data, offset, strings)),
+        30 => Some(parse_type_30(data, offset, strings)),
+        31 => Some(parse_type_31(data, offset, strings)),
+        32 => Some(parse_type_32(data, offset, strings)),
+        33 => Some(parse_type_33(data, offset, strings)),
+        34 => Some(parse_type_34(data, offset, strings)),
+        35 => Some(parse_type_35(data, offset, strings)),
+        36 => Some(parse_type_36(data, offset, strings)),
+        37 => Some(parse_type_37(data, offset, strings)),
+        42 => Some(parse_type_42(data, offset, strings)),
+        127 => Some(parse_type_127(data, offset, strings)),
+        _ => None,
+    }
+}
+
+/// Parser for SMBIOS Type 10: On Board Device Information (obsolete, superseded by Type 41).
+fn parse_type_10(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x04) {
+        return info;
+    }
+    // Each device is a (type, description string) byte pair after the 4-byte header
+    let count = formatted_length(data, offset).saturating_sub(4) / 2;
+    for i in 0..count {
+        let pos = offset + 0x04 + i * 2;
+        let device_type = data[pos];
+        let description_idx = data[pos + 1];
+        let status = if device_type & 0x80 != 0 {
+            "Enabled"
+        } else {
+            "Disabled"
+        };
+        info.push((
+            format!("Device {} Type", i + 1),
+            format!(
+                "{} ({})",
+                onboard_device_type_str(device_type & 0x7F),
+                status
+            ),
+        ));
+        info.push((
+            format!("Device {} Description", i + 1),
+            get_string_by_index(strings, description_idx),
+        ));
+    }
+    info
+}
+
+/// Returns human-readable onboard device type string (low 7 bits of the device type byte).
+fn onboard_device_type_str(code: u8) -> String {
+    match code {
+        0x01 => "Other".to_string(),
+        0x02 => "Unknown".to_string(),
+        0x03 => "Video".to_string(),
+        0x04 => "SCSI Controller".to_string(),
+        0x05 => "Ethernet".to_string(),
+        0x06 => "Token Ring".to_string(),
+        0x07 => "Sound".to_string(),
+        0x08 => "PATA Controller".to_string(),
+        0x09 => "SATA Controller".to_string(),
+        0x0A => "SAS Controller".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Parser for SMBIOS Type 12: System Configuration Options.
+fn parse_type_12(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x05) {
+        return info;
+    }
+    let count = data[offset + 0x04];
+    info.push(("Option Count".into(), format!("{}", count)));
+    for i in 1..=count {
+        info.push((format!("Option {}", i), get_string_by_index(strings, i)));
+    }
+    info
+}
+
+/// Parser for SMBIOS Type 15: System Event Log.
+fn parse_type_15(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x0E) {
+        return info;
+    }
+    let area_length = LittleEndian::read_u16(&data[offset + 0x04..offset + 0x06]);
+    let header_start = LittleEndian::read_u16(&data[offset + 0x06..offset + 0x08]);
+    let data_start = LittleEndian::read_u16(&data[offset + 0x08..offset + 0x0A]);
+    let access_method = data[offset + 0x0C];
+    let log_status = data[offset + 0x0D];
+    info.push(("Area Length".into(), format!("{} bytes", area_length)));
+    info.push(("Header Start Offset".into(), format!("{}", header_start)));
+    info.push(("Data Start Offset".into(), format!("{}", data_start)));
+    info.push(("Access Method".into(), format!("0x{:02X}", access_method)));
+    info.push(("Log Status".into(), format!("0x{:02X}", log_status)));
+    info
+}
+
+/// Converts an SMBIOS memory error type code (Types 18 and 33) to a string.
+fn memory_error_type_str(code: u8) -> String {
+    match code {
+        0x01 => "Other".to_string(),
+        0x02 => "Unknown".to_string(),
+        0x03 => "OK".to_string(),
+        0x04 => "Bad Read".to_string(),
+        0x05 => "Parity Error".to_string(),
+        0x06 => "Single-bit Error".to_string(),
+        0x07 => "Double-bit Error".to_string(),
+        0x08 => "Multi-bit Error".to_string(),
+        0x09 => "Nibble Error".to_string(),
+        0x0A => "Checksum Error".to_string(),
+        0x0B => "CRC Error".to_string(),
+        0x0C => "Corrected Single-bit Error".to_string(),
+        0x0D => "Corrected Error".to_string(),
+        0x0E => "Uncorrectable Error".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Converts an SMBIOS memory error granularity code (Types 18 and 33) to a string.
+fn memory_error_granularity_str(code: u8) -> String {
+    match code {
+        0x01 => "Other".to_string(),
+        0x02 => "Unknown".to_string(),
+        0x03 => "Device Level".to_string(),
+        0x04 => "Memory Partition Level".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Converts an SMBIOS memory error operation code (Types 18 and 33) to a string.
+fn memory_error_operation_str(code: u8) -> String {
+    match code {
+        0x01 => "Other".to_string(),
+        0x02 => "Unknown".to_string(),
+        0x03 => "Read".to_string(),
+        0x04 => "Write".to_string(),
+        0x05 => "Partial Write".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Formats a 32-bit memory error address, where 0x80000000 means "unknown".
+fn memory_error_address_str(value: u32) -> String {
+    if value == 0x8000_0000 {
+        "Unknown".to_string()
+    } else {
+        format!("0x{:08X}", value)
+    }
+}
+
+/// Parser for SMBIOS Type 18: 32-bit Memory Error Information.
+fn parse_type_18(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x17) {
+        return info;
+    }
+    info.push((
+        "Error Type".into(),
+        memory_error_type_str(data[offset + 0x04]),
+    ));
+    info.push((
+        "Error Granularity".into(),
+        memory_error_granularity_str(data[offset + 0x05]),
+    ));
+    info.push((
+        "Error Operation".into(),
+        memory_error_operation_str(data[offset + 0x06]),
+    ));
+
+    let syndrome = LittleEndian::read_u32(&data[offset + 0x07..offset + 0x0B]);
+    info.push((
+        "Vendor Syndrome".into(),
+        if syndrome == 0 {
+            "Unknown".to_string()
+        } else {
+            format!("0x{:08X}", syndrome)
+        },
+    ));
+    info.push((
+        "Memory Array Error Address".into(),
+        memory_error_address_str(LittleEndian::read_u32(&data[offset + 0x0B..offset + 0x0F])),
+    ));
+    info.push((
+        "Device Error Address".into(),
+        memory_error_address_str(LittleEndian::read_u32(&data[offset + 0x0F..offset + 0x13])),
+    ));
+    info.push((
+        "Error Resolution".into(),
+        memory_error_address_str(LittleEndian::read_u32(&data[offset + 0x13..offset + 0x17])),
+    ));
+    info
+}
+
+/// Formats a 64-bit memory error address, where 0x8000000000000000 means "unknown".
+fn memory_error_address64_str(value: u64) -> String {
+    if value == 0x8000_0000_0000_0000 {
+        "Unknown".to_string()
+    } else {
+        format!("0x{:016X}", value)
+    }
+}
+
+/// Parser for SMBIOS Type 33: 64-bit Memory Error Information.
+///
+/// Same layout as Type 18 with 64-bit addresses, for systems with memory above 4 GB.
+fn parse_type_33(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x1F) {
+        return info;
+    }
+    info.push((
+        "Error Type".into(),
+        memory_error_type_str(data[offset + 0x04]),
+    ));
+    info.push((
+        "Error Granularity".into(),
+        memory_error_granularity_str(data[offset + 0x05]),
+    ));
+    info.push((
+        "Error Operation".into(),
+        memory_error_operation_str(data[offset + 0x06]),
+    ));
+
+    let syndrome = LittleEndian::read_u32(&data[offset + 0x07..offset + 0x0B]);
+    info.push((
+        "Vendor Syndrome".into(),
+        if syndrome == 0 {
+            "Unknown".to_string()
+        } else {
+            format!("0x{:08X}", syndrome)
+        },
+    ));
+    info.push((
+        "Memory Array Error Address".into(),
+        memory_error_address64_str(LittleEndian::read_u64(&data[offset + 0x0B..offset + 0x13])),
+    ));
+    info.push((
+        "Device Error Address".into(),
+        memory_error_address64_str(LittleEndian::read_u64(&data[offset + 0x13..offset + 0x1B])),
+    ));
+    info.push((
+        "Error Resolution".into(),
+        memory_error_address_str(LittleEndian::read_u32(&data[offset + 0x1B..offset + 0x1F])),
+    ));
+    info
+}
+
+/// Formats a byte count in the largest unit that represents it exactly, e.g. `128 GB`.
+pub fn memory_size_str(bytes: u64) -> String {
+    const UNITS: [&str; 7] = ["bytes", "KB", "MB", "GB", "TB", "PB", "EB"];
+    let mut value = bytes;
+    let mut unit = 0;
+    while unit + 1 < UNITS.len() && value >= 1024 && value & 0x3FF == 0 {
+        value /= 1024;
+        unit += 1;
+    }
+    if bytes == 1 {
+        return "1 byte".to_string();
+    }
+    format!("{} {}", value, UNITS[unit])
+}
+
+/// Decodes the address range shared by SMBIOS Types 19 and 20.
+///
+/// The 32-bit addresses at 0x04 and 0x08 count kilobytes. A starting address of 0xFFFFFFFF
+/// means the 64-bit byte addresses at `ext_at` and `ext_at + 8` are used instead.
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS data buffer.
+/// * `offset` - The offset of the structure.
+/// * `ext_at` - The offset of the extended starting address within the structure.
+///
+/// # Returns
+/// "Starting Address", "Ending Address" and "Range Size" rows, with byte addresses.
+fn mapped_address_range(data: &[u8], offset: usize, ext_at: usize) -> Vec<(String, String)> {
+    let start_kb = LittleEndian::read_u32(&data[offset + 0x04..offset + 0x08]);
+    let end_kb = LittleEndian::read_u32(&data[offset + 0x08..offset + 0x0C]);
+    let (start, end) = if start_kb != 0xFFFFFFFF {
+        (start_kb as u64 * 1024, end_kb as u64 * 1024 + 1023)
+    } else if require(data, offset, ext_at + 16) {
+        let at = offset + ext_at;
+        (
+            LittleEndian::read_u64(&data[at..at + 8]),
+            LittleEndian::read_u64(&data[at + 8..at + 16]),
+        )
+    } else {
+        return vec![
+            (
+                "Starting Address".to_string(),
+                "Extended (>4TB)".to_string(),
+            ),
+            ("Ending Address".to_string(), "Extended (>4TB)".to_string()),
+        ];
+    };
+
+    let size = match end.checked_sub(start).and_then(|d| d.checked_add(1)) {
+        Some(size) => memory_size_str(size),
+        None if end >= start => "16 EB".to_string(),
+        None => "Invalid range (end < start)".to_string(),
+    };
+    vec![
+        ("Starting Address".to_string(), format!("0x{:016X}", start)),
+        ("Ending Address".to_string(), format!("0x{:016X}", end)),
+        ("Range Size".to_string(), size),
+    ]
+}
+
+/// Parser for SMBIOS Type 20: Memory Device Mapped Address.
+fn parse_type_20(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x13) {
+        return info;
+    }
+
+    let device_handle = LittleEndian::read_u16(&data[offset + 0x0C..offset + 0x0E]);
+    let mapped_handle = LittleEndian::read_u16(&data[offset + 0x0E..offset + 0x10]);
+
+    info.extend(mapped_address_range(data, offset, 0x13));
+
+    info.push((
+        "Memory Device Handle".to_string(),
+        format!("0x{:04X}", device_handle),
+    ));
+    info.push((
+        "Memory Array Mapped Address Handle".to_string(),
+        format!("0x{:04X}", mapped_handle),
+    ));
+
+    let unknown_or = |value: u8| {
+        if value == 0xFF {
+            "Unknown".to_string()
+        } else {
+            value.to_string()
+        }
+    };
+    info.push((
+        "Partition Row Position".to_string(),
+        unknown_or(data[offset + 0x10]),
+    ));
+    info.push((
+        "Interleave Position".to_string(),
+        match data[offset + 0x11] {
+            0 => "Non-interleaved".to_string(),
+            value => unknown_or(value),
+        },
+    ));
+    info.push((
+        "Interleaved Data Depth".to_string(),
+        match data[offset + 0x12] {
+            0 => "Non-interleaved".to_string(),
+            value => unknown_or(value),
+        },
+    ));
+    info
+}
+
+/// Parser for SMBIOS Type 21: Built-in Pointing Device.
+fn parse_type_21(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x07) {
+        return info;
+    }
+    let device_type = data[offset + 0x04];
+    let interface = data[offset + 0x05];
+    let buttons = data[offset + 0x06];
+
+    info.push(("Type".to_string(), pointing_device_type_str(device_type)));
+    info.push((
+        "Interface".to_string(),
+        pointing_device_interface_str(interface),
+    ));
+    info.push(("Number of Buttons".to_string(), buttons.to_string()));
+    info
+}
+
+/// Returns human-readable pointing device type string.
+fn pointing_device_type_str(code: u8) -> String {
+    match code {
+        0x01 => "Other".to_string(),
+        0x02 => "Unknown".to_string(),
+        0x03 => "Mouse".to_string(),
+        0x04 => "Track Ball".to_string(),
+        0x05 => "Track Point".to_string(),
+        0x06 => "Glide Point".to_string(),
+        0x07 => "Touch Pad".to_string(),
+        0x08 => "Touch Screen".to_string(),
+        0x09 => "Optical Sensor".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Returns human-readable pointing device interface string.
+fn pointing_device_interface_str(code: u8) -> String {
+    match code {
+        0x01 => "Other".to_string(),
+        0x02 => "Unknown".to_string(),
+        0x03 => "Serial".to_string(),
+        0x04 => "PS/2".to_string(),
+        0x05 => "Infrared".to_string(),
+        0x06 => "HP-HIL".to_string(),
+        0x07 => "Bus Mouse".to_string(),
+        0x08 => "ADB (Apple Desktop Bus)".to_string(),
+        0xA0 => "Bus Mouse DB-9".to_string(),
+        0xA1 => "Bus Mouse Micro-DIN".to_string(),
+        0xA2 => "USB".to_string(),
+        0xA3 => "I2C".to_string(),
+        0xA4 => "SPI".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Decodes the Type 22 Device Chemistry byte.
+fn battery_chemistry_str(code: u8) -> String {
+    match code {
+        0x01 => "Other".to_string(),
+        0x02 => "Unknown".to_string(),
+        0x03 => "Lead Acid".to_string(),
+        0x04 => "Nickel Cadmium".to_string(),
+        0x05 => "Nickel Metal Hydride".to_string(),
+        0x06 => "Lithium-ion".to_string(),
+        0x07 => "Zinc Air".to_string(),
+        0x08 => "Lithium Polymer".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Parser for SMBIOS Type 22: Portable Battery.
+fn parse_type_22(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x0E) {
+        return info;
+    }
+    info.push((
+        "Location".into(),
+        get_string_by_index(strings, data[offset + 0x04]),
+    ));
+    info.push((
+        "Manufacturer".into(),
+        get_string_by_index(strings, data[offset + 0x05]),
+    ));
+    info.push((
+        "Manufacture Date".into(),
+        get_string_by_index(strings, data[offset + 0x06]),
+    ));
+    info.push((
+        "Serial Number".into(),
+        get_string_by_index(strings, data[offset + 0x07]),
+    ));
+    info.push((
+        "Device Name".into(),
+        get_string_by_index(strings, data[offset + 0x08]),
+    ));
+    info.push((
+        "Device Chemistry".into(),
+        battery_chemistry_str(data[offset + 0x09]),
+    ));
+    // Design Capacity is scaled by the multiplier added in SMBIOS 2.2
+    let capacity = LittleEndian::read_u16(&data[offset + 0x0A..offset + 0x0C]) as u32;
+    let multiplier = if require(data, offset, 0x16) {
+        data[offset + 0x15].max(1) as u32
+    } else {
+        1
+    };
+    info.push((
+        "Design Capacity".into(),
+        if capacity == 0 {
+            "Unknown".to_string()
+        } else {
+            format!("{} mWh", capacity * multiplier)
+        },
+    ));
+    let voltage = LittleEndian::read_u16(&data[offset + 0x0C..offset + 0x0E]);
+    info.push((
+        "Design Voltage".into(),
+        if voltage == 0 {
+            "Unknown".to_string()
+        } else {
+            format!("{} mV", voltage)
+        },
+    ));
+    info
+}
+
+/// Decodes a System Reset boot option (2-bit field of the Type 23 Capabilities byte).
+fn reset_boot_option_str(code: u8) -> String {
+    match code {
+        1 => "Operating System".to_string(),
+        2 => "System Utilities".to_string(),
+        3 => "Do Not Reboot".to_string(),
+        _ => "Reserved".to_string(),
+    }
+}
+
+/// Formats a Type 23 WORD field, where 0xFFFF means unknown.
+fn reset_word_str(value: u16, unit: &str) -> String {
+    if value == 0xFFFF {
+        "Unknown".to_string()
+    } else {
+        format!("{}{}", value, unit)
+    }
+}
+
+/// Parser for SMBIOS Type 23: System Reset.
+fn parse_type_23(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x0D) {
+        return info;
+    }
+    let capabilities = data[offset + 0x04];
+    let read_u16 = |at: usize| LittleEndian::read_u16(&data[offset + at..offset + at + 2]);
+    info.push((
+        "Reset Capabilities".into(),
+        format!("0x{:02X}", capabilities),
+    ));
+    info.push((
+        "Status".into(),
+        if capabilities & 0x01 != 0 {
+            "Enabled".to_string()
+        } else {
+            "Disabled".to_string()
+        },
+    ));
+    info.push((
+        "Watchdog Timer".into(),
+        if capabilities & 0x20 != 0 {
+            "Present".to_string()
+        } else {
+            "Not Present".to_string()
+        },
+    ));
+    info.push((
+        "Boot Option".into(),
+        reset_boot_option_str((capabilities >> 1) & 0x03),
+    ));
+    info.push((
+        "Boot Option On Limit".into(),
+        reset_boot_option_str((capabilities >> 3) & 0x03),
+    ));
+    info.push(("Reset Count".into(), reset_word_str(read_u16(0x05), "")));
+    info.push(("Reset Limit".into(), reset_word_str(read_u16(0x07), "")));
+    info.push((
+        "Timer Interval".into(),
+        reset_word_str(read_u16(0x09), " min"),
+    ));
+    info.push(("Timeout".into(), reset_word_str(read_u16(0x0B), " min")));
+    info
+}
+
+/// Decodes a 2-bit Hardware Security status field (Type 24).
+fn hardware_security_status_str(code: u8) -> String {
+    match code {
+        0 => "Disabled".to_string(),
+        1 => "Enabled".to_string(),
+        2 => "Not Implemented".to_string(),
+        _ => "Unknown".to_string(),
+    }
+}
+
+/// Parser for SMBIOS Type 24: Hardware Security.
+fn parse_type_24(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x05) {
+        return info;
+    }
+    let settings = data[offset + 0x04];
+    info.push((
+        "Hardware Security Settings".into(),
+        format!("0x{:02X}", settings),
+    ));
+    info.push((
+        "Power-On Password Status".into(),
+        hardware_security_status_str(settings >> 6),
+    ));
+    info.push((
+        "Keyboard Password Status".into(),
+        hardware_security_status_str((settings >> 4) & 0x03),
+    ));
+    info.push((
+        "Administrator Password Status".into(),
+        hardware_security_status_str((settings >> 2) & 0x03),
+    ));
+    info.push((
+        "Front Panel Reset Status".into(),
+        hardware_security_status_str(settings & 0x03),
+    ));
+    info
+}
+
+/// Formats a BCD byte from Type 25, where 0xFF stands for "any" and is shown as `*`.
+fn power_control_bcd_str(value: u8) -> String {
+    if value == 0xFF {
+        "*".to_string()
+    } else {
+        format!("{:02X}", value)
+    }
+}
+
+/// Parser for SMBIOS Type 25: System Power Controls.
+fn parse_type_25(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x09) {
+        return info;
+    }
+    let field = |at: usize| power_control_bcd_str(data[offset + at]);
+    info.push((
+        "Next Scheduled Power-on".into(),
+        format!(
+            "{}-{} {}:{}:{}",
+            field(0x04),
+            field(0x05),
+            field(0x06),
+            field(0x07),
+            field(0x08)
+        ),
+    ));
+    info.push((
+        "Format".into(),
+        "MM-DD hh:mm:ss (BCD, * = every)".to_string(),
+    ));
+    info
+}
+
+/// The physical quantity measured by an SMBIOS probe structure (Types 26, 28, 29).
+#[derive(Clone, Copy)]
+enum ProbeKind {
+    Voltage,
+    Temperature,
+    Current,
+}
+
+impl ProbeKind {
+    /// Formats a reading (mV, 1/10 deg C, or mA), honoring the 0x8000 "unknown" sentinel.
+    fn value_str(self, raw: u16) -> String {
+        if raw == 0x8000 {
+            return "Unknown".to_string();
+        }
+        let value = raw as i16 as f64;
+        match self {
+            ProbeKind::Voltage => format!("{:.3} V", value / 1000.0),
+            ProbeKind::Temperature => format!("{:.1} deg C", value / 10.0),
+            ProbeKind::Current => format!("{:.3} A", value / 1000.0),
+        }
+    }
+
+    /// Formats a resolution (1/10 mV, 1/1000 deg C, or 1/10 mA).
+    fn resolution_str(self, raw: u16) -> String {
+        if raw == 0x8000 {
+            return "Unknown".to_string();
+        }
+        match self {
+            ProbeKind::Voltage => format!("{:.1} mV", raw as f64 / 10.0),
+            ProbeKind::Temperature => format!("{:.3} deg C", raw as f64 / 1000.0),
+            ProbeKind::Current => format!("{:.1} mA", raw as f64 / 10.0),
+        }
+    }
+
+    /// Formats a +/- tolerance, which uses the same units as the readings.
+    fn tolerance_str(self, raw: u16) -> String {
+        match self.value_str(raw).as_str() {
+            "Unknown" => "Unknown".to_string(),
+            value => format!("+/- {}", value),
+        }
+    }
+
+    /// Decodes the location bits (4:0) of the Location and Status byte.
+    fn location_str(self, code: u8) -> String {
+        match (self, code) {
+            (_, 1) => "Other".to_string(),
+            (_, 2) => "Unknown".to_string(),
+            (_, 3) => "Processor".to_string(),
+            (_, 4) => "Disk".to_string(),
+            (_, 5) => "Peripheral Bay".to_string(),
+            (_, 6) => "System Management Module".to_string(),
+            (_, 7) => "Motherboard".to_string(),
+            (_, 8) => "Memory Module".to_string(),
+            (_, 9) => "Processor Module".to_string(),
+            (_, 10) => "Power Unit".to_string(),
+            (_, 11) => "Add-in Card".to_string(),
+            (ProbeKind::Temperature, 12) => "Front Panel Board".to_string(),
+            (ProbeKind::Temperature, 13) => "Back Panel Board".to_string(),
+            (ProbeKind::Temperature, 14) => "Power System Board".to_string(),
+            (ProbeKind::Temperature, 15) => "Drive Back Plane".to_string(),
+            _ => format!("Unknown (0x{:02X})", code),
+        }
+    }
+}
+
+/// Decodes the status bits (7:5) shared by probe and cooling device structures.
+fn probe_status_str(code: u8) -> String {
+    match code {
+        1 => "Other".to_string(),
+        2 => "Unknown".to_string(),
+        3 => "OK".to_string(),
+        4 => "Non-critical".to_string(),
+        5 => "Critical".to_string(),
+        6 => "Non-recoverable".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Decodes the device type bits (4:0) of a Type 27 cooling device.
+fn cooling_device_type_str(code: u8) -> String {
+    match code {
+        1 => "Other".to_string(),
+        2 => "Unknown".to_string(),
+        3 => "Fan".to_string(),
+        4 => "Centrifugal Blower".to_string(),
+        5 => "Chip Fan".to_string(),
+        6 => "Cabinet Fan".to_string(),
+        7 => "Power Supply Fan".to_string(),
+        8 => "Heat Pipe".to_string(),
+        9 => "Integrated Refrigeration".to_string(),
+        16 => "Active Cooling".to_string(),
+        17 => "Passive Cooling".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Shared parser for the probe structures (Types 26, 28, and 29), which have identical layouts.
+fn parse_probe(
+    data: &[u8],
+    offset: usize,
+    strings: &[String],
+    kind: ProbeKind,
+) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x14) {
+        return info;
+    }
+    let read_u16 = |at: usize| LittleEndian::read_u16(&data[offset + at..offset + at + 2]);
+    let location_status = data[offset + 0x05];
+    info.push((
+        "Description".into(),
+        get_string_by_index(strings, data[offset + 0x04]),
+    ));
+    info.push(("Location".into(), kind.location_str(location_status & 0x1F)));
+    info.push(("Status".into(), probe_status_str(location_status >> 5)));
+    info.push(("Maximum Value".into(), kind.value_str(read_u16(0x06))));
+    info.push(("Minimum Value".into(), kind.value_str(read_u16(0x08))));
+    info.push(("Resolution".into(), kind.resolution_str(read_u16(0x0A))));
+    info.push(("Tolerance".into(), kind.tolerance_str(read_u16(0x0C))));
+    let accuracy = read_u16(0x0E);
+    info.push((
+        "Accuracy".into(),
+        if accuracy == 0x8000 {
+            "Unknown".to_string()
+        } else {
+            format!("+/- {:.2}%", accuracy as f64 / 100.0)
+        },
+    ));
+    info.push((
+        "OEM-defined".into(),
+        format!(
+            "0x{:08X}",
+            LittleEndian::read_u32(&data[offset + 0x10..offset + 0x14])
+        ),
+    ));
+    // Nominal Value is only present when the structure is 0x16 bytes long
+    if require(data, offset, 0x16) {
+        info.push(("Nominal Value".into(), kind.value_str(read_u16(0x14))));
+    }
+    info
+}
+
+/// Parser for SMBIOS Type 26: Voltage Probe.
+fn parse_type_26(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
+    parse_probe(data, offset, strings, ProbeKind::Voltage)
+}
+
+/// Parser for SMBIOS Type 27: Cooling Device.
+fn parse_type_27(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x0C) {
+        return info;
+    }
+    // The description string was added in SMBIOS 2.7
+    if require(data, offset, 0x0F) {
+        info.push((
+            "Description".into(),
+            get_string_by_index(strings, data[offset + 0x0E]),
+        ));
+    }
+    let probe = LittleEndian::read_u16(&data[offset + 0x04..offset + 0x06]);
+    info.push((
+        "Temperature Probe Handle".into(),
+        if probe == 0xFFFF {
+            "None".to_string()
+        } else {
+            format!("0x{:04X}", probe)
+        },
+    ));
+    let type_status = data[offset + 0x06];
+    info.push((
+        "Device Type".into(),
+        cooling_device_type_str(type_status & 0x1F),
+    ));
+    info.push(("Status".into(), probe_status_str(type_status >> 5)));
+    info.push((
+        "Cooling Unit Group".into(),
+        match data[offset + 0x07] {
+            0 => "None".to_string(),
+            group => format!("{}", group),
+        },
+    ));
+    info.push((
+        "OEM-defined".into(),
+        format!(
+            "0x{:08X}",
+            LittleEndian::read_u32(&data[offset + 0x08..offset + 0x0C])
+        ),
+    ));
+    if require(data, offset, 0x0E) {
+        let speed = LittleEndian::read_u16(&data[offset + 0x0C..offset + 0x0E]);
+        info.push((
+            "Nominal Speed".into(),
+            if speed == 0x8000 {
+                "Unknown".to_string()
+            } else {
+                format!("{} rpm", speed)
+            },
+        ));
+    }
+    info
+}
+
+/// Parser for SMBIOS Type 28: Temperature Probe.
+fn parse_type_28(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
+    parse_probe(data, offset, strings, ProbeKind::Temperature)
+}
+
+/// Parser for SMBIOS Type 29: Electrical Current Probe.
+fn parse_type_29(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
+    parse_probe(data, offset, strings, ProbeKind::Current)
+}
+
+/// Parser for SMBIOS Type 30: Out-of-Band Remote Access.
+fn parse_type_30(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x06) {
+        return info;
+    }
+    info.push((
+        "Manufacturer Name".into(),
+        get_string_by_index(strings, data[offset + 0x04]),
+    ));
+    let flags = data[offset + 0x05];
+    info.push((
+        "Connections".into(),
+        flags_with_names(
+            format!("0x{:02X}", flags),
+            &decode_flags(
+                flags as u64,
+                &[(0, "Inbound Enabled"), (1, "Outbound Enabled")],
+            ),
+        ),
+    ));
+    info
+}
+
+/// Parser for SMBIOS Type 31: Boot Integrity Services (BIS) Entry Point.
+fn parse_type_31(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x10) {
+        return info;
+    }
+    info.push(("Checksum".into(), format!("0x{:02X}", data[offset + 0x04])));
+    info.push((
+        "16-bit Entry Point".into(),
+        format!(
+            "0x{:08X}",
+            LittleEndian::read_u32(&data[offset + 0x08..offset + 0x0C])
+        ),
+    ));
+    info.push((
+        "32-bit Entry Point".into(),
+        format!(
+            "0x{:08X}",
+            LittleEndian::read_u32(&data[offset + 0x0C..offset + 0x10])
+        ),
+    ));
+    info
+}
+
+/// Parser for SMBIOS Type 0: BIOS Information.
+fn parse_type_0(
+    data: &[u8],
+    offset: usize,
+    strings: &[String],
+    version: Option<SmbiosVersion>,
+) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x0A) {
+        return info;
+    }
+    let vendor_idx = data[offset + 0x04];
+    let ver_idx = data[offset + 0x05];
+    let date_idx = data[offset + 0x08];
+    let rom_size_enc = data[offset + 0x09];
+
+    info.push((
+        "Vendor".to_string(),
+        get_string_by_index(strings, vendor_idx),
+    ));
+    info.push(("Version".to_string(), get_string_by_index(strings, ver_idx)));
+    info.push((
+        "Release Date".to_string(),
+        get_string_by_index(strings, date_idx),
+    ));
+
+    // 0xFF means 16 MB or more; SMBIOS 3.1 moved the real size to the word at 0x18
+    let size = match rom_size_enc {
+        0xFF if version.is_some_and(|v| v < SmbiosVersion::new(3, 1)) => {
+            "16 MB or greater".to_string()
+        }
+        0xFF if require(data, offset, 0x1A) => {
+            extended_rom_size_str(LittleEndian::read_u16(&data[offset + 0x18..offset + 0x1A]))
+        }
+        0xFF => "16 MB or greater (no Extended BIOS ROM Size field)".to_string(),
+        _ => format!("{} KB", (rom_size_enc as u32 + 1) * 64),
+    };
+    info.push(("ROM Size".to_string(), size));
+
+    // SMBIOS 2.0 structures end at the characteristics; later fields are bounded by the length
+    if !require(data, offset, 0x12) {
+        return info;
+    }
+    let characteristics = LittleEndian::read_u64(&data[offset + 0x0A..offset + 0x12]);
+    info.push((
+        "Characteristics".to_string(),
+        flags_with_names(
+            format!("0x{:016X}", characteristics),
+            &decode_flags(characteristics, BIOS_CHARACTERISTIC_DEFS),
+        ),
+    ));
+    for (i, defs) in [BIOS_CHARACTERISTIC_EXT1_DEFS, BIOS_CHARACTERISTIC_EXT2_DEFS]
+        .into_iter()
+        .enumerate()
+    {
+        if require(data, offset, 0x13 + i) {
+            let byte = data[offset + 0x12 + i];
+            info.push((
+                format!("Characteristics Extension {}", i + 1),
+                flags_with_names(format!("0x{:02X}", byte), &decode_flags(byte as u64, defs)),
+            ));
+        }
+    }
+    if require(data, offset, 0x18) {
+        info.push((
+            "System BIOS Release".to_string(),
+            firmware_release_str(data[offset + 0x14], data[offset + 0x15]),
+        ));
+        info.push((
+            "EC Firmware Release".to_string(),
+            firmware_release_str(data[offset + 0x16], data[offset + 0x17]),
+        ));
+    }
+    info
+}
+
+/// Formats the SMBIOS Type 0 Extended BIOS ROM Size word (offset 0x18, SMBIOS 3.1+).
+///
+/// Bits 15:14 select the unit (00b = MB, 01b = GB) and bits 13:0 hold the size.
+fn extended_rom_size_str(value: u16) -> String {
+    let size = value & 0x3FFF;
+    match value >> 14 {
+        0 => format!("{} MB", size),
+        1 => format!("{} GB", size),
+        unit => format!("{} (reserved unit {})", size, unit),
+    }
+}
+
+/// Bit definitions for the SMBIOS Type 0 BIOS Characteristics qword (offset 0x0A).
+///
+/// Bits 32-63 are reserved for the BIOS and system vendors and are not named.
+const BIOS_CHARACTERISTIC_DEFS: &[(u32, &str)] = &[
+    (2, "Characteristics Unknown"),
+    (3, "Characteristics Not Supported"),
+    (4, "ISA"),
+    (5, "MCA"),
+    (6, "EISA"),
+    (7, "PCI"),
+    (8, "PC Card (PCMCIA)"),
+    (9, "Plug and Play"),
+    (10, "APM"),
+    (11, "BIOS Is Upgradeable"),
+    (12, "BIOS Shadowing Allowed"),
+    (13, "VL-VESA"),
+    (14, "ESCD"),
+    (15, "Boot from CD"),
+    (16, "Selectable Boot"),
+    (17, "BIOS ROM Is Socketed"),
+    (18, "Boot from PC Card"),
+    (19, "EDD"),
+    (20, "Int 13h NEC 9800 1.2 MB Floppy"),
+    (21, "Int 13h Toshiba 1.2 MB Floppy"),
+    (22, "Int 13h 5.25\" 360 KB Floppy"),
+    (23, "Int 13h 5.25\" 1.2 MB Floppy"),
+    (24, "Int 13h 3.5\" 720 KB Floppy"),
+    (25, "Int 13h 3.5\" 2.88 MB Floppy"),
+    (26, "Int 5h Print Screen"),
+    (27, "Int 9h 8042 Keyboard"),
+    (28, "Int 14h Serial"),
+    (29, "Int 17h Printer"),
+    (30, "Int 10h CGA/Mono Video"),
+    (31, "NEC PC-98"),
+];
+
+/// Bit definitions for the SMBIOS Type 0 BIOS Characteristics Extension Byte 1 (offset 0x12).
+const BIOS_CHARACTERISTIC_EXT1_DEFS: &[(u32, &str)] = &[
+    (0, "ACPI"),
+    (1, "USB Legacy"),
+    (2, "AGP"),
+    (3, "I2O Boot"),
+    (4, "LS-120 Boot"),
+    (5, "ATAPI ZIP Boot"),
+    (6, "IEEE 1394 Boot"),
+    (7, "Smart Battery"),
+];
+
+/// Bit definitions for the SMBIOS Type 0 BIOS Characteristics Extension Byte 2 (offset 0x13).
+const BIOS_CHARACTERISTIC_EXT2_DEFS: &[(u32, &str)] = &[
+    (0, "BIOS Boot Specification"),
+    (1, "Function Key Network Boot"),
+    (2, "Targeted Content Distribution"),
+    (3, "UEFI Supported"),
+    (4, "Virtual Machine"),
+    (5, "Manufacturing Mode Supported"),
+    (6, "Manufacturing Mode Enabled"),
+];
+
+/// Formats an SMBIOS Type 0 major/minor release pair.
+///
+/// # Arguments
+/// * `major` - The major release byte.
+/// * `minor` - The minor release byte.
+///
+/// # Returns
+/// The release as "major.minor", or "Not Supported" when both bytes are 0xFF.
+fn firmware_release_str(major: u8, minor: u8) -> String {
+    if major == 0xFF && minor == 0xFF {
+        "Not Supported".to_string()
+    } else {
+        format!("{}.{}", major, minor)
+    }
+}
+
+/// Formats the 16-byte UUID of a Type 1 structure.
+///
+/// SMBIOS 2.6 and later store the first three fields little-endian (matching RFC 4122 as
+/// encoded by Windows); earlier versions stored every byte in network order. All-FF means the
+/// UUID is not present but can be set; all-zero means it is not present and cannot be set.
+///
+/// # Arguments
+/// * `bytes` - The 16 UUID bytes as stored in the structure.
+/// * `pre_2_6` - Read the bytes in the big-endian order used before SMBIOS 2.6.
+pub fn smbios_uuid_str(bytes: &[u8], pre_2_6: bool) -> String {
+    if bytes.iter().all(|&b| b == 0xFF) {
+        return "Not Present".to_string();
+    }
+    if bytes.iter().all(|&b| b == 0x00) {
+        return "Not Settable".to_string();
+    }
+    let parsed = if pre_2_6 {
+        uuid::Uuid::from_slice(bytes)
+    } else {
+        uuid::Uuid::from_slice_le(bytes)
+    };
+    match parsed {
+        Ok(u) => u.to_string().to_uppercase(),
+        Err(_) => hex::encode(bytes).to_uppercase(),
+    }
+}
+
+/// Parser for SMBIOS Type 1: System Information.
+fn parse_type_1(
+    data: &[u8],
+    offset: usize,
+    strings: &[String],
+    version: Option<SmbiosVersion>,
+) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x18) {
+        return info;
+    }
+    let man_idx = data[offset + 0x04];
+    let prod_idx = data[offset + 0x05];
+    let ver_idx = data[offset + 0x06];
+    let ser_idx = data[offset + 0x07];
+
+    info.push((
+        "Manufacturer".to_string(),
+        get_string_by_index(strings, man_idx),
+    ));
+    info.push((
+        "Product Name".to_string(),
+        get_string_by_index(strings, prod_idx),
+    ));
+    info.push(("Version".to_string(), get_string_by_index(strings, ver_idx)));
+    info.push((
+        "Serial Number".to_string(),
+        get_string_by_index(strings, ser_idx),
+    ));
+
+    // UUID at 0x08 (16 bytes). Without the table version the pre-2.6 reading is listed
+    // alongside the 2.6+ wire order.
+    let uuid_bytes = &data[offset + 0x08..offset + 0x18];
+    if let Some(version) = version {
+        let pre_2_6 = version < SmbiosVersion::new(2, 6);
+        info.push(("UUID".to_string(), smbios_uuid_str(uuid_bytes, pre_2_6)));
+        return info;
+    }
+    info.push(("UUID".to_string(), smbios_uuid_str(uuid_bytes, false)));
+    if uuid_bytes.iter().any(|&b| b != 0x00) && uuid_bytes.iter().any(|&b| b != 0xFF) {
+        info.push((
+            "UUID (Pre-2.6 Order)".to_string(),
+            smbios_uuid_str(uuid_bytes, true),
+        ));
+    }
+    info
+}
+
+/// Parser for SMBIOS Type 2: Baseboard (or Module) Information.
+fn parse_type_2(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x09) {
+        return info;
+    }
+    let man_idx = data[offset + 0x04];
+    let prod_idx = data[offset + 0x05];
+    let ver_idx = data[offset + 0x06];
+    let ser_idx = data[offset + 0x07];
+    let asset_idx = data[offset + 0x08];
+
+    info.push((
+        "Manufacturer".to_string(),
+        get_string_by_index(strings, man_idx),
+    ));
+    info.push((
+        "Product Name".to_string(),
+        get_string_by_index(strings, prod_idx),
+    ));
+    info.push(("Version".to_string(), get_string_by_index(strings, ver_idx)));
+    info.push((
+        "Serial Number".to_string(),
+        get_string_by_index(strings, ser_idx),
+    ));
+    info.push((
+        "Asset Tag".to_string(),
+        get_string_by_index(strings, asset_idx),
+    ));
+
+    // Older boards end at Asset Tag; everything after it is bounded by the structure length
+    if !require(data, offset, 0x0E) {
+        return info;
+    }
+    let features = data[offset + 0x09];
+    info.push((
+        "Feature Flags".to_string(),
+        flags_with_names(
+            format!("0x{:02X}", features),
+            &decode_flags(features as u64, BASEBOARD_FEATURE_DEFS),
+        ),
+    ));
+    info.push((
+        "Location in Chassis".to_string(),
+        get_string_by_index(strings, data[offset + 0x0A]),
+    ));
+    info.push((
+        "Chassis Handle".to_string(),
+        handle_ref_str(LittleEndian::read_u16(&data[offset + 0x0B..offset + 0x0D])),
+    ));
+    info.push((
+        "Board Type".to_string(),
+        board_type_str(data[offset + 0x0D]),
+    ));
+
+    if !require(data, offset, 0x0F) {
+        return info;
+    }
+    let count = data[offset + 0x0E] as usize;
+    info.push(("Contained Object Handles".to_string(), format!("{}", count)));
+    for i in 0..count {
+        if !require(data, offset, 0x0F + i * 2 + 2) {
+            break;
+        }
+        let pos = offset + 0x0F + i * 2;
+        info.push((
+            format!("Contained Object {} Handle", i + 1),
+            handle_ref_str(LittleEndian::read_u16(&data[pos..pos + 2])),
+        ));
+    }
+    info
+}
+
+/// Bit definitions for the SMBIOS Type 2 Feature Flags byte (offset 0x09).
+const BASEBOARD_FEATURE_DEFS: &[(u32, &str)] = &[
+    (0, "Hosting Board"),
+    (1, "Requires Daughter Board"),
+    (2, "Removable"),
+    (3, "Replaceable"),
+    (4, "Hot Swappable"),
+];
+
+/// Converts an SMBIOS Type 2 Board Type code to its name.
+///
+/// # Arguments
+/// * `code` - The Board Type byte (offset 0x0D).
+///
+/// # Returns
+/// The board type name, e.g. "Motherboard", or "Unknown (0xNN)" for undefined codes.
+fn board_type_str(code: u8) -> String {
+    match code {
+        0x01 => "Unknown".to_string(),
+        0x02 => "Other".to_string(),
+        0x03 => "Server Blade".to_string(),
+        0x04 => "Connectivity Switch".to_string(),
+        0x05 => "System Management Module".to_string(),
+        0x06 => "Processor Module".to_string(),
+        0x07 => "I/O Module".to_string(),
+        0x08 => "Memory Module".to_string(),
+        0x09 => "Daughter Board".to_string(),
+        0x0A => "Motherboard".to_string(),
+        0x0B => "Processor/Memory Module".to_string(),
+        0x0C => "Processor/IO Module".to_string(),
+        0x0D => "Interconnect Board".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Parser for SMBIOS Type 3: System Enclosure or Chassis Information.
+fn parse_type_3(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x08) {
+        return info;
+    }
+    let man_idx = data[offset + 0x04];
+    let type_code = data[offset + 0x05];
+    let ver_idx = data[offset + 0x06];
+    let ser_idx = data[offset + 0x07];
+
+    info.push((
+        "Manufacturer".to_string(),
+        get_string_by_index(strings, man_idx),
+    ));
+    info.push((
+        "Type".to_string(),
+        format!("{} (0x{:02X})", chassis_type_str(type_code), type_code),
+    ));
+    info.push(("Version".to_string(), get_string_by_index(strings, ver_idx)));
+    info.push((
+        "Serial Number".to_string(),
+        get_string_by_index(strings, ser_idx),
+    ));
+
+    // SMBIOS 2.0 chassis end at the serial number; later fields are bounded by the length
+    if !require(data, offset, 0x09) {
+        return info;
+    }
+    info.push((
+        "Asset Tag".to_string(),
+        get_string_by_index(strings, data[offset + 0x08]),
+    ));
+    if !require(data, offset, 0x0D) {
+        return info;
+    }
+    for (name, pos) in [
+        ("Boot-up State", 0x09),
+        ("Power Supply State", 0x0A),
+        ("Thermal State", 0x0B),
+    ] {
+        info.push((name.to_string(), chassis_state_str(data[offset + pos])));
+    }
+    info.push((
+        "Security Status".to_string(),
+        chassis_security_str(data[offset + 0x0C]),
+    ));
+    if !require(data, offset, 0x11) {
+        return info;
+    }
+    info.push((
+        "OEM-defined".to_string(),
+        format!(
+            "0x{:08X}",
+            LittleEndian::read_u32(&data[offset + 0x0D..offset + 0x11])
+        ),
+    ));
+    if !require(data, offset, 0x13) {
+        return info;
+    }
+    let unspecified = |value: u8, unit: &str| match value {
+        0 => "Unspecified".to_string(),
+        n => format!("{}{}", n, unit),
+    };
+    info.push(("Height".to_string(), unspecified(data[offset + 0x11], " U")));
+    info.push((
+        "Number of Power Cords".to_string(),
+        unspecified(data[offset + 0x12], ""),
+    ));
+    if !require(data, offset, 0x15) {
+        return info;
+    }
+
+    // Contained elements are count x record length bytes; the SKU string follows them
+    let count = data[offset + 0x13] as usize;
+    let record_len = data[offset + 0x14] as usize;
+    info.push(("Contained Elements".to_string(), format!("{}", count)));
+    if record_len >= 3 {
+        for i in 0..count {
+            let pos = 0x15 + i * record_len;
+            if !require(data, offset, pos + 3) {
+                break;
+            }
+            info.push((
+                format!("Contained Element {}", i + 1),
+                format!(
+                    "{}, min {}, max {}",
+                    contained_element_type_str(data[offset + pos]),
+                    data[offset + pos + 1],
+                    data[offset + pos + 2]
+                ),
+            ));
+        }
+    }
+    let sku_pos = 0x15 + count * record_len;
+    if require(data, offset, sku_pos + 1) {
+        info.push((
+            "SKU Number".to_string(),
+            get_string_by_index(strings, data[offset + sku_pos]),
+        ));
+    }
+    info
+}
+
+/// Converts an SMBIOS Type 3 state byte (Boot-up, Power Supply, or Thermal State) to its name.
+fn chassis_state_str(code: u8) -> String {
+    match code {
+        0x01 => "Other".to_string(),
+        0x02 => "Unknown".to_string(),
+        0x03 => "Safe".to_string(),
+        0x04 => "Warning".to_string(),
+        0x05 => "Critical".to_string(),
+        0x06 => "Non-recoverable".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Converts an SMBIOS Type 3 Security Status byte (offset 0x0C) to its name.
+fn chassis_security_str(code: u8) -> String {
+    match code {
+        0x01 => "Other".to_string(),
+        0x02 => "Unknown".to_string(),
+        0x03 => "None".to_string(),
+        0x04 => "External Interface Locked Out".to_string(),
+        0x05 => "External Interface Enabled".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Names the item type of a Type 3 contained element record.
+///
+/// # Arguments
+/// * `code` - The record's first byte; bit 7 set means bits 6:0 are an SMBIOS structure type,
+///   clear means they are a Type 2 board type.
+///
+/// # Returns
+/// The element type, e.g. "Type 17 (Memory Device)" or "Board: Processor Module".
+fn contained_element_type_str(code: u8) -> String {
+    let value = code & 0x7F;
+    if code & 0x80 == 0 {
+        return format!("Board: {}", board_type_str(value));
     }
-    let idx = index as usize;
-    if idx > 0 && idx <= strings.len() {
-        strings[idx - 1].clone()
-    } else {
-        format!("<Bad String Index: {}>", index)
+    match smbios_type_name(value) {
+        "" => format!("Type {}", value),
+        name => format!("Type {} ({})", value, name),
     }
 }
 
-/// Dispatches raw SMBIOS structure data to specific type parsers to get human-readable key-value pairs.
+/// Converts an SMBIOS Type 3 Chassis Type code to its name.
 ///
 /// # Arguments
-/// * `type_id` - The SMBIOS structure type (0, 1, 2, etc.).
-/// * `data` - The raw buffer.
-/// * `offset` - Starting offset of the structure.
-/// * `strings` - The extracted strings for this structure.
+/// * `code` - The Type byte (offset 0x05); bit 7 is the chassis lock flag and is ignored.
 ///
 /// # Returns
-/// `Option<Vec<(String, String)>>` containing field names and values.
-pub fn parse_smbios_details(
-    type_id: u8,
-    data: &[u8],
-    offset: usize,
-    _header_len: u8,
-    strings: &[String],
-) -> Option<Vec<(String, String)>> {
-    match type_id {
-        0 => Some(parse_type_0(data, offset, strings)),
-        1 => Some(parse_type_1(data, offset, strings)),
-        2 => Some(parse_type_2(data, offset, strings)),
-        3 => Some(parse_type_3(data, offset, strings)),
-        4 => Some(parse_type_4(data, offset, strings)),
-        10 => Some(parse_type_10(data, offset, strings)),
-        12 => Some(parse_type_12(data, offset, strings)),
-        15 => Some(parse_type_15(data, offset, strings)),
-        7 => Some(parse_type_7(data, offset, strings)),
-        8 => Some(parse_type_8(data, offset, strings)),
-        9 => Some(parse_type_9(data, offset, strings)),
-        11 => Some(parse_type_11(data, offset, strings)),
-        13 => Some(parse_type_13(data, offset, strings)),
-        16 => Some(parse_type_16(data, offset, strings)),
-        17 => Some(parse_type_17(data, offset, strings)),
-        19 => Some(parse_type_19(data, offset, strings)),
-        18 => Some(parse_type_18(data, offset, strings)),
-        22 => Some(parse_type_22(data, offset, strings)),
-        23 => Some(parse_type_23(data, offset, strings)),
-        24 => Some(parse_type_24(data, offset, strings)),
-        25 => Some(parse_type_25(data, offset, strings)),
-        26 => Some(parse_type_26(data, offset, strings)),
-        28 => Some(parse_type_28(data, offset, strings)),
-        31 => Some(parse_type_31(data, offset, strings)),
-        32 => Some(parse_type_32(data, offset, strings)),
-        127 => Some(parse_type_127(data, offset, strings)),
-        _ => None,
-    }
+/// The chassis type name, e.g. "Notebook", or "Unknown (0xNN)" for undefined codes.
+pub fn chassis_type_str(code: u8) -> String {
+    let name = match code & 0x7F {
+        0x01 => "Other",
+        0x02 => "Unknown",
+        0x03 => "Desktop",
+        0x04 => "Low Profile Desktop",
+        0x05 => "Pizza Box",
+        0x06 => "Mini Tower",
+        0x07 => "Tower",
+        0x08 => "Portable",
+        0x09 => "Laptop",
+        0x0A => "Notebook",
+        0x0B => "Hand Held",
+        0x0C => "Docking Station",
+        0x0D => "All in One",
+        0x0E => "Sub Notebook",
+        0x0F => "Space-saving",
+        0x10 => "Lunch Box",
+        0x11 => "Main Server Chassis",
+        0x12 => "Expansion Chassis",
+        0x13 => "SubChassis",
+        0x14 => "Bus Expansion Chassis",
+        0x15 => "Peripheral Chassis",
+        0x16 => "RAID Chassis",
+        0x17 => "Rack Mount Chassis",
+        0x18 => "Sealed-case PC",
+        0x19 => "Multi-system Chassis",
+        0x1A => "Compact PCI",
+        0x1B => "Advanced TCA",
+        0x1C => "Blade",
+        0x1D => "Blade Enclosure",
+        0x1E => "Tablet",
+        0x1F => "Convertible",
+        0x20 => "Detachable",
+        0x21 => "IoT Gateway",
+        0x22 => "Embedded PC",
+        0x23 => "Mini PC",
+        0x24 => "Stick PC",
+        _ => return format!("Unknown (0x{:02X})", code & 0x7F),
+    };
+    name.to_string()
 }
 
-/// Parser for SMBIOS Type 10: On Board Device Information (table 2.0+ uses Type 10).
-fn parse_type_10(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
-    let mut info = Vec::new();
-    if offset + 0x06 <= data.len() {
-        let device_type = data[offset + 0x04];
-        let description_idx = data[offset + 0x05];
-        info.push(("Device Type Raw".into(), format!("0x{:02X}", device_type)));
-        info.push((
-            "Description".into(),
-            get_string_by_index(strings, description_idx),
-        ));
+/// Formats a structure handle reference, treating 0xFFFE and 0xFFFF as "not provided".
+fn handle_ref_str(handle: u16) -> String {
+    match handle {
+        0xFFFE | 0xFFFF => "Not Provided".to_string(),
+        _ => format!("0x{:04X}", handle),
     }
-    info
 }
 
-/// Parser for SMBIOS Type 12: System Configuration Options.
-fn parse_type_12(_data: &[u8], _offset: usize, strings: &[String]) -> Vec<(String, String)> {
-    let mut info = Vec::new();
-    for (i, s) in strings.iter().enumerate() {
-        info.push((format!("Option {}", i + 1), s.clone()));
+/// Formats a Memory Error Information Handle (Types 16 and 17).
+///
+/// 0xFFFE means no error information structure is provided, and 0xFFFF means no error
+/// was detected.
+fn memory_error_handle_str(handle: u16) -> String {
+    match handle {
+        0xFFFE => "Not Provided".to_string(),
+        0xFFFF => "No Error".to_string(),
+        _ => format!("0x{:04X}", handle),
     }
-    info
 }
 
-/// Parser for SMBIOS Type 15: System Event Log.
-fn parse_type_15(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
+/// Parser for SMBIOS Type 4: Processor Information.
+fn parse_type_4(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
     let mut info = Vec::new();
-    if offset + 0x14 <= data.len() {
-        let area_length = LittleEndian::read_u16(&data[offset + 0x04..offset + 0x06]);
-        let header_start = LittleEndian::read_u16(&data[offset + 0x06..offset + 0x08]);
-        let data_start = LittleEndian::read_u16(&data[offset + 0x08..offset + 0x0A]);
-        let access_method = data[offset + 0x0C];
-        let log_status = data[offset + 0x0D];
-        info.push(("Area Length".into(), format!("{} bytes", area_length)));
-        info.push(("Header Start Offset".into(), format!("{}", header_start)));
-        info.push(("Data Start Offset".into(), format!("{}", data_start)));
-        info.push(("Access Method".into(), format!("0x{:02X}", access_method)));
-        info.push(("Log Status".into(), format!("0x{:02X}", log_status)));
+    if !require(data, offset, 0x11) {
+        return info;
     }
-    info
-}
+    let sock_idx = data[offset + 0x04];
+    let type_enum = data[offset + 0x05];
+    let man_idx = data[offset + 0x07];
+    let ver_idx = data[offset + 0x10];
 
-/// Parser for SMBIOS Type 18: 32-bit Memory Error Information.
-fn parse_type_18(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
-    let mut info = Vec::new();
-    if offset + 0x17 <= data.len() {
-        info.push((
-            "Error Type".into(),
-            format!("0x{:02X}", data[offset + 0x04]),
-        ));
-        info.push((
-            "Error Granularity".into(),
-            format!("0x{:02X}", data[offset + 0x05]),
-        ));
-        info.push((
-            "Error Operation".into(),
-            format!("0x{:02X}", data[offset + 0x06]),
-        ));
+    info.push((
+        "Socket Designator".to_string(),
+        get_string_by_index(strings, sock_idx),
+    ));
+    info.push(("Processor Type".to_string(), format!("0x{:02X}", type_enum)));
+    info.push((
+        "Manufacturer".to_string(),
+        get_string_by_index(strings, man_idx),
+    ));
+    info.push(("Version".to_string(), get_string_by_index(strings, ver_idx)));
+    if require(data, offset, 0x19) {
+        let status = data[offset + 0x18];
         info.push((
-            "Vendor Syndrome".into(),
-            format!(
-                "0x{:08X}",
-                LittleEndian::read_u32(&data[offset + 0x07..offset + 0x0B])
-            ),
-        ));
-        info.push((
-            "Memory Array Address".into(),
-            format!(
-                "0x{:08X}",
-                LittleEndian::read_u32(&data[offset + 0x0B..offset + 0x0F])
-            ),
-        ));
-        info.push((
-            "Device Address".into(),
-            format!(
-                "0x{:08X}",
-                LittleEndian::read_u32(&data[offset + 0x0F..offset + 0x13])
-            ),
-        ));
-        info.push((
-            "Resolution".into(),
-            format!(
-                "0x{:08X}",
-                LittleEndian::read_u32(&data[offset + 0x13..offset + 0x17])
-            ),
+            "Status".to_string(),
+            format!("{} (0x{:02X})", processor_status_str(status), status),
         ));
     }
+
+    if let Some((core_count, thread_count)) = processor_core_thread_counts(data, offset) {
+        info.push(("Core Count".to_string(), core_count.to_string()));
+        info.push(("Thread Count".to_string(), thread_count.to_string()));
+    }
+
+    // Cache handles were added in SMBIOS 2.1
+    if require(data, offset, 0x20) {
+        for (name, field) in [
+            ("L1 Cache Handle", 0x1A),
+            ("L2 Cache Handle", 0x1C),
+            ("L3 Cache Handle", 0x1E),
+        ] {
+            let handle = LittleEndian::read_u16(&data[offset + field..offset + field + 2]);
+            info.push((name.to_string(), handle_ref_str(handle)));
+        }
+    }
     info
 }
 
-/// Parser for SMBIOS Type 22: Portable Battery.
-fn parse_type_22(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
-    let mut info = Vec::new();
-    if offset + 0x0E <= data.len() {
-        info.push((
-            "Location".into(),
-            get_string_by_index(strings, data[offset + 0x04]),
-        ));
-        info.push((
-            "Manufacturer".into(),
-            get_string_by_index(strings, data[offset + 0x05]),
-        ));
-        info.push((
-            "Manufacture Date".into(),
-            get_string_by_index(strings, data[offset + 0x06]),
-        ));
-        info.push((
-            "Serial Number".into(),
-            get_string_by_index(strings, data[offset + 0x07]),
-        ));
-        info.push((
-            "Device Name".into(),
-            get_string_by_index(strings, data[offset + 0x0A]),
-        ));
-        info.push((
-            "Device Chemistry".into(),
-            format!("0x{:02X}", data[offset + 0x0B]),
-        ));
-        info.push((
-            "Design Capacity".into(),
-            format!(
-                "{} mWh?",
-                LittleEndian::read_u16(&data[offset + 0x0C..offset + 0x0E])
-            ),
-        ));
+/// Decodes the Type 4 Status byte, e.g. "Populated, Enabled".
+fn processor_status_str(status: u8) -> String {
+    let socket = if processor_socket_populated(status) {
+        "Populated"
+    } else {
+        "Unpopulated"
+    };
+    let cpu = match status & 0x07 {
+        0x00 => "Unknown".to_string(),
+        0x01 => "Enabled".to_string(),
+        0x02 => "Disabled by User".to_string(),
+        0x03 => "Disabled by BIOS".to_string(),
+        0x04 => "Idle".to_string(),
+        0x07 => "Other".to_string(),
+        code => format!("Unknown (0x{:02X})", code),
+    };
+    format!("{}, {}", socket, cpu)
+}
+
+/// Reads the core and thread counts of an SMBIOS Type 4 structure.
+///
+/// Processors with more than 255 cores or threads report 0xFF in the byte fields and the
+/// real value in the SMBIOS 3.0 Core Count 2 (0x2A) and Thread Count 2 (0x2E) words.
+///
+/// # Arguments
+/// * `data` - The SMBIOS table data.
+/// * `offset` - Starting offset of the Type 4 structure.
+///
+/// # Returns
+/// `(cores, threads)`, or `None` if the structure predates SMBIOS 2.5 and has no counts.
+pub fn processor_core_thread_counts(data: &[u8], offset: usize) -> Option<(u16, u16)> {
+    if !require(data, offset, 0x26) {
+        return None;
     }
-    info
+    let mut cores = data[offset + 0x23] as u16;
+    let mut threads = data[offset + 0x25] as u16;
+    if cores == 0xFF && require(data, offset, 0x2C) {
+        cores = LittleEndian::read_u16(&data[offset + 0x2A..offset + 0x2C]);
+    }
+    if threads == 0xFF && require(data, offset, 0x30) {
+        threads = LittleEndian::read_u16(&data[offset + 0x2E..offset + 0x30]);
+    }
+    Some((cores, threads))
 }
 
-/// Parser for SMBIOS Type 23: System Reset.
-fn parse_type_23(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
+/// Returns whether the "CPU Socket Populated" bit (bit 6) of a Type 4 Status byte is set.
+pub fn processor_socket_populated(status: u8) -> bool {
+    status & 0x40 != 0
+}
+
+/// Parser for SMBIOS Type 17: Memory Device Information.
+fn parse_type_17(
+    data: &[u8],
+    offset: usize,
+    strings: &[String],
+    version: Option<SmbiosVersion>,
+) -> Vec<(String, String)> {
     let mut info = Vec::new();
-    if offset + 0x0D <= data.len() {
-        info.push((
-            "Reset Capabilities".into(),
-            format!("0x{:02X}", data[offset + 0x04]),
-        ));
-        info.push((
-            "Reset Count".into(),
-            format!(
-                "{}",
-                LittleEndian::read_u16(&data[offset + 0x05..offset + 0x07])
-            ),
-        ));
-        info.push((
-            "Reset Limit".into(),
-            format!(
-                "{}",
-                LittleEndian::read_u16(&data[offset + 0x07..offset + 0x09])
-            ),
-        ));
-        info.push((
-            "Timer Interval".into(),
-            format!(
-                "{} ms",
-                LittleEndian::read_u16(&data[offset + 0x09..offset + 0x0B])
-            ),
-        ));
+    if !require(data, offset, 0x1B) {
+        return info;
+    }
+    let total_width = LittleEndian::read_u16(&data[offset + 0x08..offset + 0x0A]);
+    let data_width = LittleEndian::read_u16(&data[offset + 0x0A..offset + 0x0C]);
+    let size = LittleEndian::read_u16(&data[offset + 0x0C..offset + 0x0E]);
+    let speed = LittleEndian::read_u16(&data[offset + 0x15..offset + 0x17]);
+
+    let man_idx = data[offset + 0x17];
+    let ser_idx = data[offset + 0x18];
+    let asset_idx = data[offset + 0x19];
+    let part_idx = data[offset + 0x1A];
+
+    let dev_idx = data[offset + 0x10];
+    let bank_idx = data[offset + 0x11];
+
+    info.push((
+        "Device Locator".to_string(),
+        get_string_by_index(strings, dev_idx),
+    ));
+    info.push((
+        "Bank Locator".to_string(),
+        get_string_by_index(strings, bank_idx),
+    ));
+    info.push((
+        "Physical Array Handle".to_string(),
+        handle_ref_str(LittleEndian::read_u16(&data[offset + 0x04..offset + 0x06])),
+    ));
+    info.push((
+        "Error Info Handle".to_string(),
+        memory_error_handle_str(LittleEndian::read_u16(&data[offset + 0x06..offset + 0x08])),
+    ));
+
+    // Before SMBIOS 2.7 there is no Extended Size field, so 0x7FFF is a plain 32767 MB
+    let pre_2_7 = version.is_some_and(|v| v < SmbiosVersion::new(2, 7));
+    info.push((
+        "Size".to_string(),
+        match memory_device_size(data, offset) {
+            _ if size == 0x7FFF && pre_2_7 => memory_size_str(0x7FFF << 20),
+            None => "Unknown".to_string(),
+            Some(0) => "No Module Installed".to_string(),
+            Some(bytes) => memory_size_str(bytes),
+        },
+    ));
+    if size != 0 {
+        let memory_type = data[offset + 0x12];
         info.push((
-            "Timeout".into(),
-            format!(
-                "{} ms",
-                LittleEndian::read_u16(&data[offset + 0x0B..offset + 0x0D])
-            ),
+            "Memory Type".to_string(),
+            memory_device_type_str(memory_type),
         ));
     }
+
+    let format_speed = |s: u16| {
+        if s != 0 {
+            format!("{} MT/s", s)
+        } else {
+            "Unknown".to_string()
+        }
+    };
+    info.push(("Rated Speed".to_string(), format_speed(speed)));
+    // Configured Memory Speed was added in SMBIOS 2.7
+    if require(data, offset, 0x22) {
+        let configured = LittleEndian::read_u16(&data[offset + 0x20..offset + 0x22]);
+        info.push(("Configured Speed".to_string(), format_speed(configured)));
+    }
+    // Minimum/Maximum/Configured Voltage were added in SMBIOS 2.8
+    if require(data, offset, 0x28) {
+        let voltages = [
+            ("Minimum Voltage", 0x22),
+            ("Maximum Voltage", 0x24),
+            ("Configured Voltage", 0x26),
+        ];
+        for (name, field) in voltages {
+            let mv = LittleEndian::read_u16(&data[offset + field..offset + field + 2]);
+            info.push((
+                name.to_string(),
+                if mv != 0 {
+                    format!("{} mV", mv)
+                } else {
+                    "Unknown".to_string()
+                },
+            ));
+        }
+    }
+    // Module Manufacturer ID was added in SMBIOS 3.2
+    let module_manufacturer = require(data, offset, 0x2E)
+        .then(|| LittleEndian::read_u16(&data[offset + 0x2C..offset + 0x2E]));
+    let manufacturer = get_string_by_index(strings, man_idx);
+    let jedec_name = module_manufacturer.and_then(jedec_manufacturer_name);
+    info.push((
+        "Manufacturer".to_string(),
+        match jedec_name {
+            Some(name) if is_placeholder_memory_manufacturer(&manufacturer) => {
+                format!("{} (from JEDEC ID)", name)
+            }
+            _ => manufacturer,
+        },
+    ));
+    info.push((
+        "Serial Number".to_string(),
+        get_string_by_index(strings, ser_idx),
+    ));
+    info.push((
+        "Asset Tag".to_string(),
+        get_string_by_index(strings, asset_idx),
+    ));
+    info.push((
+        "Part Number".to_string(),
+        get_string_by_index(strings, part_idx),
+    ));
+    if let Some(id) = module_manufacturer {
+        info.push(("Module Manufacturer ID".to_string(), jedec_id_str(id)));
+    }
+
+    info.push(("Total Width".to_string(), format!("{} bits", total_width)));
+    info.push(("Data Width".to_string(), format!("{} bits", data_width)));
     info
 }
 
-/// Parser for SMBIOS Type 24: Hardware Security.
-fn parse_type_24(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
-    let mut info = Vec::new();
-    if offset + 0x05 <= data.len() {
-        info.push((
-            "Hardware Security Settings".into(),
-            format!("0x{:02X}", data[offset + 0x04]),
-        ));
+/// JEDEC JEP106 memory module manufacturers, keyed by the SMBIOS Type 17 ID word.
+///
+/// The low byte is the number of 0x7F continuation codes (the bank minus one) and the high
+/// byte the manufacturer code, both with their odd parity bit set as stored in SPD. Not all
+/// firmware sets the parity bit of the bank byte, so lookups ignore it.
+const JEDEC_MANUFACTURERS: &[(u16, &str)] = &[
+    (0x2C00, "Micron"),
+    (0x4F00, "Transcend"),
+    (0x7A00, "Apacer"),
+    (0x8900, "Intel"),
+    (0x9400, "Smart Modular"),
+    (0xAD00, "SK Hynix"),
+    (0xC100, "Infineon"),
+    (0xCE00, "Samsung"),
+    (0xFE00, "Elpida"),
+    (0x9801, "Kingston"),
+    (0x9E02, "Corsair"),
+    (0x0B83, "Nanya"),
+    (0xCB04, "ADATA"),
+    (0xCD04, "G.Skill"),
+    (0xEF04, "Team Group"),
+    (0x9B85, "Crucial"),
+];
+
+/// Looks up a Type 17 Module Manufacturer ID in the JEDEC manufacturer table.
+///
+/// # Arguments
+/// * `id` - The ID word as stored at offset 0x2C.
+///
+/// # Returns
+/// The manufacturer name, or `None` for 0 (unknown) and IDs not in the table.
+pub fn jedec_manufacturer_name(id: u16) -> Option<&'static str> {
+    JEDEC_MANUFACTURERS
+        .iter()
+        .find(|&&(code, _)| code & 0xFF7F == id & 0xFF7F)
+        .map(|&(_, name)| name)
+}
+
+/// Formats a JEDEC ID word the way dmidecode does, e.g. "Bank 1, Hex 0xCE (Samsung)".
+fn jedec_id_str(id: u16) -> String {
+    if id == 0 {
+        return "Unknown".to_string();
+    }
+    let raw = format!("Bank {}, Hex 0x{:02X}", (id & 0x7F) + 1, id >> 8);
+    match jedec_manufacturer_name(id) {
+        Some(name) => format!("{} ({})", raw, name),
+        None => raw,
     }
-    info
 }
 
-/// Parser for SMBIOS Type 25: System Power Controls.
-fn parse_type_25(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
-    let mut info = Vec::new();
-    if offset + 0x09 <= data.len() {
-        info.push((
-            "Next Scheduled Power-on Month".into(),
-            format!("{}", data[offset + 0x04]),
-        ));
-        info.push(("Day".into(), format!("{}", data[offset + 0x05])));
-        info.push(("Hour".into(), format!("{}", data[offset + 0x06])));
-        info.push(("Minute".into(), format!("{}", data[offset + 0x07])));
-        info.push(("Second".into(), format!("{}", data[offset + 0x08])));
+/// Checks whether a Type 17 Manufacturer string was left blank or filled with a stock value.
+fn is_placeholder_memory_manufacturer(value: &str) -> bool {
+    let value = value.trim();
+    value.is_empty()
+        || value.starts_with("<Bad String Index")
+        || ["None", "Unknown", "Undefined", "Not Specified", "NO DIMM"]
+            .iter()
+            .any(|p| p.eq_ignore_ascii_case(value))
+        || value
+            .strip_prefix("Manufacturer")
+            .is_some_and(|rest| rest.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Names of the Type 6 Current Memory Type bits.
+const MEMORY_MODULE_TYPE_DEFS: &[(u32, &str)] = &[
+    (0, "Other"),
+    (1, "Unknown"),
+    (2, "Standard"),
+    (3, "Fast Page Mode"),
+    (4, "EDO"),
+    (5, "Parity"),
+    (6, "ECC"),
+    (7, "SIMM"),
+    (8, "DIMM"),
+    (9, "Burst EDO"),
+    (10, "SDRAM"),
+];
+
+/// Decodes a Type 6 Installed/Enabled Size byte (bits 0-6 are log2 of the size in MB).
+fn memory_module_size_str(code: u8) -> String {
+    let banks = if code & 0x80 != 0 {
+        "Double-bank"
+    } else {
+        "Single-bank"
+    };
+    match code & 0x7F {
+        0x7D => "Not Determinable".to_string(),
+        0x7E => "Disabled".to_string(),
+        0x7F => "Not Installed".to_string(),
+        n if n < 32 => format!("{} MB ({})", 1u64 << n, banks),
+        n => format!("Unknown (0x{:02X})", n),
     }
-    info
 }
 
-/// Parser for SMBIOS Type 26: Voltage Probe.
-fn parse_type_26(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
+/// Parser for SMBIOS Type 6: Memory Module Information (obsolete).
+fn parse_type_6(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
     let mut info = Vec::new();
-    if offset + 0x14 <= data.len() {
-        info.push((
-            "Description".into(),
-            get_string_by_index(strings, data[offset + 0x04]),
-        ));
-        info.push(("Location".into(), format!("0x{:02X}", data[offset + 0x05])));
-        info.push(("Status".into(), format!("0x{:02X}", data[offset + 0x06])));
-        info.push((
-            "Maximum Value".into(),
-            format!(
-                "{}",
-                LittleEndian::read_u16(&data[offset + 0x08..offset + 0x0A])
-            ),
-        ));
-        info.push((
-            "Minimum Value".into(),
-            format!(
-                "{}",
-                LittleEndian::read_u16(&data[offset + 0x0A..offset + 0x0C])
-            ),
-        ));
-        info.push((
-            "Resolution".into(),
-            format!(
-                "{}",
-                LittleEndian::read_u16(&data[offset + 0x0C..offset + 0x0E])
-            ),
-        ));
-        info.push((
-            "Tolerance".into(),
-            format!(
-                "{}",
-                LittleEndian::read_u16(&data[offset + 0x0E..offset + 0x10])
-            ),
-        ));
-        info.push((
-            "Accuracy".into(),
-            format!(
-                "{}",
-                LittleEndian::read_u16(&data[offset + 0x10..offset + 0x12])
-            ),
-        ));
-        info.push((
-            "OEM-defined".into(),
-            format!(
-                "0x{:04X}",
-                LittleEndian::read_u16(&data[offset + 0x12..offset + 0x14])
-            ),
-        ));
+    if !require(data, offset, 0x0C) {
+        return info;
     }
+
+    info.push((
+        "Socket Designator".to_string(),
+        get_string_by_index(strings, data[offset + 0x04]),
+    ));
+
+    // Each nibble names one RAS line; 0xF means no connection
+    let banks = data[offset + 0x05];
+    let connections: Vec<String> = [banks >> 4, banks & 0x0F]
+        .iter()
+        .filter(|&&n| n != 0x0F)
+        .map(|n| n.to_string())
+        .collect();
+    info.push((
+        "Bank Connections".to_string(),
+        if connections.is_empty() {
+            "None".to_string()
+        } else {
+            connections.join(" ")
+        },
+    ));
+
+    let speed = data[offset + 0x06];
+    info.push((
+        "Current Speed".to_string(),
+        if speed != 0 {
+            format!("{} ns", speed)
+        } else {
+            "Unknown".to_string()
+        },
+    ));
+
+    let mem_type = LittleEndian::read_u16(&data[offset + 0x07..offset + 0x09]);
+    info.push((
+        "Type".to_string(),
+        flags_with_names(
+            format!("0x{:04X}", mem_type),
+            &decode_flags(mem_type as u64, MEMORY_MODULE_TYPE_DEFS),
+        ),
+    ));
+    info.push((
+        "Installed Size".to_string(),
+        memory_module_size_str(data[offset + 0x09]),
+    ));
+    info.push((
+        "Enabled Size".to_string(),
+        memory_module_size_str(data[offset + 0x0A]),
+    ));
+
+    let err = data[offset + 0x0B];
+    let status = if err & 0x04 != 0 {
+        "See Event Log".to_string()
+    } else if err & 0x03 == 0 {
+        "OK".to_string()
+    } else {
+        let mut parts = Vec::new();
+        if err & 0x01 != 0 {
+            parts.push("Uncorrectable Errors");
+        }
+        if err & 0x02 != 0 {
+            parts.push("Correctable Errors");
+        }
+        parts.join(", ")
+    };
+    info.push(("Error Status".to_string(), status));
     info
 }
 
-/// Parser for SMBIOS Type 28: Temperature Probe.
-fn parse_type_28(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
+/// Parser for SMBIOS Type 7: Cache Information.
+fn parse_type_7(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
     let mut info = Vec::new();
-    if offset + 0x14 <= data.len() {
-        info.push((
-            "Description".into(),
-            get_string_by_index(strings, data[offset + 0x04]),
-        ));
-        info.push(("Location".into(), format!("0x{:02X}", data[offset + 0x05])));
-        info.push(("Status".into(), format!("0x{:02X}", data[offset + 0x06])));
-        info.push((
-            "Maximum Value".into(),
-            format!(
-                "{}",
-                LittleEndian::read_u16(&data[offset + 0x08..offset + 0x0A])
-            ),
-        ));
-        info.push((
-            "Minimum Value".into(),
-            format!(
-                "{}",
-                LittleEndian::read_u16(&data[offset + 0x0A..offset + 0x0C])
-            ),
-        ));
-        info.push((
-            "Resolution".into(),
-            format!(
-                "{}",
-                LittleEndian::read_u16(&data[offset + 0x0C..offset + 0x0E])
-            ),
-        ));
+    if !require(data, offset, 0x10) {
+        return info;
+    }
+    let sock_idx = data[offset + 0x04];
+    let cfg = LittleEndian::read_u16(&data[offset + 0x05..offset + 0x07]);
+    let max_size = LittleEndian::read_u16(&data[offset + 0x07..offset + 0x09]);
+    let inst_size = LittleEndian::read_u16(&data[offset + 0x09..offset + 0x0B]);
+    let speed = data[offset + 0x0F];
+
+    info.push((
+        "Socket Designator".to_string(),
+        get_string_by_index(strings, sock_idx),
+    ));
+    info.push(("Configuration".to_string(), cache_configuration_str(cfg)));
+
+    let parse_size = |s: u16| match s {
+        0 => "None".to_string(),
+        _ => format!("{} KB", cache_size_kb(s)),
+    };
+
+    info.push(("Maximum Cache Size".to_string(), parse_size(max_size)));
+    info.push(("Installed Size".to_string(), parse_size(inst_size)));
+    info.push((
+        "Speed".to_string(),
+        if speed != 0 {
+            format!("{} ns", speed)
+        } else {
+            "Unknown".to_string()
+        },
+    ));
+
+    if require(data, offset, 0x13) {
+        let err_corr = data[offset + 0x10];
+        let sys_type = data[offset + 0x11];
+        let assoc = data[offset + 0x12];
         info.push((
-            "Tolerance".into(),
+            "Error Correction".to_string(),
             format!(
-                "{}",
-                LittleEndian::read_u16(&data[offset + 0x0E..offset + 0x10])
+                "{} (0x{:02X})",
+                cache_error_correction_str(err_corr),
+                err_corr
             ),
         ));
         info.push((
-            "Accuracy".into(),
-            format!(
-                "{}",
-                LittleEndian::read_u16(&data[offset + 0x10..offset + 0x12])
-            ),
+            "System Cache Type".to_string(),
+            format!("{} (0x{:02X})", system_cache_type_str(sys_type), sys_type),
         ));
         info.push((
-            "OEM-defined".into(),
-            format!(
-                "0x{:04X}",
-                LittleEndian::read_u16(&data[offset + 0x12..offset + 0x14])
-            ),
+            "Associativity".to_string(),
+            format!("{} (0x{:02X})", cache_associativity_str(assoc), assoc),
         ));
     }
     info
 }
 
-/// Parser for SMBIOS Type 31: Out-of-Band Remote Access.
-fn parse_type_31(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
-    let mut info = Vec::new();
-    if offset + 0x07 <= data.len() {
-        info.push((
-            "Manufacturer ID".into(),
-            format!("0x{:02X}", data[offset + 0x04]),
-        ));
-        info.push((
-            "Remote Access Flags".into(),
-            format!("0x{:02X}", data[offset + 0x05]),
-        ));
-        info.push((
-            "Access Info".into(),
-            format!("0x{:02X}", data[offset + 0x06]),
-        ));
+/// Converts a Type 7 cache size word to KB, applying the 64K granularity bit.
+fn cache_size_kb(size: u16) -> u32 {
+    let val = (size & 0x7FFF) as u32;
+    if size & 0x8000 != 0 {
+        val * 64
+    } else {
+        val
     }
-    info
 }
 
-/// Parser for SMBIOS Type 0: BIOS Information.
-fn parse_type_0(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
-    let mut info = Vec::new();
-    if offset + 0x09 < data.len() {
-        let vendor_idx = data[offset + 0x04];
-        let ver_idx = data[offset + 0x05];
-        let date_idx = data[offset + 0x08];
-        let rom_size_enc = data[offset + 0x09];
+/// Decodes the Type 7 cache configuration word into its raw value and readable parts.
+fn cache_configuration_str(cfg: u16) -> String {
+    let mut parts = vec![format!("Level {}", (cfg & 0x07) + 1)];
+    parts.extend(decode_flags(cfg as u64, &[(3, "Socketed"), (7, "Enabled")]));
+    parts.push(
+        match (cfg >> 5) & 0x03 {
+            0 => "Internal",
+            1 => "External",
+            2 => "Reserved Location",
+            _ => "Unknown Location",
+        }
+        .to_string(),
+    );
+    parts.push(
+        match (cfg >> 8) & 0x03 {
+            0 => "Write Through",
+            1 => "Write Back",
+            2 => "Varies with Memory Address",
+            _ => "Unknown Mode",
+        }
+        .to_string(),
+    );
+    flags_with_names(format!("0x{:04X}", cfg), &parts)
+}
 
-        info.push((
-            "Vendor".to_string(),
-            get_string_by_index(strings, vendor_idx),
-        ));
-        info.push(("Version".to_string(), get_string_by_index(strings, ver_idx)));
-        info.push((
-            "Release Date".to_string(),
-            get_string_by_index(strings, date_idx),
-        ));
+/// Converts an SMBIOS Type 7 error correction type to a string.
+fn cache_error_correction_str(code: u8) -> String {
+    match code {
+        0x01 => "Other".to_string(),
+        0x02 => "Unknown".to_string(),
+        0x03 => "None".to_string(),
+        0x04 => "Parity".to_string(),
+        0x05 => "Single-bit ECC".to_string(),
+        0x06 => "Multi-bit ECC".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
 
-        let size = if rom_size_enc == 0xFF {
-            "Extended".to_string()
+/// Converts an SMBIOS Type 7 system cache type to a string.
+fn system_cache_type_str(code: u8) -> String {
+    match code {
+        0x01 => "Other".to_string(),
+        0x02 => "Unknown".to_string(),
+        0x03 => "Instruction".to_string(),
+        0x04 => "Data".to_string(),
+        0x05 => "Unified".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Converts an SMBIOS Type 7 associativity code to a string.
+fn cache_associativity_str(code: u8) -> String {
+    match code {
+        0x01 => "Other".to_string(),
+        0x02 => "Unknown".to_string(),
+        0x03 => "Direct Mapped".to_string(),
+        0x04 => "2-way Set-Associative".to_string(),
+        0x05 => "4-way Set-Associative".to_string(),
+        0x06 => "Fully Associative".to_string(),
+        0x07 => "8-way Set-Associative".to_string(),
+        0x08 => "16-way Set-Associative".to_string(),
+        0x09 => "12-way Set-Associative".to_string(),
+        0x0A => "24-way Set-Associative".to_string(),
+        0x0B => "32-way Set-Associative".to_string(),
+        0x0C => "48-way Set-Associative".to_string(),
+        0x0D => "64-way Set-Associative".to_string(),
+        0x0E => "20-way Set-Associative".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Builds a short name for an SMBIOS Type 7 structure from its level and type, e.g. "L2 Unified Cache".
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS data buffer.
+/// * `offset` - The offset of the Type 7 structure.
+///
+/// # Returns
+/// The name, or `None` if the structure is too short to hold the configuration word.
+pub fn cache_display_name(data: &[u8], offset: usize) -> Option<String> {
+    if !require(data, offset, 0x07) {
+        return None;
+    }
+    let cfg = LittleEndian::read_u16(&data[offset + 0x05..offset + 0x07]);
+    let level = (cfg & 0x07) + 1;
+    // System Cache Type was added in SMBIOS 2.1
+    match data
+        .get(offset + 0x11)
+        .filter(|_| require(data, offset, 0x12))
+    {
+        Some(0x03) => Some(format!("L{} Instruction Cache", level)),
+        Some(0x04) => Some(format!("L{} Data Cache", level)),
+        Some(0x05) => Some(format!("L{} Unified Cache", level)),
+        _ => Some(format!("L{} Cache", level)),
+    }
+}
+
+/// Summarizes the installed size and type of the Type 7 structure at `offset`, e.g.
+/// "1024 KB Unified".
+///
+/// Uses Installed Cache Size 2 (SMBIOS 3.1) when the 16-bit field is saturated.
+///
+/// # Returns
+/// The summary, or `None` if the structure is too short to hold the installed size.
+pub fn cache_size_summary(data: &[u8], offset: usize) -> Option<String> {
+    if !require(data, offset, 0x0B) {
+        return None;
+    }
+    let installed = LittleEndian::read_u16(&data[offset + 0x09..offset + 0x0B]);
+    let kb = if installed == 0xFFFF && require(data, offset, 0x1B) {
+        // Bit 31 selects 64K granularity, the rest is the size
+        let size2 = LittleEndian::read_u32(&data[offset + 0x17..offset + 0x1B]);
+        let val = (size2 & 0x7FFF_FFFF) as u64;
+        if size2 & 0x8000_0000 != 0 {
+            val * 64
         } else {
-            let kb = (rom_size_enc as u32 + 1) * 64;
-            format!("{} KB", kb)
-        };
-        info.push(("ROM Size".to_string(), size));
+            val
+        }
+    } else {
+        cache_size_kb(installed) as u64
+    };
+    let mut summary = format!("{} KB", kb);
+    // System Cache Type was added in SMBIOS 2.1
+    if let Some(&code @ 0x03..=0x05) = data
+        .get(offset + 0x11)
+        .filter(|_| require(data, offset, 0x12))
+    {
+        summary.push(' ');
+        summary.push_str(&system_cache_type_str(code));
     }
-    info
+    Some(summary)
 }
 
-/// Parser for SMBIOS Type 1: System Information.
-fn parse_type_1(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
+/// Parser for SMBIOS Type 9: System Slots Information.
+fn parse_type_9(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
     let mut info = Vec::new();
-    if offset + 0x18 <= data.len() {
-        let man_idx = data[offset + 0x04];
-        let prod_idx = data[offset + 0x05];
-        let ver_idx = data[offset + 0x06];
-        let ser_idx = data[offset + 0x07];
+    if !require(data, offset, 0x0B) {
+        return info;
+    }
+    let name_idx = data[offset + 0x04];
+    let slot_type = data[offset + 0x05];
+    let bus_width = data[offset + 0x06];
+    let usage = data[offset + 0x07];
+    let len = data[offset + 0x08];
+    let id = LittleEndian::read_u16(&data[offset + 0x09..offset + 0x0B]);
 
-        info.push((
-            "Manufacturer".to_string(),
-            get_string_by_index(strings, man_idx),
-        ));
-        info.push((
-            "Product Name".to_string(),
-            get_string_by_index(strings, prod_idx),
-        ));
-        info.push(("Version".to_string(), get_string_by_index(strings, ver_idx)));
-        info.push((
-            "Serial Number".to_string(),
-            get_string_by_index(strings, ser_idx),
-        ));
+    info.push((
+        "Slot Designator".to_string(),
+        get_string_by_index(strings, name_idx),
+    ));
+    info.push(("Slot Type".to_string(), slot_type_str(slot_type)));
+    info.push(("Data Bus Width".to_string(), slot_bus_width_str(bus_width)));
+    info.push(("Current Usage".to_string(), slot_usage_str(usage)));
+    info.push(("Slot Length".to_string(), slot_length_str(len)));
+    info.push(("Slot ID".to_string(), format!("0x{:04X}", id)));
 
-        // UUID at 0x08 (16 bytes)
-        let uuid_bytes = &data[offset + 0x08..offset + 0x18];
-        if let Ok(u) = uuid::Uuid::from_slice_le(uuid_bytes) {
-            info.push(("UUID".to_string(), u.to_string().to_uppercase()));
+    // Segment Group / Bus / Device-Function were added in SMBIOS 2.6
+    if require(data, offset, 0x11) {
+        let segment = LittleEndian::read_u16(&data[offset + 0x0D..offset + 0x0F]);
+        let bus = data[offset + 0x0F];
+        let dev_func = data[offset + 0x10];
+        if segment == 0xFFFF && bus == 0xFF && dev_func == 0xFF {
+            info.push(("Bus Address".to_string(), "Not Applicable".to_string()));
         } else {
-            info.push(("UUID".to_string(), hex::encode(uuid_bytes).to_uppercase()));
+            info.push((
+                "Bus Address".to_string(),
+                format!(
+                    "{:04X}:{:02X}:{:02X}.{:X}",
+                    segment,
+                    bus,
+                    dev_func >> 3,
+                    dev_func & 0x07
+                ),
+            ));
         }
     }
     info
 }
 
-/// Parser for SMBIOS Type 2: Baseboard (or Module) Information.
-fn parse_type_2(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
+/// Returns human-readable system slot type string.
+fn slot_type_str(code: u8) -> String {
+    match code {
+        0x01 => "Other".to_string(),
+        0x02 => "Unknown".to_string(),
+        0x03 => "ISA".to_string(),
+        0x04 => "MCA".to_string(),
+        0x05 => "EISA".to_string(),
+        0x06 => "PCI".to_string(),
+        0x07 => "PC Card (PCMCIA)".to_string(),
+        0x08 => "VL-VESA".to_string(),
+        0x09 => "Proprietary".to_string(),
+        0x0A => "Processor Card Slot".to_string(),
+        0x0B => "Proprietary Memory Card Slot".to_string(),
+        0x0C => "I/O Riser Card Slot".to_string(),
+        0x0D => "NuBus".to_string(),
+        0x0E => "PCI - 66MHz Capable".to_string(),
+        0x0F => "AGP".to_string(),
+        0x10 => "AGP 2X".to_string(),
+        0x11 => "AGP 4X".to_string(),
+        0x12 => "PCI-X".to_string(),
+        0x13 => "AGP 8X".to_string(),
+        0x14 => "M.2 Socket 1-DP (Mechanical Key A)".to_string(),
+        0x15 => "M.2 Socket 1-SD (Mechanical Key E)".to_string(),
+        0x16 => "M.2 Socket 2 (Mechanical Key B)".to_string(),
+        0x17 => "M.2 Socket 3 (Mechanical Key M)".to_string(),
+        0x18 => "MXM Type I".to_string(),
+        0x19 => "MXM Type II".to_string(),
+        0x1A => "MXM Type III (standard connector)".to_string(),
+        0x1B => "MXM Type III (HE connector)".to_string(),
+        0x1C => "MXM Type IV".to_string(),
+        0x1D => "MXM 3.0 Type A".to_string(),
+        0x1E => "MXM 3.0 Type B".to_string(),
+        0x1F => "PCI Express Gen 2 SFF-8639 (U.2)".to_string(),
+        0x20 => "PCI Express Gen 3 SFF-8639 (U.2)".to_string(),
+        0x21 => "PCI Express Mini 52-pin with bottom-side keep-outs".to_string(),
+        0x22 => "PCI Express Mini 52-pin without bottom-side keep-outs".to_string(),
+        0x23 => "PCI Express Mini 76-pin".to_string(),
+        0x24 => "PCI Express Gen 4 SFF-8639 (U.2)".to_string(),
+        0x25 => "PCI Express Gen 5 SFF-8639 (U.2)".to_string(),
+        0x26 => "OCP NIC 3.0 Small Form Factor (SFF)".to_string(),
+        0x27 => "OCP NIC 3.0 Large Form Factor (LFF)".to_string(),
+        0x28 => "OCP NIC Prior to 3.0".to_string(),
+        0x30 => "CXL Flexbus 1.0".to_string(),
+        0xA0 => "PC-98/C20".to_string(),
+        0xA1 => "PC-98/C24".to_string(),
+        0xA2 => "PC-98/E".to_string(),
+        0xA3 => "PC-98/Local Bus".to_string(),
+        0xA4 => "PC-98/Card".to_string(),
+        0xA5 => "PCI Express".to_string(),
+        0xA6 => "PCI Express x1".to_string(),
+        0xA7 => "PCI Express x2".to_string(),
+        0xA8 => "PCI Express x4".to_string(),
+        0xA9 => "PCI Express x8".to_string(),
+        0xAA => "PCI Express x16".to_string(),
+        0xAB => "PCI Express Gen 2".to_string(),
+        0xAC => "PCI Express Gen 2 x1".to_string(),
+        0xAD => "PCI Express Gen 2 x2".to_string(),
+        0xAE => "PCI Express Gen 2 x4".to_string(),
+        0xAF => "PCI Express Gen 2 x8".to_string(),
+        0xB0 => "PCI Express Gen 2 x16".to_string(),
+        0xB1 => "PCI Express Gen 3".to_string(),
+        0xB2 => "PCI Express Gen 3 x1".to_string(),
+        0xB3 => "PCI Express Gen 3 x2".to_string(),
+        0xB4 => "PCI Express Gen 3 x4".to_string(),
+        0xB5 => "PCI Express Gen 3 x8".to_string(),
+        0xB6 => "PCI Express Gen 3 x16".to_string(),
+        0xB8 => "PCI Express Gen 4".to_string(),
+        0xB9 => "PCI Express Gen 4 x1".to_string(),
+        0xBA => "PCI Express Gen 4 x2".to_string(),
+        0xBB => "PCI Express Gen 4 x4".to_string(),
+        0xBC => "PCI Express Gen 4 x8".to_string(),
+        0xBD => "PCI Express Gen 4 x16".to_string(),
+        0xBE => "PCI Express Gen 5".to_string(),
+        0xBF => "PCI Express Gen 5 x1".to_string(),
+        0xC0 => "PCI Express Gen 5 x2".to_string(),
+        0xC1 => "PCI Express Gen 5 x4".to_string(),
+        0xC2 => "PCI Express Gen 5 x8".to_string(),
+        0xC3 => "PCI Express Gen 5 x16".to_string(),
+        0xC4 => "PCI Express Gen 6 and Beyond".to_string(),
+        0xC5 => "EDSFF E1.S, E1.L".to_string(),
+        0xC6 => "EDSFF E3.S, E3.L".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Returns human-readable system slot data bus width string.
+fn slot_bus_width_str(code: u8) -> String {
+    match code {
+        0x01 => "Other".to_string(),
+        0x02 => "Unknown".to_string(),
+        0x03 => "8 bit".to_string(),
+        0x04 => "16 bit".to_string(),
+        0x05 => "32 bit".to_string(),
+        0x06 => "64 bit".to_string(),
+        0x07 => "128 bit".to_string(),
+        0x08 => "x1".to_string(),
+        0x09 => "x2".to_string(),
+        0x0A => "x4".to_string(),
+        0x0B => "x8".to_string(),
+        0x0C => "x12".to_string(),
+        0x0D => "x16".to_string(),
+        0x0E => "x32".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Returns human-readable system slot current usage string.
+fn slot_usage_str(code: u8) -> String {
+    match code {
+        0x01 => "Other".to_string(),
+        0x02 => "Unknown".to_string(),
+        0x03 => "Available".to_string(),
+        0x04 => "In use".to_string(),
+        0x05 => "Unavailable".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Returns human-readable system slot length string.
+fn slot_length_str(code: u8) -> String {
+    match code {
+        0x01 => "Other".to_string(),
+        0x02 => "Unknown".to_string(),
+        0x03 => "Short Length".to_string(),
+        0x04 => "Long Length".to_string(),
+        0x05 => "2.5\" drive form factor".to_string(),
+        0x06 => "3.5\" drive form factor".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Parser for SMBIOS Type 11: OEM Strings Information.
+///
+/// Lists exactly the Count (offset 0x04) strings the structure declares. Empty strings,
+/// including ones in the middle of the set, and declared strings the pool does not hold
+/// are shown as `<empty>`; strings beyond the declared count are still listed.
+fn parse_type_11(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
     let mut info = Vec::new();
-    if offset + 0x08 < data.len() {
-        let man_idx = data[offset + 0x04];
-        let prod_idx = data[offset + 0x05];
-        let ver_idx = data[offset + 0x06];
-        let ser_idx = data[offset + 0x07];
-        let asset_idx = if offset + 0x08 < data.len() {
-            data[offset + 0x08]
-        } else {
-            0
+    let declared = if require(data, offset, 0x05) {
+        let count = data[offset + 0x04];
+        info.push(("String Count".to_string(), count.to_string()));
+        count as usize
+    } else {
+        0
+    };
+    for i in 0..declared.max(strings.len()) {
+        let value = match strings.get(i) {
+            Some(s) if !s.is_empty() => s.clone(),
+            _ => "<empty>".to_string(),
         };
-
-        info.push((
-            "Manufacturer".to_string(),
-            get_string_by_index(strings, man_idx),
-        ));
-        info.push((
-            "Product Name".to_string(),
-            get_string_by_index(strings, prod_idx),
-        ));
-        info.push(("Version".to_string(), get_string_by_index(strings, ver_idx)));
-        info.push((
-            "Serial Number".to_string(),
-            get_string_by_index(strings, ser_idx),
-        ));
-        info.push((
-            "Asset Tag".to_string(),
-            get_string_by_index(strings, asset_idx),
-        ));
+        info.push((format!("String {}", i + 1), value));
     }
     info
 }
 
-/// Parser for SMBIOS Type 3: System Enclosure or Chassis Information.
-fn parse_type_3(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
+/// Converts an SMBIOS Type 32 boot status code to the DMTF description.
+fn boot_status_str(code: u8) -> String {
+    match code {
+        0 => "No errors detected".to_string(),
+        1 => "No bootable media".to_string(),
+        2 => "Operating system failed to load".to_string(),
+        3 => "Firmware-detected hardware failure".to_string(),
+        4 => "Operating system-detected hardware failure".to_string(),
+        5 => "User-requested boot".to_string(),
+        6 => "System security violation".to_string(),
+        7 => "Previously-requested image".to_string(),
+        8 => "System watchdog timer expired".to_string(),
+        9..=127 => format!("Reserved (0x{:02X})", code),
+        128..=191 => format!("Vendor/OEM-specific (0x{:02X})", code),
+        192..=255 => format!("Product-specific (0x{:02X})", code),
+    }
+}
+
+/// Parser for SMBIOS Type 32: System Boot Information.
+fn parse_type_32(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
     let mut info = Vec::new();
-    if offset + 0x07 < data.len() {
-        let man_idx = data[offset + 0x04];
-        let type_code = data[offset + 0x05];
-        let ver_idx = data[offset + 0x06];
-        let ser_idx = data[offset + 0x07];
+    if !require(data, offset, 0x0B) {
+        return info;
+    }
+    // Bytes 0x04-0x09 are reserved and should be zero
+    info.push((
+        "Reserved".to_string(),
+        hex::encode(&data[offset + 0x04..offset + 0x0A]).to_uppercase(),
+    ));
 
+    let status = data[offset + 0x0A];
+    info.push(("Boot Status".to_string(), format!("0x{:02X}", status)));
+    info.push(("Status Description".to_string(), boot_status_str(status)));
+
+    // Any bytes after the status code are additional, vendor-defined status data
+    let length = formatted_length(data, offset);
+    if length > 0x0B {
         info.push((
-            "Manufacturer".to_string(),
-            get_string_by_index(strings, man_idx),
-        ));
-        info.push(("Type".to_string(), format!("0x{:02X}", type_code)));
-        info.push(("Version".to_string(), get_string_by_index(strings, ver_idx)));
-        info.push((
-            "Serial Number".to_string(),
-            get_string_by_index(strings, ser_idx),
+            "Additional Data".to_string(),
+            hex::encode(&data[offset + 0x0B..offset + length]).to_uppercase(),
         ));
     }
     info
 }
 
-/// Parser for SMBIOS Type 4: Processor Information.
-fn parse_type_4(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
-    let mut info = Vec::new();
-    if offset + 0x10 < data.len() {
-        let sock_idx = data[offset + 0x04];
-        let type_enum = data[offset + 0x05];
-        let man_idx = data[offset + 0x07];
-        let ver_idx = data[offset + 0x10];
+/// Returns the SMBIOS Type 34 management device type.
+fn management_device_type_str(code: u8) -> String {
+    match code {
+        0x01 => "Other".to_string(),
+        0x02 => "Unknown".to_string(),
+        0x03 => "National Semiconductor LM75".to_string(),
+        0x04 => "National Semiconductor LM78".to_string(),
+        0x05 => "National Semiconductor LM79".to_string(),
+        0x06 => "National Semiconductor LM80".to_string(),
+        0x07 => "National Semiconductor LM81".to_string(),
+        0x08 => "Analog Devices ADM9240".to_string(),
+        0x09 => "Dallas Semiconductor DS1780".to_string(),
+        0x0A => "Maxim 1617".to_string(),
+        0x0B => "Genesys GL518SM".to_string(),
+        0x0C => "Winbond W83781D".to_string(),
+        0x0D => "Holtek HT82H791".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
 
-        info.push((
-            "Socket Designator".to_string(),
-            get_string_by_index(strings, sock_idx),
-        ));
-        info.push(("Processor Type".to_string(), format!("0x{:02X}", type_enum)));
-        info.push((
-            "Manufacturer".to_string(),
-            get_string_by_index(strings, man_idx),
-        ));
-        info.push(("Version".to_string(), get_string_by_index(strings, ver_idx)));
+/// Returns the SMBIOS Type 34 management device address type.
+fn management_device_address_type_str(code: u8) -> String {
+    match code {
+        0x01 => "Other".to_string(),
+        0x02 => "Unknown".to_string(),
+        0x03 => "I/O Port".to_string(),
+        0x04 => "Memory".to_string(),
+        0x05 => "SMBus".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
 
-        if offset + 0x25 < data.len() {
-            let core_count = data[offset + 0x23];
-            let thread_count = data[offset + 0x25];
-            info.push(("Core Count".to_string(), core_count.to_string()));
-            info.push(("Thread Count".to_string(), thread_count.to_string()));
-        }
+/// Parser for SMBIOS Type 34: Management Device.
+fn parse_type_34(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x0B) {
+        return info;
     }
+    info.push((
+        "Description".to_string(),
+        get_string_by_index(strings, data[offset + 0x04]),
+    ));
+    info.push((
+        "Type".to_string(),
+        management_device_type_str(data[offset + 0x05]),
+    ));
+    info.push((
+        "Address".to_string(),
+        format!(
+            "0x{:08X}",
+            LittleEndian::read_u32(&data[offset + 0x06..offset + 0x0A])
+        ),
+    ));
+    info.push((
+        "Address Type".to_string(),
+        management_device_address_type_str(data[offset + 0x0A]),
+    ));
     info
 }
 
-/// Parser for SMBIOS Type 17: Memory Device Information.
-fn parse_type_17(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
+/// Parser for SMBIOS Type 35: Management Device Component.
+///
+/// The component is a probe or cooling device (Types 26-29) watched by the Type 34 device;
+/// its thresholds, if any, are in a Type 36 structure.
+fn parse_type_35(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
     let mut info = Vec::new();
-    if offset + 0x1B < data.len() {
-        let total_width = LittleEndian::read_u16(&data[offset + 0x08..offset + 0x0A]);
-        let data_width = LittleEndian::read_u16(&data[offset + 0x0A..offset + 0x0C]);
-        let size = LittleEndian::read_u16(&data[offset + 0x0C..offset + 0x0E]);
-        let speed = LittleEndian::read_u16(&data[offset + 0x15..offset + 0x17]);
-
-        let man_idx = data[offset + 0x17];
-        let ser_idx = data[offset + 0x18];
-        let asset_idx = data[offset + 0x19];
-        let part_idx = data[offset + 0x1A];
-
-        let dev_idx = data[offset + 0x10];
-        let bank_idx = data[offset + 0x11];
-
-        info.push((
-            "Device Locator".to_string(),
-            get_string_by_index(strings, dev_idx),
-        ));
-        info.push((
-            "Bank Locator".to_string(),
-            get_string_by_index(strings, bank_idx),
-        ));
-
-        if size == 0xFFFF {
-            info.push(("Size".to_string(), "Unknown / Extended".to_string()));
-        } else if size == 0 {
-            info.push(("Size".to_string(), "No Module Installed".to_string()));
-        } else if size & 0x8000 != 0 {
-            let s_val = size & 0x7FFF;
-            info.push(("Size".to_string(), format!("{} KB", s_val)));
-        } else {
-            info.push(("Size".to_string(), format!("{} MB", size)));
-        }
+    if !require(data, offset, 0x0B) {
+        return info;
+    }
+    let read_u16 = |at: usize| LittleEndian::read_u16(&data[offset + at..offset + at + 2]);
+    info.push((
+        "Description".to_string(),
+        get_string_by_index(strings, data[offset + 0x04]),
+    ));
+    info.push((
+        "Management Device Handle".to_string(),
+        handle_ref_str(read_u16(0x05)),
+    ));
+    info.push((
+        "Component Handle".to_string(),
+        handle_ref_str(read_u16(0x07)),
+    ));
+    info.push((
+        "Threshold Handle".to_string(),
+        handle_ref_str(read_u16(0x09)),
+    ));
+    info
+}
 
+/// Parser for SMBIOS Type 36: Management Device Threshold Data.
+///
+/// The values are in the units of the component's probe; 0x8000 means not available.
+fn parse_type_36(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x10) {
+        return info;
+    }
+    let thresholds = [
+        ("Lower Non-critical Threshold", 0x04),
+        ("Upper Non-critical Threshold", 0x06),
+        ("Lower Critical Threshold", 0x08),
+        ("Upper Critical Threshold", 0x0A),
+        ("Lower Non-recoverable Threshold", 0x0C),
+        ("Upper Non-recoverable Threshold", 0x0E),
+    ];
+    for (name, field) in thresholds {
+        let value = LittleEndian::read_u16(&data[offset + field..offset + field + 2]);
         info.push((
-            "Speed".to_string(),
-            if speed != 0 {
-                format!("{} MT/s", speed)
+            name.to_string(),
+            if value == 0x8000 {
+                "Not Available".to_string()
             } else {
-                "Unknown".to_string()
+                format!("{}", value as i16)
             },
         ));
+    }
+    info
+}
+
+/// Returns the SMBIOS Type 37 memory channel type.
+fn memory_channel_type_str(code: u8) -> String {
+    match code {
+        0x01 => "Other".to_string(),
+        0x02 => "Unknown".to_string(),
+        0x03 => "RamBus".to_string(),
+        0x04 => "SyncLink".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
+
+/// Parser for SMBIOS Type 37: Memory Channel.
+///
+/// Lists each Type 17 memory device on the channel with the load it puts on it.
+fn parse_type_37(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x07) {
+        return info;
+    }
+    info.push((
+        "Type".to_string(),
+        memory_channel_type_str(data[offset + 0x04]),
+    ));
+    info.push((
+        "Maximal Load".to_string(),
+        format!("{}", data[offset + 0x05]),
+    ));
+    let count = data[offset + 0x06] as usize;
+    info.push(("Devices".to_string(), format!("{}", count)));
+    for i in 0..count {
+        let at = 0x07 + i * 3;
+        // Stop at the structure length rather than trusting the count
+        if !require(data, offset, at + 3) {
+            info.push((
+                "Warning".to_string(),
+                format!("Structure ends after {} of {} devices", i, count),
+            ));
+            break;
+        }
         info.push((
-            "Manufacturer".to_string(),
-            get_string_by_index(strings, man_idx),
-        ));
-        info.push((
-            "Serial Number".to_string(),
-            get_string_by_index(strings, ser_idx),
-        ));
-        info.push((
-            "Asset Tag".to_string(),
-            get_string_by_index(strings, asset_idx),
+            format!("Device {} Load", i + 1),
+            format!("{}", data[offset + at]),
         ));
         info.push((
-            "Part Number".to_string(),
-            get_string_by_index(strings, part_idx),
+            format!("Device {} Handle", i + 1),
+            handle_ref_str(LittleEndian::read_u16(
+                &data[offset + at + 1..offset + at + 3],
+            )),
         ));
-
-        info.push(("Total Width".to_string(), format!("{} bits", total_width)));
-        info.push(("Data Width".to_string(), format!("{} bits", data_width)));
     }
     info
 }
 
-/// Parser for SMBIOS Type 7: Cache Information.
-fn parse_type_7(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
-    let mut info = Vec::new();
-    if offset + 0x0F < data.len() {
-        let sock_idx = data[offset + 0x04];
-        let cfg = LittleEndian::read_u16(&data[offset + 0x05..offset + 0x07]);
-        let max_size = LittleEndian::read_u16(&data[offset + 0x07..offset + 0x09]);
-        let inst_size = LittleEndian::read_u16(&data[offset + 0x09..offset + 0x0B]);
-        let speed = data[offset + 0x0F];
-
-        info.push((
-            "Socket Designator".to_string(),
-            get_string_by_index(strings, sock_idx),
-        ));
-        info.push(("Configuration".to_string(), format!("0x{:04X}", cfg)));
+/// Returns the SMBIOS Type 42 management controller host interface type (DSP0239).
+fn host_interface_type_str(code: u8) -> String {
+    match code {
+        0x02 => "KCS: Keyboard Controller Style".to_string(),
+        0x03 => "8250 UART Register Compatible".to_string(),
+        0x04 => "16450 UART Register Compatible".to_string(),
+        0x05 => "16550/16550A UART Register Compatible".to_string(),
+        0x06 => "16650/16650A UART Register Compatible".to_string(),
+        0x07 => "16750/16750A UART Register Compatible".to_string(),
+        0x08 => "16850/16850A UART Register Compatible".to_string(),
+        0x40 => "Network Host Interface".to_string(),
+        0xF0 => "OEM".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
 
-        let parse_size = |s: u16| {
-            if s == 0 {
-                return "None".to_string();
-            }
-            let val = s & 0x7FFF;
-            if s & 0x8000 != 0 {
-                format!("{} KB", val * 64)
-            } else {
-                format!("{} KB", val)
-            }
-        };
+/// Returns the SMBIOS Type 42 protocol record type.
+fn host_interface_protocol_str(code: u8) -> String {
+    match code {
+        0x02 => "IPMI".to_string(),
+        0x03 => "MCTP".to_string(),
+        0x04 => "Redfish over IP".to_string(),
+        0xF0 => "OEM".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    }
+}
 
-        info.push(("Maximum Cache Size".to_string(), parse_size(max_size)));
-        info.push(("Installed Size".to_string(), parse_size(inst_size)));
-        info.push((
-            "Speed".to_string(),
-            if speed != 0 {
-                format!("{} ns", speed)
-            } else {
-                "Unknown".to_string()
-            },
-        ));
+/// Formats a 16-byte Redfish host interface IP address field in the given address format.
+fn host_interface_ip_str(format: u8, bytes: &[u8]) -> String {
+    match format {
+        0x01 => format!("{}.{}.{}.{}", bytes[0], bytes[1], bytes[2], bytes[3]),
+        0x02 => {
+            let mut octets = [0u8; 16];
+            octets.copy_from_slice(&bytes[..16]);
+            std::net::Ipv6Addr::from(octets).to_string()
+        }
+        _ => format!("Unknown Format (0x{:02X})", format),
+    }
+}
+
+/// Formats a 6-byte MAC address as `AA:BB:CC:DD:EE:FF`.
+fn mac_address_str(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|b| format!("{:02X}", b))
+        .collect::<Vec<_>>()
+        .join(":")
+}
 
-        if offset + 0x12 < data.len() {
-            let err_corr = data[offset + 0x10];
-            let sys_type = data[offset + 0x11];
-            let assoc = data[offset + 0x12];
+/// Decodes the interface-specific data of a Network Host Interface (DSP0270).
+///
+/// # Returns
+/// The decoded fields, or `None` if the device type is unknown or the data is too short.
+fn network_host_interface_fields(data: &[u8]) -> Option<Vec<(String, String)>> {
+    let device_type = *data.first()?;
+    let id = |at: usize| format!("0x{:04X}", LittleEndian::read_u16(&data[at..at + 2]));
+    let mut info = Vec::new();
+    match device_type {
+        0x02 if data.len() >= 5 => {
+            info.push((
+                "Device Type".to_string(),
+                "USB Network Interface".to_string(),
+            ));
+            info.push(("USB Vendor ID".to_string(), id(1)));
+            info.push(("USB Product ID".to_string(), id(3)));
+        }
+        0x03 if data.len() >= 9 => {
+            info.push((
+                "Device Type".to_string(),
+                "PCI/PCIe Network Interface".to_string(),
+            ));
+            info.push(("PCI Vendor ID".to_string(), id(1)));
+            info.push(("PCI Device ID".to_string(), id(3)));
+            info.push(("PCI Subsystem Vendor ID".to_string(), id(5)));
+            info.push(("PCI Subsystem ID".to_string(), id(7)));
+        }
+        0x04 if data.len() >= 13 => {
+            info.push((
+                "Device Type".to_string(),
+                "USB Network Interface v2".to_string(),
+            ));
+            info.push(("USB Vendor ID".to_string(), id(2)));
+            info.push(("USB Product ID".to_string(), id(4)));
+            info.push(("MAC Address".to_string(), mac_address_str(&data[7..13])));
+        }
+        0x05 if data.len() >= 20 => {
             info.push((
-                "Error Correction".to_string(),
-                format!("0x{:02X}", err_corr),
+                "Device Type".to_string(),
+                "PCI/PCIe Network Interface v2".to_string(),
             ));
+            info.push(("PCI Vendor ID".to_string(), id(2)));
+            info.push(("PCI Device ID".to_string(), id(4)));
+            info.push(("PCI Subsystem Vendor ID".to_string(), id(6)));
+            info.push(("PCI Subsystem ID".to_string(), id(8)));
+            info.push(("MAC Address".to_string(), mac_address_str(&data[10..16])));
+            let bdf = LittleEndian::read_u16(&data[18..20]);
             info.push((
-                "System Cache Type".to_string(),
-                format!("0x{:02X}", sys_type),
+                "Bus Address".to_string(),
+                format!(
+                    "{:04X}:{:02X}:{:02X}.{:X}",
+                    LittleEndian::read_u16(&data[16..18]),
+                    bdf >> 8,
+                    (bdf >> 3) & 0x1F,
+                    bdf & 0x07
+                ),
             ));
-            info.push(("Associativity".to_string(), format!("0x{:02X}", assoc)));
         }
+        _ => return None,
     }
-    info
+    Some(info)
 }
 
-/// Parser for SMBIOS Type 9: System Slots Information.
-fn parse_type_9(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
+/// Decodes the protocol-specific data of a Redfish over IP protocol record (DSP0270).
+///
+/// # Returns
+/// The decoded fields, or `None` if the data is shorter than the fixed part of the record.
+fn redfish_over_ip_fields(data: &[u8]) -> Option<Vec<(String, String)>> {
+    if data.len() < 91 {
+        return None;
+    }
+    let assignment = |code: u8| match code {
+        0x00 => "Unknown".to_string(),
+        0x01 => "Static".to_string(),
+        0x02 => "DHCP".to_string(),
+        0x03 => "AutoConfigure".to_string(),
+        0x04 => "Host Selected".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
+    };
+    let hostname_len = data[90] as usize;
+    let hostname = data
+        .get(91..91 + hostname_len)
+        .map_or_else(|| "<truncated>".to_string(), clean_str);
+    Some(vec![
+        ("Service UUID".to_string(), guid_str(&data[0..16])),
+        ("Host IP Assignment".to_string(), assignment(data[16])),
+        (
+            "Host IP Address".to_string(),
+            host_interface_ip_str(data[17], &data[18..34]),
+        ),
+        (
+            "Host IP Mask".to_string(),
+            host_interface_ip_str(data[17], &data[34..50]),
+        ),
+        ("Service IP Discovery".to_string(), assignment(data[50])),
+        (
+            "Service IP Address".to_string(),
+            host_interface_ip_str(data[51], &data[52..68]),
+        ),
+        (
+            "Service IP Mask".to_string(),
+            host_interface_ip_str(data[51], &data[68..84]),
+        ),
+        (
+            "Service IP Port".to_string(),
+            LittleEndian::read_u16(&data[84..86]).to_string(),
+        ),
+        (
+            "Service VLAN ID".to_string(),
+            LittleEndian::read_u32(&data[86..90]).to_string(),
+        ),
+        ("Service Hostname".to_string(), hostname),
+    ])
+}
+
+/// Parser for SMBIOS Type 42: Management Controller Host Interface.
+///
+/// The structure is variable-length: the interface-specific data and each protocol record
+/// carry their own lengths, so every part is bounded by those and by the structure length.
+/// Blobs of unknown layout are shown as hex.
+fn parse_type_42(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
     let mut info = Vec::new();
-    if offset + 0x09 < data.len() {
-        let name_idx = data[offset + 0x04];
-        let slot_type = data[offset + 0x05];
-        let bus_width = data[offset + 0x06];
-        let usage = data[offset + 0x07];
-        let len = data[offset + 0x08];
-        let id = LittleEndian::read_u16(&data[offset + 0x09..offset + 0x0B]);
+    let length = formatted_length(data, offset);
+    if length < 0x06 {
+        return info;
+    }
+    let end = offset + length;
+    let interface_type = data[offset + 0x04];
+    info.push((
+        "Interface Type".to_string(),
+        host_interface_type_str(interface_type),
+    ));
 
+    let data_len = data[offset + 0x05] as usize;
+    let data_start = offset + 0x06;
+    if data_start + data_len > end {
         info.push((
-            "Slot Designator".to_string(),
-            get_string_by_index(strings, name_idx),
+            "Interface Data".to_string(),
+            format!("<truncated: {} bytes declared>", data_len),
         ));
-        info.push(("Slot Type".to_string(), format!("0x{:02X}", slot_type)));
-        info.push(("Data Bus Width".to_string(), format!("0x{:02X}", bus_width)));
-        info.push(("Current Usage".to_string(), format!("0x{:02X}", usage)));
-        info.push(("Slot Length".to_string(), format!("0x{:02X}", len)));
-        info.push(("Slot ID".to_string(), format!("0x{:04X}", id)));
+        return info;
     }
-    info
-}
-
-/// Parser for SMBIOS Type 11: OEM Strings Information.
-fn parse_type_11(_data: &[u8], _offset: usize, strings: &[String]) -> Vec<(String, String)> {
-    let mut info = Vec::new();
-    for (i, s) in strings.iter().enumerate() {
-        info.push((format!("String {}", i + 1), s.clone()));
+    let interface_data = &data[data_start..data_start + data_len];
+    info.push((
+        "Interface Data Length".to_string(),
+        format!("{} bytes", data_len),
+    ));
+    let decoded = match interface_type {
+        0x40 => network_host_interface_fields(interface_data),
+        _ => None,
+    };
+    match decoded {
+        Some(fields) => info.extend(fields),
+        None if data_len > 0 => info.push((
+            "Interface Data".to_string(),
+            hex::encode(interface_data).to_uppercase(),
+        )),
+        None => {}
     }
-    info
-}
 
-/// Parser for SMBIOS Type 32: System Boot Information.
-fn parse_type_32(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
-    let mut info = Vec::new();
-    if offset + 0x0A < data.len() {
-        let status = data[offset + 0x0A];
-        info.push(("Boot Status".to_string(), format!("0x{:02X}", status)));
-
-        let status_msg = match status {
-            0 => "No errors detected",
-            1 => "No bootable media",
-            2 => "Normal boot",
-            3 => "User-requested boot",
-            4 => "System-requested boot",
-            5 => "Kernel panic",
-            6 => "Recovery mode",
-            _ => "Other / Unknown",
+    // Protocol records were added in SMBIOS 3.2; older structures end with the interface data
+    let mut pos = data_start + data_len;
+    if pos >= end {
+        return info;
+    }
+    let count = data[pos];
+    info.push(("Protocol Records".to_string(), count.to_string()));
+    pos += 1;
+    for n in 1..=count {
+        if pos + 2 > end {
+            info.push((format!("Protocol {}", n), "<truncated>".to_string()));
+            break;
+        }
+        let protocol = data[pos];
+        let record_len = data[pos + 1] as usize;
+        let record_start = pos + 2;
+        info.push((
+            format!("Protocol {} Type", n),
+            host_interface_protocol_str(protocol),
+        ));
+        if record_start + record_len > end {
+            info.push((
+                format!("Protocol {} Data", n),
+                format!("<truncated: {} bytes declared>", record_len),
+            ));
+            break;
+        }
+        let record = &data[record_start..record_start + record_len];
+        let decoded = match protocol {
+            0x04 => redfish_over_ip_fields(record),
+            _ => None,
         };
-        info.push(("Status Description".to_string(), status_msg.to_string()));
+        match decoded {
+            Some(fields) => info.extend(
+                fields
+                    .into_iter()
+                    .map(|(name, value)| (format!("Protocol {} {}", n, name), value)),
+            ),
+            None if record_len > 0 => info.push((
+                format!("Protocol {} Data", n),
+                hex::encode(record).to_uppercase(),
+            )),
+            None => {}
+        }
+        pos = record_start + record_len;
     }
     info
 }
@@ -1040,31 +6948,32 @@ fn parse_type_32(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String
 /// Parser for SMBIOS Type 8: Port Connector Information.
 fn parse_type_8(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
     let mut info = Vec::new();
-    if offset + 0x09 <= data.len() {
-        let int_ref_idx = data[offset + 0x04];
-        let int_conn_type = data[offset + 0x05];
-        let ext_ref_idx = data[offset + 0x06];
-        let ext_conn_type = data[offset + 0x07];
-        let port_type = data[offset + 0x08];
-
-        info.push((
-            "Internal Reference".to_string(),
-            get_string_by_index(strings, int_ref_idx),
-        ));
-        info.push((
-            "Internal Connector Type".to_string(),
-            connector_type_str(int_conn_type),
-        ));
-        info.push((
-            "External Reference".to_string(),
-            get_string_by_index(strings, ext_ref_idx),
-        ));
-        info.push((
-            "External Connector Type".to_string(),
-            connector_type_str(ext_conn_type),
-        ));
-        info.push(("Port Type".to_string(), port_type_str(port_type)));
+    if !require(data, offset, 0x09) {
+        return info;
     }
+    let int_ref_idx = data[offset + 0x04];
+    let int_conn_type = data[offset + 0x05];
+    let ext_ref_idx = data[offset + 0x06];
+    let ext_conn_type = data[offset + 0x07];
+    let port_type = data[offset + 0x08];
+
+    info.push((
+        "Internal Reference".to_string(),
+        get_string_by_index(strings, int_ref_idx),
+    ));
+    info.push((
+        "Internal Connector Type".to_string(),
+        connector_type_str(int_conn_type),
+    ));
+    info.push((
+        "External Reference".to_string(),
+        get_string_by_index(strings, ext_ref_idx),
+    ));
+    info.push((
+        "External Connector Type".to_string(),
+        connector_type_str(ext_conn_type),
+    ));
+    info.push(("Port Type".to_string(), port_type_str(port_type)));
     info
 }
 
@@ -1166,85 +7075,254 @@ fn port_type_str(code: u8) -> String {
 /// Parser for SMBIOS Type 13: BIOS Language Information.
 fn parse_type_13(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
     let mut info = Vec::new();
-    if offset + 0x16 <= data.len() {
-        let installable_langs = data[offset + 0x04];
-        let flags = data[offset + 0x05];
-        let current_lang_idx = data[offset + 0x15];
+    if !require(data, offset, 0x16) {
+        return info;
+    }
+    let installable_langs = data[offset + 0x04];
+    let flags = data[offset + 0x05];
+    let current_lang_idx = data[offset + 0x15];
 
+    info.push((
+        "Installable Languages".to_string(),
+        installable_langs.to_string(),
+    ));
+    info.push((
+        "Format".to_string(),
+        if flags & 0x01 != 0 {
+            "Abbreviated"
+        } else {
+            "Long"
+        }
+        .to_string(),
+    ));
+    info.push((
+        "Current Language".to_string(),
+        language_str(&get_string_by_index(strings, current_lang_idx)),
+    ));
+
+    for i in 1..=installable_langs {
         info.push((
-            "Installable Languages".to_string(),
-            installable_langs.to_string(),
-        ));
-        info.push((
-            "Format".to_string(),
-            if flags & 0x01 != 0 {
-                "Abbreviated"
-            } else {
-                "Long"
-            }
-            .to_string(),
+            format!("Language {}", i),
+            language_str(&get_string_by_index(strings, i)),
         ));
+    }
+    info
+}
+
+/// Returns the English name of a common ISO 639-1 language code.
+fn language_name(code: &str) -> Option<&'static str> {
+    Some(match code {
+        "ar" => "Arabic",
+        "cs" => "Czech",
+        "da" => "Danish",
+        "de" => "German",
+        "el" => "Greek",
+        "en" => "English",
+        "es" => "Spanish",
+        "fi" => "Finnish",
+        "fr" => "French",
+        "he" => "Hebrew",
+        "hu" => "Hungarian",
+        "it" => "Italian",
+        "ja" => "Japanese",
+        "ko" => "Korean",
+        "nl" => "Dutch",
+        "no" => "Norwegian",
+        "pl" => "Polish",
+        "pt" => "Portuguese",
+        "ru" => "Russian",
+        "sv" => "Swedish",
+        "tr" => "Turkish",
+        "zh" => "Chinese",
+        _ => return None,
+    })
+}
+
+/// Returns the English name of a common ISO 3166-1 territory code.
+fn territory_name(code: &str) -> Option<&'static str> {
+    Some(match code {
+        "AT" => "Austria",
+        "AU" => "Australia",
+        "BE" => "Belgium",
+        "BR" => "Brazil",
+        "CA" => "Canada",
+        "CH" => "Switzerland",
+        "CN" => "China",
+        "DE" => "Germany",
+        "ES" => "Spain",
+        "FR" => "France",
+        "GB" => "United Kingdom",
+        "IN" => "India",
+        "IT" => "Italy",
+        "JP" => "Japan",
+        "KR" => "Korea",
+        "MX" => "Mexico",
+        "NL" => "Netherlands",
+        "PT" => "Portugal",
+        "RU" => "Russia",
+        "TW" => "Taiwan",
+        "US" => "United States",
+        _ => return None,
+    })
+}
+
+/// Annotates a Type 13 language string with a friendly name when the codes are known.
+///
+/// Both the long form (`en|US|iso8859-1`) and the abbreviated form (`enUS`) are accepted,
+/// e.g. `en|US|iso8859-1 -> English (United States)`.
+fn language_str(raw: &str) -> String {
+    let (language, territory) = match raw.split_once('|') {
+        Some((language, rest)) => (language, rest.split('|').next().unwrap_or("")),
+        None => (raw.get(..2).unwrap_or(raw), raw.get(2..4).unwrap_or("")),
+    };
+    match (language_name(language), territory_name(territory)) {
+        (Some(l), Some(t)) => format!("{} -> {} ({})", raw, l, t),
+        (Some(l), None) => format!("{} -> {}", raw, l),
+        _ => raw.to_string(),
+    }
+}
+
+/// Parser for SMBIOS Type 14: Group Associations.
+fn parse_type_14(data: &[u8], offset: usize, strings: &[String]) -> Vec<(String, String)> {
+    let mut info = Vec::new();
+    if !require(data, offset, 0x05) {
+        return info;
+    }
+    info.push((
+        "Group Name".to_string(),
+        get_string_by_index(strings, data[offset + 0x04]),
+    ));
+
+    // Each item is a (structure type, structure handle) triple of bytes after the group name
+    let count = formatted_length(data, offset).saturating_sub(5) / 3;
+    for i in 0..count {
+        let pos = offset + 0x05 + i * 3;
+        let item_type = data[pos];
+        let item_handle = LittleEndian::read_u16(&data[pos + 1..pos + 3]);
+        let type_name = smbios_type_name(item_type);
+        let label = if type_name.is_empty() {
+            format!("Type {}", item_type)
+        } else {
+            format!("Type {} - {}", item_type, type_name)
+        };
         info.push((
-            "Current Language".to_string(),
-            get_string_by_index(strings, current_lang_idx),
+            format!("Item {}", i + 1),
+            format!("Handle 0x{:04X} ({})", item_handle, label),
         ));
+    }
+    info
+}
 
-        if !strings.is_empty() {
-            info.push(("Available Languages".to_string(), strings.join(", ")));
+/// Returns the installed size in bytes of the Type 17 structure at `offset`.
+///
+/// A size of 0x7FFF means the Extended Size field (SMBIOS 2.7) holds the size in MB.
+///
+/// # Returns
+/// `Some(0)` for an empty slot, or `None` if the size is unknown or the structure is too short.
+pub fn memory_device_size(data: &[u8], offset: usize) -> Option<u64> {
+    if !require(data, offset, 0x0E) {
+        return None;
+    }
+    match LittleEndian::read_u16(&data[offset + 0x0C..offset + 0x0E]) {
+        0xFFFF => None,
+        0x7FFF => {
+            if !require(data, offset, 0x20) {
+                return None;
+            }
+            let mb = LittleEndian::read_u32(&data[offset + 0x1C..offset + 0x20]) & 0x7FFF_FFFF;
+            Some(mb as u64 * 1024 * 1024)
         }
+        // Bit 15 selects KB granularity instead of MB
+        size if size & 0x8000 != 0 => Some((size & 0x7FFF) as u64 * 1024),
+        size => Some(size as u64 * 1024 * 1024),
+    }
+}
+
+/// Converts an SMBIOS Type 17 memory type code to a string.
+fn memory_device_type_str(code: u8) -> String {
+    match code {
+        0x01 => "Other".to_string(),
+        0x02 => "Unknown".to_string(),
+        0x03 => "DRAM".to_string(),
+        0x04 => "EDRAM".to_string(),
+        0x05 => "VRAM".to_string(),
+        0x06 => "SRAM".to_string(),
+        0x07 => "RAM".to_string(),
+        0x08 => "ROM".to_string(),
+        0x09 => "Flash".to_string(),
+        0x0A => "EEPROM".to_string(),
+        0x0B => "FEPROM".to_string(),
+        0x0C => "EPROM".to_string(),
+        0x0D => "CDRAM".to_string(),
+        0x0E => "3DRAM".to_string(),
+        0x0F => "SDRAM".to_string(),
+        0x10 => "SGRAM".to_string(),
+        0x11 => "RDRAM".to_string(),
+        0x12 => "DDR".to_string(),
+        0x13 => "DDR2".to_string(),
+        0x14 => "DDR2 FB-DIMM".to_string(),
+        0x18 => "DDR3".to_string(),
+        0x19 => "FBD2".to_string(),
+        0x1A => "DDR4".to_string(),
+        0x1B => "LPDDR".to_string(),
+        0x1C => "LPDDR2".to_string(),
+        0x1D => "LPDDR3".to_string(),
+        0x1E => "LPDDR4".to_string(),
+        0x1F => "Logical Non-Volatile Device".to_string(),
+        0x20 => "HBM".to_string(),
+        0x21 => "HBM2".to_string(),
+        0x22 => "DDR5".to_string(),
+        0x23 => "LPDDR5".to_string(),
+        0x24 => "HBM3".to_string(),
+        _ => format!("Unknown (0x{:02X})", code),
     }
-    info
 }
 
 /// Parser for SMBIOS Type 16: Physical Memory Array.
 fn parse_type_16(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
     let mut info = Vec::new();
-    if offset + 0x0F <= data.len() {
-        let location = data[offset + 0x04];
-        let use_code = data[offset + 0x05];
-        let err_correction = data[offset + 0x06];
-        let max_capacity = LittleEndian::read_u32(&data[offset + 0x07..offset + 0x0B]);
-        let err_info_handle = LittleEndian::read_u16(&data[offset + 0x0B..offset + 0x0D]);
-        let num_devices = LittleEndian::read_u16(&data[offset + 0x0D..offset + 0x0F]);
+    if !require(data, offset, 0x0F) {
+        return info;
+    }
+    let location = data[offset + 0x04];
+    let use_code = data[offset + 0x05];
+    let err_correction = data[offset + 0x06];
+    let max_capacity = LittleEndian::read_u32(&data[offset + 0x07..offset + 0x0B]);
+    let err_info_handle = LittleEndian::read_u16(&data[offset + 0x0B..offset + 0x0D]);
+    let num_devices = LittleEndian::read_u16(&data[offset + 0x0D..offset + 0x0F]);
 
-        info.push(("Location".to_string(), memory_array_location_str(location)));
-        info.push(("Use".to_string(), memory_array_use_str(use_code)));
-        info.push((
-            "Error Correction".to_string(),
-            memory_error_correction_str(err_correction),
-        ));
+    info.push(("Location".to_string(), memory_array_location_str(location)));
+    info.push(("Use".to_string(), memory_array_use_str(use_code)));
+    info.push((
+        "Error Correction".to_string(),
+        memory_error_correction_str(err_correction),
+    ));
 
-        if max_capacity == 0x80000000 {
-            if offset + 0x17 <= data.len() {
-                let ext_max = LittleEndian::read_u64(&data[offset + 0x0F..offset + 0x17]);
-                info.push((
-                    "Maximum Capacity".to_string(),
-                    format!("{} GB", ext_max / (1024 * 1024)),
-                ));
-            } else {
-                info.push((
-                    "Maximum Capacity".to_string(),
-                    "Extended (>2TB)".to_string(),
-                ));
-            }
+    if max_capacity == 0x80000000 {
+        if require(data, offset, 0x17) {
+            // The extended capacity counts bytes
+            let ext_max = LittleEndian::read_u64(&data[offset + 0x0F..offset + 0x17]);
+            info.push(("Maximum Capacity".to_string(), memory_size_str(ext_max)));
         } else {
             info.push((
                 "Maximum Capacity".to_string(),
-                format!("{} KB", max_capacity),
+                "Extended (>2TB)".to_string(),
             ));
         }
+    } else {
+        // The 32-bit capacity counts kilobytes
+        info.push((
+            "Maximum Capacity".to_string(),
+            memory_size_str(max_capacity as u64 * 1024),
+        ));
+    }
 
-        if err_info_handle != 0xFFFE && err_info_handle != 0xFFFF {
-            info.push((
-                "Error Info Handle".to_string(),
-                format!("0x{:04X}", err_info_handle),
-            ));
-        } else {
-            info.push(("Error Info Handle".to_string(), "Not Provided".to_string()));
-        }
+    info.push((
+        "Error Info Handle".to_string(),
+        memory_error_handle_str(err_info_handle),
+    ));
 
-        info.push(("Number of Devices".to_string(), num_devices.to_string()));
-    }
+    info.push(("Number of Devices".to_string(), num_devices.to_string()));
     info
 }
 
@@ -1301,49 +7379,18 @@ fn memory_error_correction_str(code: u8) -> String {
 /// Parser for SMBIOS Type 19: Memory Array Mapped Address.
 fn parse_type_19(data: &[u8], offset: usize, _strings: &[String]) -> Vec<(String, String)> {
     let mut info = Vec::new();
-    if offset + 0x0F <= data.len() {
-        let start_addr = LittleEndian::read_u32(&data[offset + 0x04..offset + 0x08]);
-        let end_addr = LittleEndian::read_u32(&data[offset + 0x08..offset + 0x0C]);
-        let array_handle = LittleEndian::read_u16(&data[offset + 0x0C..offset + 0x0E]);
-        let partition_width = data[offset + 0x0E];
-
-        if start_addr == 0xFFFFFFFF && end_addr == 0xFFFFFFFF {
-            if offset + 0x1F <= data.len() {
-                let ext_start = LittleEndian::read_u64(&data[offset + 0x0F..offset + 0x17]);
-                let ext_end = LittleEndian::read_u64(&data[offset + 0x17..offset + 0x1F]);
-                info.push((
-                    "Starting Address".to_string(),
-                    format!("0x{:016X}", ext_start),
-                ));
-                info.push(("Ending Address".to_string(), format!("0x{:016X}", ext_end)));
-                let size = (ext_end - ext_start + 1) / (1024 * 1024);
-                info.push(("Range Size".to_string(), format!("{} MB", size)));
-            } else {
-                info.push((
-                    "Starting Address".to_string(),
-                    "Extended (>4GB)".to_string(),
-                ));
-                info.push(("Ending Address".to_string(), "Extended (>4GB)".to_string()));
-            }
-        } else {
-            info.push((
-                "Starting Address".to_string(),
-                format!("0x{:08X} ({} KB)", start_addr, start_addr),
-            ));
-            info.push((
-                "Ending Address".to_string(),
-                format!("0x{:08X} ({} KB)", end_addr, end_addr),
-            ));
-            let size = (end_addr - start_addr + 1) / 1024;
-            info.push(("Range Size".to_string(), format!("{} MB", size)));
-        }
-
-        info.push((
-            "Physical Array Handle".to_string(),
-            format!("0x{:04X}", array_handle),
-        ));
-        info.push(("Partition Width".to_string(), partition_width.to_string()));
+    if !require(data, offset, 0x0F) {
+        return info;
     }
+    let array_handle = LittleEndian::read_u16(&data[offset + 0x0C..offset + 0x0E]);
+    let partition_width = data[offset + 0x0E];
+
+    info.extend(mapped_address_range(data, offset, 0x0F));
+    info.push((
+        "Physical Array Handle".to_string(),
+        format!("0x{:04X}", array_handle),
+    ));
+    info.push(("Partition Width".to_string(), partition_width.to_string()));
     info
 }
 
@@ -1354,3 +7401,530 @@ fn parse_type_127(_data: &[u8], _offset: usize, _strings: &[String]) -> Vec<(Str
         "End of SMBIOS structure table".to_string(),
     )]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Wraps a bare structure table in the Windows `RawSMBIOSData` header, declaring SMBIOS 3.4.
+    fn raw_smbios(table: &[u8]) -> Vec<u8> {
+        let mut blob = vec![0, 3, 4, 0];
+        blob.extend_from_slice(&(table.len() as u32).to_le_bytes());
+        blob.extend_from_slice(table);
+        blob
+    }
+
+    /// Returns the value of the first row named `key`.
+    fn field<'a>(fields: &'a [(String, String)], key: &str) -> &'a str {
+        fields
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+            .unwrap_or_else(|| panic!("no {} row in {:?}", key, fields))
+    }
+
+    /// Parses a bare structure (header at offset 0) with the latest SMBIOS rules.
+    fn details(structure: &[u8]) -> Vec<(String, String)> {
+        parse_smbios_details(
+            structure[0],
+            structure,
+            0,
+            structure[1],
+            &[],
+            Some(SmbiosVersion::new(3, 8)),
+        )
+        .expect("type has a parser")
+    }
+
+    #[test]
+    fn walk_stops_at_structure_cap() {
+        // Minimal Type 126 (inactive) structures with an empty string area
+        let mut table = Vec::new();
+        for handle in 0..(MAX_SMBIOS_STRUCTURES as u16 + 10) {
+            table.extend_from_slice(&[126, 4]);
+            table.extend_from_slice(&handle.to_le_bytes());
+            table.extend_from_slice(&[0, 0]);
+        }
+        let blob = raw_smbios(&table);
+
+        let walked = walk_smbios_structures(&blob);
+        assert_eq!(walked.len(), MAX_SMBIOS_STRUCTURES);
+        assert_eq!(walked[0].0, 8);
+        assert_eq!(walked[1].0, 14);
+        assert!(matches!(
+            try_walk_smbios_structures(&blob),
+            Err(DumperError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn walk_rejects_unterminated_string_area() {
+        // One good structure, then a length-4 header followed by 1 MB without a double NUL
+        let mut table = vec![0x7F, 4, 0x01, 0x00, 0, 0];
+        table.extend_from_slice(&[1, 4, 0x02, 0x00]);
+        table.resize(table.len() + 1024 * 1024, b'A');
+        let blob = raw_smbios(&table);
+
+        let walked = walk_smbios_structures(&blob);
+        assert_eq!(walked.len(), 1);
+        assert_eq!(walked[0].1.handle, 0x0001);
+        assert_eq!(walked[0].2, 14);
+        assert!(matches!(
+            try_walk_smbios_structures(&blob),
+            Err(DumperError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn type_16_extended_capacity_counts_bytes() {
+        let mut array = vec![16, 0x17, 0x00, 0x10, 0x03, 0x03, 0x06];
+        array.extend_from_slice(&0x8000_0000u32.to_le_bytes());
+        array.extend_from_slice(&[0xFE, 0xFF, 0x04, 0x00]);
+        array.extend_from_slice(&(128u64 << 30).to_le_bytes());
+        assert_eq!(field(&details(&array), "Maximum Capacity"), "128 GB");
+
+        // The same capacity through the 32-bit field, which counts kilobytes
+        array[0x07..0x0B].copy_from_slice(&(128u32 << 20).to_le_bytes());
+        assert_eq!(field(&details(&array), "Maximum Capacity"), "128 GB");
+    }
+
+    #[test]
+    fn memory_error_handle_sentinels() {
+        let mut device = vec![0u8; 0x1B];
+        device[0..2].copy_from_slice(&[17, 0x1B]);
+        for (handle, expected) in [
+            (0xFFFEu16, "Not Provided"),
+            (0xFFFF, "No Error"),
+            (0x0021, "0x0021"),
+        ] {
+            device[0x06..0x08].copy_from_slice(&handle.to_le_bytes());
+            assert_eq!(field(&details(&device), "Error Info Handle"), expected);
+        }
+
+        let mut array = vec![16, 0x0F, 0x00, 0x10, 0x03, 0x03, 0x06, 0, 0, 0, 0];
+        array.extend_from_slice(&[0xFF, 0xFF, 0x04, 0x00]);
+        assert_eq!(field(&details(&array), "Error Info Handle"), "No Error");
+    }
+
+    /// Builds a Type 19 structure with the given 32-bit (KB) and extended (byte) addresses.
+    fn type_19(start_kb: u32, end_kb: u32, ext_start: u64, ext_end: u64) -> Vec<u8> {
+        let mut range = vec![19, 0x1F, 0x00, 0x13];
+        range.extend_from_slice(&start_kb.to_le_bytes());
+        range.extend_from_slice(&end_kb.to_le_bytes());
+        range.extend_from_slice(&[0x00, 0x10, 0x02]);
+        range.extend_from_slice(&ext_start.to_le_bytes());
+        range.extend_from_slice(&ext_end.to_le_bytes());
+        range
+    }
+
+    #[test]
+    fn type_19_extended_range_counts_bytes() {
+        let fields = details(&type_19(0xFFFF_FFFF, 0xFFFF_FFFF, 0, (4u64 << 40) - 1));
+        assert_eq!(field(&fields, "Starting Address"), "0x0000000000000000");
+        assert_eq!(field(&fields, "Ending Address"), "0x000003FFFFFFFFFF");
+        assert_eq!(field(&fields, "Range Size"), "4 TB");
+
+        // 32-bit addresses count kilobytes
+        let fields = details(&type_19(0, (128 << 20) - 1, 0, 0));
+        assert_eq!(field(&fields, "Ending Address"), "0x0000001FFFFFFFFF");
+        assert_eq!(field(&fields, "Range Size"), "128 GB");
+    }
+
+    #[test]
+    fn memory_size_picks_the_largest_exact_unit() {
+        assert_eq!(memory_size_str(128 << 30), "128 GB");
+        assert_eq!(memory_size_str(4 << 40), "4 TB");
+        assert_eq!(memory_size_str(1536 << 20), "1536 MB");
+        assert_eq!(memory_size_str(1), "1 byte");
+    }
+
+    #[test]
+    fn fadt_decodes_pm1a_event_block_gas() {
+        let mut fadt = vec![0u8; 276];
+        fadt[0..4].copy_from_slice(b"FACP");
+        fadt[4..8].copy_from_slice(&276u32.to_le_bytes());
+        fadt[8] = 6;
+        // X_PM1a_EVT_BLK: System I/O, 32 bits wide at bit 0, word access, port 0x1800
+        fadt[148..160].copy_from_slice(&[0x01, 0x20, 0x00, 0x02, 0x00, 0x18, 0, 0, 0, 0, 0, 0]);
+
+        let fields = parse_fadt(&fadt);
+        assert_eq!(
+            field(&fields, "X_PM1a_EVT_BLK"),
+            "SystemIO 0x0000000000001800 (32-bit, offset 0, Word)"
+        );
+
+        // A table that ends inside the GAS does not list it
+        let fields = parse_fadt(&fadt[..150]);
+        assert!(fields.iter().all(|(k, _)| k != "X_PM1a_EVT_BLK"));
+    }
+
+    #[test]
+    fn gas_decodes_memory_space_and_truncation() {
+        let gas = [0x00, 0x40, 0x00, 0x04, 0x00, 0x00, 0xD0, 0xFE, 0, 0, 0, 0];
+        assert_eq!(
+            parse_gas(&gas),
+            "SystemMemory 0x00000000FED00000 (64-bit, offset 0, QWord)"
+        );
+        assert_eq!(parse_gas(&gas[..11]), "<truncated GAS>");
+    }
+
+    #[test]
+    fn type_19_reversed_range_is_reported_invalid() {
+        let fields = details(&type_19(0xFFFF_FFFF, 0xFFFF_FFFF, 0x2000, 0x1000));
+        assert_eq!(field(&fields, "Range Size"), "Invalid range (end < start)");
+
+        // In the 32-bit path an ending address below the start's last byte is reversed too
+        let fields = details(&type_19(0x100, 0x0FE, 0, 0));
+        assert_eq!(field(&fields, "Range Size"), "Invalid range (end < start)");
+    }
+
+    #[test]
+    fn type_19_equal_range_is_one_unit() {
+        let fields = details(&type_19(0xFFFF_FFFF, 0xFFFF_FFFF, 0x1000, 0x1000));
+        assert_eq!(field(&fields, "Range Size"), "1 byte");
+
+        // The 32-bit ending address names the last kilobyte, so equal addresses span 1 KB
+        let fields = details(&type_19(0x100, 0x100, 0, 0));
+        assert_eq!(field(&fields, "Starting Address"), "0x0000000000040000");
+        assert_eq!(field(&fields, "Ending Address"), "0x00000000000403FF");
+        assert_eq!(field(&fields, "Range Size"), "1 KB");
+    }
+
+    #[test]
+    fn type_19_full_64_bit_range_does_not_overflow() {
+        let fields = details(&type_19(0xFFFF_FFFF, 0xFFFF_FFFF, 0, u64::MAX));
+        assert_eq!(field(&fields, "Range Size"), "16 EB");
+    }
+
+    /// Type 127 End-of-Table, as it follows the last structure in a table.
+    const END_OF_TABLE: [u8; 6] = [127, 4, 0xFF, 0xFE, 0, 0];
+
+    #[test]
+    fn type_11_keeps_strings_after_an_empty_one() {
+        let mut table = vec![11, 5, 0x00, 0x0B, 3];
+        table.extend_from_slice(b"First\0\0Third\0\0");
+        table.extend_from_slice(&END_OF_TABLE);
+        let blob = raw_smbios(&table);
+
+        let walked = walk_smbios_structures(&blob);
+        assert_eq!(walked.len(), 2);
+        assert_eq!(walked[0].2, 8 + 5 + 14);
+        assert_eq!(walked[1].1.type_id, 127);
+
+        let strings = get_smbios_strings(&blob, 8, 5, StringEncoding::default());
+        assert_eq!(strings, ["First", "", "Third"]);
+        assert_eq!(get_string_by_index(&strings, 1), "First");
+        assert_eq!(get_string_by_index(&strings, 3), "Third");
+
+        let fields = parse_smbios_details(11, &blob, 8, 5, &strings, None).unwrap();
+        assert_eq!(field(&fields, "String 1"), "First");
+        assert_eq!(field(&fields, "String 2"), "<empty>");
+        assert_eq!(field(&fields, "String 3"), "Third");
+    }
+
+    #[test]
+    fn type_11_overstated_count_does_not_swallow_next_structure() {
+        let mut table = vec![11, 5, 0x00, 0x0B, 3];
+        table.extend_from_slice(b"Only\0\0");
+        table.extend_from_slice(&END_OF_TABLE);
+        let blob = raw_smbios(&table);
+
+        let walked = walk_smbios_structures(&blob);
+        assert_eq!(walked.len(), 2);
+        assert_eq!(walked[1].0, 8 + 5 + 6);
+        assert_eq!(walked[1].1.type_id, 127);
+
+        let strings = get_smbios_strings(&blob, 8, 5, StringEncoding::default());
+        assert_eq!(strings, ["Only"]);
+        let fields = parse_smbios_details(11, &blob, 8, 5, &strings, None).unwrap();
+        assert_eq!(field(&fields, "String 2"), "<empty>");
+        assert_eq!(field(&fields, "String 3"), "<empty>");
+    }
+
+    /// UUID 00112233-4455-6677-8899-AABBCCDDEEFF as SMBIOS 2.6+ stores it, with the first
+    /// three fields little-endian.
+    const UUID_2_6_WIRE: [u8; 16] = [
+        0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE,
+        0xFF,
+    ];
+
+    #[test]
+    fn uuid_byte_order_follows_version() {
+        assert_eq!(
+            smbios_uuid_str(&UUID_2_6_WIRE, false),
+            "00112233-4455-6677-8899-AABBCCDDEEFF"
+        );
+        // Before 2.6 the same bytes are read in network order
+        assert_eq!(
+            smbios_uuid_str(&UUID_2_6_WIRE, true),
+            "33221100-5544-7766-8899-AABBCCDDEEFF"
+        );
+    }
+
+    #[test]
+    fn uuid_sentinels() {
+        assert_eq!(smbios_uuid_str(&[0xFF; 16], false), "Not Present");
+        assert_eq!(smbios_uuid_str(&[0x00; 16], false), "Not Settable");
+        assert_eq!(smbios_uuid_str(&[0xFF; 16], true), "Not Present");
+        assert_eq!(smbios_uuid_str(&[0x00; 16], true), "Not Settable");
+    }
+
+    #[test]
+    fn type_1_uuid_uses_table_version() {
+        let mut system = vec![1, 0x1B, 0x00, 0x01, 0, 0, 0, 0];
+        system.extend_from_slice(&UUID_2_6_WIRE);
+        system.extend_from_slice(&[0x06, 0, 0]);
+        let parse = |system: &[u8], version| {
+            parse_smbios_details(1, system, 0, 0x1B, &[], version).unwrap()
+        };
+
+        let fields = parse(&system, Some(SmbiosVersion::new(2, 6)));
+        assert_eq!(
+            field(&fields, "UUID"),
+            "00112233-4455-6677-8899-AABBCCDDEEFF"
+        );
+        let fields = parse(&system, Some(SmbiosVersion::new(2, 5)));
+        assert_eq!(
+            field(&fields, "UUID"),
+            "33221100-5544-7766-8899-AABBCCDDEEFF"
+        );
+
+        // Without a version both readings are listed
+        let fields = parse(&system, None);
+        assert_eq!(
+            field(&fields, "UUID"),
+            "00112233-4455-6677-8899-AABBCCDDEEFF"
+        );
+        assert_eq!(
+            field(&fields, "UUID (Pre-2.6 Order)"),
+            "33221100-5544-7766-8899-AABBCCDDEEFF"
+        );
+
+        system[0x08..0x18].fill(0xFF);
+        let fields = parse(&system, None);
+        assert_eq!(field(&fields, "UUID"), "Not Present");
+        assert!(fields.iter().all(|(k, _)| k != "UUID (Pre-2.6 Order)"));
+    }
+
+    #[test]
+    fn truncated_structures_do_not_panic() {
+        let strings: Vec<String> = ["One", "Two", "Three"].map(String::from).to_vec();
+        let fills: [fn(usize) -> u8; 3] = [|_| 0x00, |_| 0xFF, |i| i as u8];
+        for &type_id in PARSED_SMBIOS_TYPES {
+            for fill in fills {
+                let mut full: Vec<u8> = (0..0xFF).map(fill).collect();
+                full[0] = type_id;
+                full[1] = 0xFF;
+                for version in [None, Some(SmbiosVersion::new(2, 5))] {
+                    for len in 4..=full.len() {
+                        // The buffer ends early while the Length byte still claims the whole
+                        // structure, and the Length byte itself is short
+                        let cut = &full[..len];
+                        parse_smbios_details(type_id, cut, 0, 0xFF, &strings, version);
+                        let mut short = full.clone();
+                        short[1] = len as u8;
+                        parse_smbios_details(type_id, &short, 0, len as u8, &strings, version);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Builds a Type 0 structure with a ROM Size of 0xFF and the given Extended BIOS ROM
+    /// Size, cut to `length` bytes.
+    fn type_0_extended_rom(extended: u16, length: u8) -> Vec<u8> {
+        let mut bios = vec![0, length, 0x00, 0x00, 1, 2, 0x00, 0xF0, 3, 0xFF];
+        bios.extend_from_slice(&0x08u64.to_le_bytes());
+        bios.extend_from_slice(&[0x03, 0x0D, 0x01, 0x00, 0xFF, 0xFF]);
+        bios.extend_from_slice(&extended.to_le_bytes());
+        bios.truncate(length as usize);
+        bios
+    }
+
+    #[test]
+    fn type_0_extended_rom_size_needs_3_1() {
+        let bios = type_0_extended_rom(32, 0x1A);
+        let rom_size = |version| {
+            field(
+                &parse_smbios_details(0, &bios, 0, 0x1A, &[], version).unwrap(),
+                "ROM Size",
+            )
+            .to_string()
+        };
+
+        assert_eq!(rom_size(Some(SmbiosVersion::new(3, 1))), "32 MB");
+        assert_eq!(rom_size(Some(SmbiosVersion::new(3, 8))), "32 MB");
+        assert_eq!(rom_size(None), "32 MB");
+        assert_eq!(rom_size(Some(SmbiosVersion::new(3, 0))), "16 MB or greater");
+        assert_eq!(rom_size(Some(SmbiosVersion::new(2, 8))), "16 MB or greater");
+    }
+
+    #[test]
+    fn type_0_extended_rom_size_units() {
+        assert_eq!(extended_rom_size_str(32), "32 MB");
+        assert_eq!(extended_rom_size_str(0x4000 | 2), "2 GB");
+        assert_eq!(extended_rom_size_str(0x8000 | 1), "1 (reserved unit 2)");
+    }
+
+    #[test]
+    fn type_0_too_short_for_extended_rom_size() {
+        let bios = type_0_extended_rom(32, 0x18);
+        let fields =
+            parse_smbios_details(0, &bios, 0, 0x18, &[], Some(SmbiosVersion::new(3, 1))).unwrap();
+        assert_eq!(
+            field(&fields, "ROM Size"),
+            "16 MB or greater (no Extended BIOS ROM Size field)"
+        );
+
+        // A Length byte that claims the field is not enough when the buffer ends first
+        let mut cut = type_0_extended_rom(32, 0x1A);
+        cut.truncate(0x19);
+        let fields =
+            parse_smbios_details(0, &cut, 0, 0x1A, &[], Some(SmbiosVersion::new(3, 1))).unwrap();
+        assert_eq!(
+            field(&fields, "ROM Size"),
+            "16 MB or greater (no Extended BIOS ROM Size field)"
+        );
+    }
+
+    #[test]
+    fn smbios_structure_longer_than_buffer() {
+        // A Type 1 header claiming 0x1B bytes with only 10 left in the table
+        let blob = raw_smbios(&[1, 0x1B, 0x00, 0x01, 1, 2, 3, 4, 0x33, 0x22]);
+
+        let walked = try_walk_smbios_structures(&blob).unwrap();
+        assert_eq!(walked.len(), 1);
+        assert_eq!(walked[0].1.length, 0x1B);
+        assert_eq!(walked[0].2, blob.len());
+
+        let size = smbios_structure_size(&blob, 8).unwrap();
+        assert_eq!(size.total, 10);
+        assert!(size.clamped);
+
+        assert!(get_smbios_strings(&blob, 8, 0x1B, StringEncoding::default()).is_empty());
+        let fields = parse_smbios_details(1, &blob, 8, 0x1B, &[], None).unwrap();
+        assert!(fields.is_empty());
+    }
+
+    #[test]
+    fn acpi_header_longer_than_buffer() {
+        let mut table = vec![0u8; 40];
+        table[0..4].copy_from_slice(b"XSDT");
+        table[4..8].copy_from_slice(&0x200u32.to_le_bytes());
+        table[8] = 1;
+
+        let header = parse_acpi_header(&table).unwrap();
+        assert_eq!(header.length, 0x200);
+        assert_eq!(acpi_truncation(&table), Some((0x200, 40)));
+
+        // Length-dependent decoders refuse the table instead of reading past the buffer
+        let before = table.clone();
+        assert!(!fix_acpi_checksum(&mut table));
+        assert_eq!(table, before);
+        assert_eq!(
+            parse_xsdt_entries(&table, &std::collections::HashMap::new()),
+            None
+        );
+
+        table[4..8].copy_from_slice(&40u32.to_le_bytes());
+        assert_eq!(acpi_truncation(&table), None);
+    }
+
+    #[test]
+    fn jedec_ids_resolve_across_banks_and_parity() {
+        // Bank 1 with the odd-parity bit set in the bank byte
+        assert_eq!(jedec_manufacturer_name(0xCE80), Some("Samsung"));
+        assert_eq!(jedec_id_str(0xCE80), "Bank 1, Hex 0xCE (Samsung)");
+        assert_eq!(jedec_manufacturer_name(0xCE00), Some("Samsung"));
+
+        // Three continuation codes put Nanya in bank 4
+        assert_eq!(jedec_manufacturer_name(0x0B83), Some("Nanya"));
+        assert_eq!(jedec_id_str(0x0B83), "Bank 4, Hex 0x0B (Nanya)");
+        // The same code in another bank is a different manufacturer
+        assert_eq!(jedec_manufacturer_name(0x0B00), None);
+    }
+
+    #[test]
+    fn jedec_unknown_and_unlisted_ids() {
+        assert_eq!(jedec_manufacturer_name(0), None);
+        assert_eq!(jedec_id_str(0), "Unknown");
+
+        assert_eq!(jedec_manufacturer_name(0x0100), None);
+        assert_eq!(jedec_id_str(0x0100), "Bank 1, Hex 0x01");
+    }
+
+    /// Wraps an AML body in an SSDT header whose length covers exactly `body`.
+    fn aml_table(body: &[u8]) -> Vec<u8> {
+        let mut table = vec![0u8; 36];
+        table[0..4].copy_from_slice(b"SSDT");
+        table[4..8].copy_from_slice(&((36 + body.len()) as u32).to_le_bytes());
+        table.extend_from_slice(body);
+        table
+    }
+
+    #[test]
+    fn name_op_at_end_of_scope_stays_inside_it() {
+        // Scope (\_SB_) { NameOp } followed by bytes that look like a NullName and a
+        // StringPrefix; the name must not be read past the end of the scope
+        let table = aml_table(&[
+            0x10, 0x07, b'\\', b'_', b'S', b'B', b'_', 0x08, 0x00, 0x0D, 0x00,
+        ]);
+
+        let found = walk_aml(&table);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].kind, "Scope");
+        assert_eq!(found[0].path, "\\_SB");
+        assert_eq!(field(&scan_aml_names(&table), "Names"), "0");
+    }
+
+    /// `Scope (\_SB) { Device (PCI0) { Name (_ADR, 0); Method (_STA) { Return (0x0F) }
+    /// Device (DEV0) { <NameOp> } } }` followed by a NullName and a StringPrefix.
+    #[rustfmt::skip]
+    const NESTED_AML: &[u8] = &[
+        0x10, 0x26, b'\\', b'_', b'S', b'B', b'_',
+        0x5B, 0x82, 0x1E, b'P', b'C', b'I', b'0',
+        0x08, b'_', b'A', b'D', b'R', 0x0A, 0x00,
+        0x14, 0x09, b'_', b'S', b'T', b'A', 0x00, 0xA4, 0x0A, 0x0F,
+        0x5B, 0x82, 0x06, b'D', b'E', b'V', b'0', 0x08,
+        0x00, 0x0D, 0x00,
+    ];
+
+    #[test]
+    fn nested_aml_namespace() {
+        let table = aml_table(NESTED_AML);
+        let found = walk_aml(&table);
+        let found: Vec<(&str, &str)> = found.iter().map(|d| (d.kind, d.path.as_str())).collect();
+        assert_eq!(
+            found,
+            [
+                ("Scope", "\\_SB"),
+                ("Device", "\\_SB.PCI0"),
+                ("Name", "\\_SB.PCI0._ADR"),
+                ("Method", "\\_SB.PCI0._STA"),
+                ("Device", "\\_SB.PCI0.DEV0"),
+            ]
+        );
+
+        let tree = aml_scope_tree(&table);
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree[0].children[0].path, "\\_SB.PCI0");
+        assert_eq!(tree[0].children[0].children[0].kind, "Device");
+    }
+
+    #[test]
+    fn truncated_aml_does_not_panic() {
+        let table = aml_table(NESTED_AML);
+        for len in 0..=table.len() {
+            // Cut the buffer short, both with the original and with a matching header length
+            let mut cut = table[..len].to_vec();
+            for _ in 0..2 {
+                walk_aml(&cut);
+                aml_scope_tree(&cut);
+                scan_aml_names(&cut);
+                if cut.len() >= 8 {
+                    cut[4..8].copy_from_slice(&(len as u32).to_le_bytes());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,4203 @@
+use crate::api;
+use crate::error::DumperError;
+use crate::parsers;
+use serde_json::json;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc;
+
+/// Outcome of writing a full system report to a folder.
+pub struct ReportOutcome {
+    /// File names that were written successfully.
+    pub written: Vec<String>,
+    /// Human-readable descriptions of tables or files that failed.
+    pub failed: Vec<String>,
+    /// Whether the report was cancelled before every file was written.
+    pub cancelled: bool,
+}
+
+/// What a full system report reads and how it writes it; see [`write_full_report`].
+#[derive(Clone)]
+pub struct ReportOptions {
+    /// Where ACPI tables are discovered.
+    pub source: api::AcpiSource,
+    /// How SMBIOS strings are decoded.
+    pub encoding: parsers::StringEncoding,
+    /// Write the bare SMBIOS structure table instead of the blob with the Windows header.
+    pub strip_wrapper: bool,
+    /// Replace serial numbers, UUIDs, asset tags, and the product key with [`REDACTED`].
+    pub redact: bool,
+    /// Free-text notes keyed by [`acpi_note_key`] and [`smbios_note_key`], written with the
+    /// tables and structures they annotate.
+    pub notes: BTreeMap<String, String>,
+}
+
+/// Returns the key a note on ACPI tables with this signature is stored under, e.g.
+/// `acpi:DSDT`. Every table sharing the signature shares the note.
+pub fn acpi_note_key(signature: &str) -> String {
+    format!("acpi:{}", signature)
+}
+
+/// Returns the key a note on an SMBIOS structure is stored under, e.g. `smbios:17:0011`.
+pub fn smbios_note_key(type_id: u8, handle: u16) -> String {
+    format!("smbios:{}:{:04X}", type_id, handle)
+}
+
+/// Value written in place of a field removed by redaction.
+pub const REDACTED: &str = "REDACTED";
+
+/// Returns whether a parsed SMBIOS field identifies the individual machine.
+///
+/// These are the system, baseboard, chassis, and memory device serial numbers, the system
+/// UUID, and every asset tag. Vendor, model, and version fields are kept.
+fn is_sensitive_smbios_field(type_id: u8, key: &str) -> bool {
+    key.starts_with("Asset Tag")
+        || match type_id {
+            1 => key == "Serial Number" || key.starts_with("UUID"),
+            2 | 3 | 17 => key == "Serial Number",
+            _ => false,
+        }
+}
+
+/// Replaces the values of the identifying fields of an SMBIOS structure with [`REDACTED`].
+///
+/// # Arguments
+/// * `type_id` - The structure type the fields were parsed from.
+/// * `fields` - Parsed key/value pairs of one structure, updated in place.
+pub fn redact_smbios_fields(type_id: u8, fields: &mut [(String, String)]) {
+    for (key, value) in fields.iter_mut() {
+        if is_sensitive_smbios_field(type_id, key) {
+            *value = REDACTED.to_string();
+        }
+    }
+}
+
+/// Replaces the MSDM product key, and the content hash that covers it, in the parsed fields
+/// of an ACPI table with [`REDACTED`].
+///
+/// # Arguments
+/// * `signature` - The table signature the fields were parsed from.
+/// * `fields` - Parsed key/value pairs of the table, updated in place.
+pub fn redact_acpi_fields(signature: &str, fields: &mut [(String, String)]) {
+    if signature != "MSDM" {
+        return;
+    }
+    for (key, value) in fields.iter_mut() {
+        // The hash covers the key bytes, so it could confirm a guessed key
+        if key == "Product Key" || key == "Content Hash" {
+            *value = REDACTED.to_string();
+        }
+    }
+}
+
+/// Sanitizes a filename fragment for Windows.
+pub fn clean_filename_fragment(fragment: &str) -> String {
+    let invalid = ['<', '>', ':', '"', '/', '\\', '|', '?', '*'];
+    fragment
+        .chars()
+        .map(|c| if invalid.contains(&c) { '_' } else { c })
+        .collect()
+}
+
+/// Placeholders an export filename template may use; see [`apply_filename_template`].
+pub const FILENAME_PLACEHOLDERS: [&str; 6] = ["sig", "oem", "table_id", "type", "handle", "date"];
+
+/// Builds an export file name from a template such as `{date}_{sig}_{table_id}`.
+///
+/// Each `{name}` placeholder is replaced by its value from `fields`, and placeholders with no
+/// value for the item (e.g. `{handle}` for an ACPI table) are dropped. The result is
+/// sanitized with [`clean_filename_fragment`].
+///
+/// # Arguments
+/// * `template` - The file name without its extension.
+/// * `fields` - Placeholder names and their values.
+/// * `extension` - The extension to append, without the dot.
+///
+/// # Returns
+/// The file name, or `None` if the template is empty or expands to nothing, so the caller
+/// can fall back to its built-in name.
+pub fn apply_filename_template(
+    template: &str,
+    fields: &[(&str, &str)],
+    extension: &str,
+) -> Option<String> {
+    let mut name = template.trim().to_string();
+    for placeholder in FILENAME_PLACEHOLDERS {
+        let value = fields
+            .iter()
+            .find(|(key, _)| *key == placeholder)
+            .map_or("", |(_, value)| value.trim());
+        name = name.replace(&format!("{{{}}}", placeholder), value);
+    }
+    let name = clean_filename_fragment(&name);
+    // Windows drops trailing dots and spaces, so a name made of only those is empty
+    let name = name.trim_end_matches(['.', ' ']);
+    if name.is_empty() {
+        return None;
+    }
+    Some(format!("{}.{}", name, extension))
+}
+
+/// Maximum number of worker threads reading tables at once.
+///
+/// Registry reads are independent, but a handful of threads is enough to hide their
+/// latency without flooding the registry with requests.
+pub const READ_CONCURRENCY: usize = 4;
+
+/// Reads many ACPI tables on a small pool of worker threads.
+///
+/// # Arguments
+/// * `tables` - The tables to read.
+/// * `read` - Reads one table; called from the worker threads.
+/// * `cancel` - Checked by each worker before it starts another table; once set, no new
+///   reads begin and the tables not yet read come back as [`DumperError::Cancelled`].
+/// * `on_read` - Called on the calling thread as each table finishes, with the table's
+///   index and the number finished so far.
+///
+/// # Returns
+/// One result per table, in the same order as `tables` regardless of completion order.
+pub fn read_tables_concurrently<R, P>(
+    tables: &[api::AcpiTableInfo],
+    read: R,
+    cancel: &AtomicBool,
+    mut on_read: P,
+) -> Vec<Result<Vec<u8>, DumperError>>
+where
+    R: Fn(&api::AcpiTableInfo) -> Result<Vec<u8>, DumperError> + Sync,
+    P: FnMut(usize, usize),
+{
+    let mut results: Vec<Option<Result<Vec<u8>, DumperError>>> =
+        tables.iter().map(|_| None).collect();
+    let next = AtomicUsize::new(0);
+    let workers = READ_CONCURRENCY.min(tables.len());
+    std::thread::scope(|scope| {
+        let (sender, receiver) = mpsc::channel();
+        for _ in 0..workers {
+            let sender = sender.clone();
+            let (next, read) = (&next, &read);
+            scope.spawn(move || loop {
+                if cancel.load(Ordering::Relaxed) {
+                    break;
+                }
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(info) = tables.get(index) else {
+                    break;
+                };
+                if sender.send((index, read(info))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+        for (done, (index, result)) in receiver.iter().enumerate() {
+            results[index] = Some(result);
+            on_read(index, done + 1);
+        }
+    });
+    results
+        .into_iter()
+        .map(|r| {
+            r.unwrap_or_else(|| {
+                Err(if cancel.load(Ordering::Relaxed) {
+                    DumperError::Cancelled
+                } else {
+                    DumperError::Other("Read worker stopped".to_string())
+                })
+            })
+        })
+        .collect()
+}
+
+/// A node in the ACPI table hierarchy rooted at the RSDP.
+pub struct AcpiTreeNode {
+    /// Text shown for the node.
+    pub label: String,
+    /// The table behind the node, or `None` for grouping nodes.
+    pub table: Option<api::AcpiTableInfo>,
+    /// Tables referenced by this node.
+    pub children: Vec<AcpiTreeNode>,
+}
+
+impl AcpiTreeNode {
+    /// Creates a node for a discovered table, appending its address when known.
+    fn for_table(info: &api::AcpiTableInfo, address: Option<u64>) -> AcpiTreeNode {
+        let mut label = match info.table_id.trim() {
+            "" => info.signature.clone(),
+            id => format!("{} ({})", info.signature, id),
+        };
+        if let Some(addr) = address.or(info.physical_address) {
+            label.push_str(&format!(" @ 0x{:X}", addr));
+        }
+        AcpiTreeNode {
+            label,
+            table: Some(info.clone()),
+            children: Vec::new(),
+        }
+    }
+}
+
+/// Builds the RSDP -> RSDT/XSDT -> tables -> (FADT -> DSDT/FACS) hierarchy.
+///
+/// Windows does not report the physical address of most tables, so XSDT entries are matched
+/// to discovered tables by count: tables beyond the number of root entries, and DSDT/FACS
+/// without an FADT, are placed under an "Unreferenced" node.
+///
+/// # Arguments
+/// * `tables` - All discovered tables.
+pub fn build_acpi_tree(tables: &[api::AcpiTableInfo]) -> Vec<AcpiTreeNode> {
+    let find = |sig: &str| tables.iter().find(|t| t.signature == sig);
+    let rsdp = find("RSDP");
+    let root = find("XSDT").or_else(|| find("RSDT"));
+    let fadt = tables
+        .iter()
+        .find(|t| t.signature == "FACP" || t.signature == "FADT");
+
+    // Addresses the RSDP and FADT tell us about
+    let mut root_address = None;
+    if let Some(data) = rsdp.and_then(|info| api::get_acpi_table(info).ok()) {
+        let xsdt_sig = root.map(|t| t.signature == "XSDT").unwrap_or(false);
+        if xsdt_sig && data.len() >= 32 {
+            root_address = Some(u64::from_le_bytes(data[24..32].try_into().unwrap()));
+        } else if data.len() >= 20 {
+            root_address = Some(u32::from_le_bytes(data[16..20].try_into().unwrap()) as u64);
+        }
+    }
+    let fadt_refs: HashMap<String, u64> = fadt
+        .and_then(|info| api::get_acpi_table(info).ok())
+        .map(|d| {
+            parsers::parse_fadt_references(&d)
+                .into_iter()
+                .map(|(addr, sig)| (sig, addr))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Number of entries in the root table, if it can be read
+    let entry_count = root
+        .and_then(|info| api::get_acpi_table(info).ok())
+        .filter(|d| d.len() >= 36)
+        .map(|d| {
+            let len = (u32::from_le_bytes(d[4..8].try_into().unwrap()) as usize).min(d.len());
+            let width = if root.map(|t| t.signature == "XSDT").unwrap_or(false) {
+                8
+            } else {
+                4
+            };
+            len.saturating_sub(36) / width
+        });
+
+    let mut unreferenced = Vec::new();
+    let mut root_children = Vec::new();
+    for info in tables {
+        match info.signature.as_str() {
+            "RSDP" | "XSDT" | "RSDT" => {}
+            "DSDT" | "FACS" if fadt.is_some() => {}
+            "DSDT" | "FACS" => unreferenced.push(AcpiTreeNode::for_table(info, None)),
+            _ => {
+                let mut node = AcpiTreeNode::for_table(info, None);
+                if Some(info) == fadt {
+                    for child in tables
+                        .iter()
+                        .filter(|t| t.signature == "DSDT" || t.signature == "FACS")
+                    {
+                        let address = fadt_refs.get(&child.signature).copied();
+                        node.children.push(AcpiTreeNode::for_table(child, address));
+                    }
+                }
+                if entry_count.is_none_or(|n| root_children.len() < n) {
+                    root_children.push(node);
+                } else {
+                    unreferenced.push(node);
+                }
+            }
+        }
+    }
+
+    let mut nodes = Vec::new();
+    let children = match root {
+        Some(info) => {
+            let mut node = AcpiTreeNode::for_table(info, root_address);
+            node.children = root_children;
+            vec![node]
+        }
+        None => root_children,
+    };
+    match rsdp {
+        Some(info) => {
+            let mut node = AcpiTreeNode::for_table(info, None);
+            node.children = children;
+            nodes.push(node);
+        }
+        None => nodes.push(AcpiTreeNode {
+            label: "RSDP".to_string(),
+            table: None,
+            children,
+        }),
+    }
+    if !unreferenced.is_empty() {
+        nodes.push(AcpiTreeNode {
+            label: "Unreferenced".to_string(),
+            table: None,
+            children: unreferenced,
+        });
+    }
+    nodes
+}
+
+/// Where a physical address named by the RSDT, XSDT or FADT leads.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AcpiAddressTarget {
+    /// A discovered table known to be at the address.
+    Listed(api::AcpiTableInfo),
+    /// A table missing from the discovered list, read from the firmware API by the signature
+    /// known to be at the address.
+    Fetched(api::AcpiTableInfo),
+    /// Nothing ties the address to a table, and the firmware API cannot read by address.
+    NotRetrievable,
+}
+
+impl AcpiAddressTarget {
+    /// Returns the table the address leads to, if any.
+    pub fn table(&self) -> Option<&api::AcpiTableInfo> {
+        match self {
+            AcpiAddressTarget::Listed(info) | AcpiAddressTarget::Fetched(info) => Some(info),
+            AcpiAddressTarget::NotRetrievable => None,
+        }
+    }
+
+    /// Describes the target, e.g. "DSDT", "FACS, fetched by signature" or "not directly
+    /// retrievable".
+    pub fn describe(&self) -> String {
+        match self {
+            AcpiAddressTarget::Listed(info) => info.signature.clone(),
+            AcpiAddressTarget::Fetched(info) => {
+                format!("{}, fetched by signature", info.signature)
+            }
+            AcpiAddressTarget::NotRetrievable => "not directly retrievable".to_string(),
+        }
+    }
+}
+
+/// A physical address a table points at, with the table it resolves to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AcpiAddressRef {
+    /// Name of the pointer, e.g. "Entry3" for a root table entry or "FACS" for the FADT field.
+    pub label: String,
+    /// The physical address.
+    pub address: u64,
+    /// The table at the address.
+    pub target: AcpiAddressTarget,
+}
+
+/// Collects the addresses whose table signature is known from another table: the DSDT and
+/// FACS pointers in the FADT.
+fn acpi_address_signatures(tables: &[api::AcpiTableInfo]) -> HashMap<u64, String> {
+    tables
+        .iter()
+        .find(|t| t.signature == "FACP" || t.signature == "FADT")
+        .and_then(|info| api::get_acpi_table(info).ok())
+        .map(|d| parsers::parse_fadt_references(&d).into_iter().collect())
+        .unwrap_or_default()
+}
+
+/// Resolves a physical address to a table.
+///
+/// The firmware API reads tables by signature, not by address, so the address is first
+/// matched against the addresses of discovered tables. Failing that, a table whose signature
+/// is known to live at the address is taken from the discovered list, or read from the
+/// firmware API when it was not enumerated (the FACS, which has no standard header, often
+/// is not).
+///
+/// # Arguments
+/// * `address` - The physical address.
+/// * `signature` - The signature known to be at the address, if any.
+/// * `tables` - All discovered tables.
+pub fn resolve_acpi_address(
+    address: u64,
+    signature: Option<&str>,
+    tables: &[api::AcpiTableInfo],
+) -> AcpiAddressTarget {
+    if let Some(info) = tables.iter().find(|t| t.physical_address == Some(address)) {
+        return AcpiAddressTarget::Listed(info.clone());
+    }
+    let Some(sig) = signature else {
+        return AcpiAddressTarget::NotRetrievable;
+    };
+    if let Some(info) = tables.iter().find(|t| t.signature == sig) {
+        return AcpiAddressTarget::Listed(info.clone());
+    }
+    match api::get_system_firmware_table(api::SIG_ACPI, sig) {
+        Ok(data) if !data.is_empty() => {
+            // The FACS has no OEM fields to read
+            let header = parsers::parse_acpi_header(&data)
+                .ok()
+                .filter(|_| !data.starts_with(b"FACS"));
+            AcpiAddressTarget::Fetched(api::AcpiTableInfo {
+                signature: sig.to_string(),
+                signature_valid: true,
+                // The firmware API read above only accepts 4-character signatures
+                signature_bytes: sig.as_bytes().try_into().unwrap_or_default(),
+                registry_sig: sig.to_string(),
+                oem_id: header
+                    .as_ref()
+                    .map(|h| h.oem_id.clone())
+                    .unwrap_or_default(),
+                table_id: header
+                    .as_ref()
+                    .map(|h| h.oem_table_id.clone())
+                    .unwrap_or_default(),
+                revision: header.map(|h| h._revision as u32).unwrap_or(0),
+                registry_path: None,
+                physical_address: Some(address),
+            })
+        }
+        _ => AcpiAddressTarget::NotRetrievable,
+    }
+}
+
+/// Lists the physical addresses a table points at and resolves each to a table.
+///
+/// These are the entries of the RSDT or XSDT and the DSDT and FACS pointers of the FADT.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the table.
+/// * `tables` - All discovered tables.
+///
+/// # Returns
+/// The pointers in table order; empty for tables that hold none.
+pub fn acpi_address_refs(data: &[u8], tables: &[api::AcpiTableInfo]) -> Vec<AcpiAddressRef> {
+    let empty_lookup = HashMap::new();
+    let pointers: Vec<(String, u64, Option<String>)> = if data.starts_with(b"FACP") {
+        parsers::parse_fadt_references(data)
+            .into_iter()
+            .map(|(addr, sig)| (format!("{} Address", sig), addr, Some(sig)))
+            .collect()
+    } else {
+        let entries = if data.starts_with(b"XSDT") {
+            parsers::parse_xsdt_entries(data, &empty_lookup)
+        } else {
+            parsers::parse_rsdt_entries(data, &empty_lookup)
+        };
+        let Some(entries) = entries.filter(|e| !e.is_empty()) else {
+            return Vec::new();
+        };
+        let known = acpi_address_signatures(tables);
+        entries
+            .into_iter()
+            .map(|(_, addr, label)| (label, addr, known.get(&addr).cloned()))
+            .collect()
+    };
+    pointers
+        .into_iter()
+        .map(|(label, address, sig)| AcpiAddressRef {
+            target: resolve_acpi_address(address, sig.as_deref(), tables),
+            label,
+            address,
+        })
+        .collect()
+}
+
+/// Hashes a table's bytes with 64-bit FNV-1a, so same-signature tables can be told apart
+/// and the value stays the same between runs and builds.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the table.
+pub fn acpi_content_hash(data: &[u8]) -> u64 {
+    data.iter().fold(0xCBF2_9CE4_8422_2325, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x0000_0100_0000_01B3)
+    })
+}
+
+/// Formats a hash from [`acpi_content_hash`] for display.
+pub fn acpi_content_hash_str(data: &[u8]) -> String {
+    format!("0x{:016X} (FNV-1a)", acpi_content_hash(data))
+}
+
+/// Marks which tables are byte-identical copies of an earlier table in the list.
+///
+/// # Arguments
+/// * `hashes` - The content hash of each table, or `None` if it could not be read.
+///
+/// # Returns
+/// One flag per table, set when an earlier table has the same hash. Unread tables are
+/// never marked.
+pub fn identical_copies(hashes: &[Option<u64>]) -> Vec<bool> {
+    let mut seen = HashSet::new();
+    hashes
+        .iter()
+        .map(|hash| hash.is_some_and(|h| !seen.insert(h)))
+        .collect()
+}
+
+/// Returns the minimum FADT length a revision implies, or `None` for revisions with no
+/// published layout (revision 2 was only used by pre-2.0 drafts).
+fn fadt_min_length(revision: u8) -> Option<usize> {
+    match revision {
+        1 => Some(116),
+        3 | 4 => Some(244),
+        5 => Some(268),
+        6.. => Some(276),
+        _ => None,
+    }
+}
+
+/// Checks a table against the spec rules that can be verified without guessing.
+///
+/// The rules are deliberately conservative, so firmware that merely uses an older layout
+/// is not flagged: a truncated read, a checksum that does not sum to zero, an FADT shorter
+/// than its revision requires, non-zero FADT reserved bytes, and XSDT/RSDT entries that are
+/// ragged, null, or fewer than the distinct tables they must point to.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the table.
+/// * `tables` - All discovered tables, compared against the XSDT/RSDT entry count.
+///
+/// # Returns
+/// One sentence per problem; empty for a clean table, the FACS and the RSDP.
+pub fn acpi_table_warnings(data: &[u8], tables: &[api::AcpiTableInfo]) -> Vec<String> {
+    let mut warnings = Vec::new();
+    if data.len() < 36 || data.starts_with(b"FACS") || data.starts_with(b"RSD PTR ") {
+        return warnings;
+    }
+    if let Some((reported, read)) = parsers::acpi_truncation(data) {
+        // Nothing past the header can be checked over a partial read
+        warnings.push(acpi_truncation_str(reported, read));
+        return warnings;
+    }
+    let sum = parsers::acpi_checksum_sum(data);
+    if sum != 0 {
+        warnings.push(format!(
+            "Checksum is invalid: the table sums to 0x{:02X} instead of 0x00",
+            sum
+        ));
+    }
+    let length = u32::from_le_bytes(data[4..8].try_into().unwrap()) as usize;
+    if length < 36 {
+        return warnings;
+    }
+    let revision = data[8];
+    if data.starts_with(b"FACP") {
+        if let Some(min) = fadt_min_length(revision).filter(|&min| length < min) {
+            warnings.push(format!(
+                "FADT revision {} requires at least {} bytes, but the table is {} bytes",
+                revision, min, length
+            ));
+        }
+        // ACPI 2.0 retired INT_MODEL at offset 44 but still allows the 1.0 value of 1
+        if revision >= 3 && length > 44 && data[44] > 1 {
+            warnings.push(format!(
+                "Reserved byte at offset 0x2C is 0x{:02X}; it must be 0 or 1",
+                data[44]
+            ));
+        }
+        if length > 111 && data[111] != 0 {
+            warnings.push(format!(
+                "Reserved byte at offset 0x6F is 0x{:02X}; it must be 0",
+                data[111]
+            ));
+        }
+    }
+    let (signature, entry_size) = if data.starts_with(b"XSDT") {
+        ("XSDT", 8)
+    } else if data.starts_with(b"RSDT") {
+        ("RSDT", 4)
+    } else {
+        return warnings;
+    };
+    if !(length - 36).is_multiple_of(entry_size) {
+        warnings.push(format!(
+            "{} entry area is {} bytes, not a multiple of the {}-byte entry size",
+            signature,
+            length - 36,
+            entry_size
+        ));
+    }
+    let entries: Vec<u64> = data[36..length]
+        .chunks_exact(entry_size)
+        .map(|chunk| match entry_size {
+            8 => u64::from_le_bytes(chunk.try_into().unwrap()),
+            _ => u32::from_le_bytes(chunk.try_into().unwrap()) as u64,
+        })
+        .collect();
+    let null = entries.iter().filter(|&&addr| addr == 0).count();
+    if null > 0 {
+        warnings.push(format!("{} of {} entries are null", null, entries.len()));
+    }
+    // Every distinct signature must appear at least once; tables reached through the FADT
+    // or the RSDP do not, and a table count may include several SSDTs or repeated reads
+    let referenced: HashSet<&str> = tables
+        .iter()
+        .map(|t| t.signature.as_str())
+        .filter(|sig| !matches!(*sig, "DSDT" | "FACS" | "XSDT" | "RSDT" | "RSDP"))
+        .collect();
+    if entries.len() < referenced.len() {
+        warnings.push(format!(
+            "{} has {} entries, fewer than the {} distinct table signatures found",
+            signature,
+            entries.len(),
+            referenced.len()
+        ));
+    }
+    warnings
+}
+
+/// Builds the human-readable parsed view of a single ACPI table.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the table.
+/// * `tables` - All discovered tables, used to cross-reference XSDT/RSDT entries with the FADT.
+/// * `redact` - Replace the MSDM product key with [`REDACTED`] instead of masking it.
+pub fn acpi_table_text(data: &[u8], tables: &[api::AcpiTableInfo], redact: bool) -> String {
+    let mut out = String::new();
+    let warnings = acpi_table_warnings(data, tables);
+    for warning in &warnings {
+        out.push_str(&format!("WARNING: {}\n", warning));
+    }
+    if !warnings.is_empty() {
+        out.push('\n');
+    }
+    // The FACS has no standard header, so parse_acpi_header would misread its fields
+    if data.starts_with(b"FACS") {
+        let details = parsers::parse_facs(data);
+        if details.is_empty() {
+            out.push_str("Error parsing FACS\n");
+            return out;
+        }
+        out.push_str("Signature: FACS (Firmware ACPI Control Structure)\n");
+        out.push_str("Spec:      ACPI specification\n");
+        out.push_str(&format!("Hash:      {}\n", acpi_content_hash_str(data)));
+        out.push_str("\n====================\nFACS Details:\n");
+        let width = details.iter().map(|(n, _)| n.len()).max().unwrap_or(0) + 2;
+        for (name, value) in details {
+            out.push_str(&format!("{:<width$}{}\n", format!("{}:", name), value));
+        }
+        return out;
+    }
+    if let Ok(header) = parsers::parse_acpi_header(data) {
+        match parsers::acpi_signature_description(&header.signature) {
+            Some(desc) => out.push_str(&format!("Signature: {} ({})\n", header.signature, desc)),
+            None => out.push_str(&format!("Signature: {}\n", header.signature)),
+        }
+        out.push_str(&format!("Length:    {}\n", header.length));
+        out.push_str(&format!("OEM ID:    {}\n", header.oem_id));
+        out.push_str(&format!("Table ID:  {}\n", header.oem_table_id));
+        out.push_str(&format!("Revision:  {}\n", header._revision));
+        out.push_str(&format!(
+            "OEM Rev:   {}\n",
+            parsers::oem_revision_str(header._oem_revision)
+        ));
+        if let Some(spec) = parsers::acpi_signature_spec(&header.signature) {
+            out.push_str(&format!("Spec:      {}\n", spec));
+        }
+        let hash = if redact && header.signature == "MSDM" {
+            REDACTED.to_string()
+        } else {
+            acpi_content_hash_str(data)
+        };
+        out.push_str(&format!("Hash:      {}\n", hash));
+
+        if let Some(mut details) = parsers::parse_acpi_details(&header.signature, data) {
+            if redact {
+                redact_acpi_fields(&header.signature, &mut details);
+            }
+            if !details.is_empty() {
+                out.push_str(&format!(
+                    "\n====================\n{} Details:\n",
+                    header.signature
+                ));
+                let width = details.iter().map(|(n, _)| n.len()).max().unwrap_or(0) + 2;
+                for (name, value) in details {
+                    out.push_str(&format!("{:<width$}{}\n", format!("{}:", name), value));
+                }
+            }
+        }
+
+        if header.signature == "MCFG" {
+            if let Some(region) = parsers::parse_mcfg_regions(data).first() {
+                let address = region
+                    .config_address(region.start_bus, 0, 0)
+                    .unwrap_or(region.base_address);
+                if let Err(e) = api::read_physical_memory(address, 4096) {
+                    out.push_str(&format!("\nLive PCI enumeration unavailable: {}\n", e));
+                }
+            }
+        }
+
+        let refs = acpi_address_refs(data, tables);
+        if !refs.is_empty() {
+            let title = match header.signature.as_str() {
+                "XSDT" | "RSDT" => "Entries",
+                _ => "References",
+            };
+            out.push_str(&format!(
+                "\n====================\n{} {}:\n",
+                header.signature, title
+            ));
+            // RSDT pointers are 32-bit; print them at their own width
+            let digits = if header.signature == "RSDT" { 8 } else { 16 };
+            for r in refs {
+                out.push_str(&format!(
+                    "{:<17}0x{:0digits$X} ({})\n",
+                    r.label,
+                    r.address,
+                    r.target.describe()
+                ));
+            }
+        }
+    } else {
+        out.push_str("Error parsing ACPI Header\n");
+    }
+    out
+}
+
+/// Describes a truncated ACPI table read, e.g. "Table reports 4096 bytes but only 512 were
+/// read (truncated)".
+pub fn acpi_truncation_str(reported: u32, read: usize) -> String {
+    format!(
+        "Table reports {} bytes but only {} were read (truncated)",
+        reported, read
+    )
+}
+
+/// Retrieves the header fields and decoded details of a single ACPI table as key/value pairs.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the table.
+pub fn acpi_table_fields(data: &[u8]) -> Option<Vec<(String, String)>> {
+    if data.starts_with(b"FACS") {
+        let details = parsers::parse_facs(data);
+        if details.is_empty() {
+            return None;
+        }
+        let mut fields = vec![
+            ("Signature".to_string(), "FACS".to_string()),
+            ("Content Hash".to_string(), acpi_content_hash_str(data)),
+        ];
+        fields.extend(details);
+        return Some(fields);
+    }
+    let header = parsers::parse_acpi_header(data).ok()?;
+    let mut fields = vec![
+        ("Signature".to_string(), header.signature.clone()),
+        ("Length".to_string(), format!("{}", header.length)),
+        ("OEM ID".to_string(), header.oem_id.clone()),
+        ("Table ID".to_string(), header.oem_table_id.clone()),
+        ("Revision".to_string(), format!("{}", header._revision)),
+        (
+            "OEM Revision".to_string(),
+            parsers::oem_revision_str(header._oem_revision),
+        ),
+        ("Content Hash".to_string(), acpi_content_hash_str(data)),
+    ];
+    if let Some(details) = parsers::parse_acpi_details(&header.signature, data) {
+        fields.extend(details);
+    }
+    Some(fields)
+}
+
+/// One field of the 36-byte ACPI table header with its position and raw bytes.
+pub struct HeaderFieldLayout {
+    /// Field name, e.g. `Checksum`.
+    pub name: &'static str,
+    /// Offset of the field from the start of the table.
+    pub offset: usize,
+    /// Size of the field in bytes.
+    pub size: usize,
+    /// The field's bytes as space-separated hex.
+    pub raw: String,
+    /// The decoded value.
+    pub value: String,
+}
+
+/// Lays out the standard ACPI table header field by field.
+///
+/// # Arguments
+/// * `data` - The raw binary data of the table.
+///
+/// # Returns
+/// The nine header fields in offset order, or `None` if the data is too short or is an
+/// RSDP or FACS, which do not start with the standard header.
+pub fn acpi_header_layout(data: &[u8]) -> Option<Vec<HeaderFieldLayout>> {
+    if data.starts_with(b"RSD PTR ") || data.starts_with(b"FACS") {
+        return None;
+    }
+    let header = parsers::parse_acpi_header(data).ok()?;
+    let checksum = if parsers::acpi_truncation(data).is_some() {
+        format!("0x{:02X} (not checked, table truncated)", header._checksum)
+    } else if data.iter().fold(0u8, |sum, &b| sum.wrapping_add(b)) == 0 {
+        format!("0x{:02X} (valid)", header._checksum)
+    } else {
+        format!("0x{:02X} (invalid)", header._checksum)
+    };
+    let fields = [
+        ("Signature", 0x00, 4, header.signature),
+        ("Length", 0x04, 4, format!("{} bytes", header.length)),
+        ("Revision", 0x08, 1, header._revision.to_string()),
+        ("Checksum", 0x09, 1, checksum),
+        ("OEM ID", 0x0A, 6, header.oem_id),
+        ("OEM Table ID", 0x10, 8, header.oem_table_id),
+        (
+            "OEM Revision",
+            0x18,
+            4,
+            parsers::oem_revision_str(header._oem_revision),
+        ),
+        ("Creator ID", 0x1C, 4, header._creator_id),
+        (
+            "Creator Revision",
+            0x20,
+            4,
+            format!("0x{:08X}", header._creator_revision),
+        ),
+    ];
+    Some(
+        fields
+            .into_iter()
+            .map(|(name, offset, size, value)| HeaderFieldLayout {
+                name,
+                offset,
+                size,
+                raw: data[offset..offset + size]
+                    .iter()
+                    .map(|b| format!("{:02X}", b))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                value,
+            })
+            .collect(),
+    )
+}
+
+/// Formats key/value pairs as a Markdown `| Field | Value |` table.
+pub fn markdown_table(fields: &[(String, String)]) -> String {
+    let escape = |s: &str| s.replace('|', "\\|").replace('\n', "<br>");
+    let mut out = String::from("| Field | Value |\n| --- | --- |\n");
+    for (k, v) in fields {
+        out.push_str(&format!("| {} | {} |\n", escape(k), escape(v)));
+    }
+    out
+}
+
+/// Byte distribution of a raw buffer, used to spot padding and packed regions.
+pub struct ByteStats {
+    /// Total number of bytes.
+    pub length: usize,
+    /// Number of 0x00 bytes.
+    pub zero_count: usize,
+    /// Number of printable ASCII bytes (0x20..=0x7E).
+    pub printable_count: usize,
+    /// The most frequent byte value and how often it occurs, `None` for empty input.
+    pub most_common: Option<(u8, usize)>,
+    /// Byte counts per 16-value bucket (0x00-0x0F, 0x10-0x1F, ...).
+    pub histogram: [usize; 16],
+}
+
+/// Computes [`ByteStats`] for `data`.
+pub fn byte_stats(data: &[u8]) -> ByteStats {
+    let mut counts = [0usize; 256];
+    for &b in data {
+        counts[b as usize] += 1;
+    }
+    let mut histogram = [0usize; 16];
+    for (value, count) in counts.iter().enumerate() {
+        histogram[value / 16] += count;
+    }
+    let most_common = counts
+        .iter()
+        .enumerate()
+        .filter(|(_, &count)| count > 0)
+        .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(&a.0)))
+        .map(|(value, &count)| (value as u8, count));
+
+    ByteStats {
+        length: data.len(),
+        zero_count: counts[0],
+        printable_count: counts[0x20..=0x7E].iter().sum(),
+        most_common,
+        histogram,
+    }
+}
+
+/// Generates a standardized hex dump string from a byte slice.
+///
+/// Each line includes the offset, 16 hex bytes, and the corresponding ASCII representation.
+///
+/// # Arguments
+/// * `data` - The bytes to dump.
+/// * `base_offset` - Value added to the printed offsets, e.g. a structure's position in the
+///   SMBIOS blob; 0 numbers the dump from the start of `data`.
+pub fn hex_dump_str(data: &[u8], base_offset: usize) -> String {
+    let mut out = String::new();
+    for row in 0..data.len().div_ceil(16) {
+        out.push_str(&hex_dump_line(data, row, base_offset));
+        out.push('\n');
+    }
+    out
+}
+
+/// Formats one line of [`hex_dump_str`] without its trailing newline.
+///
+/// # Arguments
+/// * `data` - The full byte slice being dumped.
+/// * `row` - The zero-based line number; line `n` covers bytes `n * 16` to `n * 16 + 15`.
+/// * `base_offset` - Value added to the printed offset.
+///
+/// # Returns
+/// The offset, hex, and ASCII columns of the line, or an empty string past the end.
+pub fn hex_dump_line(data: &[u8], row: usize, base_offset: usize) -> String {
+    let offset = row * 16;
+    let chunk = match data.get(offset..data.len().min(offset + 16)) {
+        Some(chunk) if !chunk.is_empty() => chunk,
+        _ => return String::new(),
+    };
+    let hex_part: Vec<String> = chunk.iter().map(|b| format!("{:02X}", b)).collect();
+    let ascii_part: String = chunk
+        .iter()
+        .map(|&b| {
+            if (32..127).contains(&b) {
+                b as char
+            } else {
+                '.'
+            }
+        })
+        .collect();
+    format!(
+        "{:04X}  {:<48}  {}",
+        base_offset + offset,
+        hex_part.join(" "),
+        ascii_part
+    )
+}
+
+/// Formats the hex dump as the hex view shows it with **Collapse runs** on.
+///
+/// # Arguments
+/// * `data` - The bytes to dump.
+/// * `base_offset` - Value added to the printed offsets.
+/// * `runs` - The runs from [`hex_runs`]; each becomes one marker line.
+pub fn hex_dump_str_collapsed(data: &[u8], base_offset: usize, runs: &[HexRun]) -> String {
+    let rows = data.len().div_ceil(16);
+    let mut out = String::new();
+    for view_row in 0..collapsed_row_count(rows, runs) {
+        match collapsed_row_at(runs, view_row) {
+            HexViewRow::Line(row) => out.push_str(&hex_dump_line(data, row, base_offset)),
+            HexViewRow::Run(index) => out.push_str(&runs[index].describe(base_offset)),
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Names an ASCII control character.
+///
+/// # Returns
+/// The abbreviation and name, e.g. `("LF", "Line Feed")` for 0x0A, or `None` for bytes
+/// that are not control characters.
+pub fn control_char_name(byte: u8) -> Option<(&'static str, &'static str)> {
+    const NAMES: [(&str, &str); 32] = [
+        ("NUL", "Null"),
+        ("SOH", "Start of Heading"),
+        ("STX", "Start of Text"),
+        ("ETX", "End of Text"),
+        ("EOT", "End of Transmission"),
+        ("ENQ", "Enquiry"),
+        ("ACK", "Acknowledge"),
+        ("BEL", "Bell"),
+        ("BS", "Backspace"),
+        ("HT", "Horizontal Tab"),
+        ("LF", "Line Feed"),
+        ("VT", "Vertical Tab"),
+        ("FF", "Form Feed"),
+        ("CR", "Carriage Return"),
+        ("SO", "Shift Out"),
+        ("SI", "Shift In"),
+        ("DLE", "Data Link Escape"),
+        ("DC1", "Device Control 1"),
+        ("DC2", "Device Control 2"),
+        ("DC3", "Device Control 3"),
+        ("DC4", "Device Control 4"),
+        ("NAK", "Negative Acknowledge"),
+        ("SYN", "Synchronous Idle"),
+        ("ETB", "End of Transmission Block"),
+        ("CAN", "Cancel"),
+        ("EM", "End of Medium"),
+        ("SUB", "Substitute"),
+        ("ESC", "Escape"),
+        ("FS", "File Separator"),
+        ("GS", "Group Separator"),
+        ("RS", "Record Separator"),
+        ("US", "Unit Separator"),
+    ];
+    match byte {
+        0x00..=0x1F => Some(NAMES[byte as usize]),
+        0x7F => Some(("DEL", "Delete")),
+        _ => None,
+    }
+}
+
+/// Describes one byte of the hex dump for the ASCII column's hover text.
+///
+/// # Returns
+/// The hex and decimal value with the character or control character name, e.g.
+/// `0x0A (10) LF - Line Feed`, `0x41 (65) 'A'`, or `0xFF (255) not ASCII`.
+pub fn hex_byte_description(byte: u8) -> String {
+    let what = match control_char_name(byte) {
+        Some((short, name)) => format!("{} - {}", short, name),
+        None if byte.is_ascii() => format!("'{}'", byte as char),
+        None => "not ASCII".to_string(),
+    };
+    format!("0x{:02X} ({}) {}", byte, byte, what)
+}
+
+/// Computes where each line of [`hex_dump_str`] starts without formatting the dump.
+///
+/// # Arguments
+/// * `len` - The number of bytes being dumped.
+/// * `base_offset` - Value added to the printed offsets, which widens the offset column.
+///
+/// # Returns
+/// The character offset of every line, followed by the total length of the dump, so line
+/// `n` spans `starts[n]..starts[n + 1]` including its newline.
+pub fn hex_dump_line_starts(len: usize, base_offset: usize) -> Vec<usize> {
+    let mut starts = Vec::with_capacity(len.div_ceil(16) + 1);
+    let mut pos = 0;
+    starts.push(pos);
+    for offset in (0..len).step_by(16) {
+        let offset_width = format!("{:04X}", base_offset + offset).len();
+        pos += offset_width + 2 + 48 + 2 + (len - offset).min(16) + 1;
+        starts.push(pos);
+    }
+    starts
+}
+
+/// Shortest run of one repeated byte the hex view collapses.
+pub const HEX_RUN_MIN: usize = 16;
+
+/// Whole hex dump lines that hold nothing but one repeated byte, e.g. zero padding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexRun {
+    /// Index of the first line of the run (16 bytes per line).
+    pub first_row: usize,
+    /// Number of lines in the run.
+    pub rows: usize,
+    /// The repeated byte.
+    pub byte: u8,
+}
+
+impl HexRun {
+    /// Describes the collapsed lines, e.g. "… 0x40 bytes of 0x00 (0x0120-0x015F) …".
+    ///
+    /// # Arguments
+    /// * `base_offset` - Value added to the printed offsets, as in [`hex_dump_line`].
+    pub fn describe(&self, base_offset: usize) -> String {
+        let start = base_offset + self.first_row * 16;
+        let len = self.rows * 16;
+        format!(
+            "… 0x{:X} bytes of 0x{:02X} (0x{:04X}-0x{:04X}) …",
+            len,
+            self.byte,
+            start,
+            start + len - 1
+        )
+    }
+}
+
+/// Finds the hex dump lines that can be collapsed into one line each.
+///
+/// A run is at least [`HEX_RUN_MIN`] identical bytes, but only the whole lines inside it are
+/// collapsed so every line still shown keeps its exact offset. Runs covering fewer than two
+/// whole lines are left alone, as their marker would be no shorter.
+///
+/// # Arguments
+/// * `data` - The bytes being dumped.
+///
+/// # Returns
+/// The runs in line order.
+pub fn hex_runs(data: &[u8]) -> Vec<HexRun> {
+    let mut runs: Vec<HexRun> = Vec::new();
+    for (row, line) in data.chunks_exact(16).enumerate() {
+        let byte = line[0];
+        if line.iter().any(|&b| b != byte) {
+            continue;
+        }
+        match runs.last_mut() {
+            Some(run) if run.byte == byte && run.first_row + run.rows == row => run.rows += 1,
+            _ => runs.push(HexRun {
+                first_row: row,
+                rows: 1,
+                byte,
+            }),
+        }
+    }
+    runs.retain(|run| run.rows * 16 >= HEX_RUN_MIN && run.rows >= 2);
+    runs
+}
+
+/// A line of the hex view once runs are collapsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HexViewRow {
+    /// An ordinary hex dump line (index into the full dump).
+    Line(usize),
+    /// The marker standing in for a collapsed run (index into the runs).
+    Run(usize),
+}
+
+/// Returns the number of lines the hex view shows once `runs` are collapsed.
+pub fn collapsed_row_count(rows: usize, runs: &[HexRun]) -> usize {
+    rows - runs.iter().map(|run| run.rows - 1).sum::<usize>()
+}
+
+/// Maps a line of the collapsed hex view to what it shows.
+///
+/// # Arguments
+/// * `runs` - The collapsed runs, from [`hex_runs`].
+/// * `view_row` - The line index in the collapsed view.
+pub fn collapsed_row_at(runs: &[HexRun], view_row: usize) -> HexViewRow {
+    let mut hidden = 0;
+    for (index, run) in runs.iter().enumerate() {
+        let marker = run.first_row - hidden;
+        if view_row < marker {
+            break;
+        }
+        if view_row == marker {
+            return HexViewRow::Run(index);
+        }
+        hidden += run.rows - 1;
+    }
+    HexViewRow::Line(view_row + hidden)
+}
+
+/// Maps a line of the full hex dump to the collapsed view line that shows it.
+///
+/// Lines inside a collapsed run map to the run's marker.
+pub fn collapsed_row_of(runs: &[HexRun], row: usize) -> usize {
+    let mut hidden = 0;
+    for run in runs {
+        if row < run.first_row {
+            break;
+        }
+        if row < run.first_row + run.rows {
+            return run.first_row - hidden;
+        }
+        hidden += run.rows - 1;
+    }
+    row - hidden
+}
+
+/// Retrieves parsed key/value pairs for a single SMBIOS structure.
+///
+/// Falls back to the raw string pool, and finally to the handle and size, for structure
+/// types without a dedicated parser.
+///
+/// # Arguments
+/// * `structure` - The bytes of one structure (formatted area plus string pool).
+/// * `encoding` - How the string pool is decoded.
+pub fn smbios_fields(
+    structure: &[u8],
+    encoding: parsers::StringEncoding,
+) -> Option<Vec<(String, String)>> {
+    smbios_fields_with_version(structure, encoding, None)
+}
+
+/// Like [`smbios_fields`], decoding the version-sensitive fields by the rules of `version`.
+///
+/// # Arguments
+/// * `structure` - The bytes of one structure (formatted area plus string pool).
+/// * `encoding` - How the string pool is decoded.
+/// * `version` - The SMBIOS version whose rules apply, if known.
+pub fn smbios_fields_with_version(
+    structure: &[u8],
+    encoding: parsers::StringEncoding,
+    version: Option<parsers::SmbiosVersion>,
+) -> Option<Vec<(String, String)>> {
+    let (header, next_off) = parsers::parse_smbios_structure(structure, 0).ok()?;
+    let strings = parsers::get_smbios_strings(structure, 0, header.length, encoding);
+    if let Some(details) = parsers::parse_smbios_details(
+        header.type_id,
+        structure,
+        0,
+        header.length,
+        &strings,
+        version,
+    ) {
+        return Some(details);
+    }
+    if !strings.is_empty() {
+        let mut out = Vec::new();
+        for (i, s) in strings.iter().enumerate() {
+            out.push((format!("String {}", i + 1), s.clone()));
+        }
+        return Some(out);
+    }
+    // fallback to show raw slice length
+    Some(vec![
+        ("Handle".to_string(), format!("0x{:04X}", header.handle)),
+        ("Length".to_string(), format!("{} bytes", next_off)),
+    ])
+}
+
+/// One parsed field of one SMBIOS structure: a row of the flat SMBIOS field table.
+#[derive(Clone)]
+pub struct SmbiosFieldRow {
+    /// Offset of the owning structure in the blob.
+    pub offset: usize,
+    /// Type of the owning structure.
+    pub type_id: u8,
+    /// Handle of the owning structure.
+    pub handle: u16,
+    /// Field name, e.g. `Serial Number`.
+    pub field: String,
+    /// Field value as shown in the parsed view.
+    pub value: String,
+}
+
+impl SmbiosFieldRow {
+    /// Returns the type column text, e.g. `17 Memory Device`.
+    pub fn type_label(&self) -> String {
+        match parsers::smbios_type_name(self.type_id) {
+            "" => self.type_id.to_string(),
+            name => format!("{} {}", self.type_id, name),
+        }
+    }
+
+    /// Returns whether any column contains `needle`, ignoring case.
+    ///
+    /// # Arguments
+    /// * `needle` - The filter text, already lowercased.
+    pub fn matches(&self, needle: &str) -> bool {
+        needle.is_empty()
+            || self.type_label().to_lowercase().contains(needle)
+            || format!("0x{:04x}", self.handle).contains(needle)
+            || self.field.to_lowercase().contains(needle)
+            || self.value.to_lowercase().contains(needle)
+    }
+}
+
+/// Flattens the parsed fields of every structure in a raw SMBIOS blob, in table order.
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS blob.
+/// * `encoding` - How the string pools are decoded.
+/// * `version` - The SMBIOS version whose rules apply, if known.
+pub fn smbios_field_rows(
+    data: &[u8],
+    encoding: parsers::StringEncoding,
+    version: Option<parsers::SmbiosVersion>,
+) -> Vec<SmbiosFieldRow> {
+    let mut rows = Vec::new();
+    for (offset, header, next_off) in parsers::walk_smbios_structures(data) {
+        let fields = smbios_fields_with_version(&data[offset..next_off], encoding, version);
+        for (field, value) in fields.unwrap_or_default() {
+            rows.push(SmbiosFieldRow {
+                offset,
+                type_id: header.type_id,
+                handle: header.handle,
+                field,
+                value,
+            });
+        }
+    }
+    rows
+}
+
+/// Formats SMBIOS field table rows as CSV with a `type,type_name,handle,field,value` header.
+pub fn smbios_field_rows_csv(rows: &[&SmbiosFieldRow]) -> String {
+    let mut out = String::from("type,type_name,handle,field,value\n");
+    for row in rows {
+        out.push_str(&format!(
+            "{},{},0x{:04X},{},{}\n",
+            row.type_id,
+            csv_field(parsers::smbios_type_name(row.type_id)),
+            row.handle,
+            csv_field(&row.field),
+            csv_field(&row.value)
+        ));
+    }
+    out
+}
+
+/// Parses a single SMBIOS structure with another type's parser, ignoring its type byte.
+///
+/// A debugging aid for OEM structures: the first row names the parser that was forced so
+/// the output cannot be mistaken for a real decode.
+///
+/// # Arguments
+/// * `structure` - The bytes of one structure (formatted area plus string pool).
+/// * `encoding` - How the string pool is decoded.
+/// * `type_id` - The type whose parser is applied to the bytes.
+/// * `version` - The SMBIOS version whose rules apply, if known.
+pub fn smbios_fields_as(
+    structure: &[u8],
+    encoding: parsers::StringEncoding,
+    type_id: u8,
+    version: Option<parsers::SmbiosVersion>,
+) -> Option<Vec<(String, String)>> {
+    let (header, _) = parsers::parse_smbios_structure(structure, 0).ok()?;
+    let strings = parsers::get_smbios_strings(structure, 0, header.length, encoding);
+    let mut fields = vec![(
+        "Interpreted As".to_string(),
+        format!(
+            "Type {} parser on Type {} data (forced)",
+            type_id, header.type_id
+        ),
+    )];
+    match parsers::parse_smbios_details(type_id, structure, 0, header.length, &strings, version) {
+        Some(details) => fields.extend(details),
+        None => fields.push((
+            "Note".to_string(),
+            format!("No parser for Type {}", type_id),
+        )),
+    }
+    Some(fields)
+}
+
+/// Parses one structure with [`smbios_fields`] for an export, redacting it when asked.
+///
+/// # Arguments
+/// * `structure` - The bytes of one structure (formatted area plus string pool).
+/// * `encoding` - How the string pool is decoded.
+/// * `redact` - Replace the identifying fields with [`REDACTED`].
+fn export_smbios_fields(
+    structure: &[u8],
+    encoding: parsers::StringEncoding,
+    redact: bool,
+) -> Vec<(String, String)> {
+    let mut fields = smbios_fields(structure, encoding).unwrap_or_default();
+    if redact {
+        if let Some(&type_id) = structure.first() {
+            redact_smbios_fields(type_id, &mut fields);
+        }
+    }
+    fields
+}
+
+/// Cites the SMBIOS version that introduced a structure type, e.g. "SMBIOS 3.1+".
+///
+/// When `version` is older than the type, the citation says so, since firmware that
+/// declares an older version may lay the structure out its own way.
+///
+/// # Arguments
+/// * `type_id` - The structure type.
+/// * `version` - The SMBIOS version the structure is read as, if known.
+///
+/// # Returns
+/// The citation, or `None` for OEM and unassigned types.
+pub fn smbios_spec_reference(
+    type_id: u8,
+    version: Option<parsers::SmbiosVersion>,
+) -> Option<String> {
+    let introduced = parsers::smbios_type_introduced(type_id)?;
+    let mut text = format!("SMBIOS {}+", introduced);
+    if let Some(obsolete) = parsers::smbios_type_obsoleted(type_id) {
+        text.push_str(&format!(", obsolete since {}", obsolete));
+    }
+    if let Some(version) = version.filter(|v| *v < introduced) {
+        text.push_str(&format!(
+            " (newer than the {} this table is read as)",
+            version
+        ));
+    }
+    Some(text)
+}
+
+/// Builds the human-readable parsed view of a single SMBIOS structure.
+///
+/// # Arguments
+/// * `structure` - The bytes of one structure (formatted area plus string pool).
+/// * `table` - The full SMBIOS blob, used to resolve handle references (may be empty).
+/// * `encoding` - How the string pool is decoded.
+/// * `version` - The SMBIOS version whose rules apply, if known.
+pub fn smbios_structure_text(
+    structure: &[u8],
+    table: &[u8],
+    encoding: parsers::StringEncoding,
+    version: Option<parsers::SmbiosVersion>,
+) -> String {
+    let mut out = String::new();
+    if let Ok((header, _)) = parsers::parse_smbios_structure(structure, 0) {
+        let strings = parsers::get_smbios_strings(structure, 0, header.length, encoding);
+
+        out.push_str(&format!(
+            "Type {} (Handle 0x{:04X})\n",
+            header.type_id, header.handle
+        ));
+        if let Some(size) = parsers::smbios_structure_size(structure, 0) {
+            out.push_str(&format!(
+                "Formatted: {} bytes, Total: {} bytes\n",
+                size.formatted, size.total
+            ));
+            if size.clamped {
+                out.push_str(
+                    "Warning: the string area is not terminated; the structure was cut off at the end of the table\n",
+                );
+            }
+        }
+        if let Some(reference) = smbios_spec_reference(header.type_id, version) {
+            out.push_str(&format!("Spec: {}\n", reference));
+        }
+        out.push_str("====================\n");
+
+        if let Some(mut details) = parsers::parse_smbios_details(
+            header.type_id,
+            structure,
+            0,
+            header.length,
+            &strings,
+            version,
+        ) {
+            resolve_smbios_handles(table, &mut details, encoding);
+            for (k, v) in details {
+                out.push_str(&format!("{:25}: {}\n", k, v));
+            }
+        } else if !strings.is_empty() {
+            out.push_str("Strings:\n");
+            for (i, s) in strings.iter().enumerate() {
+                out.push_str(&format!("  {}: {}\n", i + 1, s));
+            }
+        } else {
+            out.push_str("No strings.\n");
+        }
+    }
+    out
+}
+
+/// Describes the structure with the given handle, e.g. "Type 17 - Memory Device, DIMM_A1".
+///
+/// # Arguments
+/// * `table` - The full SMBIOS blob.
+/// * `handle` - The structure handle to look up.
+/// * `encoding` - How the string pool is decoded.
+///
+/// # Returns
+/// The label, or `None` if no structure carries that handle.
+pub fn smbios_handle_label(
+    table: &[u8],
+    handle: u16,
+    encoding: parsers::StringEncoding,
+) -> Option<String> {
+    let (off, header, _) = parsers::walk_smbios_structures(table)
+        .into_iter()
+        .find(|(_, header, _)| header.handle == handle)?;
+
+    let mut label = match parsers::smbios_type_name(header.type_id) {
+        "" => format!("Type {}", header.type_id),
+        name => format!("Type {} - {}", header.type_id, name),
+    };
+    // Memory devices are far easier to recognise by their slot name than by their handle.
+    if header.type_id == 17 && header.length > 0x10 {
+        let strings = parsers::get_smbios_strings(table, off, header.length, encoding);
+        let index = table.get(off + 0x10).copied().unwrap_or(0) as usize;
+        if let Some(locator) = index.checked_sub(1).and_then(|i| strings.get(i)) {
+            label.push_str(&format!(", {}", locator));
+        }
+    }
+    if header.type_id == 7 {
+        if let Some(name) = parsers::cache_display_name(table, off) {
+            label.push_str(&format!(", {}", name));
+        }
+    }
+    Some(label)
+}
+
+/// Appends the target structure to every "... Handle" field whose value is a bare handle.
+///
+/// References to Type 7 cache structures show the cache instead, e.g.
+/// "0x0007 -> 1024 KB Unified".
+///
+/// # Arguments
+/// * `table` - The full SMBIOS blob.
+/// * `fields` - Parsed key/value pairs of one structure, updated in place.
+/// * `encoding` - How the string pool is decoded.
+pub fn resolve_smbios_handles(
+    table: &[u8],
+    fields: &mut [(String, String)],
+    encoding: parsers::StringEncoding,
+) {
+    for (key, value) in fields.iter_mut() {
+        if !key.ends_with("Handle") {
+            continue;
+        }
+        let Some(hex) = value.strip_prefix("0x") else {
+            continue;
+        };
+        let Ok(handle) = u16::from_str_radix(hex, 16) else {
+            continue;
+        };
+        if let Some(summary) = smbios_cache_summary(table, handle) {
+            value.push_str(&format!(" -> {}", summary));
+        } else if let Some(label) = smbios_handle_label(table, handle, encoding) {
+            value.push_str(&format!(" ({})", label));
+        }
+    }
+}
+
+/// Summarizes the Type 7 cache structure with the given handle, e.g. "1024 KB Unified".
+///
+/// # Returns
+/// The summary, or `None` if no cache structure carries that handle.
+fn smbios_cache_summary(table: &[u8], handle: u16) -> Option<String> {
+    let (off, _, _) = parsers::walk_smbios_structures(table)
+        .into_iter()
+        .find(|(_, header, _)| header.handle == handle && header.type_id == 7)?;
+    parsers::cache_size_summary(table, off)
+}
+
+/// Converts parsed key/value pairs into a JSON object.
+pub fn fields_json(fields: Vec<(String, String)>) -> serde_json::Value {
+    let map: BTreeMap<_, _> = fields.into_iter().collect();
+    json!(map)
+}
+
+/// Serializes every structure of a raw SMBIOS blob as JSON.
+///
+/// With `redact` the identifying fields read [`REDACTED`]. Structures with an entry in
+/// `notes` get a `"notes"` field.
+pub fn smbios_json(
+    data: &[u8],
+    encoding: parsers::StringEncoding,
+    redact: bool,
+    notes: &BTreeMap<String, String>,
+) -> serde_json::Value {
+    let version = parsers::parse_raw_smbios_data_header(data).map(|(h, _)| {
+        json!({
+            "major": h._major_version,
+            "minor": h._minor_version,
+            "dmi_revision": h._dmi_revision,
+        })
+    });
+
+    let structures: Vec<serde_json::Value> = parsers::walk_smbios_structures(data)
+        .into_iter()
+        .map(|(off, header, next_off)| {
+            let fields = export_smbios_fields(&data[off..next_off], encoding, redact);
+            let mut structure = json!({
+                "type": header.type_id,
+                "handle": format!("0x{:04X}", header.handle),
+                "name": parsers::smbios_type_name(header.type_id),
+                "fields": fields_json(fields),
+            });
+            if let Some(note) = notes.get(&smbios_note_key(header.type_id, header.handle)) {
+                structure["notes"] = json!(note);
+            }
+            structure
+        })
+        .collect();
+
+    json!({
+        "version": version,
+        "structures": structures,
+    })
+}
+
+/// Escapes text for XML element content or a quoted attribute.
+///
+/// Control characters that XML 1.0 does not allow are replaced with U+FFFD.
+pub fn xml_escape(text: &str) -> String {
+    let cleaned: String = text
+        .chars()
+        .map(|c| {
+            if c.is_control() && !matches!(c, '\t' | '\n' | '\r') {
+                '\u{FFFD}'
+            } else {
+                c
+            }
+        })
+        .collect();
+    html_escape(&cleaned)
+}
+
+/// Serializes the SMBIOS structures as XML.
+///
+/// The layout is stable for consumers: a `<SMBIOS major minor dmiRevision>` root holding one
+/// `<Structure type handle name>` per structure, each holding `<Field name>value</Field>`
+/// elements in parse order.
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS blob.
+/// * `encoding` - How the string pools are decoded.
+/// * `types` - The structure types to include, or an empty slice for all of them.
+/// * `redact` - Replace the identifying fields with [`REDACTED`].
+pub fn smbios_xml(
+    data: &[u8],
+    encoding: parsers::StringEncoding,
+    types: &[u8],
+    redact: bool,
+) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    match parsers::parse_raw_smbios_data_header(data) {
+        Some((h, _)) => out.push_str(&format!(
+            "<SMBIOS major=\"{}\" minor=\"{}\" dmiRevision=\"{}\">\n",
+            h._major_version, h._minor_version, h._dmi_revision
+        )),
+        None => out.push_str("<SMBIOS>\n"),
+    }
+
+    for (off, header, next_off) in parsers::walk_smbios_structures(data) {
+        if !types.is_empty() && !types.contains(&header.type_id) {
+            continue;
+        }
+        out.push_str(&format!(
+            "  <Structure type=\"{}\" handle=\"0x{:04X}\" name=\"{}\">\n",
+            header.type_id,
+            header.handle,
+            xml_escape(parsers::smbios_type_name(header.type_id))
+        ));
+        for (k, v) in export_smbios_fields(&data[off..next_off], encoding, redact) {
+            out.push_str(&format!(
+                "    <Field name=\"{}\">{}</Field>\n",
+                xml_escape(&k),
+                xml_escape(&v)
+            ));
+        }
+        out.push_str("  </Structure>\n");
+    }
+    out.push_str("</SMBIOS>\n");
+    out
+}
+
+/// Inventory fields most consumers want, flattened out of the SMBIOS structures and ACPI tables.
+///
+/// String fields are `None` when the firmware does not provide the structure, and serialize
+/// as `null`.
+#[derive(serde::Serialize)]
+pub struct SystemInfo {
+    /// BIOS vendor (Type 0).
+    pub bios_vendor: Option<String>,
+    /// BIOS version string (Type 0).
+    pub bios_version: Option<String>,
+    /// BIOS release date (Type 0).
+    pub bios_release_date: Option<String>,
+    /// System manufacturer (Type 1).
+    pub system_manufacturer: Option<String>,
+    /// System product name (Type 1).
+    pub system_product: Option<String>,
+    /// System serial number (Type 1).
+    pub system_serial: Option<String>,
+    /// System UUID (Type 1).
+    pub system_uuid: Option<String>,
+    /// Baseboard manufacturer (Type 2).
+    pub baseboard_manufacturer: Option<String>,
+    /// Baseboard product name (Type 2).
+    pub baseboard_product: Option<String>,
+    /// Baseboard serial number (Type 2).
+    pub baseboard_serial: Option<String>,
+    /// Chassis type name, e.g. `Notebook` (Type 3).
+    pub chassis_type: Option<String>,
+    /// Processor model of the first populated socket (Type 4 Version).
+    pub processor: Option<String>,
+    /// Cores summed over populated sockets, or 0 if no processor reports a count.
+    pub cores: u32,
+    /// Threads summed over populated sockets, or 0 if no processor reports a count.
+    pub threads: u32,
+    /// Installed memory in bytes, summed over the memory devices (Type 17).
+    pub memory_bytes: u64,
+    /// TPM specification version, e.g. `2.0`, from Type 43 or the TPM2/TCPA table.
+    pub tpm_version: Option<String>,
+}
+
+impl SystemInfo {
+    /// Replaces the serial numbers and the UUID with [`REDACTED`], keeping absent fields absent.
+    pub fn redact(&mut self) {
+        for field in [
+            &mut self.system_serial,
+            &mut self.system_uuid,
+            &mut self.baseboard_serial,
+        ] {
+            if field.is_some() {
+                *field = Some(REDACTED.to_string());
+            }
+        }
+    }
+}
+
+/// Flattens the common inventory fields out of an SMBIOS blob and the ACPI table list.
+///
+/// # Arguments
+/// * `tables` - All discovered ACPI tables, used for the TPM version when SMBIOS lacks Type 43.
+/// * `smbios` - The raw SMBIOS blob.
+/// * `encoding` - How the string pools are decoded.
+pub fn system_info(
+    tables: &[api::AcpiTableInfo],
+    smbios: &[u8],
+    encoding: parsers::StringEncoding,
+) -> SystemInfo {
+    let field = |type_id, key| {
+        first_smbios_field(smbios, type_id, key, encoding)
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+    let structures = parsers::walk_smbios_structures(smbios);
+
+    let packages: Vec<CpuPackage> = cpu_packages(smbios, encoding)
+        .into_iter()
+        .filter(|p| p.populated)
+        .collect();
+    let processor = packages
+        .iter()
+        .map(|p| p.version.clone())
+        .find(|v| !v.is_empty());
+    let (cores, threads) = packages
+        .iter()
+        .filter_map(|p| p.counts)
+        .fold((0u32, 0u32), |(c, t), (pc, pt)| {
+            (c + pc as u32, t + pt as u32)
+        });
+
+    let chassis_type = structures
+        .iter()
+        .find(|(off, h, _)| h.type_id == 3 && h.length > 0x05 && off + 0x05 < smbios.len())
+        .map(|(off, _, _)| parsers::chassis_type_str(smbios[off + 0x05]));
+
+    // Type 43 carries the spec version at 0x08 (major) and 0x09 (minor)
+    let tpm_version = structures
+        .iter()
+        .find(|(off, h, _)| h.type_id == 43 && h.length >= 0x0A && off + 0x0A <= smbios.len())
+        .map(|(off, _, _)| format!("{}.{}", smbios[off + 0x08], smbios[off + 0x09]))
+        .or_else(|| {
+            if tables.iter().any(|t| t.signature == "TPM2") {
+                Some("2.0".to_string())
+            } else if tables.iter().any(|t| t.signature == "TCPA") {
+                Some("1.2".to_string())
+            } else {
+                None
+            }
+        });
+
+    SystemInfo {
+        bios_vendor: field(0, "Vendor"),
+        bios_version: field(0, "Version"),
+        bios_release_date: field(0, "Release Date"),
+        system_manufacturer: field(1, "Manufacturer"),
+        system_product: field(1, "Product Name"),
+        system_serial: field(1, "Serial Number"),
+        system_uuid: field(1, "UUID"),
+        baseboard_manufacturer: field(2, "Manufacturer"),
+        baseboard_product: field(2, "Product Name"),
+        baseboard_serial: field(2, "Serial Number"),
+        chassis_type,
+        processor,
+        cores,
+        threads,
+        memory_bytes: memory_slots(smbios, encoding)
+            .iter()
+            .filter_map(|s| s.size)
+            .sum(),
+        tpm_version,
+    }
+}
+
+/// Reads the firmware of this machine and flattens it into a [`SystemInfo`] record.
+///
+/// ACPI tables come from both discovery sources and SMBIOS strings are decoded with the
+/// default encoding; an unreadable SMBIOS table leaves the SMBIOS fields empty.
+pub fn collect_system_info() -> SystemInfo {
+    let tables = api::load_acpi_tables(api::AcpiSource::default());
+    let smbios = api::get_smbios_data().unwrap_or_default();
+    system_info(&tables, &smbios, parsers::StringEncoding::default())
+}
+
+/// Serializes the ACPI table list and the SMBIOS structures into a single JSON document.
+///
+/// With `redact` the identifying SMBIOS fields and inventory values read [`REDACTED`].
+/// Tables and structures with an entry in `notes` get a `"notes"` field.
+pub fn report_json(
+    tables: &[api::AcpiTableInfo],
+    smbios: &[u8],
+    encoding: parsers::StringEncoding,
+    redact: bool,
+    notes: &BTreeMap<String, String>,
+) -> serde_json::Value {
+    let acpi: Vec<serde_json::Value> = tables
+        .iter()
+        .map(|t| {
+            let mut entry = json!({
+                "signature": t.signature,
+                "oem_id": t.oem_id,
+                "table_id": t.table_id,
+                "revision": t.revision,
+                "registry_path": t.registry_path,
+            });
+            if let Some(note) = notes.get(&acpi_note_key(&t.signature)) {
+                entry["notes"] = json!(note);
+            }
+            entry
+        })
+        .collect();
+
+    let mut system = system_info(tables, smbios, encoding);
+    if redact {
+        system.redact();
+    }
+    json!({
+        "system": system,
+        "acpi": acpi,
+        "smbios": smbios_json(smbios, encoding, redact, notes),
+    })
+}
+
+/// Builds the document `--watch` compares between reads: [`report_json`] with each ACPI
+/// table's parsed fields added, so changed table contents show up as well as added or
+/// removed tables.
+///
+/// # Arguments
+/// * `tables` - The discovered ACPI tables.
+/// * `smbios` - The raw SMBIOS blob.
+/// * `encoding` - How SMBIOS strings are decoded.
+/// * `redact` - Redact identifying SMBIOS fields and the MSDM product key.
+/// * `read` - Reads a table's bytes; a table that cannot be read gets `null` fields.
+pub fn watch_snapshot(
+    tables: &[api::AcpiTableInfo],
+    smbios: &[u8],
+    encoding: parsers::StringEncoding,
+    redact: bool,
+    read: impl Fn(&api::AcpiTableInfo) -> Result<Vec<u8>, DumperError>,
+) -> serde_json::Value {
+    let mut snapshot = report_json(tables, smbios, encoding, redact, &BTreeMap::new());
+    if let Some(acpi) = snapshot["acpi"].as_array_mut() {
+        for (entry, t) in acpi.iter_mut().zip(tables) {
+            let fields = read(t).ok().and_then(|data| acpi_table_fields(&data));
+            entry["fields"] = match fields {
+                Some(mut fields) => {
+                    if redact {
+                        redact_acpi_fields(&t.signature, &mut fields);
+                    }
+                    fields_json(fields)
+                }
+                None => serde_json::Value::Null,
+            };
+        }
+    }
+    snapshot
+}
+
+/// How a value differs between a reference JSON report and the current one.
+pub enum JsonChange {
+    /// Present only in the current report.
+    Added(serde_json::Value),
+    /// Present only in the reference report.
+    Removed(serde_json::Value),
+    /// Present in both with different values (reference, current).
+    Changed(serde_json::Value, serde_json::Value),
+}
+
+/// One field-level difference found by [`diff_json`].
+pub struct JsonDifference {
+    /// Location of the value, e.g. `smbios.structures[handle=0x0000].fields.Version`.
+    pub path: String,
+    /// What changed at `path`.
+    pub change: JsonChange,
+}
+
+impl JsonDifference {
+    /// Formats the difference as one `+`, `-`, or `~` line.
+    pub fn describe(&self) -> String {
+        match &self.change {
+            JsonChange::Added(v) => format!("+ {}: {}", self.path, v),
+            JsonChange::Removed(v) => format!("- {}: {}", self.path, v),
+            JsonChange::Changed(old, new) => format!("~ {}: {} -> {}", self.path, old, new),
+        }
+    }
+}
+
+/// Compares two JSON reports field by field.
+///
+/// Object keys are matched by name. Array elements are matched by identity rather than
+/// position: SMBIOS structures by `handle` and ACPI tables by signature and table ID, so
+/// enumeration order does not produce differences.
+///
+/// # Arguments
+/// * `reference` - The golden report.
+/// * `current` - The report of the system being checked.
+///
+/// # Returns
+/// The differences, with paths in reference order followed by additions.
+pub fn diff_json(
+    reference: &serde_json::Value,
+    current: &serde_json::Value,
+) -> Vec<JsonDifference> {
+    let mut out = Vec::new();
+    diff_json_at(String::new(), reference, current, &mut out);
+    out
+}
+
+/// Joins a parent JSON path and a child segment (an object key or `[element]`).
+fn json_child_path(parent: &str, segment: &str) -> String {
+    if parent.is_empty() || segment.starts_with('[') {
+        format!("{}{}", parent, segment)
+    } else {
+        format!("{}.{}", parent, segment)
+    }
+}
+
+/// Labels the elements of a JSON array by identity, numbering repeated labels.
+///
+/// Elements with a `handle` are labelled by it, elements with a `signature` by signature
+/// and table ID, and anything else by its index.
+fn json_element_labels(items: &[serde_json::Value]) -> Vec<String> {
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let base = if let Some(handle) = item.get("handle").and_then(|v| v.as_str()) {
+                format!("handle={}", handle)
+            } else if let Some(sig) = item.get("signature").and_then(|v| v.as_str()) {
+                let id = item.get("table_id").and_then(|v| v.as_str()).unwrap_or("");
+                format!("{} {}", sig, id.trim()).trim_end().to_string()
+            } else {
+                return format!("[{}]", i);
+            };
+            let n = seen.entry(base.clone()).or_insert(0);
+            *n += 1;
+            if *n == 1 {
+                format!("[{}]", base)
+            } else {
+                format!("[{}#{}]", base, n)
+            }
+        })
+        .collect()
+}
+
+/// Recursively appends the differences between `reference` and `current` under `path`.
+fn diff_json_at(
+    path: String,
+    reference: &serde_json::Value,
+    current: &serde_json::Value,
+    out: &mut Vec<JsonDifference>,
+) {
+    use serde_json::Value;
+    match (reference, current) {
+        (Value::Object(a), Value::Object(b)) => {
+            for (key, av) in a {
+                let child = json_child_path(&path, key);
+                match b.get(key) {
+                    Some(bv) => diff_json_at(child, av, bv, out),
+                    None => out.push(JsonDifference {
+                        path: child,
+                        change: JsonChange::Removed(av.clone()),
+                    }),
+                }
+            }
+            for (key, bv) in b.iter().filter(|(k, _)| !a.contains_key(*k)) {
+                out.push(JsonDifference {
+                    path: json_child_path(&path, key),
+                    change: JsonChange::Added(bv.clone()),
+                });
+            }
+        }
+        (Value::Array(a), Value::Array(b)) => {
+            let b_labels = json_element_labels(b);
+            let b_by_label: HashMap<&str, &Value> =
+                b_labels.iter().map(String::as_str).zip(b).collect();
+            let a_labels = json_element_labels(a);
+            for (label, av) in a_labels.iter().zip(a) {
+                let child = json_child_path(&path, label);
+                match b_by_label.get(label.as_str()) {
+                    Some(bv) => diff_json_at(child, av, bv, out),
+                    None => out.push(JsonDifference {
+                        path: child,
+                        change: JsonChange::Removed(av.clone()),
+                    }),
+                }
+            }
+            for (label, bv) in b_labels.iter().zip(b) {
+                if !a_labels.contains(label) {
+                    out.push(JsonDifference {
+                        path: json_child_path(&path, label),
+                        change: JsonChange::Added(bv.clone()),
+                    });
+                }
+            }
+        }
+        (a, b) if a != b => out.push(JsonDifference {
+            path,
+            change: JsonChange::Changed(a.clone(), b.clone()),
+        }),
+        _ => {}
+    }
+}
+
+/// How one SMBIOS structure differs between two blobs.
+pub enum SmbiosStructureChange {
+    /// Present only in blob A.
+    OnlyInA,
+    /// Present only in blob B.
+    OnlyInB,
+    /// Present in both with these field differences.
+    Changed(Vec<SmbiosFieldChange>),
+}
+
+/// One parsed field that differs between two matched SMBIOS structures.
+pub struct SmbiosFieldChange {
+    /// Field name; repeated names are numbered, e.g. `Item #2`.
+    pub field: String,
+    /// The value in blob A, or `None` if A lacks the field.
+    pub a: Option<String>,
+    /// The value in blob B, or `None` if B lacks the field.
+    pub b: Option<String>,
+}
+
+impl SmbiosFieldChange {
+    /// Formats the change as `Field: a -> b`, showing a missing side as `(absent)`.
+    pub fn describe(&self) -> String {
+        let side = |v: &Option<String>| v.clone().unwrap_or_else(|| "(absent)".to_string());
+        format!("{}: {} -> {}", self.field, side(&self.a), side(&self.b))
+    }
+}
+
+/// One SMBIOS structure found by [`diff_smbios`] to differ between two blobs.
+pub struct SmbiosStructureDiff {
+    /// Structure type.
+    pub type_id: u8,
+    /// Handle in blob A, if the structure is there.
+    pub handle_a: Option<u16>,
+    /// Handle in blob B, if the structure is there.
+    pub handle_b: Option<u16>,
+    /// Description from [`smbios_handle_label`], e.g. "Type 17 - Memory Device, DIMM_A1".
+    pub label: String,
+    /// What differs.
+    pub change: SmbiosStructureChange,
+}
+
+impl SmbiosStructureDiff {
+    /// Returns the handle to show, e.g. `0x0011` or `0x0011/0x0013` when it moved.
+    pub fn handle_str(&self) -> String {
+        match (self.handle_a, self.handle_b) {
+            (Some(a), Some(b)) if a != b => format!("0x{:04X}/0x{:04X}", a, b),
+            (Some(h), _) | (None, Some(h)) => format!("0x{:04X}", h),
+            (None, None) => String::new(),
+        }
+    }
+}
+
+/// Compares two SMBIOS blobs structure by structure and field by field.
+///
+/// Structures are matched by type and handle. Any left over are then paired in table order
+/// within their type, so two units of the same model still line up when the firmware
+/// numbers its handles differently or lists structures in another order. Each side is parsed
+/// by the rules of the SMBIOS version it declares. A matched pair whose parsed fields agree but
+/// whose formatted bytes differ (e.g. an OEM type without a parser) reports the bytes.
+///
+/// # Arguments
+/// * `a` - The first raw SMBIOS blob.
+/// * `b` - The second raw SMBIOS blob.
+/// * `encoding` - How the string pools are decoded.
+///
+/// # Returns
+/// The differing structures sorted by type, then by their position in A, then in B.
+pub fn diff_smbios(
+    a: &[u8],
+    b: &[u8],
+    encoding: parsers::StringEncoding,
+) -> Vec<SmbiosStructureDiff> {
+    let walk_a = parsers::walk_smbios_structures(a);
+    let walk_b = parsers::walk_smbios_structures(b);
+    let mut pairs: Vec<(Option<usize>, Option<usize>)> = Vec::new();
+    let mut used_b = vec![false; walk_b.len()];
+
+    // 1. Same type and handle
+    let mut unmatched_a = Vec::new();
+    for (ia, (_, ha, _)) in walk_a.iter().enumerate() {
+        let found = walk_b.iter().enumerate().position(|(ib, (_, hb, _))| {
+            !used_b[ib] && hb.type_id == ha.type_id && hb.handle == ha.handle
+        });
+        match found {
+            Some(ib) => {
+                used_b[ib] = true;
+                pairs.push((Some(ia), Some(ib)));
+            }
+            None => unmatched_a.push(ia),
+        }
+    }
+    // 2. The rest in table order within each type
+    for ia in unmatched_a {
+        let type_id = walk_a[ia].1.type_id;
+        let found = (0..walk_b.len()).find(|&ib| !used_b[ib] && walk_b[ib].1.type_id == type_id);
+        if let Some(ib) = found {
+            used_b[ib] = true;
+        }
+        pairs.push((Some(ia), found));
+    }
+    pairs.extend(
+        (0..walk_b.len())
+            .filter(|&ib| !used_b[ib])
+            .map(|ib| (None, Some(ib))),
+    );
+
+    let version =
+        |data: &[u8]| parsers::parse_raw_smbios_data_header(data).map(|(h, _)| h.version());
+    let (version_a, version_b) = (version(a), version(b));
+    let mut out = Vec::new();
+    for (ia, ib) in pairs {
+        let sa = ia.map(|i| &walk_a[i]);
+        let sb = ib.map(|i| &walk_b[i]);
+        let (type_id, label) = match (sa, sb) {
+            (Some((_, h, _)), _) => (h.type_id, smbios_handle_label(a, h.handle, encoding)),
+            (None, Some((_, h, _))) => (h.type_id, smbios_handle_label(b, h.handle, encoding)),
+            (None, None) => continue,
+        };
+        let change = match (sa, sb) {
+            (Some((oa, ha, na)), Some((ob, hb, nb))) => {
+                let fields_a = smbios_fields_with_version(&a[*oa..*na], encoding, version_a)
+                    .unwrap_or_default();
+                let fields_b = smbios_fields_with_version(&b[*ob..*nb], encoding, version_b)
+                    .unwrap_or_default();
+                let mut changes = diff_smbios_fields(&fields_a, &fields_b);
+                // The handle bytes are left out; a moved handle is shown by handle_str
+                let formatted = |data: &[u8], off: usize, len: u8| {
+                    let end = (off + len as usize).min(data.len());
+                    let mut bytes = data[off..end].to_vec();
+                    if let Some(handle) = bytes.get_mut(2..4) {
+                        handle.fill(0);
+                    }
+                    bytes
+                };
+                let bytes_a = formatted(a, *oa, ha.length);
+                let bytes_b = formatted(b, *ob, hb.length);
+                if changes.is_empty() && bytes_a != bytes_b {
+                    let hex = |bytes: &[u8]| {
+                        bytes
+                            .iter()
+                            .map(|x| format!("{:02X}", x))
+                            .collect::<Vec<_>>()
+                            .join(" ")
+                    };
+                    changes.push(SmbiosFieldChange {
+                        field: "Formatted Area".to_string(),
+                        a: Some(hex(&bytes_a)),
+                        b: Some(hex(&bytes_b)),
+                    });
+                }
+                if changes.is_empty() {
+                    continue;
+                }
+                SmbiosStructureChange::Changed(changes)
+            }
+            (Some(_), None) => SmbiosStructureChange::OnlyInA,
+            _ => SmbiosStructureChange::OnlyInB,
+        };
+        out.push(SmbiosStructureDiff {
+            type_id,
+            handle_a: sa.map(|(_, h, _)| h.handle),
+            handle_b: sb.map(|(_, h, _)| h.handle),
+            label: label.unwrap_or_else(|| format!("Type {}", type_id)),
+            change,
+        });
+    }
+    // Stable, so structures keep their A-then-B order within a type
+    out.sort_by_key(|d| d.type_id);
+    out
+}
+
+/// Compares two parsed field lists by name, numbering repeated names in order.
+fn diff_smbios_fields(a: &[(String, String)], b: &[(String, String)]) -> Vec<SmbiosFieldChange> {
+    let numbered = |fields: &[(String, String)]| {
+        let mut seen: HashMap<&str, usize> = HashMap::new();
+        fields
+            .iter()
+            .map(|(key, value)| {
+                let n = seen.entry(key.as_str()).or_insert(0);
+                *n += 1;
+                let key = if *n == 1 {
+                    key.clone()
+                } else {
+                    format!("{} #{}", key, n)
+                };
+                (key, value.clone())
+            })
+            .collect::<Vec<_>>()
+    };
+    let a = numbered(a);
+    let b = numbered(b);
+    let mut out = Vec::new();
+    for (key, value) in &a {
+        let other = b.iter().find(|(k, _)| k == key).map(|(_, v)| v);
+        if other != Some(value) {
+            out.push(SmbiosFieldChange {
+                field: key.clone(),
+                a: Some(value.clone()),
+                b: other.cloned(),
+            });
+        }
+    }
+    for (key, value) in &b {
+        if !a.iter().any(|(k, _)| k == key) {
+            out.push(SmbiosFieldChange {
+                field: key.clone(),
+                a: None,
+                b: Some(value.clone()),
+            });
+        }
+    }
+    out
+}
+
+/// Formats a structured SMBIOS diff as text grouped by structure type.
+///
+/// Each structure is one `-` (only in A), `+` (only in B), or `~` (changed) line, with the
+/// changed fields indented below it, and a summary line ends the text.
+///
+/// # Arguments
+/// * `diffs` - The differences from [`diff_smbios`].
+pub fn smbios_diff_text(diffs: &[SmbiosStructureDiff]) -> String {
+    let mut out = String::new();
+    let mut current_type = None;
+    for diff in diffs {
+        if current_type != Some(diff.type_id) {
+            current_type = Some(diff.type_id);
+            let name = match parsers::smbios_type_name(diff.type_id) {
+                "" => format!("Type {}", diff.type_id),
+                name => format!("Type {} - {}", diff.type_id, name),
+            };
+            out.push_str(&format!("{}\n", name));
+        }
+        let marker = match diff.change {
+            SmbiosStructureChange::OnlyInA => "- ",
+            SmbiosStructureChange::OnlyInB => "+ ",
+            SmbiosStructureChange::Changed(_) => "~ ",
+        };
+        out.push_str(&format!(
+            "  {}{} ({})\n",
+            marker,
+            diff.handle_str(),
+            diff.label
+        ));
+        if let SmbiosStructureChange::Changed(fields) = &diff.change {
+            for field in fields {
+                out.push_str(&format!("      {}\n", field.describe()));
+            }
+        }
+    }
+    out.push_str(&format!("{}\n", smbios_diff_summary(diffs)));
+    out
+}
+
+/// Counts a structured SMBIOS diff, e.g. "1 only in A, 0 only in B, 2 changed".
+pub fn smbios_diff_summary(diffs: &[SmbiosStructureDiff]) -> String {
+    let count =
+        |f: fn(&SmbiosStructureChange) -> bool| diffs.iter().filter(|d| f(&d.change)).count();
+    format!(
+        "{} only in A, {} only in B, {} changed",
+        count(|c| matches!(c, SmbiosStructureChange::OnlyInA)),
+        count(|c| matches!(c, SmbiosStructureChange::OnlyInB)),
+        count(|c| matches!(c, SmbiosStructureChange::Changed(_))),
+    )
+}
+
+/// Produces a `dmidecode`-style text dump of every structure in a raw SMBIOS blob.
+///
+/// With `redact` the identifying fields read [`REDACTED`].
+pub fn smbios_dump_text(data: &[u8], encoding: parsers::StringEncoding, redact: bool) -> String {
+    smbios_dump_text_filtered(data, encoding, &[], redact)
+}
+
+/// Produces a `dmidecode`-style text dump of the structures of the given types.
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS blob.
+/// * `encoding` - How the string pools are decoded.
+/// * `types` - The structure types to include, or an empty slice for all of them.
+/// * `redact` - Replace the identifying fields with [`REDACTED`].
+pub fn smbios_dump_text_filtered(
+    data: &[u8],
+    encoding: parsers::StringEncoding,
+    types: &[u8],
+    redact: bool,
+) -> String {
+    let mut out = String::new();
+    if let Some((hdr, _)) = parsers::parse_raw_smbios_data_header(data) {
+        out.push_str(&format!(
+            "# SMBIOS {}.{} present.\n",
+            hdr._major_version, hdr._minor_version
+        ));
+    }
+
+    let structures: Vec<_> = parsers::walk_smbios_structures(data)
+        .into_iter()
+        .filter(|(_, header, _)| types.is_empty() || types.contains(&header.type_id))
+        .collect();
+    out.push_str(&format!("# {} structures.\n", structures.len()));
+
+    for (off, header, next_off) in structures {
+        out.push_str(&format!(
+            "\nHandle 0x{:04X}, DMI type {}, {} bytes\n",
+            header.handle, header.type_id, header.length
+        ));
+        let name = parsers::smbios_type_name(header.type_id);
+        if name.is_empty() {
+            out.push_str(&format!("Type {}\n", header.type_id));
+        } else {
+            out.push_str(&format!("{}\n", name));
+        }
+        let mut fields = export_smbios_fields(&data[off..next_off], encoding, redact);
+        resolve_smbios_handles(data, &mut fields, encoding);
+        for (k, v) in fields {
+            out.push_str(&format!("\t{}: {}\n", k, v));
+        }
+    }
+    out
+}
+
+/// Produces the parsed view of every ACPI table, one titled section per table.
+///
+/// # Arguments
+/// * `tables` - All discovered ACPI tables.
+/// * `redact` - Replace the MSDM product key with [`REDACTED`].
+/// * `read` - Reads the raw bytes of a table.
+pub fn acpi_dump_text(
+    tables: &[api::AcpiTableInfo],
+    redact: bool,
+    read: impl Fn(&api::AcpiTableInfo) -> Result<Vec<u8>, DumperError>,
+) -> String {
+    let mut out = format!("# {} ACPI tables.\n", tables.len());
+    for info in tables {
+        out.push_str(&format!(
+            "\n==================== {} ({}) ====================\n",
+            info.signature,
+            info.table_id.trim()
+        ));
+        match read(info) {
+            Ok(data) => out.push_str(&acpi_table_text(&data, tables, redact)),
+            Err(e) => out.push_str(&format!("Read failed: {}\n", e)),
+        }
+    }
+    out
+}
+
+/// Produces the hex dump of every ACPI table, one titled section per table.
+///
+/// # Arguments
+/// * `tables` - All discovered ACPI tables.
+/// * `redact` - Leave out the MSDM bytes, which hold the product key in plain text.
+/// * `read` - Reads the raw bytes of a table.
+pub fn acpi_hex_dump_text(
+    tables: &[api::AcpiTableInfo],
+    redact: bool,
+    read: impl Fn(&api::AcpiTableInfo) -> Result<Vec<u8>, DumperError>,
+) -> String {
+    let mut out = format!("# {} ACPI tables.\n", tables.len());
+    for info in tables {
+        out.push_str(&format!(
+            "\n==================== {} ({}) ====================\n",
+            info.signature,
+            info.table_id.trim()
+        ));
+        match read(info) {
+            Ok(_) if redact && info.signature == "MSDM" => {
+                out.push_str("Omitted: holds the product key\n")
+            }
+            Ok(data) => out.push_str(&hex_dump_str(&data, 0)),
+            Err(e) => out.push_str(&format!("Read failed: {}\n", e)),
+        }
+    }
+    out
+}
+
+/// Produces the hex dump of the SMBIOS structures of the given types.
+///
+/// Each structure is dumped with its string area, numbered from its offset in the blob so
+/// the offsets match a dump of the whole blob.
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS blob.
+/// * `types` - The structure types to include, or an empty slice for all of them.
+pub fn smbios_hex_dump_text(data: &[u8], types: &[u8]) -> String {
+    let structures: Vec<_> = parsers::walk_smbios_structures(data)
+        .into_iter()
+        .filter(|(_, header, _)| types.is_empty() || types.contains(&header.type_id))
+        .collect();
+    let mut out = format!("# {} structures.\n", structures.len());
+    for (off, header, next_off) in structures {
+        out.push_str(&format!(
+            "\nHandle 0x{:04X}, DMI type {}, {} bytes\n",
+            header.handle, header.type_id, header.length
+        ));
+        out.push_str(&hex_dump_str(&data[off..next_off], off));
+    }
+    out
+}
+
+/// Lists each SMBIOS structure type present in a raw blob with its count, sorted by type.
+pub fn smbios_type_summary(data: &[u8]) -> String {
+    let mut counts: BTreeMap<u8, usize> = BTreeMap::new();
+    for (_, header, _) in parsers::walk_smbios_structures(data) {
+        *counts.entry(header.type_id).or_default() += 1;
+    }
+
+    let mut out = String::new();
+    for (type_id, count) in counts {
+        let name = match parsers::smbios_type_name(type_id) {
+            "" => "Unknown",
+            name => name,
+        };
+        out.push_str(&format!("Type {:<3} {} x{}\n", type_id, name, count));
+    }
+    out
+}
+
+/// Sizes of the loaded firmware tables, for the Tables by Size readout.
+pub struct FirmwareFootprint {
+    /// Each ACPI table with its size in bytes, largest first.
+    pub tables: Vec<(api::AcpiTableInfo, usize)>,
+    /// Total bytes across all ACPI tables.
+    pub acpi_total: usize,
+    /// Size of the SMBIOS blob, or `None` if it was not loaded.
+    pub smbios_size: Option<usize>,
+}
+
+impl FirmwareFootprint {
+    /// Returns the largest ACPI table and its size.
+    pub fn largest(&self) -> Option<&(api::AcpiTableInfo, usize)> {
+        self.tables.first()
+    }
+
+    /// Returns the total bytes across the ACPI tables and the SMBIOS blob.
+    pub fn total(&self) -> usize {
+        self.acpi_total + self.smbios_size.unwrap_or(0)
+    }
+}
+
+/// Collects the size of every ACPI table and the SMBIOS blob.
+///
+/// # Arguments
+/// * `tables` - The discovered ACPI tables.
+/// * `sizes` - Byte size of each table in `tables`, in the same order; missing entries count as 0.
+/// * `smbios` - The raw SMBIOS blob, if it was loaded.
+///
+/// # Returns
+/// The footprint, with the tables sorted by descending size and then by signature.
+pub fn firmware_footprint(
+    tables: &[api::AcpiTableInfo],
+    sizes: &[usize],
+    smbios: Option<&[u8]>,
+) -> FirmwareFootprint {
+    let mut sized: Vec<(api::AcpiTableInfo, usize)> = tables
+        .iter()
+        .enumerate()
+        .map(|(i, t)| (t.clone(), sizes.get(i).copied().unwrap_or(0)))
+        .collect();
+    sized.sort_by(|(a, a_size), (b, b_size)| {
+        b_size
+            .cmp(a_size)
+            .then(a.signature.cmp(&b.signature))
+            .then(a.table_id.cmp(&b.table_id))
+    });
+    FirmwareFootprint {
+        acpi_total: sized.iter().map(|(_, size)| size).sum(),
+        tables: sized,
+        smbios_size: smbios.map(<[u8]>::len),
+    }
+}
+
+/// ACPI tables a typical PC firmware provides, with what each describes.
+///
+/// An entry is satisfied by any of its signatures. Add a row here to extend the baseline
+/// [`acpi_table_advisory`] checks against.
+pub const EXPECTED_ACPI_TABLES: &[(&[&str], &str)] = &[
+    (&["XSDT", "RSDT"], "root list of every other table"),
+    (&["FACP"], "fixed hardware registers and power management"),
+    (&["DSDT"], "AML definition of the platform's devices"),
+    (&["FACS"], "waking vector and Global Lock"),
+    (&["APIC"], "interrupt controllers and processors"),
+    (&["HPET"], "high precision event timer"),
+    (&["MCFG"], "PCI Express configuration space (ECAM) windows"),
+];
+
+/// Result of comparing the discovered ACPI tables against [`EXPECTED_ACPI_TABLES`].
+pub struct TableAdvisory {
+    /// Expected tables that were not found, as the signatures (e.g. "XSDT/RSDT") and what
+    /// the table describes.
+    pub missing: Vec<(String, &'static str)>,
+    /// Discovered tables whose signature is not a recognized ACPI table, usually OEM tables.
+    pub unusual: Vec<api::AcpiTableInfo>,
+}
+
+/// Compares the discovered ACPI tables against the commonly expected ones.
+///
+/// A DSDT or FACS that the FADT points at counts as present even when it was not
+/// enumerated, since Windows does not always list them.
+///
+/// # Arguments
+/// * `tables` - The discovered tables.
+/// * `read` - Reads a table, used for the FADT.
+///
+/// # Returns
+/// The missing expected tables in baseline order, and the unrecognized tables in
+/// discovery order.
+pub fn acpi_table_advisory<F>(tables: &[api::AcpiTableInfo], read: F) -> TableAdvisory
+where
+    F: Fn(&api::AcpiTableInfo) -> Result<Vec<u8>, DumperError>,
+{
+    let mut present: HashSet<String> = tables.iter().map(|t| t.signature.clone()).collect();
+    if let Some(data) = tables
+        .iter()
+        .find(|t| t.signature == "FACP")
+        .and_then(|t| read(t).ok())
+    {
+        present.extend(
+            parsers::parse_fadt_references(&data)
+                .into_iter()
+                .map(|(_, sig)| sig),
+        );
+    }
+    let missing = EXPECTED_ACPI_TABLES
+        .iter()
+        .filter(|(sigs, _)| !sigs.iter().any(|s| present.contains(*s)))
+        .map(|(sigs, purpose)| (sigs.join("/"), *purpose))
+        .collect();
+    let unusual = tables
+        .iter()
+        .filter(|t| parsers::acpi_signature_description(&t.signature).is_none())
+        .cloned()
+        .collect();
+    TableAdvisory { missing, unusual }
+}
+
+/// Finds the SMBIOS structures that differ between two captures of the blob.
+///
+/// Structures are matched by handle and compared including their string area.
+///
+/// # Arguments
+/// * `previous` - The blob loaded before.
+/// * `current` - The freshly read blob.
+///
+/// # Returns
+/// The handles in `current` whose structure is new or has different bytes.
+pub fn changed_smbios_handles(previous: &[u8], current: &[u8]) -> HashSet<u16> {
+    let old: HashMap<u16, &[u8]> = parsers::walk_smbios_structures(previous)
+        .into_iter()
+        .map(|(offset, header, next)| (header.handle, &previous[offset..next]))
+        .collect();
+    parsers::walk_smbios_structures(current)
+        .into_iter()
+        .filter(|(offset, header, next)| old.get(&header.handle) != Some(&&current[*offset..*next]))
+        .map(|(_, header, _)| header.handle)
+        .collect()
+}
+
+/// One SMBIOS probe or cooling device (Types 26-29) and its nominal reading.
+pub struct SensorReading {
+    /// Offset of the structure in the SMBIOS blob.
+    pub offset: usize,
+    /// SMBIOS structure type (26, 27, 28, or 29).
+    pub type_id: u8,
+    /// Structure handle.
+    pub handle: u16,
+    /// Description string, or an empty string if none is given.
+    pub description: String,
+    /// Probe location, or the device type for cooling devices.
+    pub location: String,
+    /// Reported status, e.g. `OK` or `Critical`.
+    pub status: String,
+    /// Nominal reading with units, or `Unknown`.
+    pub nominal: String,
+}
+
+/// Collects every voltage, temperature, and current probe and cooling device in an SMBIOS blob.
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS blob.
+/// * `encoding` - How the string pools are decoded.
+pub fn smbios_sensors(data: &[u8], encoding: parsers::StringEncoding) -> Vec<SensorReading> {
+    let mut sensors = Vec::new();
+    for (off, header, next_off) in parsers::walk_smbios_structures(data) {
+        if !(26..=29).contains(&header.type_id) {
+            continue;
+        }
+        let fields = smbios_fields(&data[off..next_off], encoding).unwrap_or_default();
+        let field = |key: &str| {
+            fields
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.clone())
+        };
+        sensors.push(SensorReading {
+            offset: off,
+            type_id: header.type_id,
+            handle: header.handle,
+            description: field("Description").unwrap_or_default(),
+            location: field("Location")
+                .or_else(|| field("Device Type"))
+                .unwrap_or_default(),
+            status: field("Status").unwrap_or_default(),
+            nominal: field("Nominal Value")
+                .or_else(|| field("Nominal Speed"))
+                .unwrap_or_else(|| "Unknown".to_string()),
+        });
+    }
+    sensors
+}
+
+/// A memory slot described by an SMBIOS Type 17 structure.
+pub struct MemorySlot {
+    /// Offset of the structure in the SMBIOS blob.
+    pub offset: usize,
+    /// Device locator string, e.g. `DIMM_A1`.
+    pub locator: String,
+    /// Installed size in bytes; `Some(0)` for an empty slot and `None` if unknown.
+    pub size: Option<u64>,
+    /// Memory type, e.g. `DDR4`, or an empty string for an empty slot.
+    pub memory_type: String,
+}
+
+impl MemorySlot {
+    /// Whether a module is installed; slots of unknown size count as populated.
+    pub fn populated(&self) -> bool {
+        self.size != Some(0)
+    }
+
+    /// Returns a short label such as "DIMM_A1 (16 GB DDR4)" or "DIMM_B2 (Empty)".
+    pub fn label(&self) -> String {
+        let detail = match self.size {
+            Some(0) => "Empty".to_string(),
+            size => {
+                let size =
+                    size.map_or_else(|| "Unknown Size".to_string(), parsers::memory_size_str);
+                match self.memory_type.as_str() {
+                    "" => size,
+                    memory_type => format!("{} {}", size, memory_type),
+                }
+            }
+        };
+        format!("{} ({})", self.locator, detail)
+    }
+}
+
+/// Whether the structure at `offset` is an empty memory slot or an unused system slot.
+///
+/// Type 17 devices with a size of 0 and Type 9 slots whose Current Usage is "Available"
+/// count as empty; every other structure, including devices of unknown size, does not.
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS blob.
+/// * `offset` - Offset of the structure in the blob.
+pub fn is_empty_slot(data: &[u8], offset: usize) -> bool {
+    match data.get(offset) {
+        Some(17) => parsers::memory_device_size(data, offset) == Some(0),
+        // Current Usage 0x03 is "Available"
+        Some(9) => {
+            data.get(offset + 0x01).is_some_and(|&length| length > 0x07)
+                && data.get(offset + 0x07) == Some(&0x03)
+        }
+        _ => false,
+    }
+}
+
+/// Collects every Type 17 memory device in an SMBIOS blob.
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS blob.
+/// * `encoding` - How the string pools are decoded.
+pub fn memory_slots(data: &[u8], encoding: parsers::StringEncoding) -> Vec<MemorySlot> {
+    let mut slots = Vec::new();
+    for (off, header, next_off) in parsers::walk_smbios_structures(data) {
+        if header.type_id != 17 {
+            continue;
+        }
+        let fields = smbios_fields(&data[off..next_off], encoding).unwrap_or_default();
+        let field = |key: &str| {
+            fields
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.trim().to_string())
+                .unwrap_or_default()
+        };
+        slots.push(MemorySlot {
+            offset: off,
+            locator: field("Device Locator"),
+            size: parsers::memory_device_size(data, off),
+            memory_type: field("Memory Type"),
+        });
+    }
+    slots
+}
+
+/// Summarizes the memory slots, e.g. "Populated 2/4 slots, 32 GB total, max per array 128 GB".
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS blob.
+/// * `encoding` - How the string pools are decoded.
+/// * `populated_only` - Leave empty slots out, e.g. "2 modules, 32 GB total".
+///
+/// # Returns
+/// The summary, or `None` if the blob has no Type 17 structures.
+pub fn memory_summary(
+    data: &[u8],
+    encoding: parsers::StringEncoding,
+    populated_only: bool,
+) -> Option<String> {
+    let slots = memory_slots(data, encoding);
+    if slots.is_empty() {
+        return None;
+    }
+    let populated = slots.iter().filter(|s| s.populated()).count();
+    let total: u64 = slots.iter().filter_map(|s| s.size).sum();
+    let mut summary = if populated_only {
+        format!(
+            "{} {}, {} total",
+            populated,
+            if populated == 1 { "module" } else { "modules" },
+            parsers::memory_size_str(total)
+        )
+    } else {
+        format!(
+            "Populated {}/{} slots, {} total",
+            populated,
+            slots.len(),
+            parsers::memory_size_str(total)
+        )
+    };
+    let mut capacities: Vec<String> = Vec::new();
+    for (off, header, next_off) in parsers::walk_smbios_structures(data) {
+        if header.type_id != 16 {
+            continue;
+        }
+        let fields = smbios_fields(&data[off..next_off], encoding).unwrap_or_default();
+        if let Some((_, capacity)) = fields.iter().find(|(k, _)| k == "Maximum Capacity") {
+            if !capacities.contains(capacity) {
+                capacities.push(capacity.clone());
+            }
+        }
+    }
+    if !capacities.is_empty() {
+        summary.push_str(&format!(", max per array {}", capacities.join(" / ")));
+    }
+    Some(summary)
+}
+
+/// A processor socket and the Type 4 structures that describe it.
+pub struct ProcessorSocket {
+    /// Socket designator string, e.g. `CPU0`.
+    pub designator: String,
+    /// Whether any structure for the socket has the "CPU Socket Populated" status bit set.
+    pub populated: bool,
+    /// Processor versions of the populated structures, e.g. the CPU model name.
+    pub processors: Vec<String>,
+    /// Handles of the Type 4 structures for the socket.
+    pub handles: Vec<u16>,
+    /// Offset of the first Type 4 structure for the socket in the SMBIOS blob.
+    pub offset: usize,
+}
+
+/// Groups the Type 4 processor structures of an SMBIOS blob by socket designator.
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS blob.
+/// * `encoding` - How the string pools are decoded.
+///
+/// # Returns
+/// One entry per designator, in the order the sockets first appear.
+pub fn smbios_sockets(data: &[u8], encoding: parsers::StringEncoding) -> Vec<ProcessorSocket> {
+    let mut sockets: Vec<ProcessorSocket> = Vec::new();
+    for (off, header, next_off) in parsers::walk_smbios_structures(data) {
+        if header.type_id != 4 {
+            continue;
+        }
+        let fields = smbios_fields(&data[off..next_off], encoding).unwrap_or_default();
+        let field = |key: &str| {
+            fields
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.trim().to_string())
+                .unwrap_or_default()
+        };
+        // Status was added in SMBIOS 2.0; without it assume the socket is populated
+        let populated = match data.get(off + 0x18).filter(|_| header.length > 0x18) {
+            Some(&status) => parsers::processor_socket_populated(status),
+            None => true,
+        };
+        let designator = field("Socket Designator");
+        let index = match sockets.iter().position(|s| s.designator == designator) {
+            Some(index) => index,
+            None => {
+                sockets.push(ProcessorSocket {
+                    designator,
+                    populated: false,
+                    processors: Vec::new(),
+                    handles: Vec::new(),
+                    offset: off,
+                });
+                sockets.len() - 1
+            }
+        };
+        let socket = &mut sockets[index];
+        socket.handles.push(header.handle);
+        if populated {
+            socket.populated = true;
+            let version = field("Version");
+            if !version.is_empty() && !socket.processors.contains(&version) {
+                socket.processors.push(version);
+            }
+        }
+    }
+    sockets
+}
+
+/// One SMBIOS Type 4 processor structure, as counted by [`cpu_summary`].
+pub struct CpuPackage {
+    /// Socket designator string, e.g. `CPU0`.
+    pub designator: String,
+    /// Processor version, e.g. the CPU model name; empty if not reported.
+    pub version: String,
+    /// Whether the "CPU Socket Populated" status bit is set.
+    pub populated: bool,
+    /// `(cores, threads)`, or `None` if the structure predates SMBIOS 2.5.
+    pub counts: Option<(u16, u16)>,
+}
+
+/// Reads every Type 4 processor structure of an SMBIOS blob, in table order.
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS blob.
+/// * `encoding` - How the string pools are decoded.
+pub fn cpu_packages(data: &[u8], encoding: parsers::StringEncoding) -> Vec<CpuPackage> {
+    let mut packages = Vec::new();
+    for (off, header, next_off) in parsers::walk_smbios_structures(data) {
+        if header.type_id != 4 {
+            continue;
+        }
+        let fields = smbios_fields(&data[off..next_off], encoding).unwrap_or_default();
+        let field = |key: &str| {
+            fields
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.trim().to_string())
+                .unwrap_or_default()
+        };
+        // Status was added in SMBIOS 2.0; without it assume the socket is populated
+        let populated = match data.get(off + 0x18).filter(|_| header.length > 0x18) {
+            Some(&status) => parsers::processor_socket_populated(status),
+            None => true,
+        };
+        packages.push(CpuPackage {
+            designator: field("Socket Designator"),
+            version: field("Version"),
+            populated,
+            counts: parsers::processor_core_thread_counts(data, off),
+        });
+    }
+    packages
+}
+
+/// Summarizes the processor packages, e.g. "2 sockets, 2x 24C/48T = 48C/96T".
+///
+/// Only Type 4 structures with the populated status bit count as packages. Mixed packages
+/// are listed one by one ("24C/48T + 16C/32T = 40C/80T"), and a package without core
+/// counts shows as "?" and is left out of the total.
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS blob.
+/// * `encoding` - How the string pools are decoded.
+///
+/// # Returns
+/// The summary, or `None` if the blob has no Type 4 structures.
+pub fn cpu_summary(data: &[u8], encoding: parsers::StringEncoding) -> Option<String> {
+    let packages = cpu_packages(data, encoding);
+    if packages.is_empty() {
+        return None;
+    }
+    let populated: Vec<&CpuPackage> = packages.iter().filter(|p| p.populated).collect();
+    let mut out = match (populated.len(), packages.len()) {
+        (1, 1) => "1 socket".to_string(),
+        (p, n) if p == n => format!("{} sockets", n),
+        (p, n) => format!("{} of {} sockets populated", p, n),
+    };
+    if populated.iter().all(|p| p.counts.is_none()) {
+        return Some(out);
+    }
+
+    let counts_str = |(cores, threads): (u32, u32)| format!("{}C/{}T", cores, threads);
+    let (cores, threads) = populated
+        .iter()
+        .filter_map(|p| p.counts)
+        .fold((0u32, 0u32), |(c, t), (pc, pt)| {
+            (c + pc as u32, t + pt as u32)
+        });
+    let first = populated[0].counts;
+    if populated.len() == 1 {
+        out.push_str(&format!(", {}", counts_str((cores, threads))));
+    } else if populated.iter().all(|p| p.counts == first) {
+        out.push_str(&format!(
+            ", {}x {} = {}",
+            populated.len(),
+            counts_str(first.map_or((0, 0), |(c, t)| (c as u32, t as u32))),
+            counts_str((cores, threads))
+        ));
+    } else {
+        let parts: Vec<String> = populated
+            .iter()
+            .map(|p| match p.counts {
+                Some((c, t)) => counts_str((c as u32, t as u32)),
+                None => "?".to_string(),
+            })
+            .collect();
+        out.push_str(&format!(
+            ", {} = {}",
+            parts.join(" + "),
+            counts_str((cores, threads))
+        ));
+    }
+    Some(out)
+}
+
+/// One NUMA node, assembled from the SRAT affinity structures and the SLIT distances.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumaNode {
+    /// The proximity domain number.
+    pub domain: u32,
+    /// Number of enabled processors in the domain.
+    pub cpus: usize,
+    /// Enabled memory ranges in the domain as (base, length).
+    pub memory: Vec<(u64, u64)>,
+    /// The domain's SLIT row as (domain, distance), including the distance to itself.
+    pub distances: Vec<(u32, u8)>,
+}
+
+impl NumaNode {
+    /// Returns the total size of the node's memory ranges in bytes.
+    pub fn memory_bytes(&self) -> u64 {
+        self.memory
+            .iter()
+            .fold(0u64, |total, (_, length)| total.saturating_add(*length))
+    }
+}
+
+/// NUMA topology built from whichever of the SRAT and SLIT tables is present.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumaTopology {
+    /// Nodes ordered by proximity domain.
+    pub nodes: Vec<NumaNode>,
+    /// Whether a valid SRAT supplied the CPUs and memory.
+    pub has_srat: bool,
+    /// Whether a valid SLIT supplied the distances.
+    pub has_slit: bool,
+}
+
+impl NumaTopology {
+    /// Describes a node in one line, e.g. "Node 0: 24 CPUs, 64 GB, distance to Node 1 = 21".
+    ///
+    /// Without an SRAT the CPUs and memory are reported as unknown; without a SLIT the
+    /// distances are left out.
+    pub fn node_line(&self, node: &NumaNode) -> String {
+        let mut parts = Vec::new();
+        if self.has_srat {
+            parts.push(match node.cpus {
+                1 => "1 CPU".to_string(),
+                n => format!("{} CPUs", n),
+            });
+            parts.push(rounded_size_str(node.memory_bytes()));
+        } else {
+            parts.push("CPUs and memory unknown (no SRAT)".to_string());
+        }
+        let others: Vec<String> = node
+            .distances
+            .iter()
+            .filter(|(domain, _)| *domain != node.domain)
+            .map(|(domain, distance)| format!("Node {} = {}", domain, distance))
+            .collect();
+        match others.len() {
+            0 => {}
+            1 => parts.push(format!("distance to {}", others[0])),
+            _ => parts.push(format!("distances to {}", others.join(", "))),
+        }
+        format!("Node {}: {}", node.domain, parts.join(", "))
+    }
+}
+
+/// Formats a byte count rounded for display, e.g. `64 GB` or `1.5 GB`.
+fn rounded_size_str(bytes: u64) -> String {
+    let gb = bytes as f64 / (1u64 << 30) as f64;
+    if gb < 1.0 {
+        return format!("{} MB", (bytes + (1 << 19)) >> 20);
+    }
+    if (gb - gb.round()).abs() < 0.05 {
+        format!("{:.0} GB", gb)
+    } else {
+        format!("{:.1} GB", gb)
+    }
+}
+
+/// Combines the SRAT and SLIT into one NUMA node list.
+///
+/// SLIT locality numbers are proximity domains, so row `n` of the distance matrix belongs
+/// to domain `n`. A domain that only one of the tables mentions still gets a node.
+///
+/// # Arguments
+/// * `srat` - The raw SRAT table, if present.
+/// * `slit` - The raw SLIT table, if present.
+///
+/// # Returns
+/// The topology, or `None` if neither table is present and valid.
+pub fn numa_topology(srat: Option<&[u8]>, slit: Option<&[u8]>) -> Option<NumaTopology> {
+    let affinities = srat.and_then(parsers::srat_affinities);
+    let matrix = slit.and_then(parsers::slit_matrix);
+    if affinities.is_none() && matrix.is_none() {
+        return None;
+    }
+
+    let mut nodes: BTreeMap<u32, NumaNode> = BTreeMap::new();
+    fn entry(nodes: &mut BTreeMap<u32, NumaNode>, domain: u32) -> &mut NumaNode {
+        nodes.entry(domain).or_insert_with(|| NumaNode {
+            domain,
+            cpus: 0,
+            memory: Vec::new(),
+            distances: Vec::new(),
+        })
+    }
+    for affinity in affinities.iter().flatten() {
+        match *affinity {
+            parsers::SratAffinity::Processor { domain, .. } => entry(&mut nodes, domain).cpus += 1,
+            parsers::SratAffinity::Memory {
+                domain,
+                base,
+                length,
+                ..
+            } => entry(&mut nodes, domain).memory.push((base, length)),
+        }
+    }
+    for (row, distances) in matrix.iter().flatten().enumerate() {
+        entry(&mut nodes, row as u32).distances = distances
+            .iter()
+            .enumerate()
+            .map(|(column, &distance)| (column as u32, distance))
+            .collect();
+    }
+
+    Some(NumaTopology {
+        nodes: nodes.into_values().collect(),
+        has_srat: affinities.is_some(),
+        has_slit: matrix.is_some(),
+    })
+}
+
+/// A PCI function named by SMBIOS, from a Type 9 slot or a Type 41 onboard device.
+pub struct SmbiosPciLocation {
+    /// PCI segment group number.
+    pub segment: u16,
+    /// Bus number.
+    pub bus: u8,
+    /// Device number (0-31).
+    pub device: u8,
+    /// Function number (0-7).
+    pub function: u8,
+    /// What SMBIOS calls it, e.g. "Slot PCIEX16_1" or "Onboard Intel LAN".
+    pub name: String,
+}
+
+/// Collects the PCI bus addresses of the Type 9 slots and Type 41 onboard devices.
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS blob.
+/// * `encoding` - How the string pools are decoded.
+///
+/// # Returns
+/// One entry per structure with a bus address, skipping the "not applicable" FFFF:FF:FF.
+pub fn smbios_pci_locations(
+    data: &[u8],
+    encoding: parsers::StringEncoding,
+) -> Vec<SmbiosPciLocation> {
+    let mut locations = Vec::new();
+    for (off, header, _) in parsers::walk_smbios_structures(data) {
+        // Segment Group / Bus / Device-Function offsets of each type (SMBIOS 2.6 and later)
+        let (at, kind) = match header.type_id {
+            9 => (0x0D, "Slot"),
+            41 => (0x07, "Onboard"),
+            _ => continue,
+        };
+        let length = (header.length as usize).min(data.len() - off);
+        if length < at + 4 {
+            continue;
+        }
+        let segment = u16::from_le_bytes([data[off + at], data[off + at + 1]]);
+        let bus = data[off + at + 2];
+        let dev_func = data[off + at + 3];
+        if segment == 0xFFFF && bus == 0xFF && dev_func == 0xFF {
+            continue;
+        }
+        let strings = parsers::get_smbios_strings(data, off, header.length, encoding);
+        locations.push(SmbiosPciLocation {
+            segment,
+            bus,
+            device: dev_func >> 3,
+            function: dev_func & 0x07,
+            name: format!(
+                "{} {}",
+                kind,
+                parsers::get_string_by_index(&strings, data[off + 0x04])
+            ),
+        });
+    }
+    locations
+}
+
+/// Builds the parsed view of one PCI function's configuration space.
+///
+/// # Arguments
+/// * `location` - Bus, device, and function, if known.
+/// * `config` - The function's configuration space.
+/// * `slots` - SMBIOS slot and onboard device locations on segment 0, for cross-referencing.
+pub fn pci_function_text(
+    location: Option<(u8, u8, u8)>,
+    config: &[u8],
+    slots: &[SmbiosPciLocation],
+) -> String {
+    let mut fields = parsers::parse_pci_config_header(config);
+    let title = match location {
+        Some((bus, device, function)) => format!("{:02X}:{:02X}.{:X}", bus, device, function),
+        None => "Unknown location".to_string(),
+    };
+    if let Some((bus, device, function)) = location {
+        for slot in slots.iter().filter(|s| {
+            s.segment == 0 && s.bus == bus && s.device == device && s.function == function
+        }) {
+            fields.insert(0, ("SMBIOS".to_string(), slot.name.clone()));
+        }
+    }
+    let class = parsers::pci_class_str(config[0x0B], config[0x0A], config[0x09]);
+    let mut out = format!(
+        "[{}] {:04X}:{:04X} {}\n",
+        title,
+        u16::from_le_bytes([config[0], config[1]]),
+        u16::from_le_bytes([config[2], config[3]]),
+        class
+    );
+    let width = fields.iter().map(|(n, _)| n.len()).max().unwrap_or(0) + 2;
+    for (name, value) in fields {
+        out.push_str(&format!("  {:<width$}{}\n", format!("{}:", name), value));
+    }
+    out
+}
+
+/// Builds the parsed view of a PCI configuration space dump.
+///
+/// ECAM window dumps are numbered from bus 0 and cross-referenced with the SMBIOS slots and
+/// onboard devices on segment 0.
+///
+/// # Arguments
+/// * `data` - The dump contents.
+/// * `smbios` - The SMBIOS blob of this machine (may be empty).
+/// * `encoding` - How the SMBIOS string pools are decoded.
+pub fn pci_config_text(data: &[u8], smbios: &[u8], encoding: parsers::StringEncoding) -> String {
+    let functions = parsers::pci_config_functions(data, 0);
+    let slots = smbios_pci_locations(smbios, encoding);
+    let mut out = format!(
+        "PCI Configuration Space: {} function(s) in {} bytes\n",
+        functions.len(),
+        data.len()
+    );
+    for function in functions {
+        out.push('\n');
+        out.push_str(&pci_function_text(
+            function.location,
+            &data[function.offset..function.offset + function.length],
+            &slots,
+        ));
+    }
+    out
+}
+
+/// Strings firmware leaves in serial number fields when the OEM never filled them in.
+///
+/// Compared case-insensitively after trimming whitespace.
+const PLACEHOLDER_STRINGS: &[&str] = &[
+    "To be filled by O.E.M.",
+    "Default string",
+    "System Serial Number",
+    "Chassis Serial Number",
+    "Base Board Serial Number",
+    "Serial Number",
+    "Not Specified",
+    "Not Applicable",
+    "Not Available",
+    "None",
+    "0123456789",
+    "123456789",
+];
+
+/// An SMBIOS field whose value looks like an unfilled placeholder rather than real data.
+pub struct PlaceholderField {
+    /// Structure type.
+    pub type_id: u8,
+    /// Structure handle.
+    pub handle: u16,
+    /// Field name, e.g. `Serial Number`.
+    pub key: String,
+    /// The suspicious value.
+    pub value: String,
+    /// Why the value was flagged.
+    pub reason: &'static str,
+}
+
+/// Checks whether a parsed System, Baseboard, or Chassis serial number or the system UUID
+/// holds an obviously bogus value.
+///
+/// # Arguments
+/// * `type_id` - Type of the structure the field belongs to.
+/// * `key` - The parsed field name.
+/// * `value` - The parsed field value.
+///
+/// # Returns
+/// Why the value looks like a placeholder, or `None` if it looks genuine.
+pub fn placeholder_reason(type_id: u8, key: &str, value: &str) -> Option<&'static str> {
+    match (type_id, key) {
+        (1..=3, "Serial Number") => {
+            let value = value.trim();
+            let known = PLACEHOLDER_STRINGS
+                .iter()
+                .any(|p| p.eq_ignore_ascii_case(value));
+            if value.is_empty() || known {
+                Some("Placeholder serial number")
+            } else if value.chars().all(|c| c == '0') {
+                Some("Serial number is all zeros")
+            } else {
+                None
+            }
+        }
+        (1, "UUID") => {
+            // Older reports hold the raw digits instead of the special-case names
+            let digits: String = value.chars().filter(|c| *c != '-').collect();
+            if value == "Not Settable" || (!digits.is_empty() && digits.chars().all(|c| c == '0')) {
+                Some("UUID is all zeros (not present, not settable)")
+            } else if value == "Not Present"
+                || (!digits.is_empty() && digits.chars().all(|c| c.eq_ignore_ascii_case(&'F')))
+            {
+                Some("UUID is all FF (not present, settable)")
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Collects every placeholder serial number and UUID in an SMBIOS blob.
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS blob.
+/// * `encoding` - How the string pools are decoded.
+pub fn smbios_placeholders(
+    data: &[u8],
+    encoding: parsers::StringEncoding,
+) -> Vec<PlaceholderField> {
+    let mut found = Vec::new();
+    for (off, header, next_off) in parsers::walk_smbios_structures(data) {
+        if !(1..=3).contains(&header.type_id) {
+            continue;
+        }
+        for (key, value) in smbios_fields(&data[off..next_off], encoding).unwrap_or_default() {
+            if let Some(reason) = placeholder_reason(header.type_id, &key, &value) {
+                found.push(PlaceholderField {
+                    type_id: header.type_id,
+                    handle: header.handle,
+                    key,
+                    value,
+                    reason,
+                });
+            }
+        }
+    }
+    found
+}
+
+/// Looks up a parsed field of the first SMBIOS structure with the given type.
+fn first_smbios_field(
+    data: &[u8],
+    type_id: u8,
+    key: &str,
+    encoding: parsers::StringEncoding,
+) -> Option<String> {
+    let (off, _, next_off) = parsers::walk_smbios_structures(data)
+        .into_iter()
+        .find(|(_, h, _)| h.type_id == type_id)?;
+    smbios_fields(&data[off..next_off], encoding)?
+        .into_iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v)
+}
+
+/// Markers of virtual machine firmware: (substring, hypervisor name).
+///
+/// Matched case-sensitively against SMBIOS vendor/product strings and ACPI OEM IDs.
+const VM_SIGNATURES: &[(&str, &str)] = &[
+    ("VMware", "VMware"),
+    ("VMWARE", "VMware"),
+    ("VirtualBox", "VirtualBox"),
+    ("innotek", "VirtualBox"),
+    ("VBOX", "VirtualBox"),
+    ("QEMU", "QEMU"),
+    ("BOCHS", "QEMU"),
+    ("KVM", "KVM"),
+    ("Microsoft Corporation Virtual Machine", "Hyper-V"),
+    ("VRTUAL", "Hyper-V"),
+    ("Xen", "Xen"),
+    ("XEN", "Xen"),
+    ("Parallels", "Parallels"),
+    ("Amazon EC2", "Amazon EC2"),
+    ("Google Compute Engine", "Google Compute Engine"),
+];
+
+/// Result of the virtual machine heuristic.
+pub struct VmDetection {
+    /// Name of the detected hypervisor.
+    pub hypervisor: &'static str,
+    /// The firmware string that matched, e.g. `System: VMware, Inc. VMware7,1`.
+    pub evidence: String,
+}
+
+/// Guesses whether the firmware belongs to a virtual machine.
+///
+/// Inspects the SMBIOS system manufacturer/product (Type 1), the BIOS vendor and version
+/// (Type 0), and the ACPI OEM IDs against [`VM_SIGNATURES`].
+///
+/// # Arguments
+/// * `tables` - All discovered ACPI tables.
+/// * `smbios` - The raw SMBIOS blob.
+pub fn detect_vm(tables: &[api::AcpiTableInfo], smbios: &[u8]) -> Option<VmDetection> {
+    let field = |type_id, key| {
+        first_smbios_field(smbios, type_id, key, parsers::StringEncoding::default())
+            .unwrap_or_default()
+    };
+    let mut candidates = vec![
+        format!(
+            "System: {} {}",
+            field(1, "Manufacturer"),
+            field(1, "Product Name")
+        ),
+        format!("BIOS: {} {}", field(0, "Vendor"), field(0, "Version")),
+    ];
+    for t in tables {
+        candidates.push(format!("ACPI {} OEM ID: {}", t.signature, t.oem_id.trim()));
+    }
+
+    for candidate in candidates {
+        if let Some((_, hypervisor)) = VM_SIGNATURES
+            .iter()
+            .find(|(marker, _)| candidate.contains(marker))
+        {
+            return Some(VmDetection {
+                hypervisor,
+                evidence: candidate,
+            });
+        }
+    }
+    None
+}
+
+/// Outcome of one `--verify` consistency check.
+#[derive(Debug, PartialEq)]
+pub enum VerifyOutcome {
+    /// The check passed; the text says what was confirmed.
+    Pass(String),
+    /// The check failed; the text says why.
+    Fail(String),
+    /// The check does not apply; the text says why.
+    Skip(String),
+}
+
+/// Verifies the checksum of one ACPI table.
+///
+/// The RSDP is checked over its first 20 bytes and, from revision 2 on, over its whole
+/// length as well. The FACS has no checksum and is skipped.
+///
+/// # Arguments
+/// * `signature` - The signature the table was read under.
+/// * `data` - The raw binary data of the table.
+pub fn verify_acpi_checksum(signature: &str, data: &[u8]) -> VerifyOutcome {
+    match signature {
+        "FACS" => VerifyOutcome::Skip("no checksum".to_string()),
+        "RSDP" => {
+            if data.len() < 20 {
+                return VerifyOutcome::Fail(format!("truncated ({} bytes)", data.len()));
+            }
+            let sum = parsers::acpi_checksum_sum(&data[..20]);
+            if sum != 0 {
+                return VerifyOutcome::Fail(format!("checksum invalid (sum 0x{:02X})", sum));
+            }
+            if data[15] < 2 {
+                return VerifyOutcome::Pass("checksum OK".to_string());
+            }
+            let length = match data.get(20..24) {
+                Some(bytes) => u32::from_le_bytes(bytes.try_into().unwrap()) as usize,
+                None => return VerifyOutcome::Fail(format!("truncated ({} bytes)", data.len())),
+            };
+            match data.get(..length) {
+                Some(full) if length >= 36 => match parsers::acpi_checksum_sum(full) {
+                    0 => VerifyOutcome::Pass("checksum and extended checksum OK".to_string()),
+                    sum => VerifyOutcome::Fail(format!(
+                        "extended checksum invalid (sum 0x{:02X})",
+                        sum
+                    )),
+                },
+                _ => VerifyOutcome::Fail(format!(
+                    "length {} does not fit the {} bytes read",
+                    length,
+                    data.len()
+                )),
+            }
+        }
+        _ => {
+            if data.len() < 36 {
+                return VerifyOutcome::Fail(format!(
+                    "shorter than an ACPI header ({} bytes)",
+                    data.len()
+                ));
+            }
+            if let Some((reported, read)) = parsers::acpi_truncation(data) {
+                return VerifyOutcome::Fail(format!(
+                    "reports {} bytes but only {} were read (truncated)",
+                    reported, read
+                ));
+            }
+            match parsers::acpi_checksum_sum(data) {
+                0 => VerifyOutcome::Pass("checksum OK".to_string()),
+                sum => VerifyOutcome::Fail(format!("checksum invalid (sum 0x{:02X})", sum)),
+            }
+        }
+    }
+}
+
+/// Verifies that a raw SMBIOS blob has a valid header and walks cleanly to its end.
+///
+/// # Arguments
+/// * `data` - The raw SMBIOS blob, including the Windows 8-byte header.
+pub fn verify_smbios(data: &[u8]) -> VerifyOutcome {
+    let Some((header, _)) = parsers::parse_raw_smbios_data_header(data) else {
+        return VerifyOutcome::Fail("invalid RawSMBIOSData header".to_string());
+    };
+    match parsers::try_walk_smbios_structures(data) {
+        Ok(structures) if structures.is_empty() => {
+            VerifyOutcome::Fail("no structures found".to_string())
+        }
+        Ok(structures) => {
+            let mut handles = HashSet::new();
+            if let Some((off, h, _)) = structures
+                .iter()
+                .find(|(_, h, _)| !handles.insert(h.handle))
+            {
+                return VerifyOutcome::Fail(format!(
+                    "duplicate handle 0x{:04X} (Type {} at offset 0x{:X})",
+                    h.handle, h.type_id, off
+                ));
+            }
+            VerifyOutcome::Pass(format!(
+                "version {}.{}, {} structures",
+                header._major_version,
+                header._minor_version,
+                structures.len()
+            ))
+        }
+        Err(e) => VerifyOutcome::Fail(e.to_string()),
+    }
+}
+
+/// Builds a short plain-text overview of the system firmware.
+///
+/// With `redact` the system serial number and UUID read [`REDACTED`].
+pub fn summary_text(
+    tables: &[api::AcpiTableInfo],
+    smbios: &[u8],
+    encoding: parsers::StringEncoding,
+    redact: bool,
+) -> String {
+    let mut out = String::from("System Summary\n====================\n");
+
+    if let Some((hdr, _)) = parsers::parse_raw_smbios_data_header(smbios) {
+        out.push_str(&format!(
+            "SMBIOS Version:      {}.{} (DMI rev {})\n",
+            hdr._major_version, hdr._minor_version, hdr._dmi_revision
+        ));
+        out.push_str(&format!(
+            "SMBIOS Structures:   {}\n",
+            parsers::walk_smbios_structures(smbios).len()
+        ));
+    } else {
+        out.push_str("SMBIOS Version:      <unavailable>\n");
+    }
+
+    let rows = [
+        ("BIOS Vendor", 0, "Vendor"),
+        ("BIOS Version", 0, "Version"),
+        ("BIOS Release Date", 0, "Release Date"),
+        ("System Manufacturer", 1, "Manufacturer"),
+        ("System Product", 1, "Product Name"),
+        ("System Serial", 1, "Serial Number"),
+        ("System UUID", 1, "UUID"),
+        ("Board Manufacturer", 2, "Manufacturer"),
+        ("Board Product", 2, "Product Name"),
+        ("Processor", 4, "Version"),
+    ];
+    for (label, type_id, key) in rows {
+        if let Some(mut v) = first_smbios_field(smbios, type_id, key, encoding) {
+            if redact && is_sensitive_smbios_field(type_id, key) {
+                v = REDACTED.to_string();
+            }
+            out.push_str(&format!("{:<21}{}\n", format!("{}:", label), v));
+        }
+    }
+
+    if let Some(cpu) = cpu_summary(smbios, encoding) {
+        out.push_str(&format!("{:<21}{}\n", "CPU:", cpu));
+    }
+    if let Some(memory) = memory_summary(smbios, encoding, false) {
+        out.push_str(&format!("{:<21}{}\n", "Memory:", memory));
+    }
+
+    let sockets = smbios_sockets(smbios, encoding);
+    if !sockets.is_empty() {
+        out.push_str(&format!(
+            "{:<21}{} ({} populated)\n",
+            "Sockets:",
+            sockets.len(),
+            sockets.iter().filter(|s| s.populated).count()
+        ));
+        for socket in &sockets {
+            let status = if socket.populated {
+                "Populated"
+            } else {
+                "Empty"
+            };
+            let line = format!(
+                "  {:<12}{:<11}{}",
+                socket.designator,
+                status,
+                socket.processors.join(", ")
+            );
+            out.push_str(line.trim_end());
+            out.push('\n');
+        }
+    }
+
+    if let Some(vm) = detect_vm(tables, smbios) {
+        out.push_str(&format!(
+            "{:<21}{} ({})\n",
+            "Likely virtual:", vm.hypervisor, vm.evidence
+        ));
+    }
+
+    let placeholders = smbios_placeholders(smbios, encoding);
+    if !placeholders.is_empty() {
+        out.push_str(&format!(
+            "{:<21}{} placeholder values detected\n",
+            "Placeholders:",
+            placeholders.len()
+        ));
+        for p in &placeholders {
+            out.push_str(&format!(
+                "  Type {} {}: {} ({})\n",
+                p.type_id, p.key, p.value, p.reason
+            ));
+        }
+    }
+
+    out.push_str(&format!("\nACPI Tables: {}\n", tables.len()));
+    for t in tables {
+        out.push_str(&format!(
+            "  {:<6}{:<8}{:<10}rev {:<4}{}\n",
+            t.signature,
+            t.oem_id.trim(),
+            t.table_id.trim(),
+            t.revision,
+            t.source().label()
+        ));
+    }
+    out
+}
+
+/// Escapes text for inclusion in HTML element content or a quoted attribute.
+pub fn html_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Appends key/value pairs to `out` as a two-column HTML table.
+fn html_fields_table(out: &mut String, fields: &[(String, String)]) {
+    out.push_str("<table>\n");
+    for (k, v) in fields {
+        out.push_str(&format!(
+            "<tr><th>{}</th><td>{}</td></tr>\n",
+            html_escape(k),
+            html_escape(v)
+        ));
+    }
+    out.push_str("</table>\n");
+}
+
+/// Inline stylesheet for the HTML report, so the file has no external assets.
+const HTML_REPORT_CSS: &str = "body{font-family:Segoe UI,Arial,sans-serif;margin:2em;color:#222}\
+h1{font-size:1.6em}h2{border-bottom:1px solid #ccc;padding-bottom:.2em}\
+pre{background:#f5f5f5;padding:1em;overflow-x:auto}\
+table{border-collapse:collapse;margin:.5em 0 1.5em}\
+th,td{border:1px solid #ddd;padding:.25em .6em;text-align:left;vertical-align:top}\
+th{background:#f0f0f0;font-weight:600;white-space:nowrap}\
+td{font-family:Consolas,monospace}nav ul{margin:.2em 0}\
+.ok{color:#2a7d2a}.bad{color:#b02a2a}\
+.note{background:#fff8dc;border-left:4px solid #e0b040;padding:.5em 1em;white-space:pre-wrap}";
+
+/// Column names of the ACPI manifest, in order.
+pub const ACPI_MANIFEST_COLUMNS: [&str; 7] = [
+    "signature",
+    "oem_id",
+    "table_id",
+    "revision",
+    "length",
+    "physical_address",
+    "source",
+];
+
+/// Lists every ACPI table's identity without its contents, one row per table in the
+/// columns of [`ACPI_MANIFEST_COLUMNS`].
+///
+/// The length is the number of bytes read and is empty if the table could not be read;
+/// the physical address is empty when the discovery source does not report one.
+///
+/// # Arguments
+/// * `tables` - All discovered ACPI tables.
+/// * `read` - Reads the raw bytes of a table.
+pub fn acpi_manifest_rows(
+    tables: &[api::AcpiTableInfo],
+    read: impl Fn(&api::AcpiTableInfo) -> Result<Vec<u8>, DumperError>,
+) -> Vec<[String; 7]> {
+    tables
+        .iter()
+        .map(|t| {
+            [
+                t.signature.clone(),
+                t.oem_id.clone(),
+                t.table_id.clone(),
+                t.revision.to_string(),
+                read(t).map(|d| d.len().to_string()).unwrap_or_default(),
+                t.physical_address
+                    .map(|a| format!("0x{:X}", a))
+                    .unwrap_or_default(),
+                t.source().key().to_string(),
+            ]
+        })
+        .collect()
+}
+
+/// Quotes a CSV field when it holds a comma, quote, line break, or edge whitespace,
+/// doubling any quotes inside it (RFC 4180).
+fn csv_field(value: &str) -> String {
+    let needs_quotes = value.contains([',', '"', '\r', '\n']) || value.trim() != value;
+    if needs_quotes {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Formats [`acpi_manifest_rows`] as CSV with a header line.
+pub fn acpi_manifest_csv(rows: &[[String; 7]]) -> String {
+    let mut out = ACPI_MANIFEST_COLUMNS.join(",");
+    out.push('\n');
+    for row in rows {
+        let fields: Vec<String> = row.iter().map(|f| csv_field(f)).collect();
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Formats [`acpi_manifest_rows`] as aligned text columns with a header line.
+pub fn acpi_manifest_text(rows: &[[String; 7]]) -> String {
+    let mut widths = ACPI_MANIFEST_COLUMNS.map(str::len);
+    for row in rows {
+        for (width, field) in widths.iter_mut().zip(row) {
+            *width = (*width).max(field.len());
+        }
+    }
+    let line = |fields: [&str; 7]| {
+        let cells: Vec<String> = fields
+            .iter()
+            .zip(widths)
+            .map(|(f, w)| format!("{:<w$}", f, w = w))
+            .collect();
+        format!("{}\n", cells.join("  ").trim_end())
+    };
+    let mut out = line(ACPI_MANIFEST_COLUMNS);
+    for row in rows {
+        out.push_str(&line(row.each_ref().map(String::as_str)));
+    }
+    out
+}
+
+/// Builds a single self-contained HTML report: the system summary, a collapsible table of
+/// contents, one section per ACPI table, and one section per SMBIOS structure.
+///
+/// # Arguments
+/// * `tables` - All discovered ACPI tables.
+/// * `smbios` - The raw SMBIOS blob (may be empty).
+/// * `encoding` - How SMBIOS strings are decoded.
+/// * `redact` - Replace serial numbers, UUIDs, asset tags, and the product key with
+///   [`REDACTED`].
+/// * `notes` - Free-text notes, shown above the fields of the table or structure they
+///   annotate.
+/// * `read` - Reads the raw bytes of a table.
+pub fn report_html(
+    tables: &[api::AcpiTableInfo],
+    smbios: &[u8],
+    encoding: parsers::StringEncoding,
+    redact: bool,
+    notes: &BTreeMap<String, String>,
+    read: impl Fn(&api::AcpiTableInfo) -> Result<Vec<u8>, DumperError>,
+) -> String {
+    let structures = parsers::walk_smbios_structures(smbios);
+
+    let mut out =
+        String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str("<title>ACPI/SMBIOS Report</title>\n");
+    out.push_str(&format!(
+        "<style>{}</style>\n</head>\n<body>\n",
+        HTML_REPORT_CSS
+    ));
+    out.push_str("<h1>ACPI/SMBIOS Report</h1>\n");
+    out.push_str(&format!(
+        "<pre>{}</pre>\n",
+        html_escape(&summary_text(tables, smbios, encoding, redact))
+    ));
+
+    out.push_str("<nav><details open><summary>Contents</summary>\n");
+    out.push_str("<details open><summary><a href=\"#acpi\">ACPI Tables</a></summary><ul>\n");
+    for (i, t) in tables.iter().enumerate() {
+        out.push_str(&format!(
+            "<li><a href=\"#acpi-{}\">{} {}</a></li>\n",
+            i,
+            html_escape(&t.signature),
+            html_escape(t.table_id.trim())
+        ));
+    }
+    out.push_str("</ul></details>\n");
+    out.push_str(
+        "<details open><summary><a href=\"#smbios\">SMBIOS Structures</a></summary><ul>\n",
+    );
+    for (i, (_, header, _)) in structures.iter().enumerate() {
+        out.push_str(&format!(
+            "<li><a href=\"#smbios-{}\">Type {} (Handle 0x{:04X}) {}</a></li>\n",
+            i,
+            header.type_id,
+            header.handle,
+            html_escape(parsers::smbios_type_name(header.type_id))
+        ));
+    }
+    out.push_str("</ul></details>\n</details></nav>\n");
+
+    out.push_str("<h2 id=\"acpi\">ACPI Tables</h2>\n");
+    for (i, t) in tables.iter().enumerate() {
+        out.push_str(&format!(
+            "<h3 id=\"acpi-{}\">{} {}</h3>\n",
+            i,
+            html_escape(&t.signature),
+            html_escape(t.table_id.trim())
+        ));
+        if let Some(note) = notes.get(&acpi_note_key(&t.signature)) {
+            out.push_str(&format!("<p class=\"note\">{}</p>\n", html_escape(note)));
+        }
+        let data = match read(t) {
+            Ok(data) => data,
+            Err(e) => {
+                out.push_str(&format!(
+                    "<p class=\"bad\">Read failed: {}</p>\n",
+                    html_escape(&e.to_string())
+                ));
+                continue;
+            }
+        };
+        // FACS and the RSDP carry no checksum in the standard header position
+        if let Some((reported, read)) = parsers::acpi_truncation(&data) {
+            out.push_str(&format!(
+                "<p class=\"bad\">{}; checksum not checked</p>\n",
+                acpi_truncation_str(reported, read)
+            ));
+        } else if !matches!(t.signature.as_str(), "FACS" | "RSDP") && data.len() >= 36 {
+            if parsers::acpi_checksum_sum(&data) == 0 {
+                out.push_str("<p class=\"ok\">Checksum OK</p>\n");
+            } else {
+                out.push_str("<p class=\"bad\">Checksum invalid</p>\n");
+            }
+        }
+        match acpi_table_fields(&data) {
+            Some(mut fields) => {
+                if redact {
+                    redact_acpi_fields(&t.signature, &mut fields);
+                }
+                html_fields_table(&mut out, &fields)
+            }
+            None => out.push_str("<p class=\"bad\">Error parsing ACPI Header</p>\n"),
+        }
+    }
+
+    out.push_str("<h2 id=\"smbios\">SMBIOS Structures</h2>\n");
+    for (i, (off, header, next_off)) in structures.iter().enumerate() {
+        let name = parsers::smbios_type_name(header.type_id);
+        out.push_str(&format!(
+            "<h3 id=\"smbios-{}\">Type {} (Handle 0x{:04X}) {}</h3>\n",
+            i,
+            header.type_id,
+            header.handle,
+            html_escape(name)
+        ));
+        if let Some(note) = notes.get(&smbios_note_key(header.type_id, header.handle)) {
+            out.push_str(&format!("<p class=\"note\">{}</p>\n", html_escape(note)));
+        }
+        let mut fields = export_smbios_fields(&smbios[*off..*next_off], encoding, redact);
+        resolve_smbios_handles(smbios, &mut fields, encoding);
+        html_fields_table(&mut out, &fields);
+    }
+
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+/// Writes a single report file, recording the result in `outcome`.
+fn write_report_file(dir: &Path, name: &str, bytes: &[u8], outcome: &mut ReportOutcome) {
+    match std::fs::write(dir.join(name), bytes) {
+        Ok(()) => outcome.written.push(name.to_string()),
+        Err(e) => outcome
+            .failed
+            .push(format!("{}: write failed: {}", name, e)),
+    }
+}
+
+/// Reads every ACPI table and the SMBIOS blob and writes a complete capture to `dir`.
+///
+/// The folder receives each table as `.aml`, the raw SMBIOS blob, `summary.txt`,
+/// `report.json`, `dmidecode.txt`, `acpi.txt`, `report.html`, and a `manifest.txt` listing
+/// what was written and what failed. ACPI tables are discovered through `options.source`
+/// and SMBIOS strings are decoded with `options.encoding`. Tables are read with
+/// [`read_tables_concurrently`]; every file is written afterwards from this thread.
+///
+/// With `options.strip_wrapper` the SMBIOS blob is written as `smbios_table.bin`, the bare
+/// structure table without the Windows `RawSMBIOSData` header, instead of `smbios_raw.bin`.
+///
+/// With `options.redact` the text, JSON, and HTML files have their identifying fields
+/// redacted. The SMBIOS blob and the MSDM table hold those values in raw form, so they are
+/// left out and listed in the manifest.
+///
+/// `cancel` is checked between tables and between files. Once it is set, the tables already
+/// read are still saved, the remaining steps are skipped, and the manifest records the
+/// cancellation. `on_progress` receives the number of steps done, the total, and the table
+/// or file just handled.
+pub fn write_full_report<P>(
+    dir: &Path,
+    options: ReportOptions,
+    cancel: &AtomicBool,
+    mut on_progress: P,
+) -> ReportOutcome
+where
+    P: FnMut(usize, usize, &str),
+{
+    let mut outcome = ReportOutcome {
+        written: Vec::new(),
+        failed: Vec::new(),
+        cancelled: false,
+    };
+
+    let encoding = options.encoding;
+    let redact = options.redact;
+    let mut omitted = Vec::new();
+    let tables = api::load_acpi_tables(options.source);
+    // One step per table, then the SMBIOS read and the five derived files
+    let total = tables.len() + 6;
+    let contents = read_tables_concurrently(&tables, api::get_acpi_table, cancel, |index, done| {
+        on_progress(done, total, &tables[index].signature)
+    });
+    // acpi.txt and report.html walk the same `tables` slice, so match by address
+    // instead of reading every table from firmware again.
+    let read_loaded =
+        |info: &api::AcpiTableInfo| match tables.iter().position(|t| std::ptr::eq(t, info)) {
+            Some(i) => contents[i].clone(),
+            None => api::get_acpi_table(info),
+        };
+    let mut used_names = HashSet::new();
+    for (info, content) in tables.iter().zip(&contents) {
+        match content {
+            Ok(_) if redact && info.signature == "MSDM" => omitted.push(format!(
+                "{} ({}): holds the product key",
+                info.signature,
+                info.table_id.trim()
+            )),
+            Ok(data) => {
+                let stem = format!(
+                    "{}_{}",
+                    clean_filename_fragment(&info.signature),
+                    clean_filename_fragment(info.table_id.trim())
+                );
+                // Several tables may share a signature and table ID (e.g. SSDTs)
+                let mut name = format!("{}.aml", stem);
+                let mut n = 1;
+                while !used_names.insert(name.clone()) {
+                    n += 1;
+                    name = format!("{}_{}.aml", stem, n);
+                }
+                write_report_file(dir, &name, data, &mut outcome);
+            }
+            // Skipped tables are summarized once in the manifest instead
+            Err(DumperError::Cancelled) => {}
+            Err(e) => outcome.failed.push(format!(
+                "{} ({}): read failed: {}",
+                info.signature,
+                info.table_id.trim(),
+                e
+            )),
+        }
+    }
+
+    let mut done = tables.len();
+    let mut step = |name: &str, outcome: &mut ReportOutcome| {
+        if cancel.load(Ordering::Relaxed) {
+            outcome.cancelled = true;
+            return false;
+        }
+        done += 1;
+        on_progress(done, total, name);
+        true
+    };
+
+    let mut smbios = Vec::new();
+    if step("SMBIOS", &mut outcome) {
+        match api::get_smbios_data() {
+            Ok(data) => smbios = data,
+            Err(e) => outcome.failed.push(format!("SMBIOS: read failed: {}", e)),
+        }
+    }
+    if !smbios.is_empty() && redact {
+        omitted.push("SMBIOS blob: holds the serial numbers and UUID".to_string());
+    } else if !smbios.is_empty() {
+        if options.strip_wrapper {
+            write_report_file(
+                dir,
+                "smbios_table.bin",
+                parsers::strip_raw_smbios_header(&smbios),
+                &mut outcome,
+            );
+        } else {
+            write_report_file(dir, "smbios_raw.bin", &smbios, &mut outcome);
+        }
+    }
+
+    if step("summary.txt", &mut outcome) {
+        write_report_file(
+            dir,
+            "summary.txt",
+            summary_text(&tables, &smbios, encoding, redact).as_bytes(),
+            &mut outcome,
+        );
+    }
+    if step("report.json", &mut outcome) {
+        let json = report_json(&tables, &smbios, encoding, redact, &options.notes);
+        match serde_json::to_string_pretty(&json) {
+            Ok(s) => write_report_file(dir, "report.json", s.as_bytes(), &mut outcome),
+            Err(e) => outcome
+                .failed
+                .push(format!("report.json: serialization failed: {}", e)),
+        }
+    }
+    if step("dmidecode.txt", &mut outcome) {
+        write_report_file(
+            dir,
+            "dmidecode.txt",
+            smbios_dump_text(&smbios, encoding, redact).as_bytes(),
+            &mut outcome,
+        );
+    }
+    if step("acpi.txt", &mut outcome) {
+        write_report_file(
+            dir,
+            "acpi.txt",
+            acpi_dump_text(&tables, redact, read_loaded).as_bytes(),
+            &mut outcome,
+        );
+    }
+    if step("report.html", &mut outcome) {
+        write_report_file(
+            dir,
+            "report.html",
+            report_html(
+                &tables,
+                &smbios,
+                encoding,
+                redact,
+                &options.notes,
+                read_loaded,
+            )
+            .as_bytes(),
+            &mut outcome,
+        );
+    }
+
+    let mut manifest = String::from("Written:\n");
+    for name in &outcome.written {
+        manifest.push_str(&format!("  {}\n", name));
+    }
+    manifest.push_str("  manifest.txt\n");
+    if !omitted.is_empty() {
+        manifest.push_str("\nOmitted (redacted report):\n");
+        for o in &omitted {
+            manifest.push_str(&format!("  {}\n", o));
+        }
+    }
+    if !outcome.failed.is_empty() {
+        manifest.push_str("\nFailed:\n");
+        for f in &outcome.failed {
+            manifest.push_str(&format!("  {}\n", f));
+        }
+    }
+    if outcome.cancelled {
+        let skipped = contents
+            .iter()
+            .filter(|c| matches!(c, Err(DumperError::Cancelled)))
+            .count();
+        manifest.push_str(&format!(
+            "\nCancelled: {} tables not read, later files skipped\n",
+            skipped
+        ));
+    }
+    write_report_file(dir, "manifest.txt", manifest.as_bytes(), &mut outcome);
+
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// SMBIOS table laid out like the one a QEMU q35 guest exposes, with a serial number and
+    /// two memory devices added.
+    #[rustfmt::skip]
+    const QEMU_Q35_SMBIOS: &[u8] = &[
+        // RawSMBIOSData: SMBIOS 3.0, table length 0x1BC
+        0x00, 0x03, 0x00, 0x00, 0xBC, 0x01, 0x00, 0x00,
+        // Type 0, BIOS: vendor, version, release date
+        0x00, 0x18, 0x00, 0x00, 0x01, 0x02, 0x00, 0xE8, 0x03, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1C, 0x00, 0x00, 0xFF, 0xFF,
+        // Strings: "SeaBIOS", "rel-1.16.3-0-ga6ed6b701f0a-prebuilt.qemu.org", "04/01/2014"
+        0x53, 0x65, 0x61, 0x42, 0x49, 0x4F, 0x53, 0x00, 0x72, 0x65, 0x6C, 0x2D,
+        0x31, 0x2E, 0x31, 0x36, 0x2E, 0x33, 0x2D, 0x30, 0x2D, 0x67, 0x61, 0x36,
+        0x65, 0x64, 0x36, 0x62, 0x37, 0x30, 0x31, 0x66, 0x30, 0x61, 0x2D, 0x70,
+        0x72, 0x65, 0x62, 0x75, 0x69, 0x6C, 0x74, 0x2E, 0x71, 0x65, 0x6D, 0x75,
+        0x2E, 0x6F, 0x72, 0x67, 0x00, 0x30, 0x34, 0x2F, 0x30, 0x31, 0x2F, 0x32,
+        0x30, 0x31, 0x34, 0x00, 0x00,
+        // Type 1, System: UUID 8B5E0A3C-1F2D-4E6A-9B7C-0D1E2F3A4B5C in 2.6+ wire order
+        0x01, 0x1B, 0x00, 0x01, 0x01, 0x02, 0x03, 0x04, 0x3C, 0x0A, 0x5E, 0x8B,
+        0x2D, 0x1F, 0x6A, 0x4E, 0x9B, 0x7C, 0x0D, 0x1E, 0x2F, 0x3A, 0x4B, 0x5C,
+        0x06, 0x00, 0x00,
+        // Strings: "QEMU", "Standard PC (Q35 + ICH9, 2009)", "pc-q35-8.2", "VM-0123456789"
+        0x51, 0x45, 0x4D, 0x55, 0x00, 0x53, 0x74, 0x61, 0x6E, 0x64, 0x61, 0x72,
+        0x64, 0x20, 0x50, 0x43, 0x20, 0x28, 0x51, 0x33, 0x35, 0x20, 0x2B, 0x20,
+        0x49, 0x43, 0x48, 0x39, 0x2C, 0x20, 0x32, 0x30, 0x30, 0x39, 0x29, 0x00,
+        0x70, 0x63, 0x2D, 0x71, 0x33, 0x35, 0x2D, 0x38, 0x2E, 0x32, 0x00, 0x56,
+        0x4D, 0x2D, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39,
+        0x00, 0x00,
+        // Type 2, Baseboard
+        0x02, 0x0F, 0x00, 0x02, 0x01, 0x02, 0x03, 0x04, 0x00, 0x01, 0x00, 0x00,
+        0x03, 0x0A, 0x00,
+        // Strings: "QEMU", "Q35 Board", "1.0", "BB-42"
+        0x51, 0x45, 0x4D, 0x55, 0x00, 0x51, 0x33, 0x35, 0x20, 0x42, 0x6F, 0x61,
+        0x72, 0x64, 0x00, 0x31, 0x2E, 0x30, 0x00, 0x42, 0x42, 0x2D, 0x34, 0x32,
+        0x00, 0x00,
+        // Type 3, Chassis: type Other
+        0x03, 0x15, 0x00, 0x03, 0x01, 0x01, 0x02, 0x00, 0x00, 0x03, 0x03, 0x03,
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        // Strings: "QEMU", "pc-q35-8.2"
+        0x51, 0x45, 0x4D, 0x55, 0x00, 0x70, 0x63, 0x2D, 0x71, 0x33, 0x35, 0x2D,
+        0x38, 0x2E, 0x32, 0x00, 0x00,
+        // Type 4, Processor: populated, 4 cores / 8 threads
+        0x04, 0x30, 0x00, 0x04, 0x01, 0x03, 0xFE, 0x02, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0xD0, 0x07, 0xD0, 0x07,
+        0x41, 0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x04,
+        0x04, 0x08, 0x02, 0x00, 0xFE, 0x00, 0x04, 0x00, 0x04, 0x00, 0x08, 0x00,
+        // Strings: "CPU 0", "QEMU", "pc-q35-8.2 CPU"
+        0x43, 0x50, 0x55, 0x20, 0x30, 0x00, 0x51, 0x45, 0x4D, 0x55, 0x00, 0x70,
+        0x63, 0x2D, 0x71, 0x33, 0x35, 0x2D, 0x38, 0x2E, 0x32, 0x20, 0x43, 0x50,
+        0x55, 0x00, 0x00,
+        // Type 17, Memory Device: 16384 MB
+        0x11, 0x28, 0x00, 0x11, 0x00, 0x10, 0xFE, 0xFF, 0x40, 0x00, 0x40, 0x00,
+        0x00, 0x40, 0x09, 0x00, 0x01, 0x00, 0x1A, 0x02, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+        // Strings: "DIMM 0", "QEMU"
+        0x44, 0x49, 0x4D, 0x4D, 0x20, 0x30, 0x00, 0x51, 0x45, 0x4D, 0x55, 0x00,
+        0x00,
+        // Type 17, Memory Device: Extended Size 32768 MB
+        0x11, 0x28, 0x01, 0x11, 0x00, 0x10, 0xFE, 0xFF, 0x40, 0x00, 0x40, 0x00,
+        0xFF, 0x7F, 0x09, 0x00, 0x01, 0x00, 0x1A, 0x02, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+        // Strings: "DIMM 1", "QEMU"
+        0x44, 0x49, 0x4D, 0x4D, 0x20, 0x31, 0x00, 0x51, 0x45, 0x4D, 0x55, 0x00,
+        0x00,
+        // Type 127, End-of-Table
+        0x7F, 0x04, 0xFF, 0xFE,
+        // Strings: none
+        0x00, 0x00,
+    ];
+
+    #[test]
+    fn system_info_flattens_captured_blob() {
+        let tpm = api::AcpiTableInfo {
+            signature: "TPM2".to_string(),
+            signature_valid: true,
+            signature_bytes: *b"TPM2",
+            registry_sig: "TPM2".to_string(),
+            oem_id: "BOCHS".to_string(),
+            table_id: "BXPC".to_string(),
+            revision: 4,
+            registry_path: None,
+            physical_address: None,
+        };
+        let info = system_info(&[tpm], QEMU_Q35_SMBIOS, parsers::StringEncoding::default());
+
+        assert_eq!(info.bios_vendor.as_deref(), Some("SeaBIOS"));
+        assert_eq!(
+            info.bios_version.as_deref(),
+            Some("rel-1.16.3-0-ga6ed6b701f0a-prebuilt.qemu.org")
+        );
+        assert_eq!(info.bios_release_date.as_deref(), Some("04/01/2014"));
+        assert_eq!(info.system_manufacturer.as_deref(), Some("QEMU"));
+        assert_eq!(
+            info.system_product.as_deref(),
+            Some("Standard PC (Q35 + ICH9, 2009)")
+        );
+        assert_eq!(info.system_serial.as_deref(), Some("VM-0123456789"));
+        assert_eq!(
+            info.system_uuid.as_deref(),
+            Some("8B5E0A3C-1F2D-4E6A-9B7C-0D1E2F3A4B5C")
+        );
+        assert_eq!(info.baseboard_manufacturer.as_deref(), Some("QEMU"));
+        assert_eq!(info.baseboard_product.as_deref(), Some("Q35 Board"));
+        assert_eq!(info.baseboard_serial.as_deref(), Some("BB-42"));
+        assert_eq!(info.chassis_type.as_deref(), Some("Other"));
+        assert_eq!(info.processor.as_deref(), Some("pc-q35-8.2 CPU"));
+        assert_eq!((info.cores, info.threads), (4, 8));
+        assert_eq!(info.memory_bytes, 48 << 30);
+        // No Type 43, so the version comes from the TPM2 table
+        assert_eq!(info.tpm_version.as_deref(), Some("2.0"));
+    }
+
+    #[test]
+    fn system_info_redact_keeps_absent_fields_absent() {
+        let mut info = system_info(&[], QEMU_Q35_SMBIOS, parsers::StringEncoding::default());
+        info.baseboard_serial = None;
+        info.redact();
+        assert_eq!(info.system_serial.as_deref(), Some(REDACTED));
+        assert_eq!(info.system_uuid.as_deref(), Some(REDACTED));
+        assert_eq!(info.baseboard_serial, None);
+        assert_eq!(info.tpm_version, None);
+
+        let value = serde_json::to_value(&info).unwrap();
+        assert_eq!(value["system_serial"], REDACTED);
+        assert_eq!(value["baseboard_serial"], serde_json::Value::Null);
+        assert!(value["memory_bytes"].is_u64());
+    }
+}